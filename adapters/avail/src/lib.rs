@@ -1,6 +1,8 @@
 #[cfg(feature = "native")]
 mod avail;
 #[cfg(feature = "native")]
+pub mod node;
+#[cfg(feature = "native")]
 pub mod service;
 pub mod spec;
 pub mod verifier;
@@ -0,0 +1,167 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use async_trait::async_trait;
+use avail_subxt::api::runtime_types::sp_core::bounded::bounded_vec::BoundedVec;
+use avail_subxt::primitives::AvailExtrinsicParams;
+use avail_subxt::{api, AvailConfig};
+use futures::{Stream, StreamExt};
+use pin_project::pin_project;
+use primitive_types::H256;
+use sp_keyring::sr25519::sr25519::Pair;
+use subxt::tx::PairSigner;
+use subxt::OnlineClient;
+use tracing::info;
+
+use crate::spec::header::AvailHeader;
+
+/// The requests made by the [`DaProvider`](crate::service::DaProvider) to an Avail node.
+#[async_trait]
+pub trait AvailNode: Send + Sync {
+    /// Returns the hash of the block at `height`, if it was produced.
+    async fn block_hash(&self, height: u64) -> anyhow::Result<Option<H256>>;
+
+    /// Returns the header of the block with the given hash, if it exists.
+    async fn header(&self, hash: H256) -> anyhow::Result<Option<AvailHeader>>;
+
+    /// Returns every extrinsic of the block with the given hash, SCALE encoded, in block order.
+    async fn extrinsics(&self, hash: H256) -> anyhow::Result<Option<Vec<Vec<u8>>>>;
+
+    /// Returns the hash of the last block finalized by GRANDPA.
+    async fn finalized_head(&self) -> anyhow::Result<H256>;
+
+    /// Returns the header of the best block.
+    async fn best_header(&self) -> anyhow::Result<AvailHeader>;
+
+    /// Streams the headers of the blocks finalized by GRANDPA.
+    async fn subscribe_finalized(&self) -> anyhow::Result<AvailBlockHeaderStream>;
+
+    /// Streams the headers of the best blocks.
+    async fn subscribe_best(&self) -> anyhow::Result<AvailBlockHeaderStream>;
+
+    /// Submits `data` to the application `app_id`, without waiting for its inclusion.
+    async fn submit_data(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<()>;
+
+    /// Submits `data` to the application `app_id`, returning the hash of the block including
+    /// it once it is finalized.
+    async fn submit_data_finalized(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<H256>;
+}
+
+/// A stream of [`AvailHeader`]s, returned by the subscriptions of an [`AvailNode`].
+#[pin_project]
+pub struct AvailBlockHeaderStream {
+    #[pin]
+    inner: Pin<Box<dyn Stream<Item = anyhow::Result<AvailHeader>> + Send>>,
+}
+
+impl AvailBlockHeaderStream {
+    pub fn new(inner: impl Stream<Item = anyhow::Result<AvailHeader>> + Send + 'static) -> Self {
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl Stream for AvailBlockHeaderStream {
+    type Item = anyhow::Result<AvailHeader>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+}
+
+/// The [`AvailNode`] reached through the RPC of a node, signing the submissions with `signer`.
+pub struct SubxtNode {
+    client: OnlineClient<AvailConfig>,
+    signer: PairSigner<AvailConfig, Pair>,
+}
+
+impl SubxtNode {
+    pub fn new(client: OnlineClient<AvailConfig>, signer: PairSigner<AvailConfig, Pair>) -> Self {
+        Self { client, signer }
+    }
+}
+
+#[async_trait]
+impl AvailNode for SubxtNode {
+    async fn block_hash(&self, height: u64) -> anyhow::Result<Option<H256>> {
+        Ok(self.client.rpc().block_hash(Some(height.into())).await?)
+    }
+
+    async fn header(&self, hash: H256) -> anyhow::Result<Option<AvailHeader>> {
+        self.client
+            .rpc()
+            .header(Some(hash))
+            .await?
+            .map(|header| AvailHeader::new(header, hash))
+            .transpose()
+    }
+
+    async fn extrinsics(&self, hash: H256) -> anyhow::Result<Option<Vec<Vec<u8>>>> {
+        let block = self.client.rpc().block(Some(hash)).await?;
+        Ok(block.map(|block| {
+            block
+                .block
+                .extrinsics
+                .into_iter()
+                .map(|extrinsic| extrinsic.0)
+                .collect()
+        }))
+    }
+
+    async fn finalized_head(&self) -> anyhow::Result<H256> {
+        Ok(self.client.rpc().finalized_head().await?)
+    }
+
+    async fn best_header(&self) -> anyhow::Result<AvailHeader> {
+        self.client.blocks().at_latest().await?.try_into()
+    }
+
+    async fn subscribe_finalized(&self) -> anyhow::Result<AvailBlockHeaderStream> {
+        let blocks = self.client.blocks().subscribe_finalized().await?;
+        Ok(AvailBlockHeaderStream::new(
+            blocks.map(|block| AvailHeader::try_from(block?)),
+        ))
+    }
+
+    async fn subscribe_best(&self) -> anyhow::Result<AvailBlockHeaderStream> {
+        let blocks = self.client.blocks().subscribe_best().await?;
+        Ok(AvailBlockHeaderStream::new(
+            blocks.map(|block| AvailHeader::try_from(block?)),
+        ))
+    }
+
+    async fn submit_data(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<()> {
+        let data_transfer = api::tx().data_availability().submit_data(BoundedVec(data));
+        let extrinsic_params = AvailExtrinsicParams::new_with_app_id(app_id.into());
+
+        let h = self
+            .client
+            .tx()
+            .sign_and_submit_then_watch(&data_transfer, &self.signer, extrinsic_params)
+            .await?;
+
+        info!("Transaction submitted: {:#?}", h.extrinsic_hash());
+        Ok(())
+    }
+
+    async fn submit_data_finalized(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<H256> {
+        let data_transfer = api::tx().data_availability().submit_data(BoundedVec(data));
+        let extrinsic_params = AvailExtrinsicParams::new_with_app_id(app_id.into());
+
+        let events = self
+            .client
+            .tx()
+            .sign_and_submit_then_watch(&data_transfer, &self.signer, extrinsic_params)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+
+        info!(
+            "Transaction finalized: {:#?}, block: {:#?}",
+            events.extrinsic_hash(),
+            events.block_hash()
+        );
+        Ok(events.block_hash())
+    }
+}
@@ -1,25 +1,23 @@
 use core::time::Duration;
-use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::Arc;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
-use avail_subxt::api::runtime_types::sp_core::bounded::bounded_vec::BoundedVec;
-use avail_subxt::primitives::AvailExtrinsicParams;
-use avail_subxt::{api, AvailConfig};
-use pin_project::pin_project;
+use avail_subxt::AvailConfig;
+use primitive_types::H256;
 use reqwest::StatusCode;
+use sov_rollup_interface::da::chunks::{new_sequence_id, split_blob};
 use sov_rollup_interface::da::DaSpec;
-use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService};
 use sp_core::crypto::Pair as PairTrait;
 use sp_keyring::sr25519::sr25519::Pair;
 use subxt::tx::PairSigner;
-use subxt::OnlineClient;
 use tracing::info;
 
 use crate::avail::{Confidence, ExtrinsicsData};
+use crate::node::{AvailBlockHeaderStream, AvailNode, SubxtNode};
 use crate::spec::block::AvailBlock;
-use crate::spec::header::AvailHeader;
+use crate::spec::extrinsic::decode_submission;
 use crate::spec::transaction::AvailBlobTransaction;
 use crate::spec::DaLayerSpec;
 use crate::verifier::Verifier;
@@ -33,20 +31,32 @@ pub struct DaServiceConfig {
     pub seed: String,
     pub polling_timeout: Option<u64>,
     pub polling_interval: Option<u64>,
+    /// How many blocks below the head can still be reverted before GRANDPA finalizes them.
+    #[serde(default)]
+    pub max_reorg_depth: Option<u64>,
     pub app_id: u32,
+    /// The application the aggregated proofs are submitted to. Aggregated proofs can't be sent
+    /// or read without it.
+    #[serde(default)]
+    pub proof_app_id: Option<u32>,
 }
 
+/// The largest data accepted by `submit_data`, larger blobs are split into chunks.
+const MAX_BLOB_SIZE: usize = 512 * 1024;
 const DEFAULT_POLLING_TIMEOUT: Duration = Duration::from_secs(60);
 const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(1);
+/// GRANDPA usually finalizes a block within a couple of blocks after BABE produced it.
+const DEFAULT_MAX_REORG_DEPTH: u64 = 5;
 
 #[derive(Clone)]
 pub struct DaProvider {
-    pub node_client: OnlineClient<AvailConfig>,
+    node: Arc<dyn AvailNode>,
     pub light_client_url: String,
-    signer: PairSigner<AvailConfig, Pair>,
     polling_timeout: Duration,
     polling_interval: Duration,
+    max_reorg_depth: u64,
     app_id: u32,
+    proof_app_id: Option<u32>,
 }
 
 impl DaProvider {
@@ -67,12 +77,16 @@ impl DaProvider {
         let node_client = avail_subxt::build_client(config.node_client_url.to_string(), false)
             .await
             .unwrap();
-        let light_client_url = config.light_client_url;
 
+        Self::with_node(config, SubxtNode::new(node_client, signer))
+    }
+
+    /// Creates a DA service reading the blocks from `node` rather than from the node of the
+    /// `config`.
+    pub fn with_node(config: DaServiceConfig, node: impl AvailNode + 'static) -> Self {
         DaProvider {
-            node_client,
-            light_client_url,
-            signer,
+            node: Arc::new(node),
+            light_client_url: config.light_client_url,
             polling_timeout: match config.polling_timeout {
                 Some(i) => Duration::from_secs(i),
                 None => DEFAULT_POLLING_TIMEOUT,
@@ -81,9 +95,19 @@ impl DaProvider {
                 Some(i) => Duration::from_secs(i),
                 None => DEFAULT_POLLING_INTERVAL,
             },
+            max_reorg_depth: config.max_reorg_depth.unwrap_or(DEFAULT_MAX_REORG_DEPTH),
             app_id: config.app_id,
+            proof_app_id: config.proof_app_id,
         }
     }
+
+    /// Returns every extrinsic of the block with the given hash, SCALE encoded, in block order.
+    async fn get_extrinsics(&self, hash: H256) -> anyhow::Result<Vec<Vec<u8>>> {
+        self.node
+            .extrinsics(hash)
+            .await?
+            .ok_or_else(|| anyhow!("Block for hash: {} not found.", hash))
+    }
 }
 
 // TODO: Is there a way to avoid coupling to tokio?
@@ -156,31 +180,6 @@ async fn wait_for_appdata(
     }
 }
 
-/// Convincice copy from [`subxt::blocks::BlocksClient`]
-type BlockStream<T> = Pin<Box<dyn futures::Stream<Item = Result<T, subxt::Error>> + Send>>;
-/// Wrapper around return of [`subxt::blocks::BlocksClient::subscribe_finalized`]
-/// that maps `Ok` variant to [`AvailHeader`] and `Error` variant to [`anyhow::Error`]
-#[pin_project]
-pub struct AvailBlockHeaderStream {
-    #[pin]
-    inner: BlockStream<subxt::blocks::Block<AvailConfig, OnlineClient<AvailConfig>>>,
-}
-
-impl futures::Stream for AvailBlockHeaderStream {
-    type Item = anyhow::Result<AvailHeader>;
-
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
-        let poll_result = this.inner.poll_next(cx);
-        Poll::Ready(match poll_result {
-            Poll::Ready(Some(Ok(block))) => Some(Ok(AvailHeader::from(block))),
-            Poll::Ready(Some(Err(e))) => Some(Err(e.into())),
-            Poll::Ready(None) => None,
-            Poll::Pending => return Poll::Pending,
-        })
-    }
-}
-
 #[async_trait]
 impl DaService for DaProvider {
     type Spec = DaLayerSpec;
@@ -195,7 +194,6 @@ impl DaService for DaProvider {
     // Make an RPC call to the node to get the block at the given height, if one exists.
     // If no such block exists, block until one does.
     async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
-        let node_client = self.node_client.clone();
         let confidence_url = self.confidence_url(height);
         let appdata_url = self.appdata_url(height);
 
@@ -209,17 +207,17 @@ impl DaService for DaProvider {
         .await?;
         info!("Appdata: {:?}", appdata);
 
-        let hash = match { node_client.rpc().block_hash(Some(height.into())).await? } {
+        let hash = match { self.node.block_hash(height).await? } {
             Some(i) => i,
             None => return Err(anyhow!("Hash for height: {} not found.", height)),
         };
 
-        let header = match { node_client.rpc().header(Some(hash)).await? } {
+        let header = match { self.node.header(hash).await? } {
             Some(i) => i,
             None => return Err(anyhow!("Header for hash: {} not found.", hash)),
         };
 
-        let header = AvailHeader::new(header, hash);
+        let extrinsics = self.get_extrinsics(hash).await?;
         let transactions: Result<Vec<AvailBlobTransaction>, anyhow::Error> = appdata
             .extrinsics
             .iter()
@@ -230,38 +228,37 @@ impl DaService for DaProvider {
         Ok(AvailBlock {
             header,
             transactions,
+            extrinsics,
         })
     }
 
     async fn get_last_finalized_block_header(
         &self,
     ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
-        let node_client = self.node_client.clone();
-        let finalized_header_hash = node_client.rpc().finalized_head().await?;
+        let finalized_header_hash = self.node.finalized_head().await?;
 
-        let header = node_client
-            .rpc()
-            .header(Some(finalized_header_hash))
+        self.node
+            .header(finalized_header_hash)
             .await?
-            .ok_or(anyhow::anyhow!("No finalized head found"))?;
-        let header = AvailHeader::new(header, finalized_header_hash);
-        Ok(header)
+            .ok_or(anyhow::anyhow!("No finalized head found"))
     }
 
     async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
-        let block_stream = self.node_client.blocks().subscribe_finalized().await?;
-        Ok(AvailBlockHeaderStream {
-            inner: block_stream,
-        })
+        self.node.subscribe_finalized().await
     }
 
     async fn get_head_block_header(
         &self,
     ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
-        let node_client = self.node_client.clone();
-        let latest_block = node_client.blocks().at_latest().await?;
+        self.node.best_header().await
+    }
 
-        Ok(latest_block.into())
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        self.node.subscribe_best().await
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        self.max_reorg_depth
     }
 
     // Extract the blob transactions relevant to a particular rollup from a block.
@@ -276,41 +273,358 @@ impl DaService for DaProvider {
 
     // Extract the inclusion and completeness proof for filtered block provided.
     // The output of this method will be passed to the verifier.
-    // NOTE: The light client here has already completed DA sampling and verification of inclusion and soundness.
+    // NOTE: The completeness proof holds the extrinsics of the full block, which are fetched from
+    // the node with the block, since the light client only serves the data of the rollup
+    // application.
     async fn get_extraction_proof(
         &self,
-        _block: &Self::FilteredBlock,
+        block: &Self::FilteredBlock,
         _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
     ) -> (
         <Self::Spec as DaSpec>::InclusionMultiProof,
         <Self::Spec as DaSpec>::CompletenessProof,
     ) {
-        ((), ())
+        ((), block.extrinsics.clone())
     }
 
     async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
-        let data_transfer = api::tx()
-            .data_availability()
-            .submit_data(BoundedVec(blob.to_vec()));
+        for chunk in split_blob(blob, MAX_BLOB_SIZE, new_sequence_id())? {
+            self.node.submit_data(self.app_id, chunk).await?;
+        }
 
-        let extrinsic_params = AvailExtrinsicParams::new_with_app_id(self.app_id.into());
+        Ok(())
+    }
 
-        let h = self
-            .node_client
-            .tx()
-            .sign_and_submit_then_watch(&data_transfer, &self.signer, extrinsic_params)
+    async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
+        let envelope = AggregatedProofEnvelope::new(proof.to_vec());
+        let proof_app_id = self
+            .proof_app_id
+            .ok_or_else(|| anyhow!("No proof_app_id configured to submit aggregated proofs to"))?;
+
+        let block_hash = self
+            .node
+            .submit_data_finalized(proof_app_id, envelope.encode())
             .await?;
 
-        info!("Transaction submitted: {:#?}", h.extrinsic_hash());
+        let header = self
+            .node
+            .header(block_hash)
+            .await?
+            .ok_or_else(|| anyhow!("Header for hash: {} not found.", block_hash))?;
 
-        Ok(())
+        info!(
+            "Aggregated proof submitted, height: {}",
+            header.header.number
+        );
+
+        Ok(header.header.number as u64)
+    }
+
+    // The light client only serves the data of the rollup application, so the proofs are read
+    // from the full block.
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let Some(proof_app_id) = self.proof_app_id else {
+            return Ok(vec![]);
+        };
+
+        let hash = self
+            .node
+            .block_hash(height)
+            .await?
+            .ok_or_else(|| anyhow!("Hash for height: {} not found.", height))?;
+
+        let mut proof_blobs = Vec::new();
+        for extrinsic in self.get_extrinsics(hash).await? {
+            match decode_submission(&extrinsic) {
+                Ok(Some(submission)) if submission.app_id == proof_app_id => {
+                    proof_blobs.push(submission.data)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(AggregatedProofEnvelope::decode_proofs(proof_blobs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+    use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaVerifier};
+    use sov_rollup_interface::test_utils::{
+        test_aggregated_proof_round_trip, test_extraction_round_trip,
+        test_extraction_tamper_rejection, test_partial_reads,
+    };
+    use wiremock::matchers::{method, path_regex};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::spec::address::AvailAddress;
+    use crate::spec::header::{AvailHeader, DataLookup, Header, HeaderExtension, KateCommitment};
+    use crate::spec::proof::{data_root, extrinsics_root};
+    use crate::spec::AvailChainParams;
+
+    /// Synthetic blocks, written by hand with the extrinsic encoding of the node rather than
+    /// recorded from an Avail network:
+    /// - block 1 holds two rollup blobs and a submission to another application,
+    /// - block 2 only holds the timestamp inherent,
+    /// - block 3 holds an aggregated proof and a rollup blob.
+    ///
+    /// The roots and hashes of the headers are computed from the extrinsics when loading them.
+    const NODE_JSON: &str = include_str!("../test_data/synthetic_node.json");
+
+    /// An [`AvailNode`] serving the blocks of the fixture. Submitting data succeeds if a block of
+    /// the fixture includes it, as if that block had included the submission.
+    struct FixtureNode {
+        blocks: Vec<(AvailHeader, Vec<Vec<u8>>)>,
+    }
+
+    impl FixtureNode {
+        fn load(fixture: &Value) -> Self {
+            let mut parent_hash: H256 =
+                serde_json::from_value(fixture["genesis_hash"].clone()).unwrap();
+            let mut blocks = Vec::new();
+            for block in fixture["blocks"].as_array().unwrap() {
+                let extrinsics: Vec<Vec<u8>> = block["extrinsics"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|extrinsic| hex::decode(&extrinsic.as_str().unwrap()[2..]).unwrap())
+                    .collect();
+                let data_hashes = extrinsics
+                    .iter()
+                    .filter_map(|extrinsic| decode_submission(extrinsic).unwrap())
+                    .map(|submission| sp_core_hashing::keccak_256(&submission.data));
+
+                let header = Header {
+                    parent_hash,
+                    number: block["number"].as_u64().unwrap() as u32,
+                    state_root: H256::zero(),
+                    extrinsics_root: H256(extrinsics_root(&extrinsics)),
+                    digest: vec![0],
+                    extension: HeaderExtension {
+                        commitment: KateCommitment {
+                            rows: 1,
+                            cols: 4,
+                            data_root: H256(data_root(data_hashes)),
+                            commitment: vec![],
+                        },
+                        app_lookup: DataLookup {
+                            size: 4,
+                            index: serde_json::from_value(block["app_lookup"].clone()).unwrap(),
+                        },
+                    },
+                };
+                parent_hash = H256(header.hash());
+                blocks.push((AvailHeader::from_header(header, parent_hash), extrinsics));
+            }
+
+            Self { blocks }
+        }
+
+        fn block(&self, hash: H256) -> Option<&(AvailHeader, Vec<Vec<u8>>)> {
+            self.blocks
+                .iter()
+                .find(|(header, _)| header.hash().inner() == hash.as_fixed_bytes())
+        }
+
+        fn block_including(&self, app_id: u32, data: &[u8]) -> anyhow::Result<H256> {
+            self.blocks
+                .iter()
+                .find(|(_, extrinsics)| {
+                    extrinsics
+                        .iter()
+                        .any(|extrinsic| submits(extrinsic, app_id, |submitted| submitted == data))
+                })
+                .map(|(header, _)| H256(*header.hash().inner()))
+                .ok_or_else(|| anyhow!("No block of the fixture includes the submitted data"))
+        }
     }
 
-    async fn send_aggregated_zk_proof(&self, _proof: &[u8]) -> Result<u64, Self::Error> {
-        unimplemented!()
+    /// Returns whether `extrinsic` submits data matching `data` to `app_id`.
+    fn submits(extrinsic: &[u8], app_id: u32, data: impl FnOnce(&[u8]) -> bool) -> bool {
+        matches!(
+            decode_submission(extrinsic),
+            Ok(Some(submission)) if submission.app_id == app_id && data(&submission.data)
+        )
     }
 
-    async fn get_aggregated_proofs_at(&self, _height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
-        unimplemented!()
+    #[async_trait]
+    impl AvailNode for FixtureNode {
+        async fn block_hash(&self, height: u64) -> anyhow::Result<Option<H256>> {
+            Ok(self
+                .blocks
+                .iter()
+                .find(|(header, _)| header.height() == height)
+                .map(|(header, _)| H256(*header.hash().inner())))
+        }
+
+        async fn header(&self, hash: H256) -> anyhow::Result<Option<AvailHeader>> {
+            Ok(self.block(hash).map(|(header, _)| header.clone()))
+        }
+
+        async fn extrinsics(&self, hash: H256) -> anyhow::Result<Option<Vec<Vec<u8>>>> {
+            Ok(self.block(hash).map(|(_, extrinsics)| extrinsics.clone()))
+        }
+
+        async fn finalized_head(&self) -> anyhow::Result<H256> {
+            Ok(H256(*self.best_header().await?.hash().inner()))
+        }
+
+        async fn best_header(&self) -> anyhow::Result<AvailHeader> {
+            Ok(self.blocks.last().unwrap().0.clone())
+        }
+
+        async fn subscribe_finalized(&self) -> anyhow::Result<AvailBlockHeaderStream> {
+            let headers: Vec<_> = self
+                .blocks
+                .iter()
+                .map(|(header, _)| Ok(header.clone()))
+                .collect();
+            Ok(AvailBlockHeaderStream::new(futures::stream::iter(headers)))
+        }
+
+        async fn subscribe_best(&self) -> anyhow::Result<AvailBlockHeaderStream> {
+            self.subscribe_finalized().await
+        }
+
+        async fn submit_data(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<()> {
+            self.block_including(app_id, &data).map(|_| ())
+        }
+
+        async fn submit_data_finalized(&self, app_id: u32, data: Vec<u8>) -> anyhow::Result<H256> {
+            self.block_including(app_id, &data)
+        }
+    }
+
+    /// Serves the blocks of the fixture, with a light client serving the confidence of every
+    /// block and the rollup extrinsics of the blocks with rollup data.
+    async fn setup_service(fixture: &Value, proof_app_id: Option<u32>) -> (MockServer, DaProvider) {
+        let node = FixtureNode::load(fixture);
+        let app_id = fixture["app_id"].as_u64().unwrap() as u32;
+
+        let appdata: HashMap<u64, Vec<String>> = node
+            .blocks
+            .iter()
+            .map(|(header, extrinsics)| {
+                let rollup_extrinsics = extrinsics
+                    .iter()
+                    .filter(|extrinsic| submits(extrinsic, app_id, |_| true))
+                    .map(|extrinsic| format!("0x{}", hex::encode(extrinsic)))
+                    .collect();
+                (header.height(), rollup_extrinsics)
+            })
+            .collect();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("^/v1/confidence/[0-9]+$"))
+            .respond_with(|req: &Request| {
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "block": requested_block(req),
+                    "confidence": 99.9,
+                    "serialised_confidence": null,
+                }))
+            })
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex("^/v1/appdata/[0-9]+$"))
+            .respond_with(move |req: &Request| {
+                let block = requested_block(req);
+                match appdata.get(&block) {
+                    Some(extrinsics) if !extrinsics.is_empty() => ResponseTemplate::new(200)
+                        .set_body_json(json!({
+                            "block": block,
+                            "extrinsics": extrinsics,
+                        })),
+                    _ => ResponseTemplate::new(404),
+                }
+            })
+            .mount(&mock_server)
+            .await;
+
+        let config = DaServiceConfig {
+            light_client_url: mock_server.uri(),
+            node_client_url: String::new(),
+            seed: String::new(),
+            polling_timeout: Some(1),
+            polling_interval: Some(0),
+            max_reorg_depth: None,
+            app_id,
+            proof_app_id,
+        };
+
+        (mock_server, DaProvider::with_node(config, node))
+    }
+
+    fn requested_block(req: &Request) -> u64 {
+        req.url.path().rsplit('/').next().unwrap().parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_the_rollup_blobs() {
+        let fixture: Value = serde_json::from_str(NODE_JSON).unwrap();
+        let (_server, da_service) = setup_service(&fixture, None).await;
+        let sequencer: AvailAddress = fixture["sequencer"].as_str().unwrap().parse().unwrap();
+
+        let block = da_service.get_block_at(1).await.unwrap();
+        let mut blobs = da_service.extract_relevant_blobs(&block);
+        assert_eq!(2, blobs.len());
+        assert_eq!(b"first batch", blobs[0].full_data());
+        assert_eq!(b"second batch", blobs[1].full_data());
+        assert!(blobs.iter().all(|blob| blob.sender() == sequencer));
+
+        let block = da_service.get_block_at(2).await.unwrap();
+        assert!(da_service.extract_relevant_blobs(&block).is_empty());
+    }
+
+    #[tokio::test]
+    async fn da_service_conformance() {
+        let fixture: Value = serde_json::from_str(NODE_JSON).unwrap();
+        let proof_app_id = fixture["proof_app_id"].as_u64().unwrap() as u32;
+        let (_server, da_service) = setup_service(&fixture, Some(proof_app_id)).await;
+        let verifier = Verifier::new(AvailChainParams {
+            app_id: fixture["app_id"].as_u64().unwrap() as u32,
+        });
+
+        for height in 1..=3 {
+            let block = da_service.get_block_at(height).await.unwrap();
+            test_extraction_round_trip(&da_service, &verifier, &block).await;
+        }
+
+        let block = da_service.get_block_at(1).await.unwrap();
+        test_extraction_tamper_rejection(&da_service, &verifier, &block).await;
+        test_partial_reads(
+            da_service.extract_relevant_blobs(&block).remove(0),
+            b"first batch",
+        );
+
+        test_aggregated_proof_round_trip(&da_service, b"aggregated proof").await;
+    }
+
+    #[tokio::test]
+    async fn aggregated_proofs_need_a_proof_app_id() {
+        let fixture: Value = serde_json::from_str(NODE_JSON).unwrap();
+        let (_server, da_service) = setup_service(&fixture, None).await;
+        assert!(da_service
+            .send_aggregated_zk_proof(b"aggregated proof")
+            .await
+            .is_err());
+        assert!(da_service
+            .get_aggregated_proofs_at(3)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // The data of other applications isn't read as aggregated proofs.
+        let (_server, da_service) = setup_service(&fixture, Some(1)).await;
+        assert!(da_service
+            .get_aggregated_proofs_at(1)
+            .await
+            .unwrap()
+            .is_empty());
     }
 }
@@ -10,6 +10,8 @@ use crate::verifier::ChainValidityCondition;
 pub struct AvailBlock {
     pub header: AvailHeader,
     pub transactions: Vec<AvailBlobTransaction>,
+    /// Every extrinsic of the block, SCALE encoded, from which the rollup blobs are proven.
+    pub extrinsics: Vec<Vec<u8>>,
 }
 
 impl SlotData for AvailBlock {
@@ -0,0 +1,176 @@
+//! Decoding of the data submissions from the SCALE encoded extrinsics of a block, without the
+//! runtime types of the node, so that the verifier can read them.
+
+use codec::{Compact, Decode, Input};
+
+/// The index of the `DataAvailability` pallet in the Avail runtime.
+pub const DATA_AVAILABILITY_PALLET_INDEX: u8 = 29;
+/// The index of the `submit_data` call in the `DataAvailability` pallet.
+pub const SUBMIT_DATA_CALL_INDEX: u8 = 1;
+
+/// The version of the extrinsic format, and the bit flagging signed extrinsics.
+const EXTRINSIC_FORMAT_VERSION: u8 = 4;
+const SIGNED_EXTRINSIC_FLAG: u8 = 0b1000_0000;
+
+/// A `submit_data` extrinsic of a block.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DataSubmission {
+    /// The application the data was submitted to.
+    pub app_id: u32,
+    /// The account which signed the extrinsic, if it is identified by its account id.
+    pub sender: Option<[u8; 32]>,
+    pub data: Vec<u8>,
+    /// The hash of the extrinsic.
+    pub hash: [u8; 32],
+}
+
+/// Decodes `extrinsic`, as encoded in the block and in the leaves of the extrinsics root, and
+/// returns its data submission if it is a signed `submit_data` extrinsic.
+pub fn decode_submission(extrinsic: &[u8]) -> Result<Option<DataSubmission>, codec::Error> {
+    let input = &mut &extrinsic[..];
+    let Compact(len) = Compact::<u32>::decode(input)?;
+    if len as usize != input.len() {
+        return Err("Invalid extrinsic length".into());
+    }
+
+    let version = input.read_byte()?;
+    if version & !SIGNED_EXTRINSIC_FLAG != EXTRINSIC_FORMAT_VERSION {
+        return Err("Invalid extrinsic version".into());
+    }
+    if version & SIGNED_EXTRINSIC_FLAG == 0 {
+        // Data can only be submitted by signed extrinsics.
+        return Ok(None);
+    }
+
+    let sender = decode_address(input)?;
+    skip_signature(input)?;
+    let app_id = decode_app_id(input)?;
+
+    let call = <[u8; 2]>::decode(input)?;
+    if call != [DATA_AVAILABILITY_PALLET_INDEX, SUBMIT_DATA_CALL_INDEX] {
+        return Ok(None);
+    }
+    let data = Vec::<u8>::decode(input)?;
+
+    Ok(Some(DataSubmission {
+        app_id,
+        sender,
+        data,
+        hash: sp_core_hashing::blake2_256(extrinsic),
+    }))
+}
+
+/// Decodes a `MultiAddress<AccountId32, u32>`, returning the account id it holds, if any.
+fn decode_address(input: &mut &[u8]) -> Result<Option<[u8; 32]>, codec::Error> {
+    Ok(match input.read_byte()? {
+        0 => Some(<[u8; 32]>::decode(input)?),
+        1 => {
+            Compact::<u32>::skip(input)?;
+            None
+        }
+        2 => {
+            Vec::<u8>::skip(input)?;
+            None
+        }
+        3 => {
+            <[u8; 32]>::skip(input)?;
+            None
+        }
+        4 => {
+            <[u8; 20]>::skip(input)?;
+            None
+        }
+        _ => return Err("Invalid address variant".into()),
+    })
+}
+
+/// Skips a `MultiSignature`, which is checked by the DA layer rather than by the rollup.
+fn skip_signature(input: &mut &[u8]) -> Result<(), codec::Error> {
+    match input.read_byte()? {
+        0 | 1 => <[u8; 64]>::skip(input),
+        2 => <[u8; 65]>::skip(input),
+        _ => Err("Invalid signature variant".into()),
+    }
+}
+
+/// Decodes the signed extensions of an extrinsic, returning the application it is submitted
+/// to. They are encoded as the era, the compact nonce, the compact tip and the compact app id.
+fn decode_app_id(input: &mut &[u8]) -> Result<u32, codec::Error> {
+    // Immortal eras are encoded in a single zero byte, mortal ones in two bytes.
+    if input.read_byte()? != 0 {
+        input.read_byte()?;
+    }
+    Compact::<u32>::skip(input)?;
+    Compact::<u128>::skip(input)?;
+    let Compact(app_id) = Compact::<u32>::decode(input)?;
+
+    Ok(app_id)
+}
+
+/// Encodes a `submit_data` extrinsic with an immortal era, signed by `sender` with an empty
+/// signature, as the node would.
+#[cfg(test)]
+pub(crate) fn encode_submission(app_id: u32, sender: [u8; 32], data: &[u8]) -> Vec<u8> {
+    use codec::Encode;
+
+    let mut extrinsic = vec![EXTRINSIC_FORMAT_VERSION | SIGNED_EXTRINSIC_FLAG, 0];
+    extrinsic.extend_from_slice(&sender);
+    extrinsic.push(1);
+    extrinsic.extend_from_slice(&[0; 64]);
+    extrinsic.push(0);
+    Compact(0u32).encode_to(&mut extrinsic);
+    Compact(0u128).encode_to(&mut extrinsic);
+    Compact(app_id).encode_to(&mut extrinsic);
+    extrinsic.extend_from_slice(&[DATA_AVAILABILITY_PALLET_INDEX, SUBMIT_DATA_CALL_INDEX]);
+    data.encode_to(&mut extrinsic);
+
+    extrinsic.encode()
+}
+
+#[cfg(test)]
+mod tests {
+    use codec::Encode;
+
+    use super::*;
+
+    #[test]
+    fn decodes_submissions() {
+        let extrinsic = encode_submission(7, [1; 32], b"rollup data");
+        assert_eq!(
+            Some(DataSubmission {
+                app_id: 7,
+                sender: Some([1; 32]),
+                data: b"rollup data".to_vec(),
+                hash: sp_core_hashing::blake2_256(&extrinsic),
+            }),
+            decode_submission(&extrinsic).unwrap()
+        );
+    }
+
+    #[test]
+    fn skips_other_extrinsics() {
+        // An unsigned `timestamp.set` inherent.
+        let inherent = [vec![EXTRINSIC_FORMAT_VERSION, 3, 0], Compact(1u64).encode()]
+            .concat()
+            .encode();
+        assert_eq!(None, decode_submission(&inherent).unwrap());
+
+        // A signed call to another pallet.
+        let mut extrinsic =
+            Vec::<u8>::decode(&mut &encode_submission(7, [1; 32], b"")[..]).unwrap();
+        let call_index = extrinsic.len() - 3;
+        extrinsic[call_index] = 6;
+        assert_eq!(None, decode_submission(&extrinsic.encode()).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_extrinsics() {
+        let extrinsic = encode_submission(7, [1; 32], b"rollup data");
+        assert!(decode_submission(&extrinsic[..extrinsic.len() - 1]).is_err());
+        assert!(decode_submission(&[extrinsic.clone(), vec![0]].concat()).is_err());
+
+        let mut extrinsic = Vec::<u8>::decode(&mut &extrinsic[..]).unwrap();
+        extrinsic[0] = 3 | SIGNED_EXTRINSIC_FLAG;
+        assert!(decode_submission(&extrinsic.encode()).is_err());
+    }
+}
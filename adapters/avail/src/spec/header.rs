@@ -1,5 +1,8 @@
 #[cfg(feature = "native")]
+use anyhow::anyhow;
+#[cfg(feature = "native")]
 use avail_subxt::primitives::Header as SubxtHeader;
+use codec::{Compact, Decode, Encode};
 use primitive_types::H256;
 use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::BlockHeaderTrait;
@@ -14,7 +17,79 @@ pub struct Header {
     pub number: u32,
     pub state_root: H256,
     pub extrinsics_root: H256,
+    /// The SCALE encoded digest of the header, which the rollup doesn't read.
+    pub digest: Vec<u8>,
+    pub extension: HeaderExtension,
+}
+
+impl Header {
+    /// The keccak Merkle root of the data submissions of the block, see
+    /// [`data_root`](super::proof::data_root).
+    pub fn data_root(&self) -> H256 {
+        self.extension.commitment.data_root
+    }
+
+    /// Encodes the header as the node does to compute its hash.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        self.parent_hash.encode_to(&mut encoded);
+        Compact(self.number).encode_to(&mut encoded);
+        self.state_root.encode_to(&mut encoded);
+        self.extrinsics_root.encode_to(&mut encoded);
+        encoded.extend_from_slice(&self.digest);
+        // The extension is the first variant of the versioned header extension.
+        0u8.encode_to(&mut encoded);
+        self.extension.encode_to(&mut encoded);
+        encoded
+    }
+
+    /// The blake2 hash of the encoded header, which identifies its block.
+    pub fn hash(&self) -> [u8; 32] {
+        sp_core_hashing::blake2_256(&self.encode())
+    }
+}
+
+/// The version 1 extension of the header, which commits to the data of the block.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Encode, Decode)]
+pub struct HeaderExtension {
+    pub commitment: KateCommitment,
+    pub app_lookup: DataLookup,
+}
+
+/// The commitments to the data matrix of the block.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Encode, Decode)]
+pub struct KateCommitment {
+    #[codec(compact)]
+    pub rows: u16,
+    #[codec(compact)]
+    pub cols: u16,
     pub data_root: H256,
+    /// The KZG commitments of the rows of the extended data matrix.
+    pub commitment: Vec<u8>,
+}
+
+/// The cells of the data matrix holding the data of each application, sorted by application.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Encode, Decode, Default)]
+pub struct DataLookup {
+    #[codec(compact)]
+    pub size: u32,
+    pub index: Vec<DataLookupIndexItem>,
+}
+
+impl DataLookup {
+    /// Returns whether the data matrix holds data submitted to `app_id`.
+    pub fn contains(&self, app_id: u32) -> bool {
+        self.index.iter().any(|item| item.app_id == app_id)
+    }
+}
+
+/// The first cell of the data of an application in the data matrix.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Encode, Decode)]
+pub struct DataLookupIndexItem {
+    #[codec(compact)]
+    pub app_id: u32,
+    #[codec(compact)]
+    pub start: u32,
 }
 
 use super::hash::AvailHash;
@@ -26,18 +101,43 @@ pub struct AvailHeader {
     pub header: Header,
 }
 
-#[cfg(feature = "native")]
 impl AvailHeader {
-    pub fn new(header: SubxtHeader, hash: H256) -> Self {
-        Self {
-            hash: AvailHash::new(hash),
-            header: Header {
+    /// Converts the header returned by the node, checking that it is encoded to the given
+    /// `hash`, so that the verifier can check it.
+    #[cfg(feature = "native")]
+    pub fn new(header: SubxtHeader, hash: H256) -> anyhow::Result<Self> {
+        let extension = header.extension.encode();
+        let (version, mut extension) = extension
+            .split_first()
+            .ok_or_else(|| anyhow!("Missing header extension of block {hash}"))?;
+        if *version != 0 {
+            return Err(anyhow!(
+                "Unsupported header extension version of block {hash}"
+            ));
+        }
+
+        let header = Self::from_header(
+            Header {
                 parent_hash: header.parent_hash,
                 number: header.number,
                 state_root: header.state_root,
-                data_root: header.data_root(),
                 extrinsics_root: header.extrinsics_root,
+                digest: header.digest.encode(),
+                extension: HeaderExtension::decode(&mut extension)?,
             },
+            hash,
+        );
+        if header.header.hash() != hash.to_fixed_bytes() {
+            return Err(anyhow!("Unsupported header encoding of block {hash}"));
+        }
+
+        Ok(header)
+    }
+
+    pub fn from_header(header: Header, hash: H256) -> Self {
+        Self {
+            hash: AvailHash::new(hash),
+            header,
         }
     }
 }
@@ -66,19 +166,21 @@ impl BlockHeaderTrait for AvailHeader {
 
 #[cfg(feature = "native")]
 impl
-    From<
+    TryFrom<
         subxt::blocks::Block<
             avail_subxt::AvailConfig,
             subxt::OnlineClient<avail_subxt::AvailConfig>,
         >,
     > for AvailHeader
 {
-    fn from(
+    type Error = anyhow::Error;
+
+    fn try_from(
         block: subxt::blocks::Block<
             avail_subxt::AvailConfig,
             subxt::OnlineClient<avail_subxt::AvailConfig>,
         >,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         AvailHeader::new(block.header().clone(), block.hash())
     }
 }
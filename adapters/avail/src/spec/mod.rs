@@ -4,8 +4,10 @@ use crate::verifier::ChainValidityCondition;
 
 pub mod address;
 pub mod block;
+pub mod extrinsic;
 mod hash;
 pub mod header;
+pub mod proof;
 pub mod transaction;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
@@ -22,9 +24,16 @@ impl DaSpec for DaLayerSpec {
 
     type ValidityCondition = ChainValidityCondition;
 
-    type InclusionMultiProof = ();
+    type InclusionMultiProof = proof::AvailInclusionProof;
 
-    type CompletenessProof = ();
+    type CompletenessProof = proof::AvailCompletenessProof;
 
-    type ChainParams = ();
+    type ChainParams = AvailChainParams;
+}
+
+/// The parameters of a rollup publishing its blobs on Avail.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct AvailChainParams {
+    /// The application the rollup blobs are submitted to.
+    pub app_id: u32,
 }
@@ -0,0 +1,89 @@
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::StateVersion;
+
+/// The blobs are read from the extrinsics of the completeness proof, so they don't need a
+/// separate inclusion proof.
+pub type AvailInclusionProof = ();
+
+/// Every extrinsic of the block, SCALE encoded, in block order. They hash to the extrinsics root
+/// of the header, which commits to the application and sender of every data submission.
+pub type AvailCompletenessProof = Vec<Vec<u8>>;
+
+/// Computes the extrinsics root committed by the header of a block with the given encoded
+/// extrinsics: the root of the ordered blake2 Patricia-Merkle trie of the extrinsics, as computed
+/// by the runtime.
+pub fn extrinsics_root(extrinsics: &[Vec<u8>]) -> [u8; 32] {
+    BlakeTwo256::ordered_trie_root(extrinsics.to_vec(), StateVersion::V0).to_fixed_bytes()
+}
+
+/// Computes the data root committed by the header of a block with the given data submission
+/// hashes: a keccak binary Merkle tree, promoting the last node of odd layers. The data root of
+/// a block without submissions is zero.
+pub fn data_root(data_hashes: impl IntoIterator<Item = [u8; 32]>) -> [u8; 32] {
+    let mut layer: Vec<[u8; 32]> = data_hashes.into_iter().collect();
+    if layer.is_empty() {
+        return [0u8; 32];
+    }
+
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|nodes| match nodes {
+                [left, right] => {
+                    let mut preimage = [0u8; 64];
+                    preimage[..32].copy_from_slice(left);
+                    preimage[32..].copy_from_slice(right);
+                    sp_core_hashing::keccak_256(&preimage)
+                }
+                [node] => *node,
+                _ => unreachable!("Chunks hold one or two nodes"),
+            })
+            .collect();
+    }
+
+    layer[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use sp_core_hashing::keccak_256;
+
+    use super::*;
+
+    fn hash_pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        keccak_256(&[left, right].concat())
+    }
+
+    #[test]
+    fn extrinsics_root_test() {
+        // The root of the empty trie, which is the well known storage root of empty Substrate
+        // chains.
+        assert_eq!(
+            hex::decode("03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314")
+                .unwrap(),
+            extrinsics_root(&[])
+        );
+        assert_ne!(extrinsics_root(&[vec![1]]), extrinsics_root(&[vec![2]]));
+        assert_ne!(
+            extrinsics_root(&[vec![1], vec![2]]),
+            extrinsics_root(&[vec![2], vec![1]])
+        );
+    }
+
+    #[test]
+    fn data_root_test() {
+        let leaves: Vec<[u8; 32]> = [b"a", b"b", b"c"].iter().map(|d| keccak_256(*d)).collect();
+
+        assert_eq!([0u8; 32], data_root([]));
+        assert_eq!(leaves[0], data_root(leaves[..1].to_vec()));
+        assert_eq!(
+            hash_pair(leaves[0], leaves[1]),
+            data_root(leaves[..2].to_vec())
+        );
+        // The last leaf of the odd layer is promoted without hashing.
+        assert_eq!(
+            hash_pair(hash_pair(leaves[0], leaves[1]), leaves[2]),
+            data_root(leaves)
+        );
+    }
+}
@@ -45,6 +45,13 @@ impl BlobReaderTrait for AvailBlobTransaction {
     fn total_len(&self) -> usize {
         self.blob.total_len()
     }
+
+    fn from_verified_data(sender: AvailAddress, hash: [u8; 32], data: Vec<u8>) -> Self {
+        let len = data.len();
+        let mut blob = Self::from_data(Bytes::from(data), hash, sender);
+        blob.blob.advance(len);
+        blob
+    }
 }
 
 impl AvailBlobTransaction {
@@ -59,10 +66,8 @@ impl AvailBlobTransaction {
                 ))
             }
         };
-        let blob = match &unchecked_extrinsic.function {
-            DataAvailability(Call::submit_data { data }) => {
-                CountedBufReader::<Bytes>::new(Bytes::copy_from_slice(&data.0))
-            }
+        let data = match &unchecked_extrinsic.function {
+            DataAvailability(Call::submit_data { data }) => Bytes::copy_from_slice(&data.0),
             _ => {
                 return Err(anyhow!(
                     "Invalid type of extrinsic being converted to AvailBlobTransaction."
@@ -70,11 +75,21 @@ impl AvailBlobTransaction {
             }
         };
 
-        Ok(AvailBlobTransaction {
-            hash: sp_core_hashing::blake2_256(&unchecked_extrinsic.encode()),
+        Ok(Self::from_data(
+            data,
+            sp_core_hashing::blake2_256(&unchecked_extrinsic.encode()),
+            address,
+        ))
+    }
+
+    /// Creates the blob transaction of the `data` submitted by `address` in the extrinsic with
+    /// the given `hash`.
+    pub fn from_data(data: Bytes, hash: [u8; 32], address: AvailAddress) -> Self {
+        AvailBlobTransaction {
+            blob: CountedBufReader::new(data),
+            hash,
             address,
-            blob,
-        })
+        }
     }
 
     pub fn combine_hash(&self, hash: [u8; 32]) -> [u8; 32] {
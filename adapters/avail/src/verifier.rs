@@ -1,9 +1,12 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
-use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, DaVerifier};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, DaVerifier};
 use sov_rollup_interface::zk::ValidityCondition;
 use thiserror::Error;
 
+use crate::spec::address::AvailAddress;
+use crate::spec::extrinsic::decode_submission;
+use crate::spec::proof::{data_root, extrinsics_root};
 use crate::spec::DaLayerSpec;
 
 #[derive(Error, Debug)]
@@ -12,6 +15,24 @@ pub enum ValidityConditionError {
     BlocksNotConsecutive,
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("the header doesn't match the hash of its block")]
+    HeaderHashMismatch,
+    #[error("the extrinsics don't match the extrinsics root of the header")]
+    ExtrinsicsRootMismatch,
+    #[error("the data submissions don't match the data root of the header")]
+    DataRootMismatch,
+    #[error("the data submissions don't match the app lookup of the header")]
+    AppLookupMismatch,
+    #[error("the rollup blob {0} is missing")]
+    MissingBlob(usize),
+    #[error("the blob {0} was not submitted to the rollup")]
+    UnexpectedBlob(usize),
+    #[error("the blob {0} doesn't match the submitted data")]
+    ForgedBlob(usize),
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy, BorshDeserialize, BorshSerialize,
 )]
@@ -45,22 +66,79 @@ impl ValidityCondition for ChainValidityCondition {
     }
 }
 
-pub struct Verifier;
+pub struct Verifier {
+    app_id: u32,
+}
 
 impl DaVerifier for Verifier {
     type Spec = DaLayerSpec;
 
-    type Error = ValidityConditionError;
+    type Error = ValidationError;
 
-    // Verify that the given list of blob transactions is complete and correct.
-    // NOTE: Function return unit since application client already verifies application data.
+    // Verify that the given list of blob transactions is complete and correct: the header is
+    // checked against the hash of its block, and the completeness proof holds every extrinsic of
+    // the block, which is checked against the extrinsics root of the header. The application,
+    // sender and data of every data submission are read from the extrinsics, and must match the
+    // data root and app lookup committed by the header extension.
+    // NOTE: The KZG commitments of the extension aren't recomputed from the data, the data root
+    // binds the data submissions to the extension instead.
     fn verify_relevant_tx_list(
         &self,
         block_header: &<Self::Spec as DaSpec>::BlockHeader,
         txs: &[<Self::Spec as DaSpec>::BlobTransaction],
         _inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
-        _completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
+        completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
     ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
+        let header = &block_header.header;
+        if &header.hash() != block_header.hash().inner() {
+            return Err(ValidationError::HeaderHashMismatch);
+        }
+        if &extrinsics_root(&completeness_proof) != header.extrinsics_root.as_fixed_bytes() {
+            return Err(ValidationError::ExtrinsicsRootMismatch);
+        }
+
+        // Extrinsics which can't be decoded, e.g. calls in a format unknown to the rollup, are
+        // skipped. A data submission can't be hidden that way, since it would be missing from the
+        // data root.
+        let submissions: Vec<_> = completeness_proof
+            .iter()
+            .filter_map(|extrinsic| decode_submission(extrinsic).ok().flatten())
+            .collect();
+
+        let root = data_root(
+            submissions
+                .iter()
+                .map(|submission| sp_core_hashing::keccak_256(&submission.data)),
+        );
+        if &root != header.data_root().as_fixed_bytes() {
+            return Err(ValidationError::DataRootMismatch);
+        }
+
+        let rollup_submissions: Vec<_> = submissions
+            .iter()
+            .filter(|submission| submission.app_id == self.app_id)
+            .collect();
+        if rollup_submissions.is_empty() == header.extension.app_lookup.contains(self.app_id) {
+            return Err(ValidationError::AppLookupMismatch);
+        }
+        if txs.len() > rollup_submissions.len() {
+            return Err(ValidationError::UnexpectedBlob(rollup_submissions.len()));
+        }
+
+        for (index, submission) in rollup_submissions.iter().enumerate() {
+            let Some(tx) = txs.get(index) else {
+                return Err(ValidationError::MissingBlob(index));
+            };
+            let sender = submission.sender.map(AvailAddress::from);
+            if tx.hash() != submission.hash
+                || sender != Some(tx.sender())
+                || tx.total_len() != submission.data.len()
+                || !submission.data.starts_with(tx.verified_data())
+            {
+                return Err(ValidationError::ForgedBlob(index));
+            }
+        }
+
         let mut txs_commitment: [u8; 32] = [0u8; 32];
 
         for tx in txs {
@@ -76,7 +154,360 @@ impl DaVerifier for Verifier {
         Ok(validity_condition)
     }
 
-    fn new(_params: <Self::Spec as DaSpec>::ChainParams) -> Self {
-        Verifier {}
+    fn new(params: <Self::Spec as DaSpec>::ChainParams) -> Self {
+        Verifier {
+            app_id: params.app_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use codec::Encode;
+    use primitive_types::H256;
+    use sov_rollup_interface::test_utils::{
+        test_partial_reads, test_tamper_rejection, test_verification_round_trip,
+    };
+
+    use super::*;
+    use crate::spec::block::AvailBlock;
+    use crate::spec::extrinsic::encode_submission;
+    use crate::spec::header::{
+        AvailHeader, DataLookup, DataLookupIndexItem, Header, HeaderExtension, KateCommitment,
+    };
+    use crate::spec::transaction::AvailBlobTransaction;
+    use crate::spec::AvailChainParams;
+
+    const ROLLUP_APP_ID: u32 = 7;
+    const SENDER: [u8; 32] = [3; 32];
+
+    /// The blobs, extrinsics and header of a block with submissions to the rollup and to other
+    /// applications.
+    struct TestBlock {
+        header: AvailHeader,
+        txs: Vec<AvailBlobTransaction>,
+        extrinsics: Vec<Vec<u8>>,
+    }
+
+    impl TestBlock {
+        fn new(submissions: Vec<(u32, &str)>) -> Self {
+            // An unsigned `timestamp.set` inherent, as in every block.
+            let mut extrinsics = vec![[vec![4, 3, 0], 1u64.encode()].concat().encode()];
+            let mut txs = Vec::new();
+            for (app_id, data) in &submissions {
+                let extrinsic = encode_submission(*app_id, SENDER, data.as_bytes());
+                if *app_id == ROLLUP_APP_ID {
+                    txs.push(blob(data.as_bytes(), &extrinsic));
+                }
+                extrinsics.push(extrinsic);
+            }
+
+            let mut app_ids: Vec<u32> = submissions.iter().map(|(app_id, _)| *app_id).collect();
+            app_ids.sort();
+            app_ids.dedup();
+            let header = Header {
+                parent_hash: H256::repeat_byte(1),
+                number: 10,
+                state_root: H256::zero(),
+                extrinsics_root: H256(extrinsics_root(&extrinsics)),
+                digest: Vec::<u8>::new().encode(),
+                extension: HeaderExtension {
+                    commitment: KateCommitment {
+                        rows: 1,
+                        cols: 4,
+                        data_root: H256(data_root(
+                            submissions
+                                .iter()
+                                .map(|(_, data)| sp_core_hashing::keccak_256(data.as_bytes())),
+                        )),
+                        commitment: vec![],
+                    },
+                    app_lookup: DataLookup {
+                        size: 4,
+                        index: app_ids
+                            .into_iter()
+                            .map(|app_id| DataLookupIndexItem { app_id, start: 0 })
+                            .collect(),
+                    },
+                },
+            };
+
+            Self {
+                header: hashed(header),
+                txs,
+                extrinsics,
+            }
+        }
+
+        fn verify(self) -> Result<ChainValidityCondition, ValidationError> {
+            Verifier::new(AvailChainParams {
+                app_id: ROLLUP_APP_ID,
+            })
+            .verify_relevant_tx_list(&self.header, &self.txs, (), self.extrinsics)
+        }
+    }
+
+    /// The header of the block with the hash of `header`.
+    fn hashed(header: Header) -> AvailHeader {
+        let hash = H256(header.hash());
+        AvailHeader::from_header(header, hash)
+    }
+
+    /// The blob of the rollup `extrinsic` submitting `data`, read up to `read_len`.
+    fn partially_read_blob(data: &[u8], extrinsic: &[u8], read_len: usize) -> AvailBlobTransaction {
+        let mut blob = AvailBlobTransaction::from_data(
+            Bytes::copy_from_slice(data),
+            sp_core_hashing::blake2_256(extrinsic),
+            AvailAddress::from(SENDER),
+        );
+        blob.advance(read_len);
+        blob
+    }
+
+    fn blob(data: &[u8], extrinsic: &[u8]) -> AvailBlobTransaction {
+        partially_read_blob(data, extrinsic, data.len())
+    }
+
+    fn test_block() -> TestBlock {
+        TestBlock::new(vec![
+            (1, "other application"),
+            (ROLLUP_APP_ID, "first batch"),
+            (2, "another application"),
+            (ROLLUP_APP_ID, "second batch"),
+        ])
+    }
+
+    #[test]
+    fn verifies_complete_blobs() {
+        let block = test_block();
+        let expected_commitment = block.txs[1].combine_hash(block.txs[0].combine_hash([0; 32]));
+        let block_hash = block.header.header.hash();
+
+        let validity_condition = block.verify().unwrap();
+        assert_eq!(expected_commitment, validity_condition.txs_commitment);
+        assert_eq!([1; 32], validity_condition.prev_hash);
+        assert_eq!(block_hash, validity_condition.block_hash);
+    }
+
+    #[test]
+    fn verifies_blocks_without_rollup_blobs() {
+        TestBlock::new(vec![]).verify().unwrap();
+        TestBlock::new(vec![(1, "other application")])
+            .verify()
+            .unwrap();
+    }
+
+    #[test]
+    fn verifies_partially_read_blobs() {
+        let mut block = test_block();
+        block.txs[0] = partially_read_blob(b"first batch", &block.extrinsics[2], 5);
+        block.verify().unwrap();
+
+        // Only the data read by the rollup is verified.
+        let mut block = test_block();
+        block.txs[0] = partially_read_blob(b"firstforged", &block.extrinsics[2], 5);
+        block.verify().unwrap();
+
+        let mut block = test_block();
+        block.txs[0] = partially_read_blob(b"fakes batch", &block.extrinsics[2], 5);
+        assert_eq!(Err(ValidationError::ForgedBlob(0)), block.verify());
+    }
+
+    #[test]
+    fn verifier_conformance() {
+        let verifier = Verifier::new(AvailChainParams {
+            app_id: ROLLUP_APP_ID,
+        });
+
+        let test_block = test_block();
+        let block = AvailBlock {
+            header: test_block.header.clone(),
+            transactions: test_block.txs.clone(),
+            extrinsics: test_block.extrinsics.clone(),
+        };
+        test_verification_round_trip(
+            &verifier,
+            &block,
+            (test_block.txs, (), test_block.extrinsics),
+        );
+
+        test_tamper_rejection(&verifier, &block.header, || {
+            let test_block = test_block();
+            (test_block.txs, (), test_block.extrinsics)
+        });
+
+        let extrinsic = encode_submission(ROLLUP_APP_ID, SENDER, b"first batch");
+        test_partial_reads(
+            partially_read_blob(b"first batch", &extrinsic, 0),
+            b"first batch",
+        );
+    }
+
+    #[test]
+    fn rejects_omitted_blobs() {
+        let mut block = test_block();
+        block.txs.pop();
+        assert_eq!(Err(ValidationError::MissingBlob(1)), block.verify());
+
+        // Omitting the extrinsic too.
+        let mut block = test_block();
+        block.txs.pop();
+        block.extrinsics.pop();
+        assert_eq!(Err(ValidationError::ExtrinsicsRootMismatch), block.verify());
+
+        // Hiding the extrinsic as a submission to another application.
+        let mut block = test_block();
+        block.txs.pop();
+        block.extrinsics[4] = encode_submission(1, SENDER, b"second batch");
+        assert_eq!(Err(ValidationError::ExtrinsicsRootMismatch), block.verify());
+
+        // Hiding the extrinsic in a forged header too.
+        let mut block = test_block();
+        block.txs.pop();
+        block.extrinsics[4] = encode_submission(1, SENDER, b"second batch");
+        block.header.header.extrinsics_root = H256(extrinsics_root(&block.extrinsics));
+        assert_eq!(Err(ValidationError::HeaderHashMismatch), block.verify());
+    }
+
+    #[test]
+    fn rejects_forged_blobs() {
+        let mut block = test_block();
+        block.txs[1] = blob(b"forged batch", &block.extrinsics[4]);
+        assert_eq!(Err(ValidationError::ForgedBlob(1)), block.verify());
+
+        // A forged blob with the length of the submitted one.
+        let mut block = test_block();
+        block.txs[0] = blob(b"fake batch!", &block.extrinsics[2]);
+        assert_eq!(Err(ValidationError::ForgedBlob(0)), block.verify());
+
+        // A blob attributed to another sender.
+        let mut block = test_block();
+        block.txs[0] = AvailBlobTransaction::from_data(
+            Bytes::from_static(b"first batch"),
+            block.txs[0].hash(),
+            AvailAddress::from([4; 32]),
+        );
+        assert_eq!(Err(ValidationError::ForgedBlob(0)), block.verify());
+
+        // Forging the extrinsic too.
+        let mut block = test_block();
+        block.extrinsics[2] = encode_submission(ROLLUP_APP_ID, SENDER, b"fake batch!");
+        block.txs[0] = blob(b"fake batch!", &block.extrinsics[2]);
+        assert_eq!(Err(ValidationError::ExtrinsicsRootMismatch), block.verify());
+
+        // Hiding the submission in an undecodable extrinsic.
+        let mut block = test_block();
+        block.extrinsics[2] = vec![1, 2, 3];
+        block.header.header.extrinsics_root = H256(extrinsics_root(&block.extrinsics));
+        block.header = hashed(block.header.header);
+        assert_eq!(Err(ValidationError::DataRootMismatch), block.verify());
+    }
+
+    #[test]
+    fn skips_undecodable_extrinsics() {
+        let mut block = test_block();
+        block.extrinsics.insert(1, vec![1, 2, 3]);
+        block.header.header.extrinsics_root = H256(extrinsics_root(&block.extrinsics));
+        block.header = hashed(block.header.header);
+        assert!(block.verify().is_ok());
+    }
+
+    #[test]
+    fn rejects_injected_blobs() {
+        let mut block = test_block();
+        let extrinsic = encode_submission(ROLLUP_APP_ID, SENDER, b"injected batch");
+        block.txs.push(blob(b"injected batch", &extrinsic));
+        assert_eq!(Err(ValidationError::UnexpectedBlob(2)), block.verify());
+
+        // Injecting the extrinsic too.
+        let mut block = test_block();
+        block.txs.push(blob(b"injected batch", &extrinsic));
+        block.extrinsics.push(extrinsic);
+        assert_eq!(Err(ValidationError::ExtrinsicsRootMismatch), block.verify());
+    }
+
+    #[test]
+    fn rejects_inconsistent_header_extensions() {
+        // A header whose extension doesn't commit to the submitted data.
+        let mut block = test_block();
+        block.header.header.extension.commitment.data_root = H256::zero();
+        block.header = hashed(block.header.header);
+        assert_eq!(Err(ValidationError::DataRootMismatch), block.verify());
+
+        // A header whose app lookup omits the rollup data.
+        let mut block = test_block();
+        block
+            .header
+            .header
+            .extension
+            .app_lookup
+            .index
+            .retain(|item| item.app_id != ROLLUP_APP_ID);
+        block.header = hashed(block.header.header);
+        assert_eq!(Err(ValidationError::AppLookupMismatch), block.verify());
+
+        // Tampering with the extension of a header without updating its hash.
+        let mut block = test_block();
+        block.header.header.extension.commitment.commitment = vec![1];
+        assert_eq!(Err(ValidationError::HeaderHashMismatch), block.verify());
+    }
+
+    /// A block recorded from an Avail network: its hash, its header in the format of [`Header`],
+    /// and its extrinsics as hex strings.
+    #[derive(serde::Deserialize)]
+    struct RecordedBlock {
+        network: String,
+        hash: H256,
+        header: Header,
+        extrinsics: Vec<String>,
+    }
+
+    // Checks the encoding of the header, including the field order of the `KateCommitment` and the
+    // version byte of the extension, and the data root against blocks produced by real nodes.
+    #[test]
+    #[ignore = "no blocks recorded from an Avail network are checked in yet, see test_data/README.md"]
+    fn reproduces_recorded_blocks() {
+        let recorded = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test_data/recorded_blocks.json"
+        ))
+        .expect("Unable to read the recorded blocks");
+        let blocks: Vec<RecordedBlock> = serde_json::from_str(&recorded).unwrap();
+        assert!(!blocks.is_empty());
+
+        for block in blocks {
+            let extrinsics: Vec<Vec<u8>> = block
+                .extrinsics
+                .iter()
+                .map(|extrinsic| hex::decode(extrinsic.trim_start_matches("0x")).unwrap())
+                .collect();
+            let header = &block.header;
+            assert_eq!(
+                block.hash.as_fixed_bytes(),
+                &header.hash(),
+                "hash of block {} of {}",
+                header.number,
+                block.network
+            );
+            assert_eq!(
+                header.extrinsics_root.as_fixed_bytes(),
+                &extrinsics_root(&extrinsics),
+                "extrinsics root of block {} of {}",
+                header.number,
+                block.network
+            );
+            let data_hashes = extrinsics
+                .iter()
+                .filter_map(|extrinsic| decode_submission(extrinsic).ok().flatten())
+                .map(|submission| sp_core_hashing::keccak_256(&submission.data));
+            assert_eq!(
+                header.data_root().as_fixed_bytes(),
+                &data_root(data_hashes),
+                "data root of block {} of {}",
+                header.number,
+                block.network
+            );
+        }
     }
 }
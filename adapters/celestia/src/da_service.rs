@@ -9,12 +9,14 @@ use celestia_types::consts::appconsts::{
 };
 use celestia_types::nmt::Namespace;
 use celestia_types::ExtendedHeader;
-use jsonrpsee::core::client::Subscription;
+use jsonrpsee::core::client::{ClientT, Subscription};
+use jsonrpsee::core::params::ArrayParams;
 use jsonrpsee::http_client::{HeaderMap, HttpClient};
 use pin_project::pin_project;
+use sov_rollup_interface::da::chunks::{new_sequence_id, split_blob};
 use sov_rollup_interface::da::CountedBufReader;
-use sov_rollup_interface::services::da::DaService;
-use tracing::{debug, info, instrument, trace};
+use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService};
+use tracing::{debug, info, instrument, trace, warn};
 
 use crate::shares::Blob;
 use crate::types::FilteredCelestiaBlock;
@@ -23,15 +25,34 @@ use crate::verifier::proofs::{CompletenessProof, CorrectnessProof};
 use crate::verifier::{CelestiaSpec, CelestiaVerifier, RollupParams, PFB_NAMESPACE};
 use crate::{BlobWithSender, CelestiaHeader};
 
-// Approximate value, just to make it work.
-const GAS_PER_BYTE: usize = 20;
-const GAS_PRICE: usize = 1;
+/// The largest blob submitted in a single transaction, larger blobs are split into chunks. Blobs
+/// have to fit in the data square of a block, 64 by 64 shares by default, along with the other
+/// transactions of the block.
+const MAX_BLOB_SIZE: usize = 1_500_000;
+
+// The gas parameters of celestia-app, see `EstimateGas` of its blob module.
+/// Gas consumed per byte of the shares occupied by a blob.
+const GAS_PER_BLOB_BYTE: u64 = 8;
+/// Gas consumed per byte of a transaction.
+const TX_SIZE_COST_PER_BYTE: u64 = 10;
+/// Bytes added to a PayForBlobs transaction by each of its blobs.
+const BYTES_PER_BLOB_INFO: u64 = 70;
+/// Gas consumed by a PayForBlobs transaction regardless of its blobs.
+const PFB_GAS_FIXED_COST: u64 = 75_000;
+
+/// The JSON-RPC method returning the minimum gas price accepted by the node, in utia.
+const MIN_GAS_PRICE_METHOD: &str = "state.MinGasPrice";
+/// The default minimum gas price of celestia-app validators, in utia. Used when the node doesn't
+/// report its minimum gas price.
+const DEFAULT_MIN_GAS_PRICE: f64 = 0.002;
 
 #[derive(Debug, Clone)]
 pub struct CelestiaService {
     client: HttpClient,
     rollup_batch_namespace: Namespace,
     rollup_proof_namespace: Namespace,
+    gas_price_multiplier: f64,
+    max_gas_price: f64,
 }
 
 impl CelestiaService {
@@ -44,6 +65,8 @@ impl CelestiaService {
             client,
             rollup_batch_namespace,
             rollup_proof_namespace,
+            gas_price_multiplier: default_gas_price_multiplier(),
+            max_gas_price: default_max_gas_price(),
         }
     }
 }
@@ -62,6 +85,14 @@ pub struct CelestiaConfig {
     /// The timeout for a Celestia RPC request, in seconds
     #[serde(default = "default_request_timeout_seconds")]
     pub celestia_rpc_timeout_seconds: u64,
+    /// The minimum gas price of the node is multiplied by this factor to price blobs, and the gas
+    /// price is multiplied by it again each time a submission times out
+    #[serde(default = "default_gas_price_multiplier")]
+    pub gas_price_multiplier: f64,
+    /// The highest gas price paid for blobs, in utia. Blobs aren't submitted while the minimum
+    /// gas price of the node is higher
+    #[serde(default = "default_max_gas_price")]
+    pub max_gas_price: f64,
 }
 
 fn default_rpc_addr() -> String {
@@ -76,6 +107,14 @@ const fn default_request_timeout_seconds() -> u64 {
     60
 }
 
+const fn default_gas_price_multiplier() -> f64 {
+    1.1
+}
+
+const fn default_max_gas_price() -> f64 {
+    0.1
+}
+
 impl CelestiaService {
     pub async fn new(config: CelestiaConfig, chain_params: RollupParams) -> Self {
         let client = {
@@ -97,11 +136,87 @@ impl CelestiaService {
         }
         .expect("Client initialization is valid");
 
-        Self::with_client(
-            client,
-            chain_params.rollup_batch_namespace,
-            chain_params.rollup_proof_namespace,
-        )
+        Self {
+            gas_price_multiplier: config.gas_price_multiplier,
+            max_gas_price: config.max_gas_price,
+            ..Self::with_client(
+                client,
+                chain_params.rollup_batch_namespace,
+                chain_params.rollup_proof_namespace,
+            )
+        }
+    }
+
+    /// Returns the minimum gas price accepted by the node, in utia.
+    async fn get_min_gas_price(&self) -> f64 {
+        match self
+            .client
+            .request::<f64, _>(MIN_GAS_PRICE_METHOD, ArrayParams::new())
+            .await
+        {
+            Ok(min_gas_price) => min_gas_price,
+            Err(e) => {
+                warn!(
+                    "Minimum gas price unavailable, using {}: {}",
+                    DEFAULT_MIN_GAS_PRICE, e
+                );
+                DEFAULT_MIN_GAS_PRICE
+            }
+        }
+    }
+
+    /// Submits `blob` and returns the height of the block including it. The gas price starts at
+    /// the minimum gas price of the node times the multiplier, and is bumped by the multiplier
+    /// each time the submission times out, up to the maximum gas price.
+    ///
+    /// A submission that timed out may still be included, so the blob can be included twice.
+    /// Fails without submitting if the minimum gas price of the node exceeds the maximum gas price,
+    /// since the node would reject the submission.
+    async fn submit_blob(&self, blob: JsonBlob) -> Result<u64, BoxError> {
+        let gas_limit = estimate_gas_for_blob(blob.data.len());
+        let min_gas_price = self.get_min_gas_price().await;
+        if min_gas_price > self.max_gas_price {
+            return Err(format!(
+                "Minimum gas price of the node {} exceeds the maximum gas price {}",
+                min_gas_price, self.max_gas_price
+            )
+            .into());
+        }
+        let mut gas_price = (min_gas_price * self.gas_price_multiplier).min(self.max_gas_price);
+
+        loop {
+            let fee = get_fee(gas_limit, gas_price);
+            debug!(
+                "Submitting blob with gas limit {}, gas price {} and fee {}",
+                gas_limit, gas_price, fee
+            );
+            let result = self
+                .client
+                .blob_submit(
+                    std::slice::from_ref(&blob),
+                    SubmitOptions {
+                        fee: Some(fee),
+                        gas_limit: Some(gas_limit),
+                    },
+                )
+                .await;
+
+            match result {
+                Err(jsonrpsee::core::Error::RequestTimeout) => {
+                    let bumped_gas_price =
+                        (gas_price * self.gas_price_multiplier).min(self.max_gas_price);
+                    if bumped_gas_price <= gas_price {
+                        return Err(jsonrpsee::core::Error::RequestTimeout.into());
+                    }
+                    info!(
+                        "Blob submission timed out, resubmitting with gas price {}",
+                        bumped_gas_price
+                    );
+                    gas_price = bumped_gas_price;
+                }
+                result => return Ok(result?),
+            }
+        }
     }
 }
 
@@ -197,6 +312,15 @@ impl DaService for CelestiaService {
         Ok(CelestiaHeader::from(header))
     }
 
+    // Tendermint has instant finality, so every new head is also finalized.
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        self.subscribe_finalized_header().await
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        0
+    }
+
     fn extract_relevant_blobs(
         &self,
         block: &Self::FilteredBlock,
@@ -245,46 +369,28 @@ impl DaService for CelestiaService {
     async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
         debug!("Sending {} bytes of raw data to Celestia.", blob.len());
 
-        let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
-        let fee = gas_limit * GAS_PRICE as u64;
-
-        let blob = JsonBlob::new(self.rollup_batch_namespace, blob.to_vec())?;
-        info!("Submitting: {:?}", blob.commitment);
-
-        let height = self
-            .client
-            .blob_submit(
-                &[blob],
-                SubmitOptions {
-                    fee: Some(fee),
-                    gas_limit: Some(gas_limit),
-                },
-            )
-            .await?;
-        info!(
-            "Blob has been submitted to Celestia. block-height={}",
-            height,
-        );
+        let chunks = split_blob(blob, MAX_BLOB_SIZE, new_sequence_id())?;
+        if chunks.len() > 1 {
+            info!("Splitting the blob into {} chunks", chunks.len());
+        }
+        for chunk in chunks {
+            let blob = JsonBlob::new(self.rollup_batch_namespace, chunk)?;
+            info!("Submitting: {:?}", blob.commitment);
+
+            let height = self.submit_blob(blob).await?;
+            info!(
+                "Blob has been submitted to Celestia. block-height={}",
+                height,
+            );
+        }
         Ok(())
     }
 
     async fn send_aggregated_zk_proof(&self, aggregated_proof: &[u8]) -> Result<u64, Self::Error> {
-        let gas_limit = get_gas_limit_for_bytes(aggregated_proof.len()) as u64;
-        let fee = gas_limit * GAS_PRICE as u64;
-        let blob = JsonBlob::new(self.rollup_proof_namespace, aggregated_proof.to_vec())?;
+        let envelope = AggregatedProofEnvelope::new(aggregated_proof.to_vec()).encode();
+        let blob = JsonBlob::new(self.rollup_proof_namespace, envelope)?;
 
-        let height = self
-            .client
-            .blob_submit(
-                &[blob],
-                SubmitOptions {
-                    fee: Some(fee),
-                    gas_limit: Some(gas_limit),
-                },
-            )
-            .await?;
-
-        Ok(height)
+        self.submit_blob(blob).await
     }
 
     async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
@@ -293,35 +399,57 @@ impl DaService for CelestiaService {
             .blob_get_all(height, &[self.rollup_proof_namespace])
             .await?;
 
-        Ok(blobs.into_iter().map(|blob| blob.data).collect())
+        Ok(AggregatedProofEnvelope::decode_proofs(
+            blobs.into_iter().map(|blob| blob.data),
+        ))
     }
 }
 
-// https://docs.celestia.org/learn/submit-data/#fees-and-gas-limits
-fn get_gas_limit_for_bytes(n: usize) -> usize {
-    let fixed_cost = 75000;
+/// The number of shares occupied by a blob of `blob_len` bytes.
+fn sparse_shares_needed(blob_len: usize) -> usize {
+    if blob_len == 0 {
+        return 0;
+    }
+    let continuation_len = blob_len.saturating_sub(FIRST_SPARSE_SHARE_CONTENT_SIZE);
+    1 + continuation_len.div_ceil(CONTINUATION_SPARSE_SHARE_CONTENT_SIZE)
+}
 
-    let continuation_shares_needed =
-        n.saturating_sub(FIRST_SPARSE_SHARE_CONTENT_SIZE) / CONTINUATION_SPARSE_SHARE_CONTENT_SIZE;
-    let shares_needed = 1 + continuation_shares_needed + 1; // add one extra, pessimistic
+/// Estimates the gas consumed by a PayForBlobs transaction of a single blob of `blob_len` bytes.
+// https://docs.celestia.org/learn/submit-data/#fees-and-gas-limits
+fn estimate_gas_for_blob(blob_len: usize) -> u64 {
+    let shares_gas = (sparse_shares_needed(blob_len) * SHARE_SIZE) as u64 * GAS_PER_BLOB_BYTE;
+    shares_gas + TX_SIZE_COST_PER_BYTE * BYTES_PER_BLOB_INFO + PFB_GAS_FIXED_COST
+}
 
-    fixed_cost + shares_needed * SHARE_SIZE * GAS_PER_BYTE
+/// The fee paid for `gas_limit` at `gas_price`, in utia.
+fn get_fee(gas_limit: u64, gas_price: f64) -> u64 {
+    (gas_limit as f64 * gas_price).ceil() as u64
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
+    use celestia_types::consts::appconsts::{
+        CONTINUATION_SPARSE_SHARE_CONTENT_SIZE, FIRST_SPARSE_SHARE_CONTENT_SIZE,
+    };
     use celestia_types::nmt::Namespace;
     use celestia_types::{Blob as JsonBlob, NamespacedShares};
     use serde_json::json;
-    use sov_rollup_interface::da::{BlockHeaderTrait, DaVerifier};
-    use sov_rollup_interface::services::da::DaService;
-    use wiremock::matchers::{bearer_token, body_json, method, path};
+    use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaVerifier};
+    use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService};
+    use sov_rollup_interface::test_utils::{
+        test_aggregated_proof_round_trip, test_extraction_round_trip,
+        test_extraction_tamper_rejection, test_partial_reads,
+    };
+    use wiremock::matchers::{bearer_token, body_partial_json, method, path};
     use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
-    use super::default_request_timeout_seconds;
-    use crate::da_service::{get_gas_limit_for_bytes, CelestiaConfig, CelestiaService, GAS_PRICE};
+    use crate::da_service::{
+        default_gas_price_multiplier, default_max_gas_price, default_request_timeout_seconds,
+        estimate_gas_for_blob, get_fee, sparse_shares_needed, CelestiaConfig, CelestiaService,
+        MIN_GAS_PRICE_METHOD,
+    };
     use crate::parse_pfb_namespace;
     use crate::shares::NamespaceGroup;
     use crate::types::tests::{with_rollup_data, without_rollup_data};
@@ -329,6 +457,7 @@ mod tests {
 
     const ROLLUP_ROWS_JSON: &str = with_rollup_data::ROLLUP_ROWS_JSON;
     const ETX_ROWS_JSON: &str = with_rollup_data::ETX_ROWS_JSON;
+    const MIN_GAS_PRICE: f64 = 0.002;
 
     #[test]
     fn test_get_pfbs() {
@@ -372,6 +501,8 @@ mod tests {
             celestia_rpc_address: mock_server.uri(),
             max_celestia_response_body_size: 120_000,
             celestia_rpc_timeout_seconds: timeout_sec,
+            gas_price_multiplier: default_gas_price_multiplier(),
+            max_gas_price: default_max_gas_price(),
         };
         let rollup_batch_namespace = Namespace::new_v0(b"sov-test").unwrap();
         let rollup_proof_namespace = Namespace::new_v0(b"sov-proof").unwrap();
@@ -381,10 +512,79 @@ mod tests {
         };
 
         let da_service = CelestiaService::new(config.clone(), params.clone()).await;
+        mount_min_gas_price(&mock_server).await;
 
         (mock_server, config, da_service, params)
     }
 
+    /// Serves [`MIN_GAS_PRICE`] as the minimum gas price of the node, ahead of the other mocks.
+    async fn mount_min_gas_price(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": MIN_GAS_PRICE_METHOD })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                ResponseTemplate::new(200)
+                    .append_header("Content-Type", "application/json")
+                    .set_body_json(json!({
+                        "jsonrpc": "2.0",
+                        "id": request.id,
+                        "result": MIN_GAS_PRICE,
+                    }))
+            })
+            .with_priority(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    /// The expected body of a submission of `blob` paying `gas_price`.
+    fn submit_body(blob: JsonBlob, gas_price: f64) -> serde_json::Value {
+        let gas_limit = estimate_gas_for_blob(blob.data.len());
+        json!({
+            "jsonrpc": "2.0",
+            "method": "blob.Submit",
+            "params": [
+                [blob],
+                {
+                    "GasLimit": gas_limit,
+                    "Fee": get_fee(gas_limit, gas_price),
+                },
+            ]
+        })
+    }
+
+    #[test]
+    fn test_blob_gas_estimation() {
+        assert_eq!(0, sparse_shares_needed(0));
+        assert_eq!(1, sparse_shares_needed(1));
+        assert_eq!(1, sparse_shares_needed(FIRST_SPARSE_SHARE_CONTENT_SIZE));
+        assert_eq!(2, sparse_shares_needed(FIRST_SPARSE_SHARE_CONTENT_SIZE + 1));
+        assert_eq!(
+            2,
+            sparse_shares_needed(
+                FIRST_SPARSE_SHARE_CONTENT_SIZE + CONTINUATION_SPARSE_SHARE_CONTENT_SIZE
+            )
+        );
+        assert_eq!(
+            3,
+            sparse_shares_needed(
+                FIRST_SPARSE_SHARE_CONTENT_SIZE + CONTINUATION_SPARSE_SHARE_CONTENT_SIZE + 1
+            )
+        );
+
+        // 1 share of 512 bytes at 8 gas per byte, 70 bytes of blob info at 10 gas per byte and
+        // the fixed cost of 75000.
+        assert_eq!(4096 + 700 + 75_000, estimate_gas_for_blob(100));
+        assert_eq!(
+            2 * 4096 + 700 + 75_000,
+            estimate_gas_for_blob(FIRST_SPARSE_SHARE_CONTENT_SIZE + 1)
+        );
+
+        assert_eq!(200, get_fee(100_000, 0.002));
+        // Fees are rounded up.
+        assert_eq!(1, get_fee(1, 0.002));
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     struct BasicJsonRpcRequest {
         jsonrpc: String,
@@ -398,26 +598,16 @@ mod tests {
         let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
 
         let blob = [1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
-        let gas_limit = get_gas_limit_for_bytes(blob.len());
 
-        // TODO: Fee is hardcoded for now
-        let expected_body = json!({
-            "id": 0,
-            "jsonrpc": "2.0",
-            "method": "blob.Submit",
-            "params": [
-                [JsonBlob::new(rollup_params.rollup_batch_namespace, blob.to_vec()).unwrap()],
-                {
-                    "GasLimit": gas_limit,
-                    "Fee": gas_limit * GAS_PRICE,
-                },
-            ]
-        });
+        let expected_body = submit_body(
+            JsonBlob::new(rollup_params.rollup_batch_namespace, blob.to_vec()).unwrap(),
+            MIN_GAS_PRICE * config.gas_price_multiplier,
+        );
 
         Mock::given(method("POST"))
             .and(path("/"))
             .and(bearer_token(config.celestia_rpc_auth_token))
-            .and(body_json(&expected_body))
+            .and(body_partial_json(&expected_body))
             .respond_with(|req: &Request| {
                 let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
                 let response_json = json!({
@@ -513,7 +703,16 @@ mod tests {
     // Slower request timeout can be set
     async fn test_submit_blob_response_timeout() -> anyhow::Result<()> {
         let timeout = 1;
-        let (mock_server, _config, da_service, _namespace) = setup_service(Some(timeout)).await;
+        let (mock_server, config, _da_service, rollup_params) = setup_service(Some(timeout)).await;
+        // The gas price can't be bumped, so the blob isn't resubmitted.
+        let da_service = CelestiaService::new(
+            CelestiaConfig {
+                max_gas_price: MIN_GAS_PRICE * config.gas_price_multiplier,
+                ..config
+            },
+            rollup_params,
+        )
+        .await;
 
         let response_json = json!({
             "jsonrpc": "2.0",
@@ -558,6 +757,92 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_submit_blob_above_max_gas_price() -> anyhow::Result<()> {
+        let (mock_server, config, _da_service, rollup_params) = setup_service(None).await;
+        let da_service = CelestiaService::new(
+            CelestiaConfig {
+                max_gas_price: MIN_GAS_PRICE / 2.0,
+                ..config
+            },
+            rollup_params,
+        )
+        .await;
+
+        // The blob isn't submitted at a gas price the node would reject.
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let blob: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
+        let error = da_service
+            .send_transaction(&blob)
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("exceeds the maximum gas price"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_blob_resubmits_with_bumped_fee() -> anyhow::Result<()> {
+        let timeout = 1;
+        let (mock_server, config, _da_service, rollup_params) = setup_service(Some(timeout)).await;
+        // The gas price can be bumped once.
+        let initial_gas_price = MIN_GAS_PRICE * config.gas_price_multiplier;
+        let bumped_gas_price = initial_gas_price * config.gas_price_multiplier;
+        let da_service = CelestiaService::new(
+            CelestiaConfig {
+                max_gas_price: bumped_gas_price,
+                ..config
+            },
+            rollup_params.clone(),
+        )
+        .await;
+
+        let blob: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
+        let json_blob = JsonBlob::new(rollup_params.rollup_batch_namespace, blob.clone()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(submit_body(
+                json_blob.clone(),
+                initial_gas_price,
+            )))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(Duration::from_secs(timeout) + Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(submit_body(json_blob, bumped_gas_price)))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                ResponseTemplate::new(200)
+                    .append_header("Content-Type", "application/json")
+                    .set_body_json(json!({
+                        "jsonrpc": "2.0",
+                        "id": request.id,
+                        "result": 14,
+                    }))
+            })
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        da_service.send_transaction(&blob).await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn verification_succeeds_for_correct_blocks() {
         let blocks = [
@@ -583,6 +868,27 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn da_service_conformance() {
+        let (_, _, da_service, rollup_params) = setup_service(None).await;
+        let verifier = CelestiaVerifier::new(rollup_params);
+
+        for block in [
+            with_rollup_data::filtered_block(),
+            without_rollup_data::filtered_block(),
+        ] {
+            test_extraction_round_trip(&da_service, &verifier, &block).await;
+        }
+
+        let block = with_rollup_data::filtered_block();
+        test_extraction_tamper_rejection(&da_service, &verifier, &block).await;
+
+        let data = da_service.extract_relevant_blobs(&block)[0]
+            .full_data()
+            .to_vec();
+        test_partial_reads(da_service.extract_relevant_blobs(&block).remove(0), &data);
+    }
+
     #[tokio::test]
     async fn verification_fails_if_tx_missing() {
         let block = with_rollup_data::filtered_block();
@@ -673,25 +979,17 @@ mod tests {
         let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
 
         let zk_proof: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
-        let gas_limit = get_gas_limit_for_bytes(zk_proof.len());
+        let envelope = AggregatedProofEnvelope::new(zk_proof.clone()).encode();
 
-        let expected_body = json!({
-            "id": 0,
-            "jsonrpc": "2.0",
-            "method": "blob.Submit",
-            "params": [
-                [JsonBlob::new(rollup_params.rollup_proof_namespace, zk_proof.to_vec()).unwrap()],
-                {
-                    "GasLimit": gas_limit,
-                    "Fee": gas_limit * GAS_PRICE,
-                },
-            ]
-        });
+        let expected_body = submit_body(
+            JsonBlob::new(rollup_params.rollup_proof_namespace, envelope).unwrap(),
+            MIN_GAS_PRICE * config.gas_price_multiplier,
+        );
 
         Mock::given(method("POST"))
             .and(path("/"))
             .and(bearer_token(config.celestia_rpc_auth_token))
-            .and(body_json(&expected_body))
+            .and(body_partial_json(&expected_body))
             .respond_with(|req: &Request| {
                 let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
                 let response_json = json!({
@@ -712,4 +1010,65 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_aggregated_proof_conformance() {
+        let (mock_server, _config, da_service, rollup_params) = setup_service(None).await;
+
+        let zk_proof: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
+        // The proof namespace also holds a proof of an unknown envelope version and data which
+        // isn't a proof.
+        let mut future_envelope = AggregatedProofEnvelope::new(vec![16]).encode();
+        future_envelope[0] = AggregatedProofEnvelope::VERSION + 1;
+        let proof_blobs: Vec<JsonBlob> = [
+            AggregatedProofEnvelope::new(zk_proof.clone()).encode(),
+            future_envelope,
+            b"not a proof".to_vec(),
+        ]
+        .into_iter()
+        .map(|data| JsonBlob::new(rollup_params.rollup_proof_namespace, data).unwrap())
+        .collect();
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                ResponseTemplate::new(200)
+                    .append_header("Content-Type", "application/json")
+                    .set_body_json(json!({
+                        "jsonrpc": "2.0",
+                        "id": request.id,
+                        "result": 14,
+                    }))
+            })
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({
+                "method": "blob.GetAll",
+                "params": [14, [rollup_params.rollup_proof_namespace]],
+            })))
+            .respond_with(move |req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                ResponseTemplate::new(200)
+                    .append_header("Content-Type", "application/json")
+                    .set_body_json(json!({
+                        "jsonrpc": "2.0",
+                        "id": request.id,
+                        "result": proof_blobs,
+                    }))
+            })
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+
+        test_aggregated_proof_round_trip(&da_service, &zk_proof).await;
+        assert_eq!(
+            vec![zk_proof],
+            da_service.get_aggregated_proofs_at(14).await.unwrap()
+        );
+    }
 }
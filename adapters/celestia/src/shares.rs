@@ -17,6 +17,12 @@ pub const INFO_BYTE_LEN: usize = celestia_types::consts::appconsts::SHARE_INFO_B
 pub const SEQUENCE_LENGTH_BYTES: usize = celestia_types::consts::appconsts::SEQUENCE_LEN_BYTES;
 /// The size of a share, in bytes
 pub const SHARE_SIZE: usize = celestia_types::consts::appconsts::SHARE_SIZE;
+/// The number of data bytes in the first share of a blob
+pub const FIRST_SPARSE_SHARE_CONTENT_SIZE: usize =
+    celestia_types::consts::appconsts::FIRST_SPARSE_SHARE_CONTENT_SIZE;
+/// The number of data bytes in the following shares of a blob
+pub const CONTINUATION_SPARSE_SHARE_CONTENT_SIZE: usize =
+    celestia_types::consts::appconsts::CONTINUATION_SPARSE_SHARE_CONTENT_SIZE;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
 pub enum Share {
@@ -248,6 +254,35 @@ impl From<&NamespacedShares> for NamespaceGroup {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
 pub struct Blob(pub Vec<Share>);
 
+impl Blob {
+    /// Splits `data` into the sparse shares of a blob in `namespace`.
+    pub fn from_data(namespace: Namespace, data: &[u8]) -> Self {
+        let first_share_len = data.len().min(FIRST_SPARSE_SHARE_CONTENT_SIZE);
+        let (first, rest) = data.split_at(first_share_len);
+        let sequence_len = u32::try_from(data.len()).expect("Blob length must fit in u32");
+
+        let mut shares = vec![sparse_share(namespace, Some(sequence_len), first)];
+        shares.extend(
+            rest.chunks(CONTINUATION_SPARSE_SHARE_CONTENT_SIZE)
+                .map(|data| sparse_share(namespace, None, data)),
+        );
+        Self(shares)
+    }
+}
+
+/// Builds a share of share version 0, starting a sequence of `sequence_len` bytes if it is set.
+fn sparse_share(namespace: Namespace, sequence_len: Option<u32>, data: &[u8]) -> Share {
+    let mut share = Vec::with_capacity(SHARE_SIZE);
+    share.extend_from_slice(namespace.as_ref());
+    share.push(sequence_len.is_some() as u8);
+    if let Some(sequence_len) = sequence_len {
+        share.extend_from_slice(&sequence_len.to_be_bytes());
+    }
+    share.extend_from_slice(data);
+    share.resize(SHARE_SIZE, 0);
+    Share::new(Bytes::from(share))
+}
+
 impl<'a> From<BlobRef<'a>> for Blob {
     fn from(value: BlobRef<'a>) -> Self {
         Self(value.0.to_vec())
@@ -498,6 +533,30 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_blob_from_data() {
+        let namespace = Namespace::const_v0(*b"sov-chunks");
+        for len in [
+            0,
+            1,
+            FIRST_SPARSE_SHARE_CONTENT_SIZE,
+            FIRST_SPARSE_SHARE_CONTENT_SIZE + 1,
+            FIRST_SPARSE_SHARE_CONTENT_SIZE + 2 * CONTINUATION_SPARSE_SHARE_CONTENT_SIZE + 7,
+        ] {
+            let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let blob = Blob::from_data(namespace, &data);
+
+            assert!(blob.0[0].is_sequence_start());
+            assert!(blob.0[1..].iter().all(|share| !share.is_sequence_start()));
+            assert!(blob
+                .0
+                .iter()
+                .all(|share| share.namespace() == namespace
+                    && share.as_serialized().len() == SHARE_SIZE));
+            assert_eq!(data, blob.into_iter().collect::<Vec<u8>>());
+        }
+    }
+
     #[test]
     fn test_share_start_serialization() {
         let hex_blob = "736f762d7465737401000000b801000000b000000004ee8ca2c343fe0acd2b72249c48b56351ebfb4b7eef73ddae363880b61380cc23b3ebf15375aa110d7aa84206b1f22c1885b26e980d5e03244cc588e314b004a60b594d5751dc2a326c18923eaa74b48424c0f246733c6c028d7ee16899ad944400000001000b000000000000000e000000736f762d746573742d746f6b656e8813000000000000a3201954f70ad62230dc3d840a5bf767702c04869e85ab3eee0b962857ba75980000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
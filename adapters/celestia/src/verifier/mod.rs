@@ -3,7 +3,7 @@ use celestia_types::nmt::Namespace;
 use celestia_types::{Commitment, DataAvailabilityHeader, NamespacedShares};
 use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::{
-    self, BlobReaderTrait, BlockHashTrait as BlockHash, BlockHeaderTrait, DaSpec,
+    self, BlobReaderTrait, BlockHashTrait as BlockHash, BlockHeaderTrait, CountedBufReader, DaSpec,
 };
 use sov_rollup_interface::digest::Digest;
 use sov_rollup_interface::zk::ValidityCondition;
@@ -18,7 +18,7 @@ use sov_zk_cycle_macros::cycle_tracker;
 
 use self::address::CelestiaAddress;
 use self::proofs::*;
-use crate::shares::{NamespaceGroup, Share};
+use crate::shares::{Blob, NamespaceGroup, Share};
 use crate::types::ValidationError;
 use crate::utils::read_varint;
 use crate::{pfb_from_iter, BlobWithSender, CelestiaHeader};
@@ -29,6 +29,9 @@ pub struct CelestiaVerifier {
 
 pub const PFB_NAMESPACE: Namespace = Namespace::const_v0([0, 0, 0, 0, 0, 0, 0, 0, 0, 4]);
 pub const PARITY_SHARES_NAMESPACE: Namespace = Namespace::MAX;
+/// The namespace of the shares holding blobs reassembled from chunks. These shares are never
+/// published, so any namespace of sparse shares works.
+pub const REASSEMBLED_BLOBS_NAMESPACE: Namespace = Namespace::const_v0(*b"sov-chunks");
 
 impl BlobReaderTrait for BlobWithSender {
     type Address = CelestiaAddress;
@@ -54,6 +57,13 @@ impl BlobReaderTrait for BlobWithSender {
         self.blob.advance(num_bytes);
         self.verified_data()
     }
+
+    fn from_verified_data(sender: CelestiaAddress, hash: [u8; 32], data: Vec<u8>) -> Self {
+        let mut blob =
+            CountedBufReader::new(Blob::from_data(REASSEMBLED_BLOBS_NAMESPACE, &data).into_iter());
+        blob.advance(data.len());
+        Self { blob, sender, hash }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash, Serialize, Deserialize)]
@@ -302,6 +312,10 @@ impl da::DaVerifier for CelestiaVerifier {
             return Err(ValidationError::InvalidEtxProof("more proofs than blobs"));
         }
 
+        if tx_iter.next().is_some() {
+            return Err(ValidationError::UnexpectedTx);
+        }
+
         Ok(validity_condition)
     }
 }
@@ -3,6 +3,8 @@
 
 #[cfg(feature = "native")]
 mod service;
+#[cfg(feature = "native")]
+mod storable;
 mod types;
 mod validity_condition;
 /// Contains DaSpec and DaVerifier
@@ -10,6 +12,8 @@ pub mod verifier;
 
 #[cfg(feature = "native")]
 pub use service::*;
+#[cfg(feature = "native")]
+pub use storable::StorableMockDaService;
 pub use types::*;
 pub use validity_condition::*;
 pub use verifier::MockDaSpec;
@@ -9,15 +9,15 @@ use pin_project::pin_project;
 use sha2::Digest;
 use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, Time};
 use sov_rollup_interface::maybestd::sync::Arc;
-use sov_rollup_interface::services::da::{DaService, SlotData};
+use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService, SlotData};
 use tokio::sync::{broadcast, RwLock, RwLockWriteGuard};
 use tokio::time;
 
-use crate::types::{MockAddress, MockBlob, MockBlock, MockDaVerifier};
+use crate::types::{MockAddress, MockBlob, MockBlock, MockDaVerifier, PlannedFork};
 use crate::verifier::MockDaSpec;
 use crate::{MockBlockHeader, MockHash};
 
-const GENESIS_HEADER: MockBlockHeader = MockBlockHeader {
+pub(crate) const GENESIS_HEADER: MockBlockHeader = MockBlockHeader {
     prev_hash: MockHash([0; 32]),
     hash: MockHash([1; 32]),
     height: 0,
@@ -25,37 +25,6 @@ const GENESIS_HEADER: MockBlockHeader = MockBlockHeader {
     time: Time::from_secs(1672531200),
 };
 
-/// Definition of a fork that will be executed in `MockDaService` at specified height
-pub struct PlannedFork {
-    trigger_at_height: u64,
-    fork_height: u64,
-    blobs: Vec<Vec<u8>>,
-}
-
-impl PlannedFork {
-    /// Creates new [`PlannedFork`]. Panics if some parameters are invalid.
-    ///
-    /// # Arguments
-    ///
-    /// * `trigger_at_height` - Height at which fork is "noticed".
-    /// * `fork_height` - Height at which chain forked. Height of the first block in `blobs` will be `fork_height + 1`
-    /// * `blobs` - Blobs that will be added after fork. Single blob per each block
-    pub fn new(trigger_at_height: u64, fork_height: u64, blobs: Vec<Vec<u8>>) -> Self {
-        if fork_height > trigger_at_height {
-            panic!("Fork height must be less than trigger height");
-        }
-        let fork_len = (trigger_at_height - fork_height) as usize;
-        if fork_len < blobs.len() {
-            panic!("Not enough blobs for fork to be produced at given height");
-        }
-        Self {
-            trigger_at_height,
-            fork_height,
-            blobs,
-        }
-    }
-}
-
 #[derive(Clone)]
 /// DaService used in tests.
 /// Currently only supports single blob per block.
@@ -68,6 +37,8 @@ pub struct MockDaService {
     blocks_to_finality: u32,
     /// Used for calculating correct finality from state of `blocks`
     finalized_header_sender: broadcast::Sender<MockBlockHeader>,
+    /// Notifies subscribers every time the head changes, including on forks.
+    head_header_sender: broadcast::Sender<MockBlockHeader>,
     wait_attempts: usize,
     planned_fork: Arc<Mutex<Option<PlannedFork>>>,
 }
@@ -93,6 +64,7 @@ impl MockDaService {
             blocks: Arc::new(Default::default()),
             blocks_to_finality,
             finalized_header_sender: tx,
+            head_header_sender: broadcast::channel(16).0,
             wait_attempts: 100_0000,
             planned_fork: Arc::new(Mutex::new(None)),
         }
@@ -144,6 +116,7 @@ impl MockDaService {
             );
         }
         blocks.retain(|b| b.header().height <= height);
+        self.notify_head(&blocks);
         for blob in blobs {
             let _ = self
                 .add_blob(&blob, Default::default(), &mut blocks)
@@ -217,6 +190,7 @@ impl MockDaService {
             blobs: vec![blob],
         };
         blocks.push_back(block);
+        self.notify_head(blocks);
 
         // Enough blocks to finalize block
         if blocks.len() > self.blocks_to_finality as usize {
@@ -230,6 +204,15 @@ impl MockDaService {
         Ok(height)
     }
 
+    fn notify_head(&self, blocks: &VecDeque<MockBlock>) {
+        let head = blocks
+            .back()
+            .map(|b| b.header().clone())
+            .unwrap_or(GENESIS_HEADER);
+        // Sending only fails when nobody is subscribed.
+        let _ = self.head_header_sender.send(head);
+    }
+
     /// Executes planned fork if it is planned at given height
     async fn planned_fork_handler(&self, height: u64) -> anyhow::Result<()> {
         let planned_fork_now = {
@@ -252,14 +235,14 @@ impl MockDaService {
 }
 
 #[pin_project]
-/// Stream of finalized headers
+/// Stream of finalized or head headers
 pub struct MockDaBlockHeaderStream {
     #[pin]
     inner: tokio_stream::wrappers::BroadcastStream<MockBlockHeader>,
 }
 
 impl MockDaBlockHeaderStream {
-    /// Create new stream of finalized headers
+    /// Create new stream of headers
     pub fn new(receiver: broadcast::Receiver<MockBlockHeader>) -> Self {
         Self {
             inner: tokio_stream::wrappers::BroadcastStream::new(receiver),
@@ -342,6 +325,15 @@ impl DaService for MockDaService {
             .unwrap_or(GENESIS_HEADER))
     }
 
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        let receiver = self.head_header_sender.subscribe();
+        Ok(MockDaBlockHeaderStream::new(receiver))
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        self.blocks_to_finality as u64
+    }
+
     fn extract_relevant_blobs(
         &self,
         block: &Self::FilteredBlock,
@@ -368,17 +360,20 @@ impl DaService for MockDaService {
 
     async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
         let mut blocks = self.blocks.write().await;
-        self.add_blob(Default::default(), proof.to_vec(), &mut blocks)
+        let envelope = AggregatedProofEnvelope::new(proof.to_vec());
+        self.add_blob(Default::default(), envelope.encode(), &mut blocks)
             .await
     }
 
     async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
         let blobs = self.get_block_at(height).await?.blobs;
-        Ok(blobs.into_iter().map(|b| b.zk_proofs_data).collect())
+        Ok(AggregatedProofEnvelope::decode_proofs(
+            blobs.into_iter().map(|b| b.zk_proofs_data),
+        ))
     }
 }
 
-fn hash_to_array(bytes: &[u8]) -> [u8; 32] {
+pub(crate) fn hash_to_array(bytes: &[u8]) -> [u8; 32] {
     let mut hasher = sha2::Sha256::new();
     hasher.update(bytes);
     let result = hasher.finalize();
@@ -388,7 +383,7 @@ fn hash_to_array(bytes: &[u8]) -> [u8; 32] {
         .expect("SHA256 should be 32 bytes")
 }
 
-fn block_hash(
+pub(crate) fn block_hash(
     height: u64,
     data_hash: [u8; 32],
     proof_hash: [u8; 32],
@@ -405,6 +400,10 @@ fn block_hash(
 #[cfg(test)]
 mod tests {
     use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait};
+    use sov_rollup_interface::test_utils::{
+        test_aggregated_proof_round_trip, test_extraction_round_trip, test_finality_monotonicity,
+        test_head_tracking, test_partial_reads,
+    };
     use tokio::task::JoinHandle;
     use tokio_stream::StreamExt;
 
@@ -630,6 +629,32 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_da_service_conformance() {
+        let da = MockDaService::with_finality(MockAddress::new([1; 32]), 3);
+        test_finality_monotonicity(&da, 8).await;
+        test_head_tracking(&da, 8).await;
+
+        let block = da.get_block_at(1).await.unwrap();
+        test_extraction_round_trip(&da, &MockDaVerifier::default(), &block).await;
+
+        let data = vec![1, 2, 3, 4, 5];
+        test_partial_reads(
+            MockBlob::new(data.clone(), MockAddress::new([1; 32]), [2; 32]),
+            &data,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregated_proof_conformance() {
+        let da = MockDaService::new(MockAddress::new([1; 32]));
+        da.send_transaction(&[1, 2, 3]).await.unwrap();
+
+        test_aggregated_proof_round_trip(&da, &[4, 5, 6]).await;
+        // Batches are not returned as proofs.
+        assert!(da.get_aggregated_proofs_at(1).await.unwrap().is_empty());
+    }
+
     mod reo4g_control {
         use super::*;
         use crate::{MockAddress, MockDaService};
@@ -669,6 +694,25 @@ mod tests {
             assert_ne!(head_before, head_after);
         }
 
+        #[tokio::test]
+        async fn test_reorg_streams_head_of_fork() {
+            let da = MockDaService::with_finality(MockAddress::new([1; 32]), 4);
+            da.send_transaction(&[1]).await.unwrap();
+            da.send_transaction(&[2]).await.unwrap();
+            da.send_transaction(&[3]).await.unwrap();
+            let mut heads = da.subscribe_head_header().await.unwrap();
+
+            da.fork_at(1, vec![vec![4]]).await.unwrap();
+
+            let fork_point = heads.next().await.unwrap().unwrap();
+            assert_eq!(1, fork_point.height());
+            let new_head = heads.next().await.unwrap().unwrap();
+            assert_eq!(2, new_head.height());
+            assert_eq!(fork_point.hash(), new_head.prev_hash());
+            assert_eq!(new_head, da.get_head_block_header().await.unwrap());
+            assert_eq!(4, da.max_reorg_depth());
+        }
+
         #[tokio::test]
         async fn test_attempt_reorg_after_finalized() {
             let da = MockDaService::with_finality(MockAddress::new([1; 32]), 2);
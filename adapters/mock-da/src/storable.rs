@@ -0,0 +1,721 @@
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, Weak};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, NanoSeconds, Time};
+use sov_rollup_interface::maybestd::sync::Arc;
+use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService};
+use tokio::sync::broadcast;
+use tokio::time;
+
+use crate::service::{block_hash, hash_to_array, GENESIS_HEADER};
+use crate::types::{MockAddress, MockBlob, MockBlock, MockDaConfig, MockDaVerifier, PlannedFork};
+use crate::verifier::MockDaSpec;
+use crate::{MockBlockHeader, MockDaBlockHeaderStream, MockHash};
+
+/// How often the background task produces due blocks and announces newly finalized headers.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long a connection waits for another process to release the database.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+    height INTEGER PRIMARY KEY,
+    prev_hash BLOB NOT NULL,
+    hash BLOB NOT NULL,
+    time_secs INTEGER NOT NULL,
+    time_nanos INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS blobs (
+    height INTEGER NOT NULL,
+    idx INTEGER NOT NULL,
+    sender BLOB NOT NULL,
+    hash BLOB NOT NULL,
+    data BLOB NOT NULL,
+    zk_proofs_data BLOB NOT NULL,
+    PRIMARY KEY (height, idx)
+);
+CREATE TABLE IF NOT EXISTS pending_blobs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    sender BLOB NOT NULL,
+    data BLOB NOT NULL,
+    zk_proofs_data BLOB NOT NULL
+);
+CREATE TABLE IF NOT EXISTS executed_forks (
+    trigger_at_height INTEGER PRIMARY KEY
+);
+";
+
+/// A blob waiting to be included in a block: its sender, data and aggregated proof data.
+type BlobToInclude = (MockAddress, Vec<u8>, Vec<u8>);
+
+#[derive(Clone)]
+/// DaService used in local tests, which keeps the chain in a SQLite database.
+/// Several rollup nodes, in separate processes, can share the chain by pointing at the same
+/// database, and the chain survives restarts.
+/// Height of the first block is 1.
+pub struct StorableMockDaService {
+    sequencer_da_address: MockAddress,
+    layer: Arc<Mutex<MockDaLayer>>,
+    finalized_header_sender: broadcast::Sender<MockBlockHeader>,
+    head_header_sender: broadcast::Sender<MockBlockHeader>,
+    wait_attempts: usize,
+}
+
+/// The connection to the database and the parameters of the chain.
+struct MockDaLayer {
+    connection: Connection,
+    finalization_blocks: u32,
+    block_time: Option<Duration>,
+    planned_forks: Vec<PlannedFork>,
+    /// The height of the last finalized header sent to the subscribers.
+    last_notified_height: u64,
+    /// The hash of the last head sent to the subscribers.
+    last_notified_head: MockHash,
+}
+
+impl StorableMockDaService {
+    /// Creates a new [`StorableMockDaService`] from the config, opening or creating the database
+    /// at [`MockDaConfig::db_path`].
+    pub fn from_config(config: &MockDaConfig) -> anyhow::Result<Self> {
+        let connection = match &config.db_path {
+            Some(path) => open_file(path)?,
+            None => Connection::open_in_memory()?,
+        };
+        for planned_fork in &config.planned_forks {
+            planned_fork.validate()?;
+        }
+        connection.execute_batch(SCHEMA)?;
+
+        let mut layer = MockDaLayer {
+            connection,
+            finalization_blocks: config.finalization_blocks,
+            block_time: config.block_time_ms.map(Duration::from_millis),
+            planned_forks: config.planned_forks.clone(),
+            last_notified_height: 0,
+            last_notified_head: GENESIS_HEADER.hash,
+        };
+        layer.last_notified_height = layer.finalized_height()?;
+        layer.last_notified_head = read_head(&layer.connection)?.hash;
+
+        let (tx, _) = broadcast::channel(16);
+        let (head_tx, _) = broadcast::channel(16);
+        let layer = Arc::new(Mutex::new(layer));
+        spawn_poller(Arc::downgrade(&layer), tx.clone(), head_tx.clone());
+        Ok(Self {
+            sequencer_da_address: config.sender_address,
+            layer,
+            finalized_header_sender: tx,
+            head_header_sender: head_tx,
+            wait_attempts: 100_0000,
+        })
+    }
+
+    /// Creates a new [`StorableMockDaService`] keeping the chain in memory, with given finality.
+    pub fn in_memory(sequencer_da_address: MockAddress, finalization_blocks: u32) -> Self {
+        Self::from_config(&MockDaConfig {
+            sender_address: sequencer_da_address,
+            finalization_blocks,
+            ..Default::default()
+        })
+        .expect("Opening an in-memory database is infallible")
+    }
+
+    /// Get sequencer address
+    pub fn get_sequencer_address(&self) -> MockAddress {
+        self.sequencer_da_address
+    }
+
+    /// Change number of wait attempts before giving up on waiting for block
+    pub fn set_wait_attempts(&mut self, wait_attempts: usize) {
+        self.wait_attempts = wait_attempts;
+    }
+
+    fn layer(&self) -> MutexGuard<'_, MockDaLayer> {
+        self.layer.lock().unwrap()
+    }
+
+    async fn wait_for_height(&self, height: u64) -> anyhow::Result<MockBlock> {
+        // Waits self.wait_attempts * 10ms to get block at height
+        for _ in 0..self.wait_attempts {
+            let block = read_block(&self.layer().connection, height)?;
+            if let Some(block) = block {
+                return Ok(block);
+            }
+            time::sleep(Duration::from_millis(10)).await;
+        }
+        anyhow::bail!(
+            "No block at height={height} has been sent in {:?}",
+            Duration::from_millis((self.wait_attempts * 10) as u64),
+        );
+    }
+
+    /// Submits a blob and returns the height of the block including it.
+    fn submit(&self, data: Vec<u8>, zk_proofs_data: Vec<u8>) -> anyhow::Result<u64> {
+        let mut layer = self.layer();
+        let height = layer.submit((self.sequencer_da_address, data, zk_proofs_data))?;
+        layer.notify_head(&self.head_header_sender)?;
+        layer.notify_finalized(&self.finalized_header_sender)?;
+        Ok(height)
+    }
+}
+
+impl MockDaLayer {
+    fn finalized_height(&self) -> anyhow::Result<u64> {
+        read_finalized_height(&self.connection, self.finalization_blocks)
+    }
+
+    fn submit(&mut self, blob: BlobToInclude) -> anyhow::Result<u64> {
+        let block_time = self.block_time;
+        let tx = self
+            .connection
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let height = match block_time {
+            None => insert_block(&tx, vec![blob])?.height,
+            Some(block_time) => {
+                let (sender, data, zk_proofs_data) = blob;
+                tx.execute(
+                    "INSERT INTO pending_blobs (sender, data, zk_proofs_data) VALUES (?1, ?2, ?3)",
+                    params![sender.as_ref(), data, zk_proofs_data],
+                )?;
+                let id = tx.last_insert_rowid();
+                produce_block_if_due(&tx, block_time)?;
+                let is_pending = tx
+                    .query_row(
+                        "SELECT 1 FROM pending_blobs WHERE id = ?1",
+                        params![id],
+                        |_| Ok(()),
+                    )
+                    .optional()?
+                    .is_some();
+                let head_height = read_head(&tx)?.height;
+                if is_pending {
+                    head_height + 1
+                } else {
+                    head_height
+                }
+            }
+        };
+        tx.commit()?;
+        Ok(height)
+    }
+
+    /// Produces a block with the pending blobs if the block time elapsed since the head.
+    fn produce_due_block(&mut self) -> anyhow::Result<()> {
+        let Some(block_time) = self.block_time else {
+            return Ok(());
+        };
+        let tx = self
+            .connection
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        produce_block_if_due(&tx, block_time)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Executes the planned forks triggered at given height, which weren't executed yet by any
+    /// service sharing the database.
+    fn execute_planned_forks(&mut self, height: u64, sender: MockAddress) -> anyhow::Result<()> {
+        let planned_forks: Vec<PlannedFork> = self
+            .planned_forks
+            .iter()
+            .filter(|fork| fork.trigger_at_height == height)
+            .cloned()
+            .collect();
+        for planned_fork in planned_forks {
+            let tx = self
+                .connection
+                .transaction_with_behavior(TransactionBehavior::Immediate)?;
+            let finalized_height = read_finalized_height(&tx, self.finalization_blocks)?;
+            let newly_executed = tx.execute(
+                "INSERT OR IGNORE INTO executed_forks (trigger_at_height) VALUES (?1)",
+                params![planned_fork.trigger_at_height],
+            )? > 0;
+            if !newly_executed {
+                continue;
+            }
+            if finalized_height > planned_fork.fork_height {
+                tracing::warn!(
+                    "Skipping planned fork at height {}, last finalized height is {}",
+                    planned_fork.fork_height,
+                    finalized_height
+                );
+            } else {
+                tx.execute(
+                    "DELETE FROM blobs WHERE height > ?1",
+                    params![planned_fork.fork_height],
+                )?;
+                tx.execute(
+                    "DELETE FROM blocks WHERE height > ?1",
+                    params![planned_fork.fork_height],
+                )?;
+                for blob in planned_fork.blobs {
+                    insert_block(&tx, vec![(sender, blob, Vec::new())])?;
+                }
+            }
+            tx.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Sends the headers finalized since the last call to the subscribers.
+    fn notify_finalized(
+        &mut self,
+        sender: &broadcast::Sender<MockBlockHeader>,
+    ) -> anyhow::Result<()> {
+        let finalized_height = self.finalized_height()?;
+        while self.last_notified_height < finalized_height {
+            let height = self.last_notified_height + 1;
+            if let Some(header) = read_header(&self.connection, height)? {
+                tracing::debug!("Finalized MockHeader: {}", header);
+                // There might be no subscribers.
+                let _ = sender.send(header);
+            }
+            self.last_notified_height = height;
+        }
+        Ok(())
+    }
+
+    /// Sends the head to the subscribers if it changed since the last call.
+    fn notify_head(&mut self, sender: &broadcast::Sender<MockBlockHeader>) -> anyhow::Result<()> {
+        let head = read_head(&self.connection)?;
+        if head.hash != self.last_notified_head {
+            self.last_notified_head = head.hash;
+            // There might be no subscribers.
+            let _ = sender.send(head);
+        }
+        Ok(())
+    }
+}
+
+fn open_file(path: &Path) -> anyhow::Result<Connection> {
+    let connection = Connection::open(path)?;
+    connection.busy_timeout(BUSY_TIMEOUT)?;
+    // Lets readers in other processes proceed while a block is written.
+    connection
+        .pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+    Ok(connection)
+}
+
+/// Produces blocks and announces new heads and finalized headers as long as the service is alive,
+/// so blocks are produced on time and blocks submitted by other processes are noticed.
+fn spawn_poller(
+    layer: Weak<Mutex<MockDaLayer>>,
+    sender: broadcast::Sender<MockBlockHeader>,
+    head_sender: broadcast::Sender<MockBlockHeader>,
+) {
+    tokio::spawn(async move {
+        loop {
+            time::sleep(POLL_INTERVAL).await;
+            let Some(layer) = layer.upgrade() else {
+                break;
+            };
+            let result = {
+                let mut layer = layer.lock().unwrap();
+                layer
+                    .produce_due_block()
+                    .and_then(|_| layer.notify_head(&head_sender))
+                    .and_then(|_| layer.notify_finalized(&sender))
+            };
+            if let Err(e) = result {
+                tracing::warn!("Failed to update the mock da chain: {}", e);
+            }
+        }
+    });
+}
+
+fn produce_block_if_due(connection: &Connection, block_time: Duration) -> anyhow::Result<()> {
+    let head = read_head(connection)?;
+    let now = Time::now();
+    if head.height > 0 && as_duration(&now) < as_duration(&head.time) + block_time {
+        return Ok(());
+    }
+
+    let mut statement =
+        connection.prepare("SELECT sender, data, zk_proofs_data FROM pending_blobs ORDER BY id")?;
+    let blobs = statement
+        .query_map([], |row| {
+            Ok((
+                to_address(row.get(0)?)?,
+                row.get::<_, Vec<u8>>(1)?,
+                row.get::<_, Vec<u8>>(2)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    connection.execute("DELETE FROM pending_blobs", [])?;
+    insert_block(connection, blobs)?;
+    Ok(())
+}
+
+fn insert_block(
+    connection: &Connection,
+    blobs: Vec<BlobToInclude>,
+) -> anyhow::Result<MockBlockHeader> {
+    let head = read_head(connection)?;
+    let height = head.height + 1;
+
+    let mut data_hashes = Vec::new();
+    let mut proof_hashes = Vec::new();
+    for (idx, (sender, data, zk_proofs_data)) in blobs.iter().enumerate() {
+        let data_hash = hash_to_array(data);
+        data_hashes.extend_from_slice(&data_hash);
+        proof_hashes.extend_from_slice(&hash_to_array(zk_proofs_data));
+        connection.execute(
+            "INSERT INTO blobs (height, idx, sender, hash, data, zk_proofs_data) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![height, idx, sender.as_ref(), &data_hash[..], data, zk_proofs_data],
+        )?;
+    }
+
+    let header = MockBlockHeader {
+        prev_hash: head.hash,
+        hash: block_hash(
+            height,
+            hash_to_array(&data_hashes),
+            hash_to_array(&proof_hashes),
+            head.hash.into(),
+        ),
+        height,
+        time: Time::now(),
+    };
+    connection.execute(
+        "INSERT INTO blocks (height, prev_hash, hash, time_secs, time_nanos) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            height,
+            header.prev_hash.as_ref(),
+            header.hash.as_ref(),
+            header.time.secs(),
+            header.time.subsec_nanos()
+        ],
+    )?;
+    Ok(header)
+}
+
+fn read_head(connection: &Connection) -> anyhow::Result<MockBlockHeader> {
+    let head = connection
+        .query_row(
+            "SELECT height, prev_hash, hash, time_secs, time_nanos FROM blocks ORDER BY height DESC LIMIT 1",
+            [],
+            to_header,
+        )
+        .optional()?;
+    Ok(head.unwrap_or(GENESIS_HEADER))
+}
+
+fn read_finalized_height(connection: &Connection, finalization_blocks: u32) -> anyhow::Result<u64> {
+    let head = read_head(connection)?;
+    Ok(head.height.saturating_sub(finalization_blocks as u64))
+}
+
+fn read_header(connection: &Connection, height: u64) -> anyhow::Result<Option<MockBlockHeader>> {
+    Ok(connection
+        .query_row(
+            "SELECT height, prev_hash, hash, time_secs, time_nanos FROM blocks WHERE height = ?1",
+            params![height],
+            to_header,
+        )
+        .optional()?)
+}
+
+fn read_block(connection: &Connection, height: u64) -> anyhow::Result<Option<MockBlock>> {
+    let Some(header) = read_header(connection, height)? else {
+        return Ok(None);
+    };
+    let mut statement = connection.prepare(
+        "SELECT sender, hash, data, zk_proofs_data FROM blobs WHERE height = ?1 ORDER BY idx",
+    )?;
+    let blobs = statement
+        .query_map(params![height], |row| {
+            Ok(MockBlob::new_with_zkp_proof(
+                row.get(2)?,
+                row.get(3)?,
+                to_address(row.get(0)?)?,
+                to_hash(row.get(1)?)?.0,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Some(MockBlock {
+        header,
+        validity_cond: Default::default(),
+        blobs,
+    }))
+}
+
+fn to_header(row: &rusqlite::Row<'_>) -> rusqlite::Result<MockBlockHeader> {
+    let nanos = NanoSeconds::new(row.get(4)?).map_err(|e| conversion_error(4, e))?;
+    Ok(MockBlockHeader {
+        height: row.get(0)?,
+        prev_hash: to_hash(row.get(1)?)?,
+        hash: to_hash(row.get(2)?)?,
+        time: Time::new(row.get(3)?, nanos),
+    })
+}
+
+fn to_hash(bytes: Vec<u8>) -> rusqlite::Result<MockHash> {
+    <[u8; 32]>::try_from(bytes)
+        .map(MockHash)
+        .map_err(|_| conversion_error(0, anyhow::anyhow!("A mock hash must be 32 bytes")))
+}
+
+fn to_address(bytes: Vec<u8>) -> rusqlite::Result<MockAddress> {
+    MockAddress::try_from(&bytes[..]).map_err(|e| conversion_error(0, e))
+}
+
+fn conversion_error(
+    column: usize,
+    e: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(column, rusqlite::types::Type::Blob, e.into())
+}
+
+fn as_duration(time: &Time) -> Duration {
+    Duration::new(time.secs() as u64, time.subsec_nanos())
+}
+
+#[async_trait]
+impl DaService for StorableMockDaService {
+    type Spec = MockDaSpec;
+    type Verifier = MockDaVerifier;
+    type FilteredBlock = MockBlock;
+    type HeaderStream = MockDaBlockHeaderStream;
+    type TransactionId = ();
+    type Error = anyhow::Error;
+
+    /// Gets block at given height
+    /// If block is not available, waits until it is
+    async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
+        if height == 0 {
+            anyhow::bail!("The lowest queryable block should be > 0");
+        }
+        // Fork logic
+        {
+            let mut layer = self.layer();
+            layer.execute_planned_forks(height, self.sequencer_da_address)?;
+            layer.notify_head(&self.head_header_sender)?;
+        }
+        // Block until there's something
+        self.wait_for_height(height).await
+    }
+
+    async fn get_last_finalized_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        let layer = self.layer();
+        let finalized_height = layer.finalized_height()?;
+        Ok(read_header(&layer.connection, finalized_height)?.unwrap_or(GENESIS_HEADER))
+    }
+
+    async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        let receiver = self.finalized_header_sender.subscribe();
+        Ok(MockDaBlockHeaderStream::new(receiver))
+    }
+
+    async fn get_head_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        read_head(&self.layer().connection)
+    }
+
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        let receiver = self.head_header_sender.subscribe();
+        Ok(MockDaBlockHeaderStream::new(receiver))
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        self.layer().finalization_blocks as u64
+    }
+
+    fn extract_relevant_blobs(
+        &self,
+        block: &Self::FilteredBlock,
+    ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
+        block.blobs.clone()
+    }
+
+    async fn get_extraction_proof(
+        &self,
+        _block: &Self::FilteredBlock,
+        _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+    ) -> (
+        <Self::Spec as DaSpec>::InclusionMultiProof,
+        <Self::Spec as DaSpec>::CompletenessProof,
+    ) {
+        ([0u8; 32], ())
+    }
+
+    async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+        self.submit(blob.to_vec(), Default::default())?;
+        Ok(())
+    }
+
+    async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
+        let envelope = AggregatedProofEnvelope::new(proof.to_vec());
+        self.submit(Default::default(), envelope.encode())
+    }
+
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let blobs = self.get_block_at(height).await?.blobs;
+        Ok(AggregatedProofEnvelope::decode_proofs(
+            blobs.into_iter().map(|b| b.zk_proofs_data),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sov_rollup_interface::da::BlobReaderTrait;
+    use sov_rollup_interface::services::da::SlotData;
+    use sov_rollup_interface::test_utils::{
+        test_aggregated_proof_round_trip, test_extraction_round_trip, test_finality_monotonicity,
+        test_head_tracking,
+    };
+
+    use super::*;
+
+    fn config(db_path: &Path) -> MockDaConfig {
+        MockDaConfig {
+            sender_address: MockAddress::new([1; 32]),
+            db_path: Some(db_path.to_path_buf()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config(&dir.path().join("mock_da.sqlite"));
+
+        let block_2 = {
+            let da = StorableMockDaService::from_config(&config).unwrap();
+            da.send_transaction(&[1, 2, 3]).await.unwrap();
+            da.send_transaction(&[4, 5, 6]).await.unwrap();
+            da.get_block_at(2).await.unwrap()
+        };
+
+        let mut da = StorableMockDaService::from_config(&config).unwrap();
+        da.set_wait_attempts(2);
+        assert_eq!(block_2, da.get_block_at(2).await.unwrap());
+        assert_eq!(block_2.header, da.get_head_block_header().await.unwrap());
+
+        da.send_transaction(&[7, 8, 9]).await.unwrap();
+        let mut block_3 = da.get_block_at(3).await.unwrap();
+        assert_eq!(block_2.hash(), block_3.header().prev_hash().0);
+        assert_eq!(&[7, 8, 9], block_3.blobs[0].full_data());
+    }
+
+    #[tokio::test]
+    async fn test_services_share_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config(&dir.path().join("mock_da.sqlite"));
+        let sequencer = StorableMockDaService::from_config(&config).unwrap();
+        let full_node = StorableMockDaService::from_config(&MockDaConfig {
+            sender_address: MockAddress::new([2; 32]),
+            ..config
+        })
+        .unwrap();
+
+        sequencer.send_transaction(&[1, 2, 3]).await.unwrap();
+        full_node.send_transaction(&[4, 5, 6]).await.unwrap();
+
+        let mut block_2 = sequencer.get_block_at(2).await.unwrap();
+        assert_eq!(block_2, full_node.get_block_at(2).await.unwrap());
+        assert_eq!(MockAddress::new([2; 32]), block_2.blobs[0].sender());
+        assert_eq!(&[4, 5, 6], block_2.blobs[0].full_data());
+    }
+
+    #[tokio::test]
+    async fn test_block_time_groups_blobs() {
+        let dir = tempfile::tempdir().unwrap();
+        let da = StorableMockDaService::from_config(&MockDaConfig {
+            block_time_ms: Some(200),
+            ..config(&dir.path().join("mock_da.sqlite"))
+        })
+        .unwrap();
+
+        // The first blob starts the chain.
+        da.send_transaction(&[1]).await.unwrap();
+        da.send_transaction(&[2]).await.unwrap();
+        da.send_transaction(&[3]).await.unwrap();
+        let proof_height = da.send_aggregated_zk_proof(&[4]).await.unwrap();
+        assert_eq!(2, proof_height);
+        assert_eq!(1, da.get_head_block_header().await.unwrap().height());
+
+        let mut block_2 = da.get_block_at(2).await.unwrap();
+        let data: Vec<Vec<u8>> = block_2
+            .blobs
+            .iter_mut()
+            .map(|blob| blob.full_data().to_vec())
+            .collect();
+        assert_eq!(vec![vec![2], vec![3], vec![]], data);
+        assert_eq!(
+            vec![vec![4]],
+            da.get_aggregated_proofs_at(proof_height).await.unwrap()
+        );
+
+        // Blocks keep being produced without submissions.
+        let block_3 = da.get_block_at(3).await.unwrap();
+        assert!(block_3.blobs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_planned_fork_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = MockDaConfig {
+            finalization_blocks: 4,
+            planned_forks: vec![PlannedFork::new(4, 2, vec![vec![13], vec![14]])],
+            ..config(&dir.path().join("mock_da.sqlite"))
+        };
+        let mut da = StorableMockDaService::from_config(&config).unwrap();
+        da.set_wait_attempts(2);
+
+        for i in 1..=4 {
+            da.send_transaction(&[i]).await.unwrap();
+        }
+        let block_2 = da.get_block_at(2).await.unwrap();
+        let block_3_before = da.get_block_at(3).await.unwrap();
+
+        // Fork is happening!
+        let block_4 = da.get_block_at(4).await.unwrap();
+        let mut block_3_after = da.get_block_at(3).await.unwrap();
+        assert_ne!(block_3_before, block_3_after);
+        assert_eq!(block_2.header().hash(), block_3_after.header().prev_hash());
+        assert_eq!(block_3_after.header().hash(), block_4.header().prev_hash());
+        assert_eq!(&[13], block_3_after.blobs[0].full_data());
+
+        // The fork is executed once, including by a restarted service.
+        let restarted = StorableMockDaService::from_config(&config).unwrap();
+        assert_eq!(block_4, restarted.get_block_at(4).await.unwrap());
+    }
+
+    #[test]
+    fn test_invalid_planned_fork() {
+        let config = MockDaConfig {
+            planned_forks: vec![PlannedFork {
+                trigger_at_height: 2,
+                fork_height: 3,
+                blobs: vec![],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            "Fork height must be less than trigger height",
+            StorableMockDaService::from_config(&config)
+                .err()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_da_service_conformance() {
+        let da = StorableMockDaService::in_memory(MockAddress::new([1; 32]), 3);
+        test_finality_monotonicity(&da, 8).await;
+        test_head_tracking(&da, 8).await;
+
+        let block = da.get_block_at(1).await.unwrap();
+        test_extraction_round_trip(&da, &MockDaVerifier::default(), &block).await;
+        test_aggregated_proof_round_trip(&da, &[4, 5, 6]).await;
+    }
+}
@@ -2,6 +2,7 @@ mod address;
 
 use std::fmt::{Debug, Formatter};
 use std::hash::Hasher;
+use std::path::PathBuf;
 
 pub use address::{MockAddress, MOCK_SEQUENCER_DA_ADDRESS};
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -131,10 +132,68 @@ impl BlockHeaderTrait for MockBlockHeader {
 }
 
 /// The configuration for mock da
-#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
 pub struct MockDaConfig {
     /// The address to use to "submit" blobs on the mock da layer
     pub sender_address: MockAddress,
+    /// The path of the SQLite database holding the mock da chain. Rollup nodes configured with
+    /// the same path share the chain, which also survives restarts. The chain is kept in memory
+    /// if no path is set.
+    #[serde(default)]
+    pub db_path: Option<PathBuf>,
+    /// How many blocks should be produced on top of a block before it is finalized.
+    /// 0 means instant finality.
+    #[serde(default)]
+    pub finalization_blocks: u32,
+    /// The time between blocks, in milliseconds. Blobs submitted in the meantime are included
+    /// in the next block. If not set, each submitted blob is included in a block of its own.
+    #[serde(default)]
+    pub block_time_ms: Option<u64>,
+    /// Forks that the mock da layer executes once.
+    #[serde(default)]
+    pub planned_forks: Vec<PlannedFork>,
+}
+
+/// Definition of a fork that will be executed in `MockDaService` at specified height
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PlannedFork {
+    pub(crate) trigger_at_height: u64,
+    pub(crate) fork_height: u64,
+    pub(crate) blobs: Vec<Vec<u8>>,
+}
+
+impl PlannedFork {
+    /// Creates new [`PlannedFork`]. Panics if some parameters are invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger_at_height` - Height at which fork is "noticed".
+    /// * `fork_height` - Height at which chain forked. Height of the first block in `blobs` will be `fork_height + 1`
+    /// * `blobs` - Blobs that will be added after fork. Single blob per each block
+    pub fn new(trigger_at_height: u64, fork_height: u64, blobs: Vec<Vec<u8>>) -> Self {
+        let fork = Self {
+            trigger_at_height,
+            fork_height,
+            blobs,
+        };
+        if let Err(e) = fork.validate() {
+            panic!("{}", e);
+        }
+        fork
+    }
+
+    /// Checks the parameters of a fork which wasn't created with [`PlannedFork::new`], for
+    /// example read from a config.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.fork_height > self.trigger_at_height {
+            anyhow::bail!("Fork height must be less than trigger height");
+        }
+        let fork_len = (self.trigger_at_height - self.fork_height) as usize;
+        if fork_len < self.blobs.len() {
+            anyhow::bail!("Not enough blobs for fork to be produced at given height");
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Default)]
@@ -26,6 +26,13 @@ impl BlobReaderTrait for MockBlob {
         self.data.advance(num_bytes);
         self.verified_data()
     }
+
+    fn from_verified_data(sender: Self::Address, hash: [u8; 32], data: Vec<u8>) -> Self {
+        let len = data.len();
+        let mut blob = MockBlob::new(data, sender, hash);
+        blob.data.advance(len);
+        blob
+    }
 }
 
 /// A [`sov_rollup_interface::da::DaSpec`] suitable for testing.
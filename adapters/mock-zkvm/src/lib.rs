@@ -12,9 +12,39 @@ use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::zk::{Matches, StateTransitionData, ValidityCondition};
 
 /// A mock commitment to a particular zkVM program.
-#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, BorshDeserialize, BorshSerialize)]
 pub struct MockCodeCommitment(pub [u8; 32]);
 
+impl Serialize for MockCodeCommitment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            Serialize::serialize(&hex::encode(self.0), serializer)
+        } else {
+            Serialize::serialize(&self.0, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MockCodeCommitment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let hex_commitment: String = Deserialize::deserialize(deserializer)?;
+            let bytes = hex::decode(hex_commitment).map_err(serde::de::Error::custom)?;
+            let commitment = <[u8; 32]>::try_from(bytes.as_slice())
+                .map_err(|_| serde::de::Error::custom("Code commitment must be 32 bytes long"))?;
+            Ok(Self(commitment))
+        } else {
+            Ok(Self(<[u8; 32] as Deserialize>::deserialize(deserializer)?))
+        }
+    }
+}
+
 impl Matches<MockCodeCommitment> for MockCodeCommitment {
     fn matches(&self, other: &MockCodeCommitment) -> bool {
         self.0 == other.0
@@ -98,6 +128,7 @@ pub struct MockZkvm<ValidityCond> {
     worker_thread_notifier: Notifier,
     committed_data: VecDeque<Vec<u8>>,
     validity_condition: ValidityCond,
+    code_commitment: MockCodeCommitment,
 }
 
 impl<ValidityCond> MockZkvm<ValidityCond> {
@@ -107,9 +138,16 @@ impl<ValidityCond> MockZkvm<ValidityCond> {
             worker_thread_notifier: Default::default(),
             committed_data: Default::default(),
             validity_condition,
+            code_commitment: Default::default(),
         }
     }
 
+    /// Sets the commitment of the program the proofs of this MockZkvm are made for.
+    pub fn with_code_commitment(mut self, code_commitment: MockCodeCommitment) -> Self {
+        self.code_commitment = code_commitment;
+        self
+    }
+
     /// Simulates zk proof generation.
     pub fn make_proof(&self) {
         // We notify the worket thread.
@@ -126,13 +164,10 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::Zkvm for MockZkv
         serialized_proof: &'a [u8],
         code_commitment: &Self::CodeCommitment,
     ) -> Result<&'a [u8], Self::Error> {
-        let proof = MockProof::decode(serialized_proof)?;
-        anyhow::ensure!(
-            proof.program_id.matches(code_commitment),
-            "Proof failed to verify against requested code commitment"
-        );
-        anyhow::ensure!(proof.is_valid, "Proof is not valid");
-        Ok(proof.log)
+        <MockZkVerifier as sov_rollup_interface::zk::Zkvm>::verify(
+            serialized_proof,
+            code_commitment,
+        )
     }
 
     fn verify_and_extract_output<
@@ -142,8 +177,10 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::Zkvm for MockZkv
         serialized_proof: &[u8],
         code_commitment: &Self::CodeCommitment,
     ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
-        let output = Self::verify(serialized_proof, code_commitment)?;
-        Ok(bincode::deserialize(output)?)
+        <MockZkVerifier as sov_rollup_interface::zk::Zkvm>::verify_and_extract_output(
+            serialized_proof,
+            code_commitment,
+        )
     }
 }
 
@@ -167,10 +204,19 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::ZkvmHost
         MockZkGuest {}
     }
 
-    fn run(&mut self, _with_proof: bool) -> Result<sov_rollup_interface::zk::Proof, anyhow::Error> {
+    fn run(&mut self, with_proof: bool) -> Result<sov_rollup_interface::zk::Proof, anyhow::Error> {
         self.worker_thread_notifier.wait();
         let data = self.committed_data.pop_front().unwrap_or_default();
-        Ok(sov_rollup_interface::zk::Proof::PublicInput(data))
+        if with_proof {
+            let proof = MockProof {
+                program_id: self.code_commitment.clone(),
+                is_valid: true,
+                log: &data,
+            };
+            Ok(sov_rollup_interface::zk::Proof::Full(proof.encode_to_vec()))
+        } else {
+            Ok(sov_rollup_interface::zk::Proof::PublicInput(data))
+        }
     }
 
     fn extract_output<
@@ -179,22 +225,59 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::ZkvmHost
     >(
         proof: &sov_rollup_interface::zk::Proof,
     ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
-        match proof {
-            sov_rollup_interface::zk::Proof::PublicInput(pub_input) => {
-                let data: ProofInfo<Da::ValidityCondition> = bincode::deserialize(pub_input)?;
-                let st: StateTransitionData<Root, (), Da> = bincode::deserialize(&data.hint)?;
-
-                Ok(sov_rollup_interface::zk::StateTransition {
-                    initial_state_root: st.initial_state_root,
-                    final_state_root: st.final_state_root,
-                    slot_hash: st.da_block_header.hash(),
-                    validity_condition: data.validity_condition,
-                })
-            }
-            sov_rollup_interface::zk::Proof::Full(_) => {
-                panic!("Mock DA doesn't generate real proofs")
+        let pub_input = match proof {
+            sov_rollup_interface::zk::Proof::PublicInput(pub_input) => pub_input.as_slice(),
+            sov_rollup_interface::zk::Proof::Full(serialized_proof) => {
+                MockProof::decode(serialized_proof)?.log
             }
-        }
+        };
+
+        let data: ProofInfo<Da::ValidityCondition> = bincode::deserialize(pub_input)?;
+        let st: StateTransitionData<Root, (), Da> = bincode::deserialize(&data.hint)?;
+
+        Ok(sov_rollup_interface::zk::StateTransition {
+            initial_state_root: st.initial_state_root,
+            final_state_root: st.final_state_root,
+            slot_hash: st.da_block_header.hash(),
+            validity_condition: data.validity_condition,
+        })
+    }
+}
+
+/// A verifier for the proofs generated by [`MockZkvm`].
+///
+/// Unlike [`MockZkvm`], it holds no state, so it can be used wherever a
+/// [`Zkvm`](sov_rollup_interface::zk::Zkvm) is only needed to check proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MockZkVerifier;
+
+impl sov_rollup_interface::zk::Zkvm for MockZkVerifier {
+    type CodeCommitment = MockCodeCommitment;
+
+    type Error = anyhow::Error;
+
+    fn verify<'a>(
+        serialized_proof: &'a [u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<&'a [u8], Self::Error> {
+        let proof = MockProof::decode(serialized_proof)?;
+        anyhow::ensure!(
+            proof.program_id.matches(code_commitment),
+            "Proof failed to verify against requested code commitment"
+        );
+        anyhow::ensure!(proof.is_valid, "Proof is not valid");
+        Ok(proof.log)
+    }
+
+    fn verify_and_extract_output<
+        Da: sov_rollup_interface::da::DaSpec,
+        Root: Serialize + serde::de::DeserializeOwned,
+    >(
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
+        let output = Self::verify(serialized_proof, code_commitment)?;
+        Ok(bincode::deserialize(output)?)
     }
 }
 
@@ -254,3 +337,14 @@ fn test_mock_proof_round_trip() {
     let decoded = MockProof::decode(&encoded).unwrap();
     assert_eq!(proof, decoded);
 }
+
+#[test]
+fn test_code_commitment_json_round_trip() {
+    let commitment = MockCodeCommitment([7; 32]);
+
+    let json = serde_json::to_string(&commitment).unwrap();
+    assert_eq!(format!("\"{}\"", "07".repeat(32)), json);
+
+    let decoded: MockCodeCommitment = serde_json::from_str(&json).unwrap();
+    assert_eq!(commitment, decoded);
+}
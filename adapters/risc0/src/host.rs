@@ -138,6 +138,7 @@ impl<'host> Zkvm for Risc0Host<'host> {
 }
 
 /// A verifier for Risc0 proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct Risc0Verifier;
 
 impl Zkvm for Risc0Verifier {
@@ -16,9 +16,52 @@ pub mod metrics;
 
 /// Uniquely identifies a Risc0 binary. Roughly equivalent to
 /// the hash of the ELF file.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Risc0MethodId([u32; 8]);
 
+impl Risc0MethodId {
+    /// Creates a new [`Risc0MethodId`] from the image ID of a Risc0 binary.
+    pub fn new(id: [u32; 8]) -> Self {
+        Self(id)
+    }
+}
+
+impl Serialize for Risc0MethodId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let bytes: Vec<u8> = self.0.iter().flat_map(|word| word.to_le_bytes()).collect();
+            Serialize::serialize(&hex::encode(bytes), serializer)
+        } else {
+            Serialize::serialize(&self.0, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Risc0MethodId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let hex_id: String = Deserialize::deserialize(deserializer)?;
+            let bytes = hex::decode(hex_id).map_err(serde::de::Error::custom)?;
+            if bytes.len() != 32 {
+                return Err(serde::de::Error::custom("Method ID must be 32 bytes long"));
+            }
+            let mut id = [0u32; 8];
+            for (word, chunk) in id.iter_mut().zip(bytes.chunks_exact(4)) {
+                *word = u32::from_le_bytes(chunk.try_into().expect("Chunk is 4 bytes long"));
+            }
+            Ok(Self(id))
+        } else {
+            Ok(Self(<[u32; 8] as Deserialize>::deserialize(deserializer)?))
+        }
+    }
+}
+
 impl Matches<Self> for Risc0MethodId {
     fn matches(&self, other: &Self) -> bool {
         self.0 == other.0
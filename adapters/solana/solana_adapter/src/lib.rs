@@ -0,0 +1,8 @@
+#![doc = include_str!("../README.md")]
+
+#[cfg(feature = "native")]
+mod program;
+#[cfg(feature = "native")]
+pub mod service;
+pub mod spec;
+pub mod verifier;
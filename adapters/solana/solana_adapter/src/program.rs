@@ -0,0 +1,232 @@
+//! Decoding of the transactions of the blockroot program, and of the logs reporting the blobs
+//! they completed.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use blockroot::da::Chunk;
+use blockroot::instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::EncodedTransactionWithStatusMeta;
+
+const LOG_PREFIX: &str = "Program log: ";
+const COMPLETION_LOG: &str = "accumulation blob with digest: ";
+const BLOCKS_ROOT_LOG: &str = "blocks root for slot ";
+
+/// A blob completed by a `ProcessChunk` instruction, as reported by the logs of the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Completion {
+    /// The Merkle root of the chunks of the blob.
+    pub root: [u8; 32],
+    /// The blocks root of the slot once the root of the blob is accumulated into it.
+    pub blocks_root: [u8; 32],
+}
+
+/// A chunk processed by the blockroot program.
+#[derive(Debug)]
+pub(crate) struct ProcessedChunk {
+    /// The payer of the transaction, which is the sender of the blob.
+    pub sender: Pubkey,
+    /// The `ChunkAccumulator` account the chunk was accumulated into.
+    pub accumulator: Pubkey,
+    pub chunk: Chunk,
+    /// Set if the chunk completed its blob.
+    pub completion: Option<Completion>,
+}
+
+/// The instructions of the blockroot program which change which chunks are accumulated.
+#[derive(Debug)]
+pub(crate) enum BlockrootInstruction {
+    ProcessChunk(ProcessedChunk),
+    /// Clears the chunks accumulated for `digest` in `accumulator`, or all of them.
+    Clear {
+        accumulator: Pubkey,
+        digest: Option<[u8; 32]>,
+    },
+}
+
+/// A successful transaction and the instructions of the blockroot program it executed.
+#[derive(Debug)]
+pub(crate) struct BlockrootTransaction {
+    pub signature: Signature,
+    pub instructions: Vec<BlockrootInstruction>,
+}
+
+/// Decodes the top-level instructions of `program_id` executed by `tx`. Returns `None` for
+/// failed transactions, whose instructions had no effect, and for transactions which don't
+/// invoke the program.
+///
+/// Instructions reaching the program through cross-program invocations are not decoded, they
+/// aren't recorded in the transaction.
+pub(crate) fn decode_transaction(
+    tx: &EncodedTransactionWithStatusMeta,
+    program_id: &Pubkey,
+) -> anyhow::Result<Option<BlockrootTransaction>> {
+    let meta = tx
+        .meta
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("The transaction has no status"))?;
+    if meta.err.is_some() {
+        return Ok(None);
+    }
+    let decoded = tx
+        .transaction
+        .decode()
+        .ok_or_else(|| anyhow::anyhow!("Failed to decode the transaction"))?;
+    let signature = *decoded
+        .signatures
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("The transaction isn't signed"))?;
+
+    let account_keys = decoded.message.static_account_keys();
+    let logs = match &meta.log_messages {
+        OptionSerializer::Some(logs) => logs.as_slice(),
+        _ => &[],
+    };
+    let mut invocation_logs = program_logs(logs, program_id).into_iter();
+
+    let mut instructions = Vec::new();
+    for ix in decoded.message.instructions() {
+        if ix.program_id(account_keys) != program_id {
+            continue;
+        }
+        let logs = invocation_logs.next().unwrap_or_default();
+        // The accounts of both instructions start with the payer and the chunk accumulator.
+        let account = |position: usize| {
+            ix.accounts
+                .get(position)
+                .and_then(|index| account_keys.get(*index as usize))
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("The instruction misses account {}", position))
+        };
+        if ix.data.len() < 8 {
+            continue;
+        }
+        let (discriminator, mut data) = ix.data.split_at(8);
+
+        if *discriminator == instruction::ProcessChunk::DISCRIMINATOR {
+            let instruction::ProcessChunk { chunk, .. } =
+                instruction::ProcessChunk::deserialize(&mut data)?;
+            instructions.push(BlockrootInstruction::ProcessChunk(ProcessedChunk {
+                sender: account(0)?,
+                accumulator: account(1)?,
+                chunk,
+                completion: parse_completion(&logs),
+            }));
+        } else if *discriminator == instruction::Clear::DISCRIMINATOR {
+            let instruction::Clear { digest } = instruction::Clear::deserialize(&mut data)?;
+            instructions.push(BlockrootInstruction::Clear {
+                accumulator: account(1)?,
+                digest,
+            });
+        }
+    }
+
+    if instructions.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(BlockrootTransaction {
+        signature,
+        instructions,
+    }))
+}
+
+/// Splits the logs of a transaction into the messages logged by each top-level invocation of
+/// `program_id`, in order. Messages logged by the programs it invokes are left out.
+fn program_logs(logs: &[String], program_id: &Pubkey) -> Vec<Vec<String>> {
+    let top_level_invoke = format!("Program {} invoke [1]", program_id);
+    let mut invocations = Vec::new();
+    let mut depth = 0;
+    for log in logs {
+        if log.starts_with("Program ") && log.ends_with(']') && log.contains(" invoke [") {
+            if *log == top_level_invoke {
+                invocations.push(Vec::new());
+            }
+            depth += 1;
+        } else if log.starts_with("Program ")
+            && (log.ends_with(" success") || log.contains(" failed"))
+        {
+            depth -= 1;
+        } else if let Some(message) = log.strip_prefix(LOG_PREFIX) {
+            if let (1, Some(invocation)) = (depth, invocations.last_mut()) {
+                invocation.push(message.to_string());
+            }
+        }
+    }
+    invocations
+}
+
+/// Reads the completion of a blob from the messages logged by a `ProcessChunk` instruction.
+fn parse_completion(messages: &[String]) -> Option<Completion> {
+    let root = messages.iter().find_map(|message| {
+        let (_, root) = message
+            .strip_prefix(COMPLETION_LOG)?
+            .split_once(" has completed with root ")?;
+        parse_bytes(root)
+    })?;
+    let blocks_root = messages.iter().find_map(|message| {
+        let (_, blocks_root) = message
+            .strip_prefix(BLOCKS_ROOT_LOG)?
+            .split_once(" combined root: ")?;
+        parse_bytes(blocks_root)
+    })?;
+    Some(Completion { root, blocks_root })
+}
+
+/// Parses a hash logged in its `Debug` format, like `[1, 2, 3, ...]`.
+fn parse_bytes(s: &str) -> Option<[u8; 32]> {
+    let bytes = s
+        .trim()
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .split(", ")
+        .map(|byte| byte.parse::<u8>().ok())
+        .collect::<Option<Vec<u8>>>()?;
+    bytes.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_completion_logs() {
+        let program_id = blockroot::ID;
+        let root = [7u8; 32];
+        let blocks_root = [9u8; 32];
+        let logs: Vec<String> = [
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: ProcessChunk".to_string(),
+            "Program log: false".to_string(),
+            format!("Program {} success", program_id),
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: ProcessChunk".to_string(),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            "Program log: blocks root for slot 1, blob root: [] combined root: []".to_string(),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            "Program log: true".to_string(),
+            format!(
+                "Program log: accumulation blob with digest: {:?} has completed with root {:?}",
+                [1u8; 32], root
+            ),
+            format!(
+                "Program log: blocks root for slot 5, blob root: {:?} combined root: {:?}",
+                root, blocks_root
+            ),
+            format!(
+                "Program {} consumed 60998 of 200000 compute units",
+                program_id
+            ),
+            format!("Program {} success", program_id),
+        ]
+        .into();
+
+        let invocations = program_logs(&logs, &program_id);
+        assert_eq!(2, invocations.len());
+        assert_eq!(None, parse_completion(&invocations[0]));
+        assert_eq!(
+            Some(Completion { root, blocks_root }),
+            parse_completion(&invocations[1])
+        );
+    }
+}
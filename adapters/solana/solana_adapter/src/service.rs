@@ -0,0 +1,780 @@
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use blockroot::da::{get_chunks, CHUNK_SIZE, PREFIX};
+use bytes::Bytes;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind as ClientErrorKind};
+use solana_rpc_client_api::config::{RpcBlockConfig, RpcTransactionConfig};
+use solana_rpc_client_api::custom_error::{
+    JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_SLOT_SKIPPED, JSON_RPC_SERVER_ERROR_SLOT_SKIPPED,
+};
+use solana_rpc_client_api::request::RpcError;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use solana_sdk::{system_program, sysvar};
+use solana_transaction_status::{TransactionDetails, UiConfirmedBlock, UiTransactionEncoding};
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::services::da::{AggregatedProofEnvelope, DaService};
+use tracing::{debug, info};
+
+use crate::program::{
+    decode_transaction, BlockrootInstruction, BlockrootTransaction, ProcessedChunk,
+};
+use crate::spec::address::SolanaAddress;
+use crate::spec::blob::SolanaBlobTransaction;
+use crate::spec::block::SolanaBlock;
+use crate::spec::header::SolanaBlockHeader;
+use crate::spec::proof::{blob_data, merkle_root, ChunkLeaf};
+use crate::spec::{SolanaHash, SolanaSpec};
+use crate::verifier::SolanaVerifier;
+
+/// Runtime configuration for the DA service
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SolanaServiceConfig {
+    /// The JSON RPC endpoint of a Solana node.
+    pub rpc_url: String,
+    /// The address of the blockroot program the blobs of the rollup are posted through.
+    pub blockroot_program: String,
+    /// The address of the blockroot program the aggregated proofs are posted through. It must be
+    /// another deployment than `blockroot_program`, so that the blocks root of the rollup only
+    /// commits to its blobs.
+    pub proof_blockroot_program: String,
+    /// The keys used to post blobs. The service is read-only without them.
+    pub submitter: Option<SubmitterConfig>,
+    /// How often the node is polled for new slots, in milliseconds.
+    pub polling_interval_ms: Option<u64>,
+}
+
+/// The keypairs used to post blobs, as written by `solana-keygen`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SubmitterConfig {
+    /// The keypair paying for the transactions. It is the sender of the blobs.
+    pub signer_keypair_path: PathBuf,
+    /// The `ChunkAccumulator` account of the sequencer in the blockroot program of the blobs.
+    pub chunk_accumulator_keypair_path: PathBuf,
+    /// The `ChunkAccumulator` account of the prover in the blockroot program of the proofs.
+    pub proof_chunk_accumulator_keypair_path: PathBuf,
+}
+
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(400);
+/// How many signatures are requested at once while looking for the earlier chunks of a blob.
+const SIGNATURES_PAGE_SIZE: usize = 100;
+/// A confirmed slot is rooted, hence finalized, once 32 slots are voted on top of it.
+const MAX_REORG_DEPTH: u64 = 32;
+
+struct Submitter {
+    signer: Keypair,
+    chunk_accumulator: Keypair,
+    proof_chunk_accumulator: Keypair,
+}
+
+/// A [`DaService`] posting blobs as chunks to the blockroot program, and reading them back from
+/// the confirmed slots. The height of a block is its slot, and skipped slots are empty blocks.
+#[derive(Clone)]
+pub struct SolanaService {
+    client: Arc<RpcClient>,
+    blockroot_program: Pubkey,
+    proof_blockroot_program: Pubkey,
+    submitter: Option<Arc<Submitter>>,
+    polling_interval: Duration,
+}
+
+/// A stream of the finalized headers, polling the node for finalized slots.
+pub type SolanaHeaderStream =
+    Pin<Box<dyn futures::Stream<Item = anyhow::Result<SolanaBlockHeader>> + Send>>;
+
+impl SolanaService {
+    /// Connects to the node of `config`, reading the submitter keypairs if any.
+    pub fn new(config: SolanaServiceConfig) -> anyhow::Result<Self> {
+        let submitter = match config.submitter {
+            Some(submitter) => Some(Arc::new(Submitter {
+                signer: read_keypair(&submitter.signer_keypair_path)?,
+                chunk_accumulator: read_keypair(&submitter.chunk_accumulator_keypair_path)?,
+                proof_chunk_accumulator: read_keypair(
+                    &submitter.proof_chunk_accumulator_keypair_path,
+                )?,
+            })),
+            None => None,
+        };
+
+        Ok(Self {
+            client: Arc::new(RpcClient::new_with_commitment(
+                config.rpc_url,
+                CommitmentConfig::confirmed(),
+            )),
+            blockroot_program: Pubkey::from_str(&config.blockroot_program)?,
+            proof_blockroot_program: Pubkey::from_str(&config.proof_blockroot_program)?,
+            submitter,
+            polling_interval: config
+                .polling_interval_ms
+                .map_or(DEFAULT_POLLING_INTERVAL, Duration::from_millis),
+        })
+    }
+
+    fn submitter(&self) -> anyhow::Result<&Submitter> {
+        self.submitter
+            .as_deref()
+            .ok_or_else(|| anyhow!("No submitter keys are configured"))
+    }
+
+    /// Waits until `slot` reaches the given commitment.
+    async fn wait_for_slot(&self, slot: u64, commitment: CommitmentConfig) -> anyhow::Result<()> {
+        while self.client.get_slot_with_commitment(commitment).await? < slot {
+            tokio::time::sleep(self.polling_interval).await;
+        }
+        Ok(())
+    }
+
+    /// Streams the headers of the slots reaching the given commitment after subscription.
+    async fn subscribe_slots(
+        &self,
+        commitment: CommitmentConfig,
+    ) -> anyhow::Result<SolanaHeaderStream> {
+        let next_slot = self.client.get_slot_with_commitment(commitment).await? + 1;
+        let service = self.clone();
+
+        Ok(Box::pin(futures::stream::unfold(next_slot, move |slot| {
+            let service = service.clone();
+            async move {
+                let header: anyhow::Result<_> = async {
+                    service.wait_for_slot(slot, commitment).await?;
+                    Ok(service
+                        .fetch_slot(slot, &service.blockroot_program)
+                        .await?
+                        .0)
+                }
+                .await;
+                // A failed slot is fetched again on the next poll.
+                let next_slot = if header.is_ok() { slot + 1 } else { slot };
+                Some((header, next_slot))
+            }
+        })))
+    }
+
+    /// Fetches the confirmed block of `slot`, or `None` if the slot was skipped.
+    async fn get_confirmed_block(&self, slot: u64) -> anyhow::Result<Option<UiConfirmedBlock>> {
+        let config = RpcBlockConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            transaction_details: Some(TransactionDetails::Full),
+            rewards: Some(false),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        match self.client.get_block_with_config(slot, config).await {
+            Ok(block) => Ok(Some(block)),
+            Err(e) if is_skipped_slot(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Fetches the header of `slot` and the transactions of `program_id` executed in it.
+    async fn fetch_slot(
+        &self,
+        slot: u64,
+        program_id: &Pubkey,
+    ) -> anyhow::Result<(SolanaBlockHeader, Vec<BlockrootTransaction>)> {
+        if let Some(block) = self.get_confirmed_block(slot).await? {
+            let header = self.header(slot, &block)?;
+            return Ok((header, decode_block(&block, program_id)?));
+        }
+
+        // The header of a skipped slot follows the last block before it.
+        let mut parent_slot = slot;
+        loop {
+            parent_slot = parent_slot
+                .checked_sub(1)
+                .ok_or_else(|| anyhow!("No block precedes the skipped slot {}", slot))?;
+            if let Some(block) = self.get_confirmed_block(parent_slot).await? {
+                let parent = self.header(parent_slot, &block)?;
+                return Ok((SolanaBlockHeader::skipped(slot, &parent), Vec::new()));
+            }
+        }
+    }
+
+    fn header(&self, slot: u64, block: &UiConfirmedBlock) -> anyhow::Result<SolanaBlockHeader> {
+        // The blocks root account holds the root logged by the last blob completed in the slot.
+        let blocks_root = decode_block(block, &self.blockroot_program)?
+            .iter()
+            .flat_map(|tx| &tx.instructions)
+            .filter_map(|ix| match ix {
+                BlockrootInstruction::ProcessChunk(processed) => processed.completion.as_ref(),
+                BlockrootInstruction::Clear { .. } => None,
+            })
+            .last()
+            .map(|completion| completion.blocks_root);
+
+        Ok(SolanaBlockHeader {
+            slot,
+            parent_slot: block.parent_slot,
+            blockhash: parse_hash(&block.blockhash)?,
+            previous_blockhash: parse_hash(&block.previous_blockhash)?,
+            block_time: block.block_time,
+            blocks_root,
+        })
+    }
+
+    /// Reassembles the blobs completed by `transactions` of `program_id`, in completion order,
+    /// with their chunks.
+    async fn completed_blobs(
+        &self,
+        transactions: &[BlockrootTransaction],
+        program_id: &Pubkey,
+    ) -> anyhow::Result<Vec<(SolanaBlobTransaction, Vec<ChunkLeaf>)>> {
+        let mut blobs = Vec::new();
+        for tx in transactions {
+            for (index, ix) in tx.instructions.iter().enumerate() {
+                let BlockrootInstruction::ProcessChunk(completing) = ix else {
+                    continue;
+                };
+                let Some(completion) = &completing.completion else {
+                    continue;
+                };
+
+                let mut chunks = BlobChunks::new(completing);
+                let mut earlier_in_tx = tx.instructions[..index].iter().rev();
+                if earlier_in_tx.all(|ix| chunks.add_earlier(ix)) {
+                    self.collect_earlier_chunks(&mut chunks, tx.signature, program_id)
+                        .await?;
+                }
+                let leaves = chunks.into_leaves()?;
+                if merkle_root(&leaves) != Some(completion.root) {
+                    anyhow::bail!(
+                        "The chunks of the blob with root {} don't match it",
+                        hex::encode(completion.root)
+                    );
+                }
+
+                let blob = SolanaBlobTransaction::new(
+                    Bytes::from(blob_data(&leaves)),
+                    completion.root,
+                    SolanaAddress::from(completing.sender.to_bytes()),
+                );
+                blobs.push((blob, leaves));
+            }
+        }
+        Ok(blobs)
+    }
+
+    /// Walks back the transactions of the chunk accumulator of a blob from the transaction
+    /// `before`, until all the chunks of the blob are found.
+    async fn collect_earlier_chunks(
+        &self,
+        chunks: &mut BlobChunks,
+        mut before: Signature,
+        program_id: &Pubkey,
+    ) -> anyhow::Result<()> {
+        loop {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before: Some(before),
+                until: None,
+                limit: Some(SIGNATURES_PAGE_SIZE),
+                commitment: Some(CommitmentConfig::confirmed()),
+            };
+            let statuses = self
+                .client
+                .get_signatures_for_address_with_config(&chunks.accumulator, config)
+                .await?;
+            if statuses.is_empty() {
+                return Ok(());
+            }
+
+            for status in statuses {
+                before = Signature::from_str(&status.signature)?;
+                if status.err.is_some() {
+                    continue;
+                }
+                let config = RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                };
+                let tx = self
+                    .client
+                    .get_transaction_with_config(&before, config)
+                    .await?;
+                let Some(tx) = decode_transaction(&tx.transaction, program_id)? else {
+                    continue;
+                };
+                if !tx
+                    .instructions
+                    .iter()
+                    .rev()
+                    .all(|ix| chunks.add_earlier(ix))
+                {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Sends `blob` as chunks to `program_id`, accumulating them in `chunk_accumulator`. Returns
+    /// the signature of the transaction completing the blob.
+    async fn send_blob(
+        &self,
+        blob: &[u8],
+        program_id: &Pubkey,
+        chunk_accumulator: &Keypair,
+    ) -> anyhow::Result<Signature> {
+        let signer = &self.submitter()?.signer;
+        let (blocks_root, bump) = Pubkey::find_program_address(&[PREFIX.as_bytes()], program_id);
+        let accounts = blockroot::accounts::ProcessChunk {
+            creator: signer.pubkey(),
+            chunk_accumulator: chunk_accumulator.pubkey(),
+            blocks_root,
+            system_program: system_program::ID,
+            clock: sysvar::clock::ID,
+        }
+        .to_account_metas(None);
+
+        let mut last_signature = None;
+        for chunk in get_chunks(blob, CHUNK_SIZE) {
+            let instruction = Instruction {
+                program_id: *program_id,
+                accounts: accounts.clone(),
+                data: blockroot::instruction::ProcessChunk { bump, chunk }.data(),
+            };
+            let recent_blockhash = self.client.get_latest_blockhash().await?;
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&signer.pubkey()),
+                &[signer, chunk_accumulator],
+                recent_blockhash,
+            );
+            let signature = self.client.send_and_confirm_transaction(&tx).await?;
+            debug!("Chunk submitted: {}", signature);
+            last_signature = Some(signature);
+        }
+
+        last_signature.ok_or_else(|| anyhow!("Can't send an empty blob"))
+    }
+}
+
+/// The chunks of a blob, gathered backwards from the chunk completing it.
+struct BlobChunks {
+    digest: [u8; 32],
+    accumulator: Pubkey,
+    num_chunks: u64,
+    leaves: BTreeMap<u64, ChunkLeaf>,
+}
+
+impl BlobChunks {
+    fn new(completing: &ProcessedChunk) -> Self {
+        let mut chunks = Self {
+            digest: completing.chunk.digest,
+            accumulator: completing.accumulator,
+            num_chunks: completing.chunk.num_chunks,
+            leaves: BTreeMap::new(),
+        };
+        chunks.insert(completing);
+        chunks
+    }
+
+    fn insert(&mut self, processed: &ProcessedChunk) {
+        let chunk = &processed.chunk;
+        if chunk.chunk_num < self.num_chunks {
+            // A chunk sent again replaces the earlier one in the accumulator.
+            self.leaves
+                .entry(chunk.chunk_num)
+                .or_insert_with(|| ChunkLeaf {
+                    actual_size: chunk.actual_size,
+                    body: chunk.chunk_body.clone(),
+                });
+        }
+    }
+
+    /// Takes an instruction executed before the ones seen so far into account. Returns `false`
+    /// once no earlier instruction can contribute to the blob: when all its chunks are found,
+    /// or when the chunks accumulated before were dropped.
+    fn add_earlier(&mut self, ix: &BlockrootInstruction) -> bool {
+        match ix {
+            BlockrootInstruction::ProcessChunk(processed)
+                if processed.accumulator == self.accumulator
+                    && processed.chunk.digest == self.digest =>
+            {
+                // Completing a blob clears its chunks from the accumulator.
+                if processed.completion.is_some() {
+                    return false;
+                }
+                self.insert(processed);
+            }
+            BlockrootInstruction::Clear {
+                accumulator,
+                digest,
+            } if *accumulator == self.accumulator
+                && digest.map_or(true, |digest| digest == self.digest) =>
+            {
+                return false;
+            }
+            _ => {}
+        }
+        !self.is_complete()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.leaves.len() as u64 == self.num_chunks
+    }
+
+    fn into_leaves(self) -> anyhow::Result<Vec<ChunkLeaf>> {
+        if !self.is_complete() {
+            anyhow::bail!(
+                "Only {} of the {} chunks of the blob {} were found",
+                self.leaves.len(),
+                self.num_chunks,
+                hex::encode(self.digest)
+            );
+        }
+        Ok(self.leaves.into_values().collect())
+    }
+}
+
+fn read_keypair(path: &Path) -> anyhow::Result<Keypair> {
+    read_keypair_file(path)
+        .map_err(|e| anyhow!("Failed to read the keypair at {}: {}", path.display(), e))
+}
+
+fn is_skipped_slot(error: &ClientError) -> bool {
+    matches!(
+        error.kind(),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. })
+            if *code == JSON_RPC_SERVER_ERROR_SLOT_SKIPPED
+                || *code == JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_SLOT_SKIPPED
+    )
+}
+
+fn parse_hash(hash: &str) -> anyhow::Result<SolanaHash> {
+    Ok(SolanaHash(Hash::from_str(hash)?.to_bytes()))
+}
+
+/// Decodes the successful transactions of `program_id` in `block`, in execution order.
+fn decode_block(
+    block: &UiConfirmedBlock,
+    program_id: &Pubkey,
+) -> anyhow::Result<Vec<BlockrootTransaction>> {
+    block
+        .transactions
+        .iter()
+        .flatten()
+        .filter_map(|tx| decode_transaction(tx, program_id).transpose())
+        .collect()
+}
+
+#[async_trait]
+impl DaService for SolanaService {
+    type Spec = SolanaSpec;
+
+    type Verifier = SolanaVerifier;
+
+    type FilteredBlock = SolanaBlock;
+    type HeaderStream = SolanaHeaderStream;
+    type TransactionId = ();
+    type Error = anyhow::Error;
+
+    // Waits for the slot to be confirmed, then reassembles the blobs completed in it.
+    async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
+        self.wait_for_slot(height, CommitmentConfig::confirmed())
+            .await?;
+        let (header, transactions) = self.fetch_slot(height, &self.blockroot_program).await?;
+        let (blobs, inclusion_proof) = self
+            .completed_blobs(&transactions, &self.blockroot_program)
+            .await?
+            .into_iter()
+            .unzip();
+
+        Ok(SolanaBlock {
+            header,
+            blobs,
+            inclusion_proof,
+        })
+    }
+
+    async fn get_last_finalized_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        let slot = self
+            .client
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await?;
+        Ok(self.fetch_slot(slot, &self.blockroot_program).await?.0)
+    }
+
+    async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        self.subscribe_slots(CommitmentConfig::finalized()).await
+    }
+
+    async fn get_head_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        let slot = self
+            .client
+            .get_slot_with_commitment(CommitmentConfig::confirmed())
+            .await?;
+        Ok(self.fetch_slot(slot, &self.blockroot_program).await?.0)
+    }
+
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        self.subscribe_slots(CommitmentConfig::confirmed()).await
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        MAX_REORG_DEPTH
+    }
+
+    // Every blob completed through the blockroot program is relevant.
+    fn extract_relevant_blobs(
+        &self,
+        block: &Self::FilteredBlock,
+    ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
+        block.blobs.clone()
+    }
+
+    // The chunks of the blobs are gathered while fetching the block.
+    async fn get_extraction_proof(
+        &self,
+        block: &Self::FilteredBlock,
+        _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+    ) -> (
+        <Self::Spec as DaSpec>::InclusionMultiProof,
+        <Self::Spec as DaSpec>::CompletenessProof,
+    ) {
+        (block.inclusion_proof.clone(), ())
+    }
+
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
+        let submitter = self.submitter()?;
+        let signature = self
+            .send_blob(blob, &self.blockroot_program, &submitter.chunk_accumulator)
+            .await?;
+        info!("Blob submitted: {}", signature);
+        Ok(())
+    }
+
+    async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
+        let submitter = self.submitter()?;
+        let envelope = AggregatedProofEnvelope::new(proof.to_vec());
+        let signature = self
+            .send_blob(
+                &envelope.encode(),
+                &self.proof_blockroot_program,
+                &submitter.proof_chunk_accumulator,
+            )
+            .await?;
+
+        let slot = self
+            .client
+            .get_signature_statuses(&[signature])
+            .await?
+            .value
+            .into_iter()
+            .flatten()
+            .next()
+            .ok_or_else(|| anyhow!("Status of the transaction {} not found", signature))?
+            .slot;
+        info!(
+            "Aggregated proof submitted: {}, height: {}",
+            signature, slot
+        );
+
+        Ok(slot)
+    }
+
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let (_, transactions) = self
+            .fetch_slot(height, &self.proof_blockroot_program)
+            .await?;
+        let proof_blobs = self
+            .completed_blobs(&transactions, &self.proof_blockroot_program)
+            .await?
+            .into_iter()
+            .map(|(_, leaves)| blob_data(&leaves));
+
+        Ok(AggregatedProofEnvelope::decode_proofs(proof_blobs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use sha3::Keccak256;
+    use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaVerifier};
+    use sov_rollup_interface::services::da::SlotData;
+    use sov_rollup_interface::test_utils::{
+        test_extraction_round_trip, test_extraction_tamper_rejection,
+    };
+    use sov_rollup_interface::zk::ValidityCondition;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::spec::proof::{accumulate_blocks_root, blob_leaf};
+
+    /// The responses of a local validator running the blockroot program, synthesized from the
+    /// chunking and logging logic of the program:
+    /// - slot 100 accumulates the first chunk of a two-chunk blob,
+    /// - slot 101 is skipped,
+    /// - slot 102 completes that blob, skips a failed chunk, then completes a one-chunk blob,
+    /// - slot 103 completes an aggregated proof through the proof program.
+    const LOCAL_VALIDATOR_JSON: &str = include_str!("../test_data/local_validator.json");
+
+    /// Serves the recorded response of each request, keyed by method and first parameter.
+    async fn setup_service() -> (MockServer, SolanaService, Value) {
+        let fixture: Value = serde_json::from_str(LOCAL_VALIDATOR_JSON).unwrap();
+        let mock_server = MockServer::start().await;
+
+        let responses = fixture["responses"].clone();
+        Mock::given(method("POST"))
+            .respond_with(move |req: &Request| {
+                let request: Value = serde_json::from_slice(&req.body).unwrap();
+                let rpc_method = request["method"].as_str().unwrap();
+                let key = match &request["params"][0] {
+                    Value::Number(slot) => slot.to_string(),
+                    Value::String(key) => key.clone(),
+                    Value::Object(config) => config["commitment"].as_str().unwrap().to_string(),
+                    _ => String::new(),
+                };
+
+                let mut response = responses[rpc_method][key.as_str()].clone();
+                if response.is_null() {
+                    response = json!({
+                        "error": {
+                            "code": -32601,
+                            "message": format!("No recorded response for {} {}", rpc_method, key),
+                        }
+                    });
+                }
+                response["jsonrpc"] = json!("2.0");
+                response["id"] = request["id"].clone();
+                ResponseTemplate::new(200).set_body_json(response)
+            })
+            .mount(&mock_server)
+            .await;
+
+        let da_service = SolanaService::new(SolanaServiceConfig {
+            rpc_url: mock_server.uri(),
+            blockroot_program: fixture["blockroot_program"].as_str().unwrap().to_string(),
+            proof_blockroot_program: fixture["proof_blockroot_program"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+            submitter: None,
+            polling_interval_ms: Some(10),
+        })
+        .unwrap();
+
+        (mock_server, da_service, fixture)
+    }
+
+    #[tokio::test]
+    async fn test_get_block_with_blobs_across_slots() {
+        let (_server, da_service, fixture) = setup_service().await;
+        let block = da_service.get_block_at(102).await.unwrap();
+
+        assert_eq!(102, block.header.height());
+        assert_eq!(100, block.header.parent_slot);
+        let mut blobs = da_service.extract_relevant_blobs(&block);
+        assert_eq!(2, blobs.len());
+        let first_blob: Vec<u8> = (0..1000).map(|i| (i * 7 % 251) as u8).collect();
+        assert_eq!(first_blob, blobs[0].full_data());
+        assert_eq!(b"a single chunk blob of the rollup", blobs[1].full_data());
+        let sender: SolanaAddress = fixture["sequencer"].as_str().unwrap().parse().unwrap();
+        assert!(blobs.iter().all(|blob| blob.sender() == sender));
+        assert_eq!(
+            Some(accumulate_blocks_root(
+                Some(blob_leaf(sender.as_ref(), blobs[0].hash())),
+                blob_leaf(sender.as_ref(), blobs[1].hash())
+            )),
+            block.header.blocks_root
+        );
+
+        let verifier = SolanaVerifier::new(());
+        test_extraction_round_trip(&da_service, &verifier, &block).await;
+        test_extraction_tamper_rejection(&da_service, &verifier, &block).await;
+    }
+
+    #[tokio::test]
+    async fn test_skipped_slot() {
+        let (_server, da_service, _) = setup_service().await;
+        let blocks = [
+            da_service.get_block_at(100).await.unwrap(),
+            da_service.get_block_at(101).await.unwrap(),
+            da_service.get_block_at(102).await.unwrap(),
+        ];
+
+        // The first chunk of a blob doesn't complete anything.
+        assert!(blocks[0].blobs.is_empty());
+        assert_eq!(None, blocks[0].header.blocks_root);
+        assert_eq!(
+            SolanaBlockHeader::skipped(101, &blocks[0].header),
+            blocks[1].header
+        );
+        assert!(blocks[1].blobs.is_empty());
+
+        let verifier = SolanaVerifier::new(());
+        for block in &blocks[..2] {
+            let (blobs, inclusion_proof, completeness_proof) =
+                da_service.extract_relevant_blobs_with_proof(block).await;
+            verifier
+                .verify_relevant_tx_list(&block.header, &blobs, inclusion_proof, completeness_proof)
+                .unwrap();
+        }
+
+        let validity_condition = blocks[0]
+            .validity_condition()
+            .combine::<Keccak256>(blocks[1].validity_condition())
+            .unwrap()
+            .combine::<Keccak256>(blocks[2].validity_condition())
+            .unwrap();
+        assert_eq!(blocks[2].validity_condition(), validity_condition);
+    }
+
+    #[tokio::test]
+    async fn test_finalized_and_head_headers() {
+        let (_server, da_service, _) = setup_service().await;
+
+        let finalized = da_service.get_last_finalized_block_header().await.unwrap();
+        let head = da_service.get_head_block_header().await.unwrap();
+        assert_eq!(102, finalized.height());
+        assert_eq!(103, head.height());
+        assert_eq!(finalized.hash(), head.prev_hash());
+        // The proofs aren't posted through the blockroot program of the rollup.
+        assert_eq!(None, head.blocks_root);
+    }
+
+    #[tokio::test]
+    async fn test_get_aggregated_proofs() {
+        let (_server, da_service, _) = setup_service().await;
+
+        assert_eq!(
+            vec![b"aggregated proof".to_vec()],
+            da_service.get_aggregated_proofs_at(103).await.unwrap()
+        );
+        assert!(da_service
+            .get_aggregated_proofs_at(102)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_without_submitter() {
+        let (_server, da_service, _) = setup_service().await;
+
+        assert!(da_service.send_transaction(b"blob").await.is_err());
+        assert!(da_service
+            .send_aggregated_zk_proof(b"aggregated proof")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use core::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A Solana account address, the public key of the sender of a blob.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Eq, Hash)]
+pub struct SolanaAddress([u8; 32]);
+
+impl sov_rollup_interface::BasicAddress for SolanaAddress {}
+
+impl Display for SolanaAddress {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+impl AsRef<[u8]> for SolanaAddress {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<[u8; 32]> for SolanaAddress {
+    fn from(value: [u8; 32]) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for SolanaAddress {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = bs58::decode(s).into_vec()?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SolanaAddress {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self(<[u8; 32]>::try_from(value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_round_trip() {
+        let encoded = "6YQGvP866CHpLTdHwmLqj2Vh5q7T1GF4Kk9gS9MCta8E";
+        let address = SolanaAddress::from_str(encoded).unwrap();
+        assert_eq!(encoded, address.to_string());
+        assert!(SolanaAddress::from_str("6YQGvP866CHp").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use sov_rollup_interface::da::{BlobReaderTrait, CountedBufReader};
+
+use super::address::SolanaAddress;
+
+/// A blob posted in chunks through the blockroot program. Its hash is the Merkle root of its
+/// chunks.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SolanaBlobTransaction {
+    blob: CountedBufReader<Bytes>,
+    hash: [u8; 32],
+    sender: SolanaAddress,
+}
+
+impl BlobReaderTrait for SolanaBlobTransaction {
+    type Address = SolanaAddress;
+
+    fn sender(&self) -> SolanaAddress {
+        self.sender.clone()
+    }
+
+    fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    fn verified_data(&self) -> &[u8] {
+        self.blob.accumulator()
+    }
+
+    #[cfg(feature = "native")]
+    fn advance(&mut self, num_bytes: usize) -> &[u8] {
+        self.blob.advance(num_bytes);
+        self.verified_data()
+    }
+
+    fn total_len(&self) -> usize {
+        self.blob.total_len()
+    }
+
+    fn from_verified_data(sender: SolanaAddress, hash: [u8; 32], data: Vec<u8>) -> Self {
+        let len = data.len();
+        let mut blob = Self::new(Bytes::from(data), hash, sender);
+        blob.blob.advance(len);
+        blob
+    }
+}
+
+impl SolanaBlobTransaction {
+    /// Creates the blob of `data` with the Merkle root `hash`, posted by `sender`.
+    pub fn new(data: Bytes, hash: [u8; 32], sender: SolanaAddress) -> Self {
+        Self {
+            blob: CountedBufReader::new(data),
+            hash,
+            sender,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::services::da::SlotData;
+
+use super::blob::SolanaBlobTransaction;
+use super::header::SolanaBlockHeader;
+use super::proof::SolanaInclusionProof;
+use crate::verifier::ChainValidityCondition;
+
+/// A Solana slot, with the blobs completed in it in order and their chunks.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SolanaBlock {
+    pub header: SolanaBlockHeader,
+    pub blobs: Vec<SolanaBlobTransaction>,
+    pub inclusion_proof: SolanaInclusionProof,
+}
+
+impl SlotData for SolanaBlock {
+    type BlockHeader = SolanaBlockHeader;
+    type Cond = ChainValidityCondition;
+
+    fn hash(&self) -> [u8; 32] {
+        self.header.hash().0
+    }
+
+    fn header(&self) -> &Self::BlockHeader {
+        &self.header
+    }
+
+    fn validity_condition(&self) -> ChainValidityCondition {
+        ChainValidityCondition {
+            prev_hash: self.header.prev_hash().0,
+            block_hash: self.header.hash().0,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use sov_rollup_interface::da::BlockHashTrait;
+
+/// The hash of a Solana block, its blockhash.
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SolanaHash(pub [u8; 32]);
+
+impl BlockHashTrait for SolanaHash {}
+
+impl core::fmt::Display for SolanaHash {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", bs58::encode(self.0).into_string())
+    }
+}
+
+impl AsRef<[u8]> for SolanaHash {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<SolanaHash> for [u8; 32] {
+    fn from(value: SolanaHash) -> Self {
+        value.0
+    }
+}
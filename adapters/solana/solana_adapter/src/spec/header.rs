@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use sov_rollup_interface::da::{BlockHeaderTrait, Time};
+
+use super::hash::SolanaHash;
+
+/// The header of a Solana slot. The height of a header is its slot.
+///
+/// A skipped slot has no block, so its header repeats the blockhash of the last block before
+/// it, as both its hash and previous hash, and has no blocks root. The headers of consecutive
+/// slots are then always chained.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SolanaBlockHeader {
+    pub slot: u64,
+    pub parent_slot: u64,
+    pub blockhash: SolanaHash,
+    pub previous_blockhash: SolanaHash,
+    pub block_time: Option<i64>,
+    /// The digest of the `BlocksRoot` account of the blockroot program after the slot, if blobs
+    /// were completed in the slot: the leaves of these blobs, committing to their senders and
+    /// Merkle roots, accumulated in order.
+    ///
+    /// It's read from the logs of the program and isn't proven against the bank hash of the
+    /// slot, so it's trusted as given: the adapter is unsound.
+    pub blocks_root: Option<[u8; 32]>,
+}
+
+impl SolanaBlockHeader {
+    /// The header of the skipped `slot`, following the block with header `parent`.
+    pub fn skipped(slot: u64, parent: &SolanaBlockHeader) -> Self {
+        Self {
+            slot,
+            parent_slot: parent.slot,
+            blockhash: parent.blockhash,
+            previous_blockhash: parent.blockhash,
+            block_time: parent.block_time,
+            blocks_root: None,
+        }
+    }
+}
+
+impl BlockHeaderTrait for SolanaBlockHeader {
+    type Hash = SolanaHash;
+
+    fn prev_hash(&self) -> Self::Hash {
+        self.previous_blockhash
+    }
+
+    fn hash(&self) -> Self::Hash {
+        self.blockhash
+    }
+
+    fn height(&self) -> u64 {
+        self.slot
+    }
+
+    fn time(&self) -> Time {
+        Time::from_secs(self.block_time.unwrap_or_default())
+    }
+}
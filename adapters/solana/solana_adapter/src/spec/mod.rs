@@ -0,0 +1,39 @@
+use sov_rollup_interface::da::DaSpec;
+
+use crate::verifier::ChainValidityCondition;
+
+pub mod address;
+pub mod blob;
+pub mod block;
+mod hash;
+pub mod header;
+pub mod proof;
+
+pub use hash::SolanaHash;
+
+/// The types of the Solana DA layer, as seen through the blockroot program.
+///
+/// # Soundness
+///
+/// The adapter is **unsound**: the blocks roots of its headers aren't proven against the bank
+/// hashes of Solana. See [`SolanaVerifier`](crate::verifier::SolanaVerifier).
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SolanaSpec;
+
+impl DaSpec for SolanaSpec {
+    type SlotHash = SolanaHash;
+
+    type BlockHeader = header::SolanaBlockHeader;
+
+    type BlobTransaction = blob::SolanaBlobTransaction;
+
+    type Address = address::SolanaAddress;
+
+    type ValidityCondition = ChainValidityCondition;
+
+    type InclusionMultiProof = proof::SolanaInclusionProof;
+
+    type CompletenessProof = proof::SolanaCompletenessProof;
+
+    type ChainParams = ();
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// A chunk of a blob as it was hashed by the blockroot program: its body, padded to the chunk
+/// size of the blob, and the size of the blob data it holds.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct ChunkLeaf {
+    pub actual_size: u64,
+    pub body: Vec<u8>,
+}
+
+/// The chunks of every blob completed in the slot, in completion order.
+pub type SolanaInclusionProof = Vec<Vec<ChunkLeaf>>;
+
+/// Every blob completed through the blockroot program is relevant to the rollup, and the blocks
+/// root commits to all of them, so the inclusion proof is complete by itself.
+pub type SolanaCompletenessProof = ();
+
+/// The keccak hash of the concatenated `parts`, as computed by the `hashv` syscall.
+fn hashv(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Computes the Merkle root of the chunks of a blob, as the blockroot program does: a keccak
+/// binary Merkle tree over the hashes of the actual sizes and bodies of the chunks, promoting
+/// the last node of odd layers. Returns `None` for a blob without chunks.
+pub fn merkle_root(leaves: &[ChunkLeaf]) -> Option<[u8; 32]> {
+    let mut layer: Vec<[u8; 32]> = leaves
+        .iter()
+        .map(|leaf| hashv(&[&leaf.actual_size.to_le_bytes(), &leaf.body]))
+        .collect();
+
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|nodes| match nodes {
+                [left, right] => hashv(&[left, right]),
+                [node] => *node,
+                _ => unreachable!("Chunks hold one or two nodes"),
+            })
+            .collect();
+    }
+
+    layer.first().copied()
+}
+
+/// Computes the leaf of a blob in the blocks root, committing to its sender and to the Merkle root
+/// of its chunks, as the blockroot program does.
+pub fn blob_leaf(sender: &[u8], blob_root: [u8; 32]) -> [u8; 32] {
+    hashv(&[sender, &blob_root])
+}
+
+/// Accumulates the leaf of a blob completed in a slot into the blocks root of the slot, as the
+/// `BlocksRoot` account of the blockroot program does.
+pub fn accumulate_blocks_root(blocks_root: Option<[u8; 32]>, blob_leaf: [u8; 32]) -> [u8; 32] {
+    match blocks_root {
+        None => blob_leaf,
+        Some(blocks_root) => hashv(&[&blocks_root, &blob_leaf]),
+    }
+}
+
+/// Reassembles the data of a blob from its chunks, stripping their padding.
+pub fn blob_data(leaves: &[ChunkLeaf]) -> Vec<u8> {
+    leaves
+        .iter()
+        .flat_map(|leaf| {
+            let size = (leaf.actual_size as usize).min(leaf.body.len());
+            &leaf.body[..size]
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use blockroot::da::{get_chunks, merkleize, ChunkAccumulator};
+
+    use super::*;
+
+    fn leaves(data: &[u8], chunk_size: u64) -> Vec<ChunkLeaf> {
+        get_chunks(data, chunk_size)
+            .into_iter()
+            .map(|chunk| ChunkLeaf {
+                actual_size: chunk.actual_size,
+                body: chunk.chunk_body,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merkle_root_matches_program() {
+        for len in [1, 10, 64, 65, 200, 330] {
+            let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let chunks = get_chunks(&data, 64);
+
+            assert_eq!(Some(merkleize(&chunks)), merkle_root(&leaves(&data, 64)));
+            assert_eq!(data, blob_data(&leaves(&data, 64)));
+        }
+        assert_eq!(None, merkle_root(&[]));
+    }
+
+    #[test]
+    fn program_accumulates_chunks_in_any_order() {
+        let data: Vec<u8> = (0..330).map(|i| i as u8).collect();
+        let root = merkleize(&get_chunks(&data, 64));
+
+        // The last chunk first, then the others backwards.
+        let mut accumulator = ChunkAccumulator::new();
+        let mut chunks = get_chunks(&data, 64);
+        chunks.rotate_right(1);
+        chunks[1..].reverse();
+        for chunk in chunks {
+            assert!(!accumulator.is_complete(&root));
+            accumulator.accumulate(chunk);
+        }
+        assert_eq!(Some(root), accumulator.get_merkle_root(&root));
+    }
+
+    #[test]
+    fn blob_leaf_matches_program() {
+        let sender = [3; 32];
+        let root = [4; 32];
+        assert_eq!(
+            blockroot::da::blob_leaf(&sender, &root),
+            blob_leaf(&sender, root)
+        );
+        assert_ne!(blob_leaf(&[5; 32], root), blob_leaf(&sender, root));
+    }
+
+    #[test]
+    fn blocks_root_accumulation() {
+        let first = [1; 32];
+        let second = [2; 32];
+        assert_eq!(first, accumulate_blocks_root(None, first));
+        assert_eq!(
+            hashv(&[&first, &second]),
+            accumulate_blocks_root(Some(first), second)
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_rollup_interface::da::{BlobReaderTrait, DaSpec, DaVerifier};
+use sov_rollup_interface::digest::Digest;
+use sov_rollup_interface::zk::ValidityCondition;
+use thiserror::Error;
+
+use crate::spec::proof::{accumulate_blocks_root, blob_data, blob_leaf, merkle_root};
+use crate::spec::SolanaSpec;
+
+#[derive(Error, Debug)]
+pub enum ValidityConditionError {
+    #[error("conditions for validity can only be combined if the blocks are consecutive")]
+    BlocksNotConsecutive,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("the blobs don't match the blocks root of the header")]
+    BlocksRootMismatch,
+    #[error("the blob {0} has no chunks")]
+    EmptyBlob(usize),
+    #[error("the rollup blob {0} is missing")]
+    MissingBlob(usize),
+    #[error("the blob {0} was not completed in the slot")]
+    UnexpectedBlob(usize),
+    #[error("the blob {0} doesn't match its chunks")]
+    ForgedBlob(usize),
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+)]
+/// A validity condition expressing that a chain of DA layer blocks is contiguous and canonical
+pub struct ChainValidityCondition {
+    pub prev_hash: [u8; 32],
+    pub block_hash: [u8; 32],
+}
+
+impl ValidityCondition for ChainValidityCondition {
+    type Error = ValidityConditionError;
+
+    fn combine<H: Digest>(&self, rhs: Self) -> Result<Self, Self::Error> {
+        if self.block_hash != rhs.prev_hash {
+            return Err(ValidityConditionError::BlocksNotConsecutive);
+        }
+        Ok(rhs)
+    }
+}
+
+/// Verifies the blobs of a slot against the blocks root of the blockroot program.
+///
+/// # Soundness
+///
+/// This verifier is **unsound**: the blocks root of the header is trusted as given, since it isn't
+/// proven against the bank hash of the slot. It must not be used to verify proofs of a rollup with
+/// any value at stake.
+pub struct SolanaVerifier;
+
+impl DaVerifier for SolanaVerifier {
+    type Spec = SolanaSpec;
+
+    type Error = ValidationError;
+
+    // Verify that the given list of blob transactions is complete and correct: the Merkle roots
+    // of the chunks in the inclusion proof, hashed with the senders of the blobs and accumulated
+    // in order, must give the blocks root of the header, and each blob must hold the data of its
+    // chunks.
+    // UNSOUND: The blocks root of the header is read from the logs of the blockroot program. It
+    // is not proven against the bank hash of the slot, so it's trusted as given.
+    fn verify_relevant_tx_list(
+        &self,
+        block_header: &<Self::Spec as DaSpec>::BlockHeader,
+        txs: &[<Self::Spec as DaSpec>::BlobTransaction],
+        inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
+        _completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
+    ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
+        let mut blocks_root = None;
+        for (index, leaves) in inclusion_proof.iter().enumerate() {
+            let root = merkle_root(leaves).ok_or(ValidationError::EmptyBlob(index))?;
+            let Some(tx) = txs.get(index) else {
+                return Err(ValidationError::MissingBlob(index));
+            };
+            blocks_root = Some(accumulate_blocks_root(
+                blocks_root,
+                blob_leaf(tx.sender().as_ref(), root),
+            ));
+
+            let data = blob_data(leaves);
+            if tx.hash() != root
+                || tx.total_len() != data.len()
+                || !data.starts_with(tx.verified_data())
+            {
+                return Err(ValidationError::ForgedBlob(index));
+            }
+        }
+        if txs.len() > inclusion_proof.len() {
+            return Err(ValidationError::UnexpectedBlob(inclusion_proof.len()));
+        }
+        if blocks_root != block_header.blocks_root {
+            return Err(ValidationError::BlocksRootMismatch);
+        }
+
+        Ok(ChainValidityCondition {
+            prev_hash: block_header.previous_blockhash.0,
+            block_hash: block_header.blockhash.0,
+        })
+    }
+
+    fn new(_params: <Self::Spec as DaSpec>::ChainParams) -> Self {
+        SolanaVerifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use sov_rollup_interface::test_utils::{
+        test_partial_reads, test_tamper_rejection, test_verification_round_trip,
+    };
+
+    use super::*;
+    use crate::spec::address::SolanaAddress;
+    use crate::spec::blob::SolanaBlobTransaction;
+    use crate::spec::block::SolanaBlock;
+    use crate::spec::header::SolanaBlockHeader;
+    use crate::spec::proof::{ChunkLeaf, SolanaInclusionProof};
+    use crate::spec::SolanaHash;
+
+    /// Splits `data` into chunks of 4 bytes, padded like the chunks of the blockroot program.
+    fn leaves(data: &[u8]) -> Vec<ChunkLeaf> {
+        data.chunks(4)
+            .map(|chunk| {
+                let mut body = chunk.to_vec();
+                body.resize(4, 0);
+                ChunkLeaf {
+                    actual_size: chunk.len() as u64,
+                    body,
+                }
+            })
+            .collect()
+    }
+
+    /// A blob of `data` read up to `read_len` by the rollup.
+    fn partially_read_blob(data: &[u8], read_len: usize) -> SolanaBlobTransaction {
+        let mut blob = SolanaBlobTransaction::new(
+            Bytes::copy_from_slice(data),
+            merkle_root(&leaves(data)).unwrap(),
+            SolanaAddress::from([1; 32]),
+        );
+        blob.advance(read_len);
+        blob
+    }
+
+    fn blob(data: &[u8]) -> SolanaBlobTransaction {
+        partially_read_blob(data, data.len())
+    }
+
+    /// The header, blobs and proof of a slot in which blobs of `data` were completed.
+    fn test_block(
+        data: &[&[u8]],
+    ) -> (
+        SolanaBlockHeader,
+        Vec<SolanaBlobTransaction>,
+        SolanaInclusionProof,
+    ) {
+        let inclusion_proof: SolanaInclusionProof = data.iter().map(|data| leaves(data)).collect();
+        let blocks_root = inclusion_proof.iter().fold(None, |blocks_root, leaves| {
+            Some(accumulate_blocks_root(
+                blocks_root,
+                blob_leaf(&[1; 32], merkle_root(leaves).unwrap()),
+            ))
+        });
+        let header = SolanaBlockHeader {
+            slot: 10,
+            parent_slot: 9,
+            blockhash: SolanaHash([2; 32]),
+            previous_blockhash: SolanaHash([1; 32]),
+            block_time: Some(1_700_000_000),
+            blocks_root,
+        };
+        let blobs = data.iter().map(|data| blob(data)).collect();
+        (header, blobs, inclusion_proof)
+    }
+
+    fn verify(
+        (header, blobs, inclusion_proof): (
+            SolanaBlockHeader,
+            Vec<SolanaBlobTransaction>,
+            SolanaInclusionProof,
+        ),
+    ) -> Result<ChainValidityCondition, ValidationError> {
+        SolanaVerifier::new(()).verify_relevant_tx_list(&header, &blobs, inclusion_proof, ())
+    }
+
+    const BATCHES: [&[u8]; 2] = [b"first batch", b"second batch"];
+
+    #[test]
+    fn verifies_complete_blobs() {
+        let validity_condition = verify(test_block(&BATCHES)).unwrap();
+        assert_eq!([1; 32], validity_condition.prev_hash);
+        assert_eq!([2; 32], validity_condition.block_hash);
+
+        verify(test_block(&[])).unwrap();
+    }
+
+    #[test]
+    fn verifies_partially_read_blobs() {
+        let (header, mut blobs, inclusion_proof) = test_block(&BATCHES);
+        blobs[0] = partially_read_blob(b"first batch", 5);
+        verify((header, blobs, inclusion_proof)).unwrap();
+
+        let (header, mut blobs, inclusion_proof) = test_block(&BATCHES);
+        let mut forged = SolanaBlobTransaction::new(
+            Bytes::from_static(b"fakes batch"),
+            blobs[0].hash(),
+            SolanaAddress::from([1; 32]),
+        );
+        forged.advance(5);
+        blobs[0] = forged;
+        assert_eq!(
+            Err(ValidationError::ForgedBlob(0)),
+            verify((header, blobs, inclusion_proof))
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_blobs() {
+        // Omitting a blob from the proof too.
+        let (header, mut blobs, mut inclusion_proof) = test_block(&BATCHES);
+        blobs.pop();
+        inclusion_proof.pop();
+        assert_eq!(
+            Err(ValidationError::BlocksRootMismatch),
+            verify((header, blobs, inclusion_proof))
+        );
+
+        // Swapping the order of the blobs.
+        let (header, mut blobs, mut inclusion_proof) = test_block(&BATCHES);
+        blobs.reverse();
+        inclusion_proof.reverse();
+        assert_eq!(
+            Err(ValidationError::BlocksRootMismatch),
+            verify((header, blobs, inclusion_proof))
+        );
+
+        // Forging the chunks of a blob.
+        let (header, mut blobs, mut inclusion_proof) = test_block(&BATCHES);
+        blobs[1] = blob(b"forged batch");
+        inclusion_proof[1] = leaves(b"forged batch");
+        assert_eq!(
+            Err(ValidationError::BlocksRootMismatch),
+            verify((header, blobs, inclusion_proof))
+        );
+
+        // Changing the padding of a chunk changes its root.
+        let (header, blobs, mut inclusion_proof) = test_block(&BATCHES);
+        inclusion_proof[0][2].body[3] = 1;
+        assert_eq!(
+            Err(ValidationError::ForgedBlob(0)),
+            verify((header, blobs, inclusion_proof))
+        );
+
+        // Attributing a blob to another sender.
+        let (header, mut blobs, inclusion_proof) = test_block(&BATCHES);
+        blobs[1] = SolanaBlobTransaction::new(
+            Bytes::from_static(b"second batch"),
+            blobs[1].hash(),
+            SolanaAddress::from([2; 32]),
+        );
+        assert_eq!(
+            Err(ValidationError::BlocksRootMismatch),
+            verify((header, blobs, inclusion_proof))
+        );
+
+        let (header, blobs, mut inclusion_proof) = test_block(&BATCHES);
+        inclusion_proof[0].clear();
+        assert_eq!(
+            Err(ValidationError::EmptyBlob(0)),
+            verify((header, blobs, inclusion_proof))
+        );
+    }
+
+    #[test]
+    fn verifier_conformance() {
+        let verifier = SolanaVerifier::new(());
+        let (header, blobs, inclusion_proof) = test_block(&BATCHES);
+        let block = SolanaBlock {
+            header: header.clone(),
+            blobs: blobs.clone(),
+            inclusion_proof: inclusion_proof.clone(),
+        };
+        test_verification_round_trip(&verifier, &block, (blobs, inclusion_proof, ()));
+
+        test_tamper_rejection(&verifier, &header, || {
+            let (_, blobs, inclusion_proof) = test_block(&BATCHES);
+            (blobs, inclusion_proof, ())
+        });
+
+        test_partial_reads(partially_read_blob(b"first batch", 0), b"first batch");
+    }
+}
@@ -65,7 +65,7 @@ impl BlocksRoot {
         // slot number switched
         if slot_num > self.slot {
             self.digest = *blockroot;
-            self.slot = self.slot;
+            self.slot = slot_num;
         } else {
             // we're in the same solana slot
             self.digest = blocks_root_accumulator(&self.digest, blockroot);
@@ -119,30 +119,24 @@ impl ChunkAccumulator {
         let mut current_level = 0;
         let mut current_index = chunk_num as usize;
 
+        // Bubble up as long as the sibling of the current node is known. The last node of a
+        // level of odd length has no sibling and is promoted as is, so chunks can arrive in
+        // any order.
         while current_level < levels.len() - 1 {
-            if current_index % 2 == 1
-                && levels[current_level][current_index].is_some()
-                && levels[current_level][current_index - 1].is_some()
-            {
-                let left = levels[current_level][current_index - 1].as_ref().unwrap();
-                let right = levels[current_level][current_index].as_ref().unwrap();
-                let merged = hashv(&[left, right]).to_bytes();
-
-                levels[current_level + 1][current_index / 2] = Some(merged);
-                current_level += 1;
-                current_index /= 2;
-            } else if chunk_num == num_chunks - 1
-                && current_index % 2 == 0
-                && levels[current_level][current_index].is_some()
-            {
-                // Handle the case for unpaired nodes at the end of the level.
-                levels[current_level + 1][current_index / 2] =
-                    levels[current_level][current_index].clone();
-                current_level += 1;
-                current_index /= 2;
+            let level = &levels[current_level];
+            let sibling_index = current_index ^ 1;
+            let parent = if sibling_index >= level.len() {
+                level[current_index]
             } else {
-                break;
-            }
+                match (level[current_index & !1], level[current_index | 1]) {
+                    (Some(left), Some(right)) => Some(hashv(&[&left, &right]).to_bytes()),
+                    _ => break,
+                }
+            };
+
+            levels[current_level + 1][current_index / 2] = parent;
+            current_level += 1;
+            current_index /= 2;
         }
     }
 
@@ -254,6 +248,21 @@ pub fn merkleize(chunks: &[Chunk]) -> [u8; 32] {
     current_level[0]
 }
 
+/// Computes the leaf of a completed blob in the blocks root, committing to both the sender of the
+/// blob and the Merkle root of its chunks.
+///
+/// # Arguments
+///
+/// * `sender` - The public key of the payer of the transaction completing the blob.
+/// * `merkle_root` - The Merkle root of the chunks of the blob.
+///
+/// # Returns
+///
+/// The keccak hash of the sender followed by the Merkle root.
+pub fn blob_leaf(sender: &[u8; 32], merkle_root: &[u8; 32]) -> [u8; 32] {
+    hashv(&[sender.as_ref(), merkle_root.as_ref()]).0
+}
+
 /// Combines the current root and a block digest using a cryptographic hash function (keccak hashv syscall)
 ///
 /// This function essentially merges the `current_root` and `block_digest` by hashing them together.
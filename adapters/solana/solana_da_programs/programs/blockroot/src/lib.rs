@@ -4,7 +4,7 @@ use std::collections::BTreeMap;
 
 use anchor_lang::prelude::*;
 
-use crate::da::{BlocksRoot, Chunk, ChunkAccumulator, CHUNK_SIZE, PREFIX};
+use crate::da::{blob_leaf, BlocksRoot, Chunk, ChunkAccumulator, CHUNK_SIZE, PREFIX};
 
 declare_id!("6YQGvP866CHpLTdHwmLqj2Vh5q7T1GF4Kk9gS9MCta8E");
 
@@ -77,7 +77,9 @@ pub mod blockroot {
                 digest,
                 merkle_root
             );
-            blocks_root.update_root(&merkle_root, current_slot_num);
+            // The blocks root commits to the sender of the blob along with its data.
+            let leaf = blob_leaf(&ctx.accounts.creator.key().to_bytes(), &merkle_root);
+            blocks_root.update_root(&leaf, current_slot_num);
             msg!(
                 "blocks root for slot {}, blob root: {:?} combined root: {:?}",
                 current_slot_num,
@@ -120,15 +120,16 @@ async fn main() -> Result<(), anyhow::Error> {
         DefaultContext,
         RngDaSpec,
         Risc0Verifier,
-        Runtime<DefaultContext, RngDaSpec>,
+        Runtime<DefaultContext, RngDaSpec, Risc0Verifier>,
         BasicKernel<DefaultContext, _>,
     >::new();
 
     let demo_genesis_config = {
         let integ_test_conf_dir: &Path = "../test-data/genesis/integration-tests".as_ref();
-        let rt_params =
-            get_genesis_config::<DefaultContext, _>(&GenesisPaths::from_dir(integ_test_conf_dir))
-                .unwrap();
+        let rt_params = get_genesis_config::<DefaultContext, _, Risc0Verifier>(
+            &GenesisPaths::from_dir(integ_test_conf_dir),
+        )
+        .unwrap();
 
         let chain_state = read_json_file(integ_test_conf_dir.join("chain_state.json")).unwrap();
         let kernel_params = BasicKernelGenesisConfig { chain_state };
@@ -24,7 +24,7 @@ use sov_modules_api::SlotData;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_modules_stf_blueprint::{GenesisParams, StfBlueprint};
 use sov_prover_storage_manager::ProverStorageManager;
-use sov_risc0_adapter::host::Risc0Host;
+use sov_risc0_adapter::host::{Risc0Host, Risc0Verifier};
 #[cfg(feature = "bench")]
 use sov_risc0_adapter::metrics::GLOBAL_HASHMAP;
 use sov_rollup_interface::da::BlockHeaderTrait;
@@ -141,7 +141,7 @@ type BenchSTF<'a> = StfBlueprint<
     DefaultContext,
     MockDaSpec,
     Risc0Host<'a>,
-    Runtime<DefaultContext, MockDaSpec>,
+    Runtime<DefaultContext, MockDaSpec, Risc0Verifier>,
     BasicKernel<DefaultContext, MockDaSpec>,
 >;
 
@@ -186,9 +186,9 @@ async fn main() -> Result<(), anyhow::Error> {
     generate_genesis_config(genesis_conf_dir.as_str())?;
 
     let genesis_config = {
-        let rt_params = get_genesis_config::<DefaultContext, _>(&GenesisPaths::from_dir(
-            genesis_conf_dir.as_str(),
-        ))
+        let rt_params = get_genesis_config::<DefaultContext, _, Risc0Verifier>(
+            &GenesisPaths::from_dir(genesis_conf_dir.as_str()),
+        )
         .unwrap();
 
         let chain_state =
@@ -20,7 +20,7 @@ risc0_zkvm::guest::entry!(main);
 pub fn main() {
     let guest = Risc0Guest::new();
     let storage = ZkStorage::new();
-    let stf: StfBlueprint<ZkDefaultContext, _, _, Runtime<_, _>, BasicKernel<_, _>> =
+    let stf: StfBlueprint<ZkDefaultContext, _, _, Runtime<_, _, Risc0Guest>, BasicKernel<_, _>> =
         StfBlueprint::new();
 
     let stf_verifier = StfVerifier::new(
@@ -13,6 +13,7 @@ use sov_nft_module::utils::{
     get_transfer_nft_message,
 };
 use sov_nft_module::{CallMessage, CollectionAddress};
+use sov_risc0_adapter::host::Risc0Verifier;
 use sov_sequencer::utils::SimpleClient;
 
 const COLLECTION_1: &str = "Sovereign Squirrel Syndicate";
@@ -39,7 +40,8 @@ pub fn build_transaction(
     message: CallMessage<DefaultContext>,
     nonce: u64,
 ) -> Transaction<DefaultContext> {
-    let runtime_encoded_message = RuntimeCall::<DefaultContext, MockDaSpec>::nft(message);
+    let runtime_encoded_message =
+        RuntimeCall::<DefaultContext, MockDaSpec, Risc0Verifier>::nft(message);
     let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use demo_stf::genesis_config::StorageConfig;
+use demo_stf::proof_submission::VerifyProofEncoder;
 use demo_stf::runtime::Runtime;
 use sov_celestia_adapter::verifier::{CelestiaSpec, CelestiaVerifier, RollupParams};
 use sov_celestia_adapter::{CelestiaConfig, CelestiaService};
@@ -9,15 +10,20 @@ use sov_modules_rollup_blueprint::{RollupBlueprint, WalletBlueprint};
 use sov_modules_stf_blueprint::kernels::basic::BasicKernel;
 use sov_modules_stf_blueprint::StfBlueprint;
 use sov_prover_storage_manager::ProverStorageManager;
-use sov_risc0_adapter::host::Risc0Host;
+use sov_risc0_adapter::host::{Risc0Host, Risc0Verifier};
 use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::{DefaultStorageSpec, Storage, ZkStorage};
 use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig};
 
-use crate::{ROLLUP_BATCH_NAMESPACE, ROLLUP_PROOF_NAMESPACE};
+use crate::{read_prover_priv_key, ROLLUP_BATCH_NAMESPACE, ROLLUP_PROOF_NAMESPACE};
 
 /// Rollup with CelestiaDa
-pub struct CelestiaDemoRollup {}
+#[derive(Default)]
+pub struct CelestiaDemoRollup {
+    /// The Ethereum RPC configuration.
+    #[cfg(feature = "experimental")]
+    pub eth_config: crate::EthConfig,
+}
 
 #[async_trait]
 impl RollupBlueprint for CelestiaDemoRollup {
@@ -30,9 +36,9 @@ impl RollupBlueprint for CelestiaDemoRollup {
     type NativeContext = DefaultContext;
 
     type StorageManager = ProverStorageManager<CelestiaSpec, DefaultStorageSpec>;
-    type ZkRuntime = Runtime<Self::ZkContext, Self::DaSpec>;
+    type ZkRuntime = Runtime<Self::ZkContext, Self::DaSpec, <Self::Vm as ZkvmHost>::Guest>;
 
-    type NativeRuntime = Runtime<Self::NativeContext, Self::DaSpec>;
+    type NativeRuntime = Runtime<Self::NativeContext, Self::DaSpec, Risc0Verifier>;
 
     type NativeKernel = BasicKernel<Self::NativeContext, Self::DaSpec>;
     type ZkKernel = BasicKernel<Self::ZkContext, Self::DaSpec>;
@@ -68,9 +74,11 @@ impl RollupBlueprint for CelestiaDemoRollup {
         >(storage, ledger_db, da_service, sequencer)?;
 
         #[cfg(feature = "experimental")]
-        crate::eth::register_ethereum::<Self::DaService>(
+        crate::eth::register_ethereum::<Self::DaService, Risc0Verifier>(
             da_service.clone(),
             storage.clone(),
+            ledger_db,
+            &self.eth_config,
             &mut rpc_methods,
         )?;
 
@@ -95,8 +103,17 @@ impl RollupBlueprint for CelestiaDemoRollup {
         &self,
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
-        _da_service: &Self::DaService,
+        da_service: &Self::DaService,
+        storage: &<Self::NativeContext as Spec>::Storage,
     ) -> Self::ProverService {
+        let prover_key = read_prover_priv_key().expect("Failed to read the prover's private key");
+        let proof_encoder =
+            VerifyProofEncoder::<Self::NativeContext, Self::DaSpec, Risc0Verifier>::new(
+                prover_key,
+                0,
+                storage.clone(),
+            );
+
         let vm = Risc0Host::new(risc0::ROLLUP_ELF);
         let zk_stf = StfBlueprint::new();
         let zk_storage = ZkStorage::new();
@@ -113,6 +130,7 @@ impl RollupBlueprint for CelestiaDemoRollup {
             zk_storage,
             rollup_config.prover_service,
         )
+        .with_proof_submission(da_service.clone(), proof_encoder)
     }
 
     fn create_storage_manager(
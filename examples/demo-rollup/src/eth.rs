@@ -1,14 +1,20 @@
+use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::Context as _;
 use sov_cli::wallet_state::PrivateKeyAndAddress;
+use sov_db::ledger_db::LedgerDB;
 use sov_ethereum::experimental::EthRpcConfig;
-use sov_ethereum::GasPriceOracleConfig;
+use sov_ethereum::{GasPriceOracleConfig, MempoolConfig};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_prover_storage_manager::SnapshotManager;
+use sov_rollup_interface::rpc::LedgerRpcProvider;
 use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::zk::Zkvm;
 use sov_state::ProverStorage;
+use sov_stf_runner::from_toml_path;
 
 const TX_SIGNER_PRIV_KEY_PATH: &str = "../test-data/keys/tx_signer_private_key.json";
 
@@ -23,10 +29,38 @@ fn read_sov_tx_signer_priv_key() -> Result<DefaultPrivateKey, anyhow::Error> {
     Ok(key_and_address.private_key)
 }
 
+/// The Ethereum RPC configuration, read from the `[eth]` section of the rollup config.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(default)]
+pub struct EthConfig {
+    /// If set, the pending EVM transactions are published in a blob every `blob_interval_ms`
+    /// milliseconds. Otherwise, they wait for `eth_publishBatch`.
+    pub blob_interval_ms: Option<u64>,
+    /// The limits of the pool of EVM transactions.
+    pub mempool: MempoolConfig,
+}
+
+impl EthConfig {
+    /// Reads the `[eth]` section of the rollup config at `path`. The defaults are used if the
+    /// section is missing.
+    pub fn from_rollup_config_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        #[derive(serde::Deserialize)]
+        struct RollupConfigWithEth {
+            #[serde(default)]
+            eth: EthConfig,
+        }
+
+        let config: RollupConfigWithEth = from_toml_path(path)?;
+        Ok(config.eth)
+    }
+}
+
 // register ethereum methods.
-pub(crate) fn register_ethereum<Da: DaService>(
+pub(crate) fn register_ethereum<Da: DaService, Vm: Zkvm + 'static>(
     da_service: Da,
     storage: ProverStorage<sov_state::DefaultStorageSpec, SnapshotManager>,
+    ledger_db: &LedgerDB,
+    eth_config: &EthConfig,
     methods: &mut jsonrpsee::RpcModule<()>,
 ) -> Result<(), anyhow::Error> {
     let eth_rpc_config = {
@@ -36,11 +70,17 @@ pub(crate) fn register_ethereum<Da: DaService>(
             sov_tx_signer_priv_key: read_sov_tx_signer_priv_key()?,
             eth_signer,
             gas_price_oracle_config: GasPriceOracleConfig::default(),
+            mempool_config: eth_config.mempool.clone(),
+            blob_interval: eth_config.blob_interval_ms.map(Duration::from_millis),
         }
     };
 
-    let ethereum_rpc =
-        sov_ethereum::get_ethereum_rpc::<DefaultContext, Da>(da_service, eth_rpc_config, storage);
+    let ethereum_rpc = sov_ethereum::get_ethereum_rpc::<DefaultContext, Da, Vm>(
+        da_service,
+        eth_rpc_config,
+        storage,
+        ledger_db.subscribe_slots()?,
+    );
     methods
         .merge(ethereum_rpc)
         .context("Failed to merge Ethereum RPC modules")
@@ -4,8 +4,12 @@
 use std::env;
 use std::str::FromStr;
 
+use anyhow::Context as _;
 use const_rollup_config::{ROLLUP_BATCH_NAMESPACE_RAW, ROLLUP_PROOF_NAMESPACE_RAW};
 use sov_celestia_adapter::types::Namespace;
+use sov_cli::wallet_state::PrivateKeyAndAddress;
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 mod mock_rollup;
 pub use mock_rollup::*;
 use tracing_subscriber::layer::SubscriberExt;
@@ -16,6 +20,8 @@ mod celestia_rollup;
 pub use celestia_rollup::*;
 #[cfg(feature = "experimental")]
 mod eth;
+#[cfg(feature = "experimental")]
+pub use eth::EthConfig;
 
 /// The rollup stores its data in the namespace b"sov-test" on Celestia
 /// You can change this constant to point your rollup at a different namespace
@@ -24,6 +30,19 @@ pub const ROLLUP_BATCH_NAMESPACE: Namespace = Namespace::const_v0(ROLLUP_BATCH_N
 /// The rollup stores the zk proofs in the namespace b"sov-test-p" on Celestia.
 pub const ROLLUP_PROOF_NAMESPACE: Namespace = Namespace::const_v0(ROLLUP_PROOF_NAMESPACE_RAW);
 
+const PROVER_PRIV_KEY_PATH: &str = "../test-data/keys/prover_private_key.json";
+
+/// The prover service posts its proofs to the DA layer in `VerifyProof` transactions.
+/// This function reads the private key of the prover, which signs these transactions.
+fn read_prover_priv_key() -> Result<DefaultPrivateKey, anyhow::Error> {
+    let data = std::fs::read_to_string(PROVER_PRIV_KEY_PATH).context("Unable to read file")?;
+
+    let key_and_address: PrivateKeyAndAddress<DefaultContext> = serde_json::from_str(&data)
+        .with_context(|| format!("Unable to convert data {} to PrivateKeyAndAddress", &data))?;
+
+    Ok(key_and_address.private_key)
+}
+
 /// Default initialization of logging
 pub fn initialize_logging() {
     tracing_subscriber::registry()
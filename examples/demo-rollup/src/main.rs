@@ -91,7 +91,11 @@ async fn new_rollup_with_celestia_da(
         )?,
     };
 
-    let mock_rollup = CelestiaDemoRollup {};
+    let mock_rollup = CelestiaDemoRollup {
+        #[cfg(feature = "experimental")]
+        eth_config: sov_demo_rollup::EthConfig::from_rollup_config_path(rollup_config_path)
+            .context("Failed to read the Ethereum RPC configuration")?,
+    };
     mock_rollup
         .create_new_rollup(
             rt_genesis_paths,
@@ -120,7 +124,11 @@ async fn new_rollup_with_mock_da(
         )?,
     };
 
-    let mock_rollup = MockDemoRollup {};
+    let mock_rollup = MockDemoRollup {
+        #[cfg(feature = "experimental")]
+        eth_config: sov_demo_rollup::EthConfig::from_rollup_config_path(rollup_config_path)
+            .context("Failed to read the Ethereum RPC configuration")?,
+    };
     mock_rollup
         .create_new_rollup(
             rt_genesis_paths,
@@ -1,25 +1,33 @@
 use async_trait::async_trait;
 use demo_stf::genesis_config::StorageConfig;
+use demo_stf::proof_submission::VerifyProofEncoder;
 use demo_stf::runtime::Runtime;
 use sov_db::ledger_db::LedgerDB;
-use sov_mock_da::{MockDaConfig, MockDaService, MockDaSpec};
+use sov_mock_da::{MockDaConfig, MockDaSpec, StorableMockDaService};
 use sov_modules_api::default_context::{DefaultContext, ZkDefaultContext};
 use sov_modules_api::{Address, Spec};
 use sov_modules_rollup_blueprint::RollupBlueprint;
 use sov_modules_stf_blueprint::kernels::basic::BasicKernel;
 use sov_modules_stf_blueprint::StfBlueprint;
 use sov_prover_storage_manager::ProverStorageManager;
-use sov_risc0_adapter::host::Risc0Host;
+use sov_risc0_adapter::host::{Risc0Host, Risc0Verifier};
 use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::{DefaultStorageSpec, Storage, ZkStorage};
 use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig};
 
+use crate::read_prover_priv_key;
+
 /// Rollup with MockDa
-pub struct MockDemoRollup {}
+#[derive(Default)]
+pub struct MockDemoRollup {
+    /// The Ethereum RPC configuration.
+    #[cfg(feature = "experimental")]
+    pub eth_config: crate::EthConfig,
+}
 
 #[async_trait]
 impl RollupBlueprint for MockDemoRollup {
-    type DaService = MockDaService;
+    type DaService = StorableMockDaService;
     type DaSpec = MockDaSpec;
     type DaConfig = MockDaConfig;
     type Vm = Risc0Host<'static>;
@@ -29,8 +37,8 @@ impl RollupBlueprint for MockDemoRollup {
 
     type StorageManager = ProverStorageManager<MockDaSpec, DefaultStorageSpec>;
 
-    type ZkRuntime = Runtime<Self::ZkContext, Self::DaSpec>;
-    type NativeRuntime = Runtime<Self::NativeContext, Self::DaSpec>;
+    type ZkRuntime = Runtime<Self::ZkContext, Self::DaSpec, <Self::Vm as ZkvmHost>::Guest>;
+    type NativeRuntime = Runtime<Self::NativeContext, Self::DaSpec, Risc0Verifier>;
 
     type NativeKernel = BasicKernel<Self::NativeContext, Self::DaSpec>;
     type ZkKernel = BasicKernel<Self::ZkContext, Self::DaSpec>;
@@ -66,9 +74,11 @@ impl RollupBlueprint for MockDemoRollup {
         >(storage, ledger_db, da_service, sequencer)?;
 
         #[cfg(feature = "experimental")]
-        crate::eth::register_ethereum::<Self::DaService>(
+        crate::eth::register_ethereum::<Self::DaService, Risc0Verifier>(
             da_service.clone(),
             storage.clone(),
+            ledger_db,
+            &self.eth_config,
             &mut rpc_methods,
         )?;
 
@@ -79,15 +89,25 @@ impl RollupBlueprint for MockDemoRollup {
         &self,
         rollup_config: &RollupConfig<Self::DaConfig>,
     ) -> Self::DaService {
-        MockDaService::new(rollup_config.da.sender_address)
+        StorableMockDaService::from_config(&rollup_config.da)
+            .expect("Failed to open the mock DA database")
     }
 
     async fn create_prover_service(
         &self,
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
-        _da_service: &Self::DaService,
+        da_service: &Self::DaService,
+        storage: &<Self::NativeContext as Spec>::Storage,
     ) -> Self::ProverService {
+        let prover_key = read_prover_priv_key().expect("Failed to read the prover's private key");
+        let proof_encoder =
+            VerifyProofEncoder::<Self::NativeContext, Self::DaSpec, Risc0Verifier>::new(
+                prover_key,
+                0,
+                storage.clone(),
+            );
+
         let vm = Risc0Host::new(risc0::MOCK_DA_ELF);
         let zk_stf = StfBlueprint::new();
         let zk_storage = ZkStorage::new();
@@ -101,6 +121,7 @@ impl RollupBlueprint for MockDemoRollup {
             zk_storage,
             rollup_config.prover_service,
         )
+        .with_proof_submission(da_service.clone(), proof_encoder)
     }
 
     fn create_storage_manager(
@@ -185,7 +185,7 @@ macro_rules! jsonrpc_result {
 #[test]
 fn test_get_head() {
     let payload = jsonrpc_req!("ledger_getHead", []);
-    let expected = jsonrpc_result!({"number":1,"hash":"0xd1231a38586e68d0405dc55ae6775e219f29fff1f7e0c6410d0ac069201e550b","batch_range":{"start":1,"end":3}});
+    let expected = jsonrpc_result!({"number":1,"hash":"0xd1231a38586e68d0405dc55ae6775e219f29fff1f7e0c6410d0ac069201e550b","status":"Finalized","batch_range":{"start":1,"end":3}});
 
     regular_test_helper(payload, &expected);
 }
@@ -381,6 +381,7 @@ proptest!(
         let expected = jsonrpc_result!({
             "number": slots.len(),
             "hash": format!("0x{}", hex::encode(last_slot.slot_data().hash())),
+            "status": "Finalized",
             "batch_range": {
                 "start": last_slot_start_batch,
                 "end": last_slot_end_batch
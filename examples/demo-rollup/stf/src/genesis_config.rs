@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context as _};
 use sov_accounts::AccountConfig;
+use sov_attester_incentives::AttesterIncentivesConfig;
 use sov_bank::BankConfig;
 #[cfg(feature = "experimental")]
 use sov_evm::EvmConfig;
@@ -14,7 +15,9 @@ pub use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::Context;
 use sov_modules_stf_blueprint::Runtime as RuntimeTrait;
 use sov_nft_module::NonFungibleTokenConfig;
+use sov_prover_incentives::ProverIncentivesConfig;
 use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::Zkvm;
 use sov_sequencer_registry::SequencerConfig;
 pub use sov_state::config::Config as StorageConfig;
 use sov_stf_runner::read_json_file;
@@ -22,7 +25,7 @@ use sov_value_setter::ValueSetterConfig;
 
 /// Creates config for a rollup with some default settings, the config is used in demos and tests.
 use crate::runtime::GenesisConfig;
-use crate::runtime::Runtime;
+use crate::runtime::{DaValidityCondChecker, Runtime};
 
 /// Paths pointing to genesis files.
 pub struct GenesisPaths {
@@ -36,6 +39,10 @@ pub struct GenesisPaths {
     pub accounts_genesis_path: PathBuf,
     /// NFT genesis path.
     pub nft_path: PathBuf,
+    /// Prover Incentives genesis path.
+    pub prover_incentives_genesis_path: PathBuf,
+    /// Attester Incentives genesis path.
+    pub attester_incentives_genesis_path: PathBuf,
     #[cfg(feature = "experimental")]
     /// EVM genesis path.
     pub evm_genesis_path: PathBuf,
@@ -54,6 +61,8 @@ impl GenesisPaths {
             value_setter_genesis_path: dir.as_ref().join("value_setter.json"),
             accounts_genesis_path: dir.as_ref().join("accounts.json"),
             nft_path: dir.as_ref().join("nft.json"),
+            prover_incentives_genesis_path: dir.as_ref().join("prover_incentives.json"),
+            attester_incentives_genesis_path: dir.as_ref().join("attester_incentives.json"),
             #[cfg(feature = "experimental")]
             evm_genesis_path: dir.as_ref().join("evm.json"),
         }
@@ -61,17 +70,17 @@ impl GenesisPaths {
 }
 
 /// Creates genesis configuration.
-pub fn get_genesis_config<C: Context, Da: DaSpec>(
+pub fn get_genesis_config<C: Context, Da: DaSpec, Vm: Zkvm + 'static>(
     genesis_paths: &GenesisPaths,
-) -> Result<<Runtime<C, Da> as RuntimeTrait<C, Da>>::GenesisConfig, anyhow::Error> {
+) -> Result<<Runtime<C, Da, Vm> as RuntimeTrait<C, Da>>::GenesisConfig, anyhow::Error> {
     let genesis_config =
         create_genesis_config(genesis_paths).context("Unable to read genesis configuration")?;
     validate_config(genesis_config)
 }
 
-pub(crate) fn validate_config<C: Context, Da: DaSpec>(
-    genesis_config: <Runtime<C, Da> as RuntimeTrait<C, Da>>::GenesisConfig,
-) -> Result<<Runtime<C, Da> as RuntimeTrait<C, Da>>::GenesisConfig, anyhow::Error> {
+pub(crate) fn validate_config<C: Context, Da: DaSpec, Vm: Zkvm + 'static>(
+    genesis_config: <Runtime<C, Da, Vm> as RuntimeTrait<C, Da>>::GenesisConfig,
+) -> Result<<Runtime<C, Da, Vm> as RuntimeTrait<C, Da>>::GenesisConfig, anyhow::Error> {
     let token_address = &sov_bank::get_genesis_token_address::<C>(
         &genesis_config.bank.tokens[0].token_name,
         genesis_config.bank.tokens[0].salt,
@@ -90,12 +99,32 @@ pub(crate) fn validate_config<C: Context, Da: DaSpec>(
         )
     }
 
+    let bonding_token_addr = &genesis_config.prover_incentives.bonding_token_address;
+
+    if bonding_token_addr != token_address {
+        bail!(
+            "Wrong token address in `prover_incentives_config` expected {} but found {}",
+            token_address,
+            bonding_token_addr
+        )
+    }
+
+    let attester_bonding_token_addr = &genesis_config.attester_incentives.bonding_token_address;
+
+    if attester_bonding_token_addr != token_address {
+        bail!(
+            "Wrong token address in `attester_incentives_config` expected {} but found {}",
+            token_address,
+            attester_bonding_token_addr
+        )
+    }
+
     Ok(genesis_config)
 }
 
-fn create_genesis_config<C: Context, Da: DaSpec>(
+fn create_genesis_config<C: Context, Da: DaSpec, Vm: Zkvm + 'static>(
     genesis_paths: &GenesisPaths,
-) -> anyhow::Result<<Runtime<C, Da> as RuntimeTrait<C, Da>>::GenesisConfig> {
+) -> anyhow::Result<<Runtime<C, Da, Vm> as RuntimeTrait<C, Da>>::GenesisConfig> {
     let bank_config: BankConfig<C> = read_json_file(&genesis_paths.bank_genesis_path)?;
 
     let sequencer_registry_config: SequencerConfig<C, Da> =
@@ -108,6 +137,16 @@ fn create_genesis_config<C: Context, Da: DaSpec>(
 
     let nft_config: NonFungibleTokenConfig = read_json_file(&genesis_paths.nft_path)?;
 
+    let prover_incentives_config: ProverIncentivesConfig<C, Vm> =
+        read_json_file(&genesis_paths.prover_incentives_genesis_path)?;
+
+    let attester_incentives_config: AttesterIncentivesConfig<
+        C,
+        Vm,
+        Da,
+        DaValidityCondChecker<Da::ValidityCondition>,
+    > = read_json_file(&genesis_paths.attester_incentives_genesis_path)?;
+
     #[cfg(feature = "experimental")]
     let evm_config: EvmConfig = read_json_file(&genesis_paths.evm_genesis_path)?;
 
@@ -117,6 +156,8 @@ fn create_genesis_config<C: Context, Da: DaSpec>(
         value_setter_config,
         accounts_config,
         nft_config,
+        prover_incentives_config,
+        attester_incentives_config,
         #[cfg(feature = "experimental")]
         evm_config,
     ))
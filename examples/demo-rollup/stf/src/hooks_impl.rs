@@ -1,19 +1,20 @@
 use sov_accounts::AccountsTxHook;
-use sov_bank::BankTxHook;
+use sov_bank::{get_gas_token_address, Amount, BankTxHook, Coins, UnpaidBaseCostError};
 use sov_modules_api::hooks::{ApplyBlobHooks, FinalizeHook, SlotHooks, TxHooks};
 use sov_modules_api::transaction::Transaction;
-use sov_modules_api::{AccessoryWorkingSet, Context, Spec, WorkingSet};
-use sov_modules_stf_blueprint::{RuntimeTxHook, SequencerOutcome};
+use sov_modules_api::{AccessoryWorkingSet, Context, ModuleInfo, Spec, WorkingSet};
+use sov_modules_stf_blueprint::{RuntimeTxHook, SequencerOutcome, SlashingReason};
 #[cfg(feature = "experimental")]
 use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::da::{BlobReaderTrait, DaSpec};
+use sov_rollup_interface::zk::Zkvm;
 use sov_sequencer_registry::SequencerRegistry;
 use sov_state::Storage;
 use tracing::info;
 
 use crate::runtime::Runtime;
 
-impl<C: Context, Da: DaSpec> TxHooks for Runtime<C, Da> {
+impl<C: Context, Da: DaSpec, Vm: Zkvm + 'static> TxHooks for Runtime<C, Da, Vm> {
     type Context = C;
     type PreArg = RuntimeTxHook<C>;
     type PreResult = C;
@@ -24,13 +25,45 @@ impl<C: Context, Da: DaSpec> TxHooks for Runtime<C, Da> {
         working_set: &mut WorkingSet<C>,
         arg: &RuntimeTxHook<C>,
     ) -> anyhow::Result<C> {
-        let RuntimeTxHook { height, sequencer } = arg;
-        let AccountsTxHook { sender, sequencer } =
+        let RuntimeTxHook {
+            height,
+            sequencer,
+            sequencer_da_address,
+        } = arg;
+        let AccountsTxHook { sender, .. } =
             self.accounts
                 .pre_dispatch_tx_hook(tx, working_set, sequencer)?;
 
-        let hook = BankTxHook { sender, sequencer };
-        self.bank.pre_dispatch_tx_hook(tx, working_set, &hook)?;
+        // The transaction of a direct blob wasn't included by any sequencer, so its sender
+        // stands in for one.
+        let sequencer = match sequencer_da_address {
+            Some(da_address) => {
+                let da_address = Da::Address::try_from(da_address.as_slice())?;
+                self.sequencer_registry
+                    .get_sequencer_rollup_address(&da_address, working_set)
+                    .ok_or_else(|| anyhow::anyhow!("Sequencer {} is not registered", da_address))?
+            }
+            None => sender.clone(),
+        };
+
+        // Gas fees are escrowed by the sequencer registry, which pays them out to the
+        // sequencer once the whole blob has been executed.
+        let hook = BankTxHook {
+            sender,
+            sequencer,
+            gas_escrow: self.sequencer_registry.address().clone(),
+        };
+        self.bank
+            .pre_dispatch_tx_hook(tx, working_set, &hook)
+            .map_err(|e| {
+                // A sequencer that can't pay for the base cost of a transaction it included
+                // is slashed.
+                if sequencer_da_address.is_some() && e.is::<UnpaidBaseCostError<C::Address>>() {
+                    anyhow::Error::new(SlashingReason::UnpaidTransactionBaseCost)
+                } else {
+                    e
+                }
+            })?;
 
         Ok(C::new(hook.sender, hook.sequencer, *height))
     }
@@ -42,12 +75,20 @@ impl<C: Context, Da: DaSpec> TxHooks for Runtime<C, Da> {
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
         self.accounts.post_dispatch_tx_hook(tx, ctx, working_set)?;
-        self.bank.post_dispatch_tx_hook(tx, ctx, working_set)?;
+        // The unused gas is refunded from the escrow of the sequencer registry, not by the
+        // sequencer of the context.
+        self.bank.refund_remaining_gas(
+            self.sequencer_registry.address(),
+            ctx.sender(),
+            working_set,
+        )?;
         Ok(())
     }
 }
 
-impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for Runtime<C, Da> {
+impl<C: Context, Da: DaSpec, Vm: Zkvm + 'static> ApplyBlobHooks<Da::BlobTransaction>
+    for Runtime<C, Da, Vm>
+{
     type Context = C;
     type BlobResult =
         SequencerOutcome<<<Da as DaSpec>::BlobTransaction as BlobReaderTrait>::Address>;
@@ -67,15 +108,31 @@ impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for Runtime<C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
         match result {
-            SequencerOutcome::Rewarded(_reward) => {
-                // TODO: Process reward here or above.
-                <SequencerRegistry<C, Da> as ApplyBlobHooks<Da::BlobTransaction>>::end_blob_hook(
-                    &self.sequencer_registry,
-                    sov_sequencer_registry::SequencerOutcome::Completed,
-                    working_set,
-                )
-            }
+            SequencerOutcome::Rewarded {
+                amount,
+                sequencer_da_address,
+            } => <SequencerRegistry<C, Da> as ApplyBlobHooks<Da::BlobTransaction>>::end_blob_hook(
+                &self.sequencer_registry,
+                sov_sequencer_registry::SequencerOutcome::Rewarded {
+                    sequencer: sequencer_da_address,
+                    amount: Amount::from(amount),
+                },
+                working_set,
+            ),
             SequencerOutcome::Ignored => Ok(()),
+            // No sequencer included the transaction of a direct blob, so the gas it paid is
+            // burned from the escrow of the sequencer registry.
+            SequencerOutcome::Direct { amount, .. } => {
+                if amount > 0 {
+                    let coins = Coins {
+                        amount: Amount::from(amount),
+                        token_address: get_gas_token_address::<C>()?,
+                    };
+                    self.bank
+                        .burn(coins, self.sequencer_registry.address(), working_set)?;
+                }
+                Ok(())
+            }
             SequencerOutcome::Slashed {
                 reason,
                 sequencer_da_address,
@@ -85,6 +142,22 @@ impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for Runtime<C,
                     &self.sequencer_registry,
                     sov_sequencer_registry::SequencerOutcome::Slashed {
                         sequencer: sequencer_da_address,
+                        reason: match reason {
+                            SlashingReason::InvalidBatchEncoding => {
+                                sov_sequencer_registry::SlashingReason::InvalidBatchEncoding
+                            }
+                            SlashingReason::StatelessVerificationFailed => {
+                                sov_sequencer_registry::SlashingReason::StatelessVerificationFailed
+                            }
+                            SlashingReason::InvalidTransactionEncoding => {
+                                sov_sequencer_registry::SlashingReason::InvalidTransactionEncoding
+                            }
+                            SlashingReason::UnpaidTransactionBaseCost => {
+                                sov_sequencer_registry::SlashingReason::UnpaidTransactionBaseCost
+                            }
+                        },
+                        // The batch is checked by the rollup itself, so nobody reported it.
+                        reporter: None,
                     },
                     working_set,
                 )
@@ -93,7 +166,7 @@ impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for Runtime<C,
     }
 }
 
-impl<C: Context, Da: DaSpec> SlotHooks<Da> for Runtime<C, Da> {
+impl<C: Context, Da: DaSpec, Vm: Zkvm + 'static> SlotHooks<Da> for Runtime<C, Da, Vm> {
     type Context = C;
 
     fn begin_slot_hook(
@@ -118,7 +191,9 @@ impl<C: Context, Da: DaSpec> SlotHooks<Da> for Runtime<C, Da> {
     }
 }
 
-impl<C: Context, Da: sov_modules_api::DaSpec> FinalizeHook<Da> for Runtime<C, Da> {
+impl<C: Context, Da: sov_modules_api::DaSpec, Vm: Zkvm + 'static> FinalizeHook<Da>
+    for Runtime<C, Da, Vm>
+{
     type Context = C;
 
     fn finalize_hook(
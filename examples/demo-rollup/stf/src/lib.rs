@@ -4,6 +4,8 @@
 #[cfg(feature = "native")]
 pub mod genesis_config;
 mod hooks_impl;
+#[cfg(feature = "native")]
+pub mod proof_submission;
 pub mod runtime;
 #[cfg(test)]
 mod tests;
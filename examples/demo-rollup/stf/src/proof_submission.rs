@@ -0,0 +1,70 @@
+//! Posts the proofs generated by the prover service back to the rollup, so that the
+//! `sov-prover-incentives` module can verify them and reward their prover.
+
+use std::marker::PhantomData;
+
+use borsh::BorshSerialize;
+use sov_accounts::{Accounts, Response};
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{Context, EncodeCall, PrivateKey, Spec, WorkingSet};
+use sov_modules_stf_blueprint::{Batch, RawTx};
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::Zkvm;
+use sov_stf_runner::ProofBlobEncoder;
+
+use crate::runtime::Runtime;
+
+/// Wraps every proof into a batch containing a single `VerifyProof` transaction,
+/// signed by the prover.
+pub struct VerifyProofEncoder<C: Context, Da: DaSpec, Vm: Zkvm + 'static> {
+    prover_key: C::PrivateKey,
+    chain_id: u64,
+    storage: <C as Spec>::Storage,
+    _phantom: PhantomData<fn() -> (Da, Vm)>,
+}
+
+impl<C: Context, Da: DaSpec, Vm: Zkvm + 'static> VerifyProofEncoder<C, Da, Vm> {
+    /// Creates a new [`VerifyProofEncoder`]. Transactions are signed with the nonce of the
+    /// prover's account in `storage`, which must follow the state of the rollup.
+    pub fn new(prover_key: C::PrivateKey, chain_id: u64, storage: <C as Spec>::Storage) -> Self {
+        Self {
+            prover_key,
+            chain_id,
+            storage,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the current nonce of the prover's account, which is zero if the account
+    /// doesn't exist yet.
+    fn prover_nonce(&self) -> anyhow::Result<u64> {
+        let mut working_set = WorkingSet::new(self.storage.clone());
+        let account = Accounts::<C>::default()
+            .get_account(self.prover_key.pub_key(), &mut working_set)
+            .map_err(|e| anyhow::anyhow!("Failed to read the prover's account: {}", e))?;
+
+        Ok(match account {
+            Response::AccountExists { nonce, .. } => nonce,
+            Response::AccountEmpty => 0,
+        })
+    }
+}
+
+impl<C: Context, Da: DaSpec, Vm: Zkvm + 'static> ProofBlobEncoder
+    for VerifyProofEncoder<C, Da, Vm>
+{
+    fn encode_proof_blob(&self, proof: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        let msg = <Runtime<C, Da, Vm> as EncodeCall<
+            sov_prover_incentives::ProverIncentives<C, Vm, Da>,
+        >>::encode_call(sov_prover_incentives::CallMessage::VerifyProof(proof));
+        let nonce = self.prover_nonce()?;
+        let tx = Transaction::<C>::new_signed_tx(&self.prover_key, msg, self.chain_id, 0, 0, nonce);
+
+        let batch = Batch {
+            txs: vec![RawTx {
+                data: tx.try_to_vec()?,
+            }],
+        };
+        Ok(batch.try_to_vec()?)
+    }
+}
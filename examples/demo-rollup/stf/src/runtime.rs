@@ -31,9 +31,15 @@
 //!  The `DispatchCall` implementation (derived by a macro) forwards the message to the appropriate module and executes its `call` method.
 
 #![allow(unused_doc_comments)]
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "native")]
 use sov_accounts::{AccountsRpcImpl, AccountsRpcServer};
 #[cfg(feature = "native")]
+use sov_attester_incentives::{AttesterIncentivesRpcImpl, AttesterIncentivesRpcServer};
+#[cfg(feature = "native")]
 use sov_bank::{BankRpcImpl, BankRpcServer};
 #[cfg(feature = "native")]
 #[cfg(feature = "experimental")]
@@ -48,7 +54,10 @@ use sov_modules_api::Spec;
 use sov_modules_api::{Context, DispatchCall, Genesis, MessageCodec};
 #[cfg(feature = "native")]
 use sov_nft_module::{NonFungibleTokenRpcImpl, NonFungibleTokenRpcServer};
+#[cfg(feature = "native")]
+use sov_prover_incentives::{ProverIncentivesRpcImpl, ProverIncentivesRpcServer};
 use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::{ValidityCondition, ValidityConditionChecker, Zkvm};
 #[cfg(feature = "native")]
 use sov_sequencer_registry::{SequencerRegistryRpcImpl, SequencerRegistryRpcServer};
 #[cfg(feature = "native")]
@@ -62,7 +71,7 @@ use crate::genesis_config::GenesisPaths;
 #[derive(Genesis, DispatchCall, MessageCodec, DefaultRuntime)]
 #[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
 #[cfg_attr(feature = "serde", serialization(serde::Serialize, serde::Deserialize))]
-pub struct Runtime<C: Context, Da: DaSpec> {
+pub struct Runtime<C: Context, Da: DaSpec, Vm: Zkvm + 'static> {
     /// The Bank module.
     pub bank: sov_bank::Bank<C>,
     /// The Sequencer Registry module.
@@ -73,31 +82,69 @@ pub struct Runtime<C: Context, Da: DaSpec> {
     pub accounts: sov_accounts::Accounts<C>,
     /// The NFT module.
     pub nft: sov_nft_module::NonFungibleToken<C>,
+    /// The Prover Incentives module.
+    pub prover_incentives: sov_prover_incentives::ProverIncentives<C, Vm, Da>,
+    #[cfg_attr(feature = "native", cli_skip)]
+    /// The Attester Incentives module.
+    pub attester_incentives: sov_attester_incentives::AttesterIncentives<
+        C,
+        Vm,
+        Da,
+        DaValidityCondChecker<<Da as DaSpec>::ValidityCondition>,
+    >,
     #[cfg(feature = "experimental")]
     #[cfg_attr(feature = "native", cli_skip)]
-    /// The EVM module.
-    pub evm: sov_evm::Evm<C>,
+    /// The EVM module. EVM contracts call the other modules through this runtime.
+    pub evm: sov_evm::Evm<C, Runtime<C, Da, Vm>>,
+}
+
+/// The validity condition checker of the attester incentives module.
+///
+/// Validity conditions are produced by the DA verifier, which the rollup runs on every slot
+/// before executing it, so every condition that reaches the module is accepted.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct DaValidityCondChecker<Cond: ValidityCondition> {
+    #[serde(skip)]
+    phantom: PhantomData<Cond>,
+}
+
+impl<Cond: ValidityCondition> Default for DaValidityCondChecker<Cond> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Cond: ValidityCondition> ValidityConditionChecker<Cond> for DaValidityCondChecker<Cond> {
+    type Error = anyhow::Error;
+
+    fn check(&mut self, _condition: &Cond) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
-impl<C, Da> sov_modules_stf_blueprint::Runtime<C, Da> for Runtime<C, Da>
+impl<C, Da, Vm> sov_modules_stf_blueprint::Runtime<C, Da> for Runtime<C, Da, Vm>
 where
     C: Context,
     Da: DaSpec,
+    Vm: Zkvm + 'static,
 {
-    type GenesisConfig = GenesisConfig<C, Da>;
+    type GenesisConfig = GenesisConfig<C, Da, Vm>;
 
     #[cfg(feature = "native")]
     type GenesisPaths = GenesisPaths;
 
     #[cfg(feature = "native")]
     fn rpc_methods(storage: <C as Spec>::Storage) -> jsonrpsee::RpcModule<()> {
-        get_rpc_methods::<C, Da>(storage)
+        get_rpc_methods::<C, Da, Vm>(storage)
     }
 
     #[cfg(feature = "native")]
     fn genesis_config(
         genesis_paths: &Self::GenesisPaths,
     ) -> Result<Self::GenesisConfig, anyhow::Error> {
-        crate::genesis_config::get_genesis_config(genesis_paths)
+        crate::genesis_config::get_genesis_config::<C, Da, Vm>(genesis_paths)
     }
 }
@@ -7,6 +7,7 @@ use sov_data_generators::bank_data::{
 use sov_data_generators::value_setter_data::{ValueSetterMessage, ValueSetterMessages};
 use sov_data_generators::MessageGenerator;
 use sov_mock_da::MockDaSpec;
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_stf_blueprint::RawTx;
@@ -20,13 +21,13 @@ pub fn simulate_da(value_setter_admin: DefaultPrivateKey) -> Vec<RawTx> {
     let mut messages = Vec::default();
 
     let bank_generator = BankMessageGenerator::<C>::default();
-    let bank_txs = bank_generator.create_raw_txs::<Runtime<C, Da>>();
+    let bank_txs = bank_generator.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>();
 
     let value_setter = ValueSetterMessages::new(vec![ValueSetterMessage {
         admin: Rc::new(value_setter_admin),
         messages: vec![99, 33],
     }]);
-    messages.extend(value_setter.create_raw_txs::<Runtime<C, Da>>());
+    messages.extend(value_setter.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>());
     messages.extend(bank_txs);
     messages
 }
@@ -34,22 +35,22 @@ pub fn simulate_da(value_setter_admin: DefaultPrivateKey) -> Vec<RawTx> {
 pub fn simulate_da_with_revert_msg() -> Vec<RawTx> {
     let mut messages = Vec::default();
     let bank_generator = BankMessageGenerator::<C>::create_invalid_transfer();
-    let bank_txns = bank_generator.create_raw_txs::<Runtime<C, Da>>();
+    let bank_txns = bank_generator.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>();
     messages.extend(bank_txns);
     messages
 }
 
 pub fn simulate_da_with_bad_sig() -> Vec<RawTx> {
     let b: BadSignatureBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>()
 }
 
 pub fn simulate_da_with_bad_nonce() -> Vec<RawTx> {
     let b: BadNonceBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>()
 }
 
 pub fn simulate_da_with_bad_serialization() -> Vec<RawTx> {
     let b: BadSerializationBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da, MockZkVerifier>>()
 }
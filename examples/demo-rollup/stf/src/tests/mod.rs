@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use sov_mock_da::MockDaSpec;
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::DaSpec;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
@@ -13,12 +14,14 @@ use crate::genesis_config::{get_genesis_config, GenesisPaths};
 use crate::runtime::{GenesisConfig, Runtime};
 
 mod da_simulation;
+mod prover_incentives_tests;
+mod sequencer_registry_tests;
 mod stf_tests;
 mod tx_revert_tests;
 pub(crate) type C = DefaultContext;
 pub(crate) type Da = MockDaSpec;
 
-pub(crate) type RuntimeTest = Runtime<DefaultContext, Da>;
+pub(crate) type RuntimeTest = Runtime<DefaultContext, Da, MockZkVerifier>;
 pub(crate) type StfBlueprintTest = StfBlueprint<
     DefaultContext,
     Da,
@@ -36,13 +39,15 @@ pub(crate) fn create_storage_manager_for_tests(
     ProverStorageManager::new(config).unwrap()
 }
 
-pub(crate) fn get_genesis_config_for_tests<Da: DaSpec>(
-) -> GenesisParams<GenesisConfig<DefaultContext, Da>, BasicKernelGenesisConfig<DefaultContext, Da>>
-{
+pub(crate) fn get_genesis_config_for_tests<Da: DaSpec>() -> GenesisParams<
+    GenesisConfig<DefaultContext, Da, MockZkVerifier>,
+    BasicKernelGenesisConfig<DefaultContext, Da>,
+> {
     let integ_test_conf_dir: &Path = "../../test-data/genesis/integration-tests".as_ref();
-    let rt_params =
-        get_genesis_config::<DefaultContext, Da>(&GenesisPaths::from_dir(integ_test_conf_dir))
-            .unwrap();
+    let rt_params = get_genesis_config::<DefaultContext, Da, MockZkVerifier>(
+        &GenesisPaths::from_dir(integ_test_conf_dir),
+    )
+    .unwrap();
 
     let chain_state = read_json_file(integ_test_conf_dir.join("chain_state.json")).unwrap();
     let kernel_params = BasicKernelGenesisConfig { chain_state };
@@ -0,0 +1,220 @@
+use sov_bank::Amount;
+use sov_cli::wallet_state::PrivateKeyAndAddress;
+use sov_data_generators::bank_data::get_default_token_address;
+use sov_mock_da::{MockAddress, MockBlob, MockBlock, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_zkvm::{MockCodeCommitment, MockProof, MockZkVerifier};
+use sov_modules_api::{Event, WorkingSet};
+use sov_modules_stf_blueprint::{SequencerOutcome, StfBlueprint};
+use sov_prover_storage_manager::ProverStorageManager;
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::services::da::SlotData;
+use sov_rollup_interface::stf::StateTransitionFunction;
+use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_rollup_interface::zk::StateTransition;
+use sov_state::{DefaultStorageSpec, ProverStorage, Storage};
+use sov_stf_runner::ProofBlobEncoder;
+
+use crate::proof_submission::VerifyProofEncoder;
+use crate::tests::{
+    create_storage_manager_for_tests, get_genesis_config_for_tests, Da, RuntimeTest,
+    StfBlueprintTest, C,
+};
+
+const PROOF_REWARD: Amount = 100;
+
+fn read_prover_private_key() -> PrivateKeyAndAddress<C> {
+    let prover_data = std::fs::read_to_string("../../test-data/keys/prover_private_key.json")
+        .expect("Unable to read file to string");
+
+    serde_json::from_str(&prover_data).unwrap_or_else(|_| {
+        panic!(
+            "Unable to convert data {} to PrivateKeyAndAddress",
+            &prover_data
+        )
+    })
+}
+
+type Root = <ProverStorage<DefaultStorageSpec> as Storage>::Root;
+type StorageManager = ProverStorageManager<Da, DefaultStorageSpec>;
+
+/// Encodes a mock proof of `transition` for the code commitment of the integration-tests genesis,
+/// which is all zeros.
+fn prove(transition: &StateTransition<Da, Root>) -> Vec<u8> {
+    let log = bincode::serialize(transition).unwrap();
+    MockProof {
+        program_id: MockCodeCommitment([0; 32]),
+        is_valid: true,
+        log: &log,
+    }
+    .encode_to_vec()
+}
+
+/// Initializes the chain and applies an empty first slot, whose transition is recorded by the
+/// chain state once the next slot begins.
+fn apply_first_slot(
+    stf: &StfBlueprintTest,
+    storage_manager: &mut StorageManager,
+) -> (MockBlock, StateTransition<Da, Root>) {
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (genesis_root, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let block_1 = genesis_block.next_mock();
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut Vec::<MockBlob>::new(),
+    );
+    storage_manager
+        .save_change_set(block_1.header(), result.change_set)
+        .unwrap();
+
+    let transition = StateTransition {
+        initial_state_root: genesis_root,
+        final_state_root: result.state_root,
+        slot_hash: block_1.header.hash(),
+        validity_condition: block_1.validity_cond,
+    };
+    (block_1, transition)
+}
+
+/// Posts `proof` in the slot following `block` and returns the events of the proof transaction.
+fn apply_proof_slot(
+    stf: &StfBlueprintTest,
+    storage_manager: &mut StorageManager,
+    block: &MockBlock,
+    pre_state_root: &Root,
+    proof: Vec<u8>,
+) -> (MockBlock, Vec<Event>) {
+    let prover = read_prover_private_key();
+    let next_block = block.next_mock();
+
+    // Post the proof the same way the prover service does.
+    let encoder = VerifyProofEncoder::<C, Da, MockZkVerifier>::new(
+        prover.private_key,
+        0,
+        storage_manager
+            .create_storage_on(next_block.header())
+            .unwrap(),
+    );
+    let blob_data = encoder.encode_proof_blob(proof).unwrap();
+    let mut blobs = [MockBlob::new(
+        blob_data,
+        MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+        [0; 32],
+    )];
+
+    let storage = storage_manager
+        .create_storage_on(next_block.header())
+        .unwrap();
+    let result = stf.apply_slot(
+        pre_state_root,
+        storage,
+        Default::default(),
+        &next_block.header,
+        &next_block.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, result.batch_receipts.len());
+    let receipt = &result.batch_receipts[0];
+    assert!(matches!(receipt.inner, SequencerOutcome::Rewarded { .. }));
+    assert_eq!(1, receipt.tx_receipts.len());
+    let events = receipt.tx_receipts[0].events.clone();
+
+    storage_manager
+        .save_change_set(next_block.header(), result.change_set)
+        .unwrap();
+    (next_block, events)
+}
+
+fn prover_balance_and_bond(
+    storage_manager: &mut StorageManager,
+    block: &MockBlock,
+) -> (Amount, Amount) {
+    let prover = read_prover_private_key();
+    let runtime = RuntimeTest::default();
+    let storage = storage_manager.create_storage_on(block.header()).unwrap();
+    let mut working_set = WorkingSet::new(storage);
+    let balance = runtime
+        .bank
+        .get_balance_of(
+            prover.address,
+            get_default_token_address(),
+            &mut working_set,
+        )
+        .unwrap();
+    let bond = runtime
+        .prover_incentives
+        .get_bond_amount(prover.address, &mut working_set);
+    (balance, bond.value)
+}
+
+#[test]
+fn test_submitted_proof_is_rewarded() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let (block_1, transition) = apply_first_slot(&stf, &mut storage_manager);
+    let block_2 = block_1.next_mock();
+    let (initial_balance, _) = prover_balance_and_bond(&mut storage_manager, &block_2);
+
+    let (block_2, events) = apply_proof_slot(
+        &stf,
+        &mut storage_manager,
+        &block_1,
+        &transition.final_state_root,
+        prove(&transition),
+    );
+    assert!(events
+        .iter()
+        .any(|event| event.key().inner() == b"rewarded_prover"));
+
+    let (balance, bond) = prover_balance_and_bond(&mut storage_manager, &block_2.next_mock());
+    assert_eq!(initial_balance + PROOF_REWARD, balance);
+    assert_eq!(10000, bond);
+}
+
+#[test]
+fn test_proof_of_unrecorded_transition_is_not_rewarded() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let (block_1, transition) = apply_first_slot(&stf, &mut storage_manager);
+    let block_2 = block_1.next_mock();
+    let (initial_balance, _) = prover_balance_and_bond(&mut storage_manager, &block_2);
+
+    // The proof is valid, but it claims a final state root the rollup never reached.
+    let forged = StateTransition {
+        final_state_root: transition.initial_state_root,
+        ..transition.clone()
+    };
+    let (block_2, events) = apply_proof_slot(
+        &stf,
+        &mut storage_manager,
+        &block_1,
+        &transition.final_state_root,
+        prove(&forged),
+    );
+    assert!(events
+        .iter()
+        .any(|event| event.key().inner() == b"unknown_transition"));
+    assert!(!events
+        .iter()
+        .any(|event| event.key().inner() == b"rewarded_prover"));
+
+    let (balance, bond) = prover_balance_and_bond(&mut storage_manager, &block_2.next_mock());
+    assert_eq!(initial_balance, balance);
+    assert_eq!(10000, bond);
+}
@@ -0,0 +1,197 @@
+use borsh::BorshSerialize;
+use sov_cli::wallet_state::PrivateKeyAndAddress;
+use sov_data_generators::new_test_blob_from_batch;
+use sov_mock_da::{MockAddress, MockBlock, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{EncodeCall, WorkingSet};
+use sov_modules_stf_blueprint::{Batch, RawTx, StfBlueprint, TxEffect};
+use sov_rollup_interface::services::da::SlotData;
+use sov_rollup_interface::stf::StateTransitionFunction;
+use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_sequencer_registry::{CallMessage, SequencerRegistry};
+
+use crate::tests::{
+    create_storage_manager_for_tests, get_genesis_config_for_tests, Da, RuntimeTest,
+    StfBlueprintTest, C,
+};
+
+// The DA address of a second sequencer, registered by the genesis sequencer so that
+// it can keep submitting blobs after the genesis sequencer exits.
+const SECOND_SEQUENCER_DA_ADDRESS: [u8; 32] = [2; 32];
+
+fn read_sequencer_private_key() -> PrivateKeyAndAddress<C> {
+    let sequencer_data =
+        std::fs::read_to_string("../../test-data/keys/token_deployer_private_key.json")
+            .expect("Unable to read file to string");
+
+    serde_json::from_str(&sequencer_data).unwrap_or_else(|_| {
+        panic!(
+            "Unable to convert data {} to PrivateKeyAndAddress",
+            &sequencer_data
+        )
+    })
+}
+
+fn sequencer_registry_tx(key: &DefaultPrivateKey, message: CallMessage, nonce: u64) -> RawTx {
+    let message = <RuntimeTest as EncodeCall<SequencerRegistry<C, Da>>>::encode_call(message);
+    let tx = Transaction::<C>::new_signed_tx(key, message, 0, 0, 0, nonce);
+    RawTx {
+        data: tx.try_to_vec().unwrap(),
+    }
+}
+
+#[test]
+fn test_sequencer_withdraws_bond_after_unbonding_period() {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let mut config = get_genesis_config_for_tests();
+    config.runtime.sequencer_registry.is_preferred_sequencer = false;
+    let sequencer_address = config.runtime.sequencer_registry.seq_rollup_address;
+    let bond = config.runtime.sequencer_registry.coins_to_lock.clone();
+    let unbonding_period = config.runtime.sequencer_registry.unbonding_period;
+    assert_eq!(100, unbonding_period);
+
+    let sequencer_key = read_sequencer_private_key();
+    assert_eq!(sequencer_address, sequencer_key.address);
+    let sequencer_key = sequencer_key.private_key;
+
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let (mut state_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // Slot 1: the genesis sequencer registers a second DA address.
+    // Slot 2: the genesis sequencer exits through the second DA address, and tries
+    // to withdraw its bond right away, which must fail.
+    // Then `unbonding_period` empty slots go by, and the withdrawal succeeds.
+    let mut slots = vec![
+        vec![new_test_blob_from_batch(
+            Batch {
+                txs: vec![sequencer_registry_tx(
+                    &sequencer_key,
+                    CallMessage::Register {
+                        da_address: SECOND_SEQUENCER_DA_ADDRESS.to_vec(),
+                    },
+                    0,
+                )],
+            },
+            &MOCK_SEQUENCER_DA_ADDRESS,
+            [1; 32],
+        )],
+        vec![new_test_blob_from_batch(
+            Batch {
+                txs: vec![
+                    sequencer_registry_tx(
+                        &sequencer_key,
+                        CallMessage::Exit {
+                            da_address: MOCK_SEQUENCER_DA_ADDRESS.to_vec(),
+                        },
+                        1,
+                    ),
+                    sequencer_registry_tx(
+                        &sequencer_key,
+                        CallMessage::Withdraw {
+                            da_address: MOCK_SEQUENCER_DA_ADDRESS.to_vec(),
+                        },
+                        2,
+                    ),
+                ],
+            },
+            &SECOND_SEQUENCER_DA_ADDRESS,
+            [2; 32],
+        )],
+    ];
+    slots.extend((0..unbonding_period).map(|_| Vec::new()));
+    slots.push(vec![new_test_blob_from_batch(
+        Batch {
+            txs: vec![sequencer_registry_tx(
+                &sequencer_key,
+                CallMessage::Withdraw {
+                    da_address: MOCK_SEQUENCER_DA_ADDRESS.to_vec(),
+                },
+                3,
+            )],
+        },
+        &SECOND_SEQUENCER_DA_ADDRESS,
+        [3; 32],
+    )]);
+
+    let mut block = genesis_block;
+    let mut tx_effects = Vec::new();
+    let mut balance_before_withdrawal = None;
+    let last_slot = slots.len() - 1;
+    for (index, mut blobs) in slots.into_iter().enumerate() {
+        block = block.next_mock();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+
+        if index == last_slot {
+            let mut working_set = WorkingSet::new(storage.clone());
+            balance_before_withdrawal = RuntimeTest::default().bank.get_balance_of(
+                sequencer_address,
+                bond.token_address,
+                &mut working_set,
+            );
+        }
+
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        state_root = result.state_root;
+        tx_effects.extend(
+            result
+                .batch_receipts
+                .into_iter()
+                .flat_map(|receipt| receipt.tx_receipts)
+                .map(|receipt| receipt.receipt),
+        );
+    }
+
+    assert_eq!(
+        vec![
+            TxEffect::Successful,
+            TxEffect::Successful,
+            TxEffect::Reverted,
+            TxEffect::Successful,
+        ],
+        tx_effects
+    );
+
+    let storage = storage_manager
+        .create_storage_on(block.next_mock().header())
+        .unwrap();
+    let mut working_set = WorkingSet::new(storage);
+    let runtime = RuntimeTest::default();
+    let balance_after_withdrawal =
+        runtime
+            .bank
+            .get_balance_of(sequencer_address, bond.token_address, &mut working_set);
+    assert_eq!(
+        Some(balance_before_withdrawal.unwrap() + bond.amount),
+        balance_after_withdrawal
+    );
+    assert!(runtime
+        .sequencer_registry
+        .get_unbonding_sequencer(
+            &MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            &mut working_set
+        )
+        .is_none());
+}
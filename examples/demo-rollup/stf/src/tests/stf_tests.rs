@@ -1,11 +1,18 @@
+use borsh::BorshSerialize;
+use sov_blob_storage::DEFERRED_SLOTS_COUNT;
 use sov_cli::wallet_state::PrivateKeyAndAddress;
 use sov_data_generators::bank_data::get_default_token_address;
 use sov_data_generators::{has_tx_events, new_test_blob_from_batch};
-use sov_mock_da::{MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_da::{MockAddress, MockBlob, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
-use sov_modules_api::{Context, PrivateKey, WorkingSet};
-use sov_modules_stf_blueprint::{Batch, SequencerOutcome, StfBlueprint};
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{Context, EncodeCall, PrivateKey, WorkingSet};
+use sov_modules_stf_blueprint::{Batch, SequencerOutcome, StfBlueprint, TxEffect};
+use sov_rollup_interface::da::direct::{
+    encode_direct_blob, MAX_DIRECT_BLOBS_PER_SLOT, MIN_DIRECT_BLOB_GAS_LIMIT,
+};
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
@@ -59,7 +66,10 @@ fn test_demo_values_in_db() {
 
         let apply_blob_outcome = result.batch_receipts[0].clone();
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                amount: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Sequencer execution should have succeeded but failed "
         );
@@ -74,7 +84,7 @@ fn test_demo_values_in_db() {
     // Generate a new storage instance after dumping data to the db.
     {
         let next_block = last_block.next_mock();
-        let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+        let runtime = &mut Runtime::<DefaultContext, MockDaSpec, MockZkVerifier>::default();
         let storage = storage_manager
             .create_storage_on(next_block.header())
             .unwrap();
@@ -131,14 +141,17 @@ fn test_demo_values_in_cache() {
     let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
     assert_eq!(
-        SequencerOutcome::Rewarded(0),
+        SequencerOutcome::Rewarded {
+            amount: 0,
+            sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+        },
         apply_blob_outcome.inner,
         "Sequencer execution should have succeeded but failed"
     );
 
     assert!(has_tx_events(&apply_blob_outcome),);
 
-    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec, MockZkVerifier>::default();
     let mut working_set = WorkingSet::new(apply_block_result.change_set);
 
     let resp = runtime
@@ -195,7 +208,10 @@ fn test_demo_values_not_in_db() {
         let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                amount: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Sequencer execution should have succeeded but failed",
         );
@@ -204,7 +220,7 @@ fn test_demo_values_not_in_db() {
     // Generate a new storage instance,
     // values are missing because change set from apply slot wasn't saved back to storage manager
     {
-        let runtime = &mut Runtime::<C, MockDaSpec>::default();
+        let runtime = &mut Runtime::<C, MockDaSpec, MockZkVerifier>::default();
         let storage = storage_manager.create_storage_on(block_2.header()).unwrap();
         let mut working_set = WorkingSet::new(storage);
 
@@ -274,6 +290,218 @@ fn test_sequencer_unknown_sequencer() {
     assert!(!has_tx_events(&apply_blob_outcome));
 }
 
+#[test]
+fn test_direct_blob_from_unknown_sender_is_force_included() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let (mut state_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // A user who isn't a registered sequencer posts a single transaction.
+    let some_user: [u8; 32] = [121; 32];
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let message = <Runtime<C, MockDaSpec, MockZkVerifier> as EncodeCall<
+        sov_value_setter::ValueSetter<C>,
+    >>::encode_call(sov_value_setter::CallMessage::SetValue(99));
+    let tx =
+        Transaction::<C>::new_signed_tx(&private_key, message, 0, 0, MIN_DIRECT_BLOB_GAS_LIMIT, 0);
+    let direct_blob = MockBlob::new(
+        encode_direct_blob(&tx.try_to_vec().unwrap()),
+        MockAddress::from(some_user),
+        [1; 32],
+    );
+
+    // The preferred sequencer ignores the transaction, which is deferred but still executed.
+    let mut blobs = vec![direct_blob];
+    let mut block = genesis_block;
+    let mut direct_receipts = Vec::new();
+    for _ in 0..=DEFERRED_SLOTS_COUNT {
+        block = block.next_mock();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        state_root = result.state_root;
+        direct_receipts.extend(result.batch_receipts);
+        blobs.clear();
+    }
+
+    assert_eq!(1, direct_receipts.len());
+    let receipt = &direct_receipts[0];
+    assert_eq!(
+        SequencerOutcome::Direct {
+            amount: 0,
+            sender_da_address: MockAddress::from(some_user),
+        },
+        receipt.inner
+    );
+    assert_eq!(1, receipt.tx_receipts.len());
+    assert_eq!(TxEffect::Successful, receipt.tx_receipts[0].receipt);
+}
+
+#[test]
+fn test_malformed_direct_blob_is_discarded() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let mut config = get_genesis_config_for_tests();
+    config.runtime.sequencer_registry.is_preferred_sequencer = false;
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let some_user: [u8; 32] = [121; 32];
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let mut tx = simulate_da(private_key).remove(0);
+    // Truncating the transaction breaks its encoding.
+    tx.data.pop();
+    let mut blobs = [MockBlob::new(
+        encode_direct_blob(&tx.data),
+        MockAddress::from(some_user),
+        [1; 32],
+    )];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    // The transaction of the direct blob can't be decoded, so blob storage discards it.
+    assert!(result.batch_receipts.is_empty());
+}
+
+#[test]
+fn test_direct_blobs_are_checked_before_being_deferred() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let (mut state_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let some_user = MockAddress::from([121; 32]);
+    // The sender of this transaction has no tokens to pay for its gas.
+    let unfunded_tx = Transaction::<C>::new_signed_tx(
+        &DefaultPrivateKey::generate(),
+        vec![1],
+        0,
+        0,
+        MIN_DIRECT_BLOB_GAS_LIMIT,
+        0,
+    );
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    // The gas limit of this transaction is too low.
+    let low_gas_limit_tx = Transaction::<C>::new_signed_tx(&private_key, vec![1], 0, 1, 0, 0);
+    let mut blobs = vec![
+        MockBlob::new(
+            encode_direct_blob(&unfunded_tx.try_to_vec().unwrap()),
+            some_user,
+            [0; 32],
+        ),
+        MockBlob::new(
+            encode_direct_blob(&low_gas_limit_tx.try_to_vec().unwrap()),
+            some_user,
+            [u8::MAX; 32],
+        ),
+    ];
+    // One direct blob more than a slot accepts. The first one pays no tip, the others pay a tip.
+    for nonce in 0..=MAX_DIRECT_BLOBS_PER_SLOT as u64 {
+        let gas_tip = u64::from(nonce > 0);
+        let tx = Transaction::<C>::new_signed_tx(
+            &private_key,
+            vec![1],
+            0,
+            gas_tip,
+            MIN_DIRECT_BLOB_GAS_LIMIT,
+            nonce,
+        );
+        blobs.push(MockBlob::new(
+            encode_direct_blob(&tx.try_to_vec().unwrap()),
+            some_user,
+            [nonce as u8 + 1; 32],
+        ));
+    }
+
+    let mut block = genesis_block;
+    let mut direct_receipts = Vec::new();
+    for _ in 0..=DEFERRED_SLOTS_COUNT {
+        block = block.next_mock();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        state_root = result.state_root;
+        direct_receipts.extend(result.batch_receipts);
+        blobs.clear();
+    }
+
+    // The direct blobs of the unfunded sender and with a low gas limit were discarded, and so was
+    // the direct blob paying the lowest tip, even though it came first.
+    assert_eq!(MAX_DIRECT_BLOBS_PER_SLOT, direct_receipts.len());
+    assert!(direct_receipts
+        .iter()
+        .all(|receipt| receipt.batch_hash != [0; 32]
+            && receipt.batch_hash != [u8::MAX; 32]
+            && receipt.batch_hash != [1; 32]));
+}
+
 fn read_private_key<C: Context>() -> PrivateKeyAndAddress<C> {
     let token_deployer_data =
         std::fs::read_to_string("../../test-data/keys/token_deployer_private_key.json")
@@ -2,6 +2,7 @@ use sov_accounts::Response;
 use sov_data_generators::bank_data::{get_default_private_key, get_default_token_address};
 use sov_data_generators::{has_tx_events, new_test_blob_from_batch};
 use sov_mock_da::{MockAddress, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::{PrivateKey, WorkingSet};
 use sov_modules_stf_blueprint::{Batch, SequencerOutcome, SlashingReason, StfBlueprint, TxEffect};
@@ -67,7 +68,10 @@ fn test_tx_revert() {
         let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                amount: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Unexpected outcome: Batch execution should have succeeded",
         );
@@ -86,7 +90,7 @@ fn test_tx_revert() {
 
     // Checks on storage after execution
     {
-        let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+        let runtime = &mut Runtime::<DefaultContext, MockDaSpec, MockZkVerifier>::default();
         let mut working_set = WorkingSet::new(storage);
         let resp = runtime
             .bank
@@ -184,7 +188,7 @@ fn test_tx_bad_signature() {
     };
 
     {
-        let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+        let runtime = &mut Runtime::<DefaultContext, MockDaSpec, MockZkVerifier>::default();
         let mut working_set = WorkingSet::new(storage);
         let nonce = match runtime
             .accounts
@@ -245,7 +249,10 @@ fn test_tx_bad_nonce() {
         // We're asserting that here to track if the logic changes
         assert_eq!(
             apply_block_result.batch_receipts[0].inner,
-            SequencerOutcome::Rewarded(0)
+            SequencerOutcome::Rewarded {
+                amount: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            }
         );
     }
 }
@@ -332,7 +339,7 @@ fn test_tx_bad_serialization() {
     };
 
     {
-        let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+        let runtime = &mut Runtime::<DefaultContext, MockDaSpec, MockZkVerifier>::default();
         let mut working_set = WorkingSet::new(storage);
 
         // Sequencer is not in the list of allowed sequencers
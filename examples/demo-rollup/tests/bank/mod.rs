@@ -5,13 +5,14 @@ use demo_stf::genesis_config::GenesisPaths;
 use demo_stf::runtime::RuntimeCall;
 use jsonrpsee::core::client::{Subscription, SubscriptionClientT};
 use jsonrpsee::rpc_params;
-use sov_bank::Coins;
+use sov_bank::{Amount, Coins};
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_api::transaction::Transaction;
 use sov_modules_api::{Address, PrivateKey, Spec};
 use sov_modules_stf_blueprint::kernels::basic::BasicKernelGenesisPaths;
+use sov_risc0_adapter::host::Risc0Verifier;
 use sov_sequencer::utils::SimpleClient;
 use sov_stf_runner::RollupProverConfig;
 
@@ -48,15 +49,19 @@ async fn bank_tx_tests() -> Result<(), anyhow::Error> {
 
 async fn build_create_token_tx(key: &DefaultPrivateKey, nonce: u64) -> Transaction<DefaultContext> {
     let user_address: <DefaultContext as Spec>::Address = key.to_address();
-    let msg = RuntimeCall::<DefaultContext, MockDaSpec>::bank(sov_bank::CallMessage::<
-        DefaultContext,
-    >::CreateToken {
-        salt: TOKEN_SALT,
-        token_name: TOKEN_NAME.to_string(),
-        initial_balance: 1000,
-        minter_address: user_address,
-        authorized_minters: vec![],
-    });
+    let msg =
+        RuntimeCall::<DefaultContext, MockDaSpec, Risc0Verifier>::bank(sov_bank::CallMessage::<
+            DefaultContext,
+        >::CreateToken {
+            salt: TOKEN_SALT,
+            token_name: TOKEN_NAME.to_string(),
+            symbol: "TKN".to_owned(),
+            decimals: 18,
+            max_supply: None,
+            initial_balance: 1000,
+            minter_address: user_address,
+            authorized_minters: vec![],
+        });
     let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
@@ -74,18 +79,19 @@ async fn build_transfer_token_tx(
     key: &DefaultPrivateKey,
     token_address: Address,
     recipient: <DefaultContext as Spec>::Address,
-    amount: u64,
+    amount: Amount,
     nonce: u64,
 ) -> Transaction<DefaultContext> {
-    let msg = RuntimeCall::<DefaultContext, MockDaSpec>::bank(sov_bank::CallMessage::<
-        DefaultContext,
-    >::Transfer {
-        to: recipient,
-        coins: Coins {
-            amount,
-            token_address,
-        },
-    });
+    let msg =
+        RuntimeCall::<DefaultContext, MockDaSpec, Risc0Verifier>::bank(sov_bank::CallMessage::<
+            DefaultContext,
+        >::Transfer {
+            to: recipient,
+            coins: Coins {
+                amount,
+                token_address,
+            },
+        });
     let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use demo_stf::genesis_config::GenesisPaths;
+use jsonrpsee::http_client::HttpClientBuilder;
+use sov_ethereum::conformance::{
+    load_fixtures, load_transactions, prepare_chain, replay, FIXTURES_DIR,
+};
+use sov_modules_stf_blueprint::kernels::basic::BasicKernelGenesisPaths;
+use sov_stf_runner::RollupProverConfig;
+
+use crate::test_helpers::start_rollup;
+
+/// Replays the Ethereum JSON-RPC fixtures of `sov-ethereum` against the demo rollup.
+#[tokio::test]
+async fn eth_rpc_conformance_test() -> Result<(), anyhow::Error> {
+    let (port_tx, port_rx) = tokio::sync::oneshot::channel();
+
+    let rollup_task = tokio::spawn(async {
+        start_rollup(
+            port_tx,
+            GenesisPaths::from_dir("../test-data/genesis/integration-tests"),
+            BasicKernelGenesisPaths {
+                chain_state: "../test-data/genesis/integration-tests/chain_state.json".into(),
+            },
+            RollupProverConfig::Skip,
+        )
+        .await;
+    });
+
+    let port = port_rx.await?.port();
+    let client = HttpClientBuilder::default().build(format!("http://localhost:{}", port))?;
+
+    let fixtures_dir = Path::new(FIXTURES_DIR);
+    prepare_chain(&client, &load_transactions(fixtures_dir)?).await?;
+
+    let mut failures = Vec::new();
+    for fixture in load_fixtures(fixtures_dir)? {
+        if let Err(e) = replay(&client, &fixture).await {
+            failures.push(format!("{}: {}", fixture.name, e));
+        }
+    }
+    rollup_task.abort();
+
+    assert!(
+        failures.is_empty(),
+        "{} fixtures failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod conformance;
 mod test_client;
 
 use std::net::SocketAddr;
@@ -6,8 +7,11 @@ use std::str::FromStr;
 use demo_stf::genesis_config::GenesisPaths;
 use ethers_core::abi::Address;
 use ethers_signers::{LocalWallet, Signer};
+use jsonrpsee::core::client::{Subscription, SubscriptionClientT};
+use jsonrpsee::rpc_params;
 use sov_evm::SimpleStorageContract;
 use sov_modules_stf_blueprint::kernels::basic::BasicKernelGenesisPaths;
+use sov_sequencer::utils::SimpleClient;
 use sov_stf_runner::RollupProverConfig;
 use test_client::TestClient;
 use tokio::time::{sleep, Duration};
@@ -69,7 +73,37 @@ async fn send_tx_test_to_eth(rpc_address: SocketAddr) -> Result<(), Box<dyn std:
     assert_eq!(latest_block, earliest_block);
     assert_eq!(latest_block.number.unwrap().as_u64(), 0);
 
-    execute(&test_client).await
+    let ws_client = SimpleClient::new("localhost", rpc_address.port()).await?;
+    let mut new_heads: Subscription<serde_json::Value> = ws_client
+        .ws()
+        .subscribe("eth_subscribe", rpc_params!["newHeads"], "eth_unsubscribe")
+        .await?;
+    let number_set_topic = SimpleStorageContract::default().number_set_topic();
+    let mut logs: Subscription<serde_json::Value> = ws_client
+        .ws()
+        .subscribe(
+            "eth_subscribe",
+            rpc_params!["logs", serde_json::json!({ "topics": [number_set_topic] })],
+            "eth_unsubscribe",
+        )
+        .await?;
+
+    execute(&test_client).await?;
+
+    // The first block deploys the contract, the second one sets its value to 923.
+    let first_head = new_heads.next().await.unwrap()?;
+    assert_eq!(first_head["number"], "0x1");
+    let second_head = new_heads.next().await.unwrap()?;
+    assert_eq!(second_head["number"], "0x2");
+
+    let first_log = logs.next().await.unwrap()?;
+    assert_eq!(first_log["blockNumber"], "0x2");
+    assert_eq!(
+        first_log["topics"][1],
+        serde_json::json!(ethereum_types::H256::from_low_u64_be(923))
+    );
+
+    Ok(())
 }
 
 async fn execute(client: &TestClient) -> Result<(), Box<dyn std::error::Error>> {
@@ -34,13 +34,14 @@ pub async fn start_rollup(
         },
         da: MockDaConfig {
             sender_address: MockAddress::from([0; 32]),
+            ..Default::default()
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
         },
     };
 
-    let mock_demo_rollup = MockDemoRollup {};
+    let mock_demo_rollup = MockDemoRollup::default();
 
     let kernel_genesis = BasicKernelGenesisConfig {
         chain_state: serde_json::from_str(
@@ -8,8 +8,8 @@ use sov_schema_db::{Schema, SchemaBatch, SeekKeyEncoder, DB};
 
 use crate::rocks_db_config::gen_rocksdb_options;
 use crate::schema::tables::{
-    BatchByHash, BatchByNumber, EventByKey, EventByNumber, SlotByHash, SlotByNumber, TxByHash,
-    TxByNumber, LEDGER_TABLES,
+    BatchByHash, BatchByNumber, EventByKey, EventByNumber, FinalizedSlot, SlotByHash, SlotByNumber,
+    TxByHash, TxByNumber, LEDGER_TABLES,
 };
 use crate::schema::types::{
     split_tx_for_storage, BatchNumber, EventNumber, SlotNumber, StoredBatch, StoredSlot,
@@ -23,7 +23,8 @@ const LEDGER_DB_PATH_SUFFIX: &str = "ledger";
 #[derive(Clone, Debug)]
 /// A database which stores the ledger history (slots, transactions, events, etc).
 /// Ledger data is first ingested into an in-memory map before being fed to the state-transition function.
-/// Once the state-transition function has been executed and finalized, the results are committed to the final db
+/// Once the state-transition function has been executed, the results are committed to the db as pending
+/// slots, which are marked as finalized once the DA layer can no longer revert them.
 pub struct LedgerDB {
     /// The database which stores the committed ledger. Uses an optimized layout which
     /// requires transactions to be executed before being committed.
@@ -106,11 +107,23 @@ impl LedgerDB {
                 + 1,
         };
 
-        Ok(Self {
+        let ledger_db = Self {
             db: Arc::new(inner),
             next_item_numbers: Arc::new(Mutex::new(next_item_numbers)),
             slot_subscriptions: tokio::sync::broadcast::channel(10).0,
-        })
+        };
+
+        // Ledgers written before slots could be pending only hold finalized slots.
+        if ledger_db.db.get::<FinalizedSlot>(&())?.is_none() {
+            let head_slot = ledger_db.get_head_slot()?.map(|(number, _)| number);
+            ledger_db
+                .db
+                .put::<FinalizedSlot>(&(), &head_slot.unwrap_or_default())?;
+        }
+        // The state of the pending slots isn't persisted, so they have to be executed again.
+        ledger_db.revert_pending_slots()?;
+
+        Ok(ledger_db)
     }
 
     /// Get the next slot, block, transaction, and event numbers
@@ -213,12 +226,31 @@ impl LedgerDB {
         schema_batch.put::<EventByKey>(&(event.key().clone(), tx_number, *event_number), &())
     }
 
-    /// Commits a slot to the database by inserting its events, transactions, and batches before
-    /// inserting the slot metadata.
+    /// Commits a finalized slot to the database by inserting its events, transactions, and batches
+    /// before inserting the slot metadata.
     pub fn commit_slot<S: SlotData, B: Serialize, T: Serialize>(
         &self,
         data_to_commit: SlotCommit<S, B, T>,
     ) -> Result<(), anyhow::Error> {
+        self.put_slot_commit(data_to_commit, true)?;
+        Ok(())
+    }
+
+    /// Commits a slot which can still be reverted by a reorg of the DA layer, returning its number.
+    /// The slot is reported as pending until [`LedgerDB::finalize_slots`] is called with its number,
+    /// and is discarded by [`LedgerDB::revert_slots_from`] or when the database is reopened.
+    pub fn commit_pending_slot<S: SlotData, B: Serialize, T: Serialize>(
+        &self,
+        data_to_commit: SlotCommit<S, B, T>,
+    ) -> Result<SlotNumber, anyhow::Error> {
+        self.put_slot_commit(data_to_commit, false)
+    }
+
+    fn put_slot_commit<S: SlotData, B: Serialize, T: Serialize>(
+        &self,
+        data_to_commit: SlotCommit<S, B, T>,
+        finalized: bool,
+    ) -> Result<SlotNumber, anyhow::Error> {
         // Create a scope to ensure that the lock is released before we commit to the db
         let mut current_item_numbers = {
             let mut next_item_numbers = self.next_item_numbers.lock().unwrap();
@@ -283,19 +315,110 @@ impl LedgerDB {
             extra_data: vec![].into(),
             batches: BatchNumber(first_batch_number)..BatchNumber(last_batch_number),
         };
-        self.put_slot(
-            &slot_to_store,
-            &SlotNumber(current_item_numbers.slot_number),
-            &mut schema_batch,
-        )?;
+        let slot_number = SlotNumber(current_item_numbers.slot_number);
+        self.put_slot(&slot_to_store, &slot_number, &mut schema_batch)?;
+        if finalized {
+            schema_batch.put::<FinalizedSlot>(&(), &slot_number)?;
+        }
 
         self.db.write_schemas(schema_batch)?;
 
         // Notify subscribers. This call returns an error IFF there are no subscribers, so we don't need to check the result
-        let _ = self
-            .slot_subscriptions
-            .send(current_item_numbers.slot_number);
+        let _ = self.slot_subscriptions.send(slot_number.0);
+
+        Ok(slot_number)
+    }
 
+    /// Marks every slot up to `slot_number` as finalized.
+    pub fn finalize_slots(&self, slot_number: SlotNumber) -> Result<(), anyhow::Error> {
+        let next_slot_number = self.next_item_numbers.lock().unwrap().slot_number;
+        anyhow::ensure!(
+            slot_number.0 < next_slot_number,
+            "Slot {} can't be finalized before it is committed",
+            slot_number.0
+        );
+        if slot_number > self.get_finalized_slot_number()? {
+            self.db.put::<FinalizedSlot>(&(), &slot_number)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of the last finalized slot, or `0` if no slot was finalized yet.
+    pub fn get_finalized_slot_number(&self) -> Result<SlotNumber, anyhow::Error> {
+        Ok(self.db.get::<FinalizedSlot>(&())?.unwrap_or_default())
+    }
+
+    /// Reverts every pending slot.
+    pub fn revert_pending_slots(&self) -> Result<(), anyhow::Error> {
+        let finalized_slot_number = self.get_finalized_slot_number()?;
+        self.revert_slots_from(SlotNumber(finalized_slot_number.0 + 1))
+    }
+
+    /// Reverts the pending slots from `first_slot` onwards, along with their batches, transactions and
+    /// events, so that the next committed slot is numbered `first_slot`. Finalized slots can't be reverted.
+    pub fn revert_slots_from(&self, first_slot: SlotNumber) -> Result<(), anyhow::Error> {
+        anyhow::ensure!(
+            first_slot > self.get_finalized_slot_number()?,
+            "Slot {} is finalized and can't be reverted",
+            first_slot.0
+        );
+
+        let mut next_item_numbers = self.next_item_numbers.lock().unwrap();
+        let Some(slot) = self.db.get::<SlotByNumber>(&first_slot)? else {
+            return Ok(());
+        };
+
+        // Items are numbered in the order they are committed, so everything from the first item of
+        // `first_slot` onwards belongs to the reverted slots.
+        let first_batch = slot.batches.start;
+        let first_tx = match self.db.get::<BatchByNumber>(&first_batch)? {
+            Some(batch) => batch.txs.start,
+            None => TxNumber(next_item_numbers.tx_number),
+        };
+        let first_event = match self.db.get::<TxByNumber>(&first_tx)? {
+            Some(tx) => tx.events.start,
+            None => EventNumber(next_item_numbers.event_number),
+        };
+
+        let mut schema_batch = SchemaBatch::new();
+        for number in first_slot.0..next_item_numbers.slot_number {
+            if let Some(slot) = self.db.get::<SlotByNumber>(&SlotNumber(number))? {
+                schema_batch.delete::<SlotByHash>(&slot.hash)?;
+            }
+            schema_batch.delete::<SlotByNumber>(&SlotNumber(number))?;
+        }
+        for number in first_batch.0..next_item_numbers.batch_number {
+            if let Some(batch) = self.db.get::<BatchByNumber>(&BatchNumber(number))? {
+                schema_batch.delete::<BatchByHash>(&batch.hash)?;
+            }
+            schema_batch.delete::<BatchByNumber>(&BatchNumber(number))?;
+        }
+        for number in first_tx.0..next_item_numbers.tx_number {
+            if let Some(tx) = self.db.get::<TxByNumber>(&TxNumber(number))? {
+                schema_batch.delete::<TxByHash>(&tx.hash)?;
+                for event_number in tx.events.start.0..tx.events.end.0 {
+                    if let Some(event) = self.db.get::<EventByNumber>(&EventNumber(event_number))? {
+                        schema_batch.delete::<EventByKey>(&(
+                            event.key().clone(),
+                            TxNumber(number),
+                            EventNumber(event_number),
+                        ))?;
+                    }
+                }
+            }
+            schema_batch.delete::<TxByNumber>(&TxNumber(number))?;
+        }
+        for number in first_event.0..next_item_numbers.event_number {
+            schema_batch.delete::<EventByNumber>(&EventNumber(number))?;
+        }
+        self.db.write_schemas(schema_batch)?;
+
+        *next_item_numbers = ItemNumbers {
+            slot_number: first_slot.0,
+            batch_number: first_batch.0,
+            tx_number: first_tx.0,
+            event_number: first_event.0,
+        };
         Ok(())
     }
 
@@ -325,3 +448,92 @@ impl LedgerDB {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_mock_da::{MockBlock, MockBlockHeader};
+    use sov_rollup_interface::stf::{BatchReceipt, Event, TransactionReceipt};
+
+    use super::*;
+
+    fn slot_commit(height: u64) -> SlotCommit<MockBlock, u32, u32> {
+        let mut slot = SlotCommit::new(MockBlock {
+            header: MockBlockHeader::from_height(height),
+            ..Default::default()
+        });
+        slot.add_batch(BatchReceipt {
+            batch_hash: [height as u8; 32],
+            tx_receipts: vec![TransactionReceipt {
+                tx_hash: [height as u8 + 100; 32],
+                body_to_save: None,
+                events: vec![Event::new("key", "value")],
+                receipt: 0,
+                gas_used: vec![],
+            }],
+            inner: 0,
+        });
+        slot
+    }
+
+    #[test]
+    fn test_revert_pending_slots() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        db.commit_slot(slot_commit(1)).unwrap();
+        assert_eq!(
+            SlotNumber(2),
+            db.commit_pending_slot(slot_commit(2)).unwrap()
+        );
+        assert_eq!(
+            SlotNumber(3),
+            db.commit_pending_slot(slot_commit(3)).unwrap()
+        );
+        assert_eq!(SlotNumber(1), db.get_finalized_slot_number().unwrap());
+
+        db.finalize_slots(SlotNumber(2)).unwrap();
+        assert!(db.revert_slots_from(SlotNumber(2)).is_err());
+        db.revert_slots_from(SlotNumber(3)).unwrap();
+
+        let next_items = db.get_next_items_numbers();
+        assert_eq!(
+            (3, 3, 3, 3),
+            (
+                next_items.slot_number,
+                next_items.batch_number,
+                next_items.tx_number,
+                next_items.event_number
+            )
+        );
+        let reverted_hash = slot_commit(3).slot_data().hash();
+        assert_eq!(None, db.db.get::<SlotByHash>(&reverted_hash).unwrap());
+        assert_eq!(None, db.db.get::<BatchByHash>(&[3; 32]).unwrap());
+        assert_eq!(None, db.db.get::<TxByHash>(&[103; 32]).unwrap());
+        assert_eq!(None, db.db.get::<EventByNumber>(&EventNumber(3)).unwrap());
+
+        // The numbers of the reverted items are reused by the next slot.
+        assert_eq!(
+            SlotNumber(3),
+            db.commit_pending_slot(slot_commit(4)).unwrap()
+        );
+        assert_eq!(
+            Some(TxNumber(3)),
+            db.db.get::<TxByHash>(&[104; 32]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pending_slots_are_reverted_on_restart() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        {
+            let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+            db.commit_slot(slot_commit(1)).unwrap();
+            db.commit_pending_slot(slot_commit(2)).unwrap();
+        }
+
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        assert_eq!(SlotNumber(1), db.get_head_slot().unwrap().unwrap().0);
+        assert_eq!(2, db.get_next_items_numbers().slot_number);
+        assert_eq!(SlotNumber(1), db.get_finalized_slot_number().unwrap());
+    }
+}
@@ -1,8 +1,8 @@
 use serde::de::DeserializeOwned;
 use sov_rollup_interface::rpc::{
     BatchIdAndOffset, BatchIdentifier, BatchResponse, EventIdentifier, ItemOrHash,
-    LedgerRpcProvider, QueryMode, SlotIdAndOffset, SlotIdentifier, SlotResponse, TxIdAndOffset,
-    TxIdentifier, TxResponse,
+    LedgerRpcProvider, QueryMode, SlotIdAndOffset, SlotIdentifier, SlotResponse, SlotStatus,
+    TxIdAndOffset, TxIdentifier, TxResponse,
 };
 use sov_rollup_interface::stf::Event;
 use tokio::sync::broadcast::Receiver;
@@ -352,10 +352,16 @@ impl LedgerDB {
         slot: StoredSlot,
         mode: QueryMode,
     ) -> Result<SlotResponse<B, T>, anyhow::Error> {
+        let status = if number <= self.get_finalized_slot_number()?.0 {
+            SlotStatus::Finalized
+        } else {
+            SlotStatus::Pending
+        };
         Ok(match mode {
             QueryMode::Compact => SlotResponse {
                 number,
                 hash: slot.hash,
+                status,
                 batch_range: slot.batches.start.into()..slot.batches.end.into(),
                 batches: None,
             },
@@ -370,6 +376,7 @@ impl LedgerDB {
                 SlotResponse {
                     number,
                     hash: slot.hash,
+                    status,
                     batch_range: slot.batches.start.into()..slot.batches.end.into(),
                     batches: batch_hashes,
                 }
@@ -384,6 +391,7 @@ impl LedgerDB {
                 SlotResponse {
                     number,
                     hash: slot.hash,
+                    status,
                     batch_range: slot.batches.start.into()..slot.batches.end.into(),
                     batches: Some(batches),
                 }
@@ -428,10 +436,11 @@ impl LedgerDB {
 
 #[cfg(test)]
 mod tests {
-    use sov_mock_da::{MockBlob, MockBlock};
-    use sov_rollup_interface::rpc::LedgerRpcProvider;
+    use sov_mock_da::{MockBlob, MockBlock, MockBlockHeader};
+    use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode, SlotIdentifier, SlotStatus};
 
     use crate::ledger_db::{LedgerDB, SlotCommit};
+    use crate::schema::types::SlotNumber;
     #[test]
     fn test_slot_subscription() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -444,4 +453,48 @@ mod tests {
 
         assert_eq!(rx.blocking_recv().unwrap(), 1);
     }
+
+    #[test]
+    fn test_slot_status() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        let slot = |height| {
+            SlotCommit::<_, u32, u32>::new(MockBlock {
+                header: MockBlockHeader::from_height(height),
+                ..Default::default()
+            })
+        };
+        db.commit_slot(slot(1)).unwrap();
+        db.commit_pending_slot(slot(2)).unwrap();
+        db.commit_pending_slot(slot(3)).unwrap();
+        db.finalize_slots(SlotNumber(2)).unwrap();
+
+        let statuses: Vec<_> = db
+            .get_slots::<u32, u32>(
+                &[
+                    SlotIdentifier::Number(1),
+                    SlotIdentifier::Number(2),
+                    SlotIdentifier::Number(3),
+                ],
+                QueryMode::Compact,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|slot| slot.unwrap().status)
+            .collect();
+        assert_eq!(
+            vec![
+                SlotStatus::Finalized,
+                SlotStatus::Finalized,
+                SlotStatus::Pending
+            ],
+            statuses
+        );
+        let head = db
+            .get_head::<u32, u32>(QueryMode::Compact)
+            .unwrap()
+            .unwrap();
+        assert_eq!(SlotStatus::Pending, head.status);
+    }
 }
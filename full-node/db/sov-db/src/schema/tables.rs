@@ -4,6 +4,7 @@
 //! Slot Tables:
 //! - `SlotNumber -> StoredSlot`
 //! - `SlotNumber -> Vec<BatchNumber>`
+//! - `() -> SlotNumber` (the last finalized slot)
 //!
 //! Batch Tables:
 //! - `BatchNumber -> StoredBatch`
@@ -51,6 +52,7 @@ pub const STATE_TABLES: &[&str] = &[
 pub const LEDGER_TABLES: &[&str] = &[
     SlotByNumber::table_name(),
     SlotByHash::table_name(),
+    FinalizedSlot::table_name(),
     BatchByHash::table_name(),
     BatchByNumber::table_name(),
     TxByHash::table_name(),
@@ -218,6 +220,12 @@ define_table_with_default_codec!(
     (SlotByHash) DbHash => SlotNumber
 );
 
+define_table_with_default_codec!(
+    /// The number of the last finalized slot. Slots above it can still be reverted by a reorg
+    /// of the DA layer
+    (FinalizedSlot) () => SlotNumber
+);
+
 define_table_with_seek_key_codec!(
     /// The primary source for batch data
     (BatchByNumber) BatchNumber => StoredBatch
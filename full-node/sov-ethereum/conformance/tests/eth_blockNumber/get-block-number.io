@@ -0,0 +1,3 @@
+// the transactions are executed in block 1
+>> {"jsonrpc":"2.0","id":1,"method":"eth_blockNumber","params":[]}
+<< {"jsonrpc":"2.0","id":1,"result":"0x1"}
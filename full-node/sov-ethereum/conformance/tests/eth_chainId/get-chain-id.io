@@ -0,0 +1,3 @@
+// retrieves the chain id of the integration tests genesis
+>> {"jsonrpc":"2.0","id":1,"method":"eth_chainId","params":[]}
+<< {"jsonrpc":"2.0","id":1,"result":"0x1"}
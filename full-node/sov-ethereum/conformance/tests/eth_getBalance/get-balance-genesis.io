@@ -0,0 +1,3 @@
+// retrieves the balance of the genesis account before it paid for the deployment
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getBalance","params":["0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","0x0"]}
+<< {"jsonrpc":"2.0","id":1,"result":"0xffffffffffffffff"}
@@ -0,0 +1,3 @@
+// accounts that don't exist have no balance
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getBalance","params":["0x00000000000000000000000000000000000000aa","latest"]}
+<< {"jsonrpc":"2.0","id":1,"result":"0x0"}
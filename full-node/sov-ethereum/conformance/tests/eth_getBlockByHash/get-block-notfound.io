@@ -0,0 +1,3 @@
+// unknown block hashes are null
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getBlockByHash","params":["0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",false]}
+<< {"jsonrpc":"2.0","id":1,"result":null}
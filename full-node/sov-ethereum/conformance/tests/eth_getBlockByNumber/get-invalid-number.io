@@ -0,0 +1,3 @@
+// malformed block numbers are invalid params
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getBlockByNumber","params":["0xzz",false]}
+<< {"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"invalid block number: 0xzz"}}
@@ -0,0 +1,5 @@
+// `latest` is the block of the deployment
+// `stateRoot` is the root of the whole rollup state, so neither it nor the block hashes are pinned
+// `mixHash` is the hash of the DA block
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getBlockByNumber","params":["latest",false]}
+<< {"jsonrpc":"2.0","id":1,"result":{"hash":"$hash","parentHash":"$hash","sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","miner":"0x0000000000000000000000000000000000000000","stateRoot":"$hash","transactionsRoot":"0x845d614e126a610c2c46b13390f34aa9a79fac9e7c79bb97171091020987efc3","receiptsRoot":"0xb63e8b007264e0f695ecb142a3999f307c61193d39f5c1037d74f52cf73e360d","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000020000000000020000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000","difficulty":"0x0","number":"0x1","gasLimit":"0x1c9c380","gasUsed":"0xdebe","timestamp":"0x1","extraData":"0x","mixHash":"$hash","nonce":"0x0000000000000000","baseFeePerGas":"0x7","totalDifficulty":"0x0","uncles":[],"transactions":["0xcb756df0bbac0e32f87c9eeb9039b96a45c87be499f1cbcd9bba56b8dd999a4c"],"size":"0x297"}}
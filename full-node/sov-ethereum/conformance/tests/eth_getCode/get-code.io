@@ -0,0 +1,3 @@
+// retrieves the runtime code of the deployed contract
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getCode","params":["0x5fbdb2315678afecb367f032d93f642f64180aa3","latest"]}
+<< {"jsonrpc":"2.0","id":1,"result":"0x602a60005260206000f3"}
@@ -0,0 +1,4 @@
+// retrieves the receipt of a contract deployment emitting a log
+// the effective gas price is the base fee, 7, plus the priority fee, 10
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getTransactionReceipt","params":["0xcb756df0bbac0e32f87c9eeb9039b96a45c87be499f1cbcd9bba56b8dd999a4c"]}
+<< {"jsonrpc":"2.0","id":1,"result":{"transactionHash":"0xcb756df0bbac0e32f87c9eeb9039b96a45c87be499f1cbcd9bba56b8dd999a4c","transactionIndex":"0x0","blockHash":"$hash","blockNumber":"0x1","cumulativeGasUsed":"0xdebe","gasUsed":"0xdebe","effectiveGasPrice":"0x11","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":null,"contractAddress":"0x5fbdb2315678afecb367f032d93f642f64180aa3","logs":[{"address":"0x5fbdb2315678afecb367f032d93f642f64180aa3","topics":["0xe1cca926219c5c0c4684e42406e9dd0d82c5ee506e35dea074e251b1a5de0706"],"data":"0x000000000000000000000000000000000000000000000000000000000000002a","blockHash":"$hash","blockNumber":"0x1","transactionHash":"0xcb756df0bbac0e32f87c9eeb9039b96a45c87be499f1cbcd9bba56b8dd999a4c","transactionIndex":"0x0","logIndex":"0x0","removed":false}],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000020000000000020000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1","type":"0x2"}}
@@ -0,0 +1,3 @@
+// unknown transactions have no receipt
+>> {"jsonrpc":"2.0","id":1,"method":"eth_getTransactionReceipt","params":["0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"]}
+<< {"jsonrpc":"2.0","id":1,"result":null}
@@ -0,0 +1,3 @@
+// the network id is the decimal chain id
+>> {"jsonrpc":"2.0","id":1,"method":"net_version","params":[]}
+<< {"jsonrpc":"2.0","id":1,"result":"1"}
@@ -0,0 +1,3 @@
+// unknown methods are reported with the JSON-RPC error code
+>> {"jsonrpc":"2.0","id":1,"method":"eth_unknownMethod","params":[]}
+<< {"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}
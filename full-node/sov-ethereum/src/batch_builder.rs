@@ -2,10 +2,24 @@ use std::borrow::BorrowMut;
 use std::collections::VecDeque;
 
 use borsh::BorshSerialize;
+use reth_primitives::Address;
 use sov_modules_api::transaction::Transaction;
 
+use crate::mempool::{EthMempool, MempoolConfig, MempoolError, PooledTransaction};
+
+/// A blob of signed transactions, along with the messages it was built from.
+pub struct EthBlob {
+    /// The signed transactions of the blob.
+    pub txs: Vec<Vec<u8>>,
+    messages: Vec<Vec<u8>>,
+    evm_txs: Vec<PooledTransaction>,
+    first_nonce: u64,
+}
+
 pub struct EthBatchBuilder<C: sov_modules_api::Context> {
     mempool: VecDeque<Vec<u8>>,
+    /// The EVM transactions, published once their nonce is next.
+    evm_mempool: EthMempool,
     sov_tx_signer_private_key: C::PrivateKey,
     nonce: u64,
     min_blob_size: Option<usize>,
@@ -17,9 +31,11 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
         sov_tx_signer_private_key: C::PrivateKey,
         nonce: u64,
         min_blob_size: Option<usize>,
+        mempool_config: MempoolConfig,
     ) -> Self {
         EthBatchBuilder {
             mempool: VecDeque::new(),
+            evm_mempool: EthMempool::new(mempool_config),
             sov_tx_signer_private_key,
             nonce,
             min_blob_size,
@@ -28,12 +44,15 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
 
     /// Signs messages with the private key of the `EthBatchBuilder` and make them `transactions`.
     /// Returns the blob of signed transactions.
-    fn make_blob(&mut self) -> Vec<Vec<u8>> {
-        let mut txs = Vec::new();
+    fn make_blob(&mut self, account_nonce: impl FnMut(&Address) -> u64) -> EthBlob {
+        let messages: Vec<_> = self.mempool.drain(..).collect();
+        let evm_txs = self.evm_mempool.take_ready(account_nonce);
 
+        let first_nonce = self.nonce;
         let nonce = self.nonce.borrow_mut();
 
-        while let Some(raw_message) = self.mempool.pop_front() {
+        let mut txs = Vec::new();
+        for raw_message in messages.iter().chain(evm_txs.iter().map(|tx| &tx.message)) {
             // TODO define a strategy to expose chain id and gas tip for ethereum frontend
             let chain_id = 0;
             let gas_tip = 0;
@@ -41,7 +60,7 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
 
             let raw_tx = Transaction::<C>::new_signed_tx(
                 &self.sov_tx_signer_private_key,
-                raw_message,
+                raw_message.clone(),
                 chain_id,
                 gas_tip,
                 gas_limit,
@@ -54,7 +73,24 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
 
             txs.push(raw_tx);
         }
-        txs
+
+        EthBlob {
+            txs,
+            messages,
+            evm_txs,
+            first_nonce,
+        }
+    }
+
+    /// Puts the messages of `blob` back in the mempools after it failed to be published, so
+    /// that they are included in the next blob.
+    pub fn requeue(&mut self, blob: EthBlob) {
+        // Blobs built after this one can't be executed without its nonces, so they are reused.
+        self.nonce = self.nonce.min(blob.first_nonce);
+        for message in blob.messages.into_iter().rev() {
+            self.mempool.push_front(message);
+        }
+        self.evm_mempool.requeue(blob.evm_txs);
     }
 
     /// Adds `messages` to the mempool.
@@ -64,16 +100,41 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
         }
     }
 
+    /// Adds the EVM transaction `tx` to the mempool. `account_nonce` is the current nonce of
+    /// its sender.
+    pub fn add_evm_transaction(
+        &mut self,
+        tx: PooledTransaction,
+        account_nonce: u64,
+    ) -> Result<(), MempoolError> {
+        self.evm_mempool.insert(tx, account_nonce)
+    }
+
     /// Attempts to create a blob with a minimum size of `min_blob_size`.
-    pub fn get_next_blob(&mut self, min_blob_size: Option<usize>) -> Vec<Vec<u8>> {
-        let min_blob_size = min_blob_size.or(self.min_blob_size);
+    /// `account_nonce` returns the current nonce of an EVM account.
+    pub fn get_next_blob(
+        &mut self,
+        min_blob_size: Option<usize>,
+        account_nonce: impl FnMut(&Address) -> u64,
+    ) -> Vec<Vec<u8>> {
+        self.take_next_blob(min_blob_size, account_nonce)
+            .map(|blob| blob.txs)
+            .unwrap_or_default()
+    }
+
+    /// Like [`EthBatchBuilder::get_next_blob`], but the returned blob can be handed back to
+    /// [`EthBatchBuilder::requeue`] if publishing it fails.
+    pub fn take_next_blob(
+        &mut self,
+        min_blob_size: Option<usize>,
+        account_nonce: impl FnMut(&Address) -> u64,
+    ) -> Option<EthBlob> {
+        let min_blob_size = min_blob_size.or(self.min_blob_size)?;
 
-        if let Some(min_blob_size) = min_blob_size {
-            if self.mempool.len() >= min_blob_size {
-                return self.make_blob();
-            }
+        if self.mempool.len() + self.evm_mempool.len() >= min_blob_size {
+            return Some(self.make_blob(account_nonce));
         }
-        Vec::default()
+        None
     }
 
     /// Adds `messages` to the mempool and attempts to create a blob with a minimum size of `min_blob_size`.
@@ -81,8 +142,9 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
         &mut self,
         min_blob_size: Option<usize>,
         messages: Vec<Vec<u8>>,
+        account_nonce: impl FnMut(&Address) -> u64,
     ) -> Vec<Vec<u8>> {
         self.add_messages(messages);
-        self.get_next_blob(min_blob_size)
+        self.get_next_blob(min_blob_size, account_nonce)
     }
 }
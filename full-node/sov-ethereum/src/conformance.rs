@@ -0,0 +1,395 @@
+//! An offline harness checking the Ethereum JSON-RPC endpoints of a node against
+//! request/response fixtures, in the format of the `ethereum/execution-apis` test vectors.
+//!
+//! A fixture is a `.io` file where every request, prefixed by `>> `, is followed by its
+//! expected response, prefixed by `<< `. Lines starting with `//` are comments. The `result` or
+//! `error` of every response must match the expected one exactly: same fields, same values and
+//! `null` where `null` is expected. The only exceptions are the following placeholders, which
+//! match values that depend on the execution environment, like block hashes:
+//!
+//! - `"$any"`: any value, including `null`;
+//! - `"$quantity"`: a hex encoded quantity without leading zeroes;
+//! - `"$data"`: hex encoded bytes;
+//! - `"$hash"`: 32 hex encoded bytes.
+//!
+//! The fixtures run against a chain built by [`prepare_chain`] from the raw transactions of
+//! [`TRANSACTIONS_FILE`].
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context};
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::core::params::ArrayParams;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::rpc_params;
+use reth_primitives::{Bytes, H256};
+use serde_json::{json, Value};
+
+/// The directory of the fixtures shipped with this crate.
+pub const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/conformance");
+
+/// The file of [`FIXTURES_DIR`] listing the raw transactions executed before the fixtures.
+pub const TRANSACTIONS_FILE: &str = "transactions.json";
+
+/// Number of times [`prepare_chain`] polls for the receipt of a transaction, every 100ms.
+const RECEIPT_POLL_ATTEMPTS: usize = 300;
+
+/// A request and the response expected for it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exchange {
+    pub request: Value,
+    pub response: Value,
+}
+
+/// The exchanges of a fixture file, replayed in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fixture {
+    /// Path of the fixture relative to the fixtures directory, without extension.
+    pub name: String,
+    pub exchanges: Vec<Exchange>,
+}
+
+impl Fixture {
+    /// Parses the content of a `.io` fixture file.
+    pub fn parse(name: impl Into<String>, content: &str) -> anyhow::Result<Self> {
+        let name = name.into();
+        let mut exchanges = Vec::new();
+        let mut request = None;
+
+        for (line_number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let parse = |json: &str| {
+                serde_json::from_str(json.trim())
+                    .with_context(|| format!("{}:{}: invalid JSON", name, line_number + 1))
+            };
+
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            } else if let Some(json) = line.strip_prefix(">>") {
+                if request.is_some() {
+                    bail!("{}:{}: request without response", name, line_number + 1);
+                }
+                request = Some(parse(json)?);
+            } else if let Some(json) = line.strip_prefix("<<") {
+                let request = request.take().ok_or_else(|| {
+                    anyhow!("{}:{}: response without request", name, line_number + 1)
+                })?;
+                exchanges.push(Exchange {
+                    request,
+                    response: parse(json)?,
+                });
+            } else {
+                bail!("{}:{}: expected `>>` or `<<`", name, line_number + 1);
+            }
+        }
+
+        if request.is_some() {
+            bail!("{}: the last request has no response", name);
+        }
+
+        Ok(Self { name, exchanges })
+    }
+}
+
+/// Loads the `.io` fixtures found in the `tests` directory of `dir`, sorted by name.
+pub fn load_fixtures(dir: &Path) -> anyhow::Result<Vec<Fixture>> {
+    let tests_dir = dir.join("tests");
+    let mut paths = Vec::new();
+    collect_fixture_paths(&tests_dir, &mut paths)?;
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path
+                .strip_prefix(&tests_dir)?
+                .with_extension("")
+                .to_string_lossy()
+                .into_owned();
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read fixture {}", path.display()))?;
+            Fixture::parse(name, &content)
+        })
+        .collect()
+}
+
+fn collect_fixture_paths(dir: &Path, paths: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_fixture_paths(&path, paths)?;
+        } else if path
+            .extension()
+            .map_or(false, |extension| extension == "io")
+        {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Loads the raw transactions of [`TRANSACTIONS_FILE`] in `dir`.
+pub fn load_transactions(dir: &Path) -> anyhow::Result<Vec<Bytes>> {
+    let path = dir.join(TRANSACTIONS_FILE);
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Sends `transactions`, publishes them in a single batch and waits until they are executed.
+pub async fn prepare_chain(client: &HttpClient, transactions: &[Bytes]) -> anyhow::Result<()> {
+    let mut hashes = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        let hash: H256 = client
+            .request("eth_sendRawTransaction", rpc_params![transaction])
+            .await?;
+        hashes.push(hash);
+    }
+    let _: String = client.request("eth_publishBatch", rpc_params![]).await?;
+
+    for hash in hashes {
+        let mut attempts = 0;
+        loop {
+            let receipt: Option<Value> = client
+                .request("eth_getTransactionReceipt", rpc_params![hash])
+                .await?;
+            if receipt.is_some() {
+                break;
+            }
+            attempts += 1;
+            if attempts == RECEIPT_POLL_ATTEMPTS {
+                bail!("Transaction {} was not executed", hash);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sends the requests of `fixture` to `client` and checks their responses.
+pub async fn replay(client: &HttpClient, fixture: &Fixture) -> anyhow::Result<()> {
+    for (index, exchange) in fixture.exchanges.iter().enumerate() {
+        let method = exchange.request["method"]
+            .as_str()
+            .ok_or_else(|| anyhow!("request #{} has no method", index))?;
+
+        let actual = send(client, method, &exchange.request["params"]).await?;
+        let expected = outcome(&exchange.response)
+            .ok_or_else(|| anyhow!("response #{} has no result nor error", index))?;
+
+        compare(&expected, &actual).map_err(|mismatch| {
+            anyhow!(
+                "request #{} ({}): {}\n  response: {}",
+                index,
+                method,
+                mismatch,
+                actual
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Sends a request and returns the `result` or `error` member of its response.
+async fn send(client: &HttpClient, method: &str, params: &Value) -> anyhow::Result<Value> {
+    let mut array_params = ArrayParams::new();
+    for param in params.as_array().into_iter().flatten() {
+        array_params.insert(param)?;
+    }
+
+    match client.request::<Value, _>(method, array_params).await {
+        Ok(result) => Ok(json!({ "result": result })),
+        Err(jsonrpsee::core::Error::Call(error)) => {
+            let mut error_object = json!({
+                "code": error.code(),
+                "message": error.message(),
+            });
+            if let Some(data) = error.data() {
+                error_object["data"] = serde_json::from_str(data.get())?;
+            }
+            Ok(json!({ "error": error_object }))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Returns the `result` or `error` member of an expected response.
+fn outcome(response: &Value) -> Option<Value> {
+    ["result", "error"].into_iter().find_map(|member| {
+        response
+            .get(member)
+            .map(|value| json!({ member: value.clone() }))
+    })
+}
+
+/// The first difference between an expected and an actual response.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("mismatch at `{path}`: expected {expected}, got {actual}")]
+pub struct Mismatch {
+    /// Path of the mismatching value, like `result.logs[0].address`.
+    pub path: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Checks that `actual` matches `expected`, which may contain placeholders.
+pub fn compare(expected: &Value, actual: &Value) -> Result<(), Mismatch> {
+    compare_at(String::new(), expected, actual)
+}
+
+fn compare_at(path: String, expected: &Value, actual: &Value) -> Result<(), Mismatch> {
+    let matches = match (expected, actual) {
+        (Value::String(placeholder), _) if placeholder.starts_with('$') => {
+            matches_placeholder(placeholder, actual)
+        }
+        (Value::Object(expected_fields), Value::Object(actual_fields)) => {
+            let extra_keys = actual_fields
+                .keys()
+                .filter(|key| !expected_fields.contains_key(*key));
+            for key in expected_fields.keys().chain(extra_keys) {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match (expected_fields.get(key), actual_fields.get(key)) {
+                    (Some(expected), Some(actual)) => compare_at(field_path, expected, actual)?,
+                    (expected, actual) => {
+                        return Err(Mismatch {
+                            path: field_path,
+                            expected: describe(expected),
+                            actual: describe(actual),
+                        })
+                    }
+                }
+            }
+            true
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                compare_at(format!("{}[{}]", path, index), expected, actual)?;
+            }
+            true
+        }
+        _ => expected == actual,
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(Mismatch {
+            path,
+            expected: describe(Some(expected)),
+            actual: describe(Some(actual)),
+        })
+    }
+}
+
+fn describe(value: Option<&Value>) -> String {
+    value.map_or_else(|| "no field".to_owned(), Value::to_string)
+}
+
+fn matches_placeholder(placeholder: &str, value: &Value) -> bool {
+    let hex_digits = || {
+        value
+            .as_str()
+            .and_then(|value| value.strip_prefix("0x"))
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+    };
+
+    match placeholder {
+        "$any" => true,
+        "$quantity" => hex_digits().map_or(false, |digits| {
+            digits == "0" || (!digits.is_empty() && !digits.starts_with('0'))
+        }),
+        "$data" => hex_digits().map_or(false, |digits| digits.len() % 2 == 0),
+        "$hash" => hex_digits().map_or(false, |digits| digits.len() == 64),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fixtures() {
+        let fixture = Fixture::parse(
+            "eth_chainId/get-chain-id",
+            r#"
+            // retrieves the chain id
+            >> {"jsonrpc":"2.0","id":1,"method":"eth_chainId"}
+            << {"jsonrpc":"2.0","id":1,"result":"0x1"}
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(1, fixture.exchanges.len());
+        assert_eq!(
+            Some(json!({ "result": "0x1" })),
+            outcome(&fixture.exchanges[0].response)
+        );
+
+        assert!(Fixture::parse("missing-response", ">> {\"method\":\"eth_chainId\"}").is_err());
+        assert!(Fixture::parse("missing-request", "<< {\"result\":\"0x1\"}").is_err());
+    }
+
+    #[test]
+    fn compares_responses() {
+        let expected = json!({
+            "result": {
+                "hash": "$hash",
+                "number": "$quantity",
+                "extraData": "$data",
+                "size": "$any",
+                "to": null,
+                "logs": [{ "removed": false }],
+            }
+        });
+        let actual = json!({
+            "result": {
+                "hash": format!("0x{}", "ab".repeat(32)),
+                "number": "0x1f",
+                "extraData": "0x",
+                "size": null,
+                "to": null,
+                "logs": [{ "removed": false }],
+            }
+        });
+        assert_eq!(Ok(()), compare(&expected, &actual));
+
+        let mut leading_zero = actual.clone();
+        leading_zero["result"]["number"] = json!("0x01f");
+        assert_eq!(
+            "result.number",
+            compare(&expected, &leading_zero).unwrap_err().path
+        );
+
+        // `null` and missing fields are different.
+        let mut missing_field = actual.clone();
+        missing_field["result"]
+            .as_object_mut()
+            .unwrap()
+            .remove("to");
+        assert_eq!(
+            Err(Mismatch {
+                path: "result.to".to_owned(),
+                expected: "null".to_owned(),
+                actual: "no field".to_owned(),
+            }),
+            compare(&expected, &missing_field)
+        );
+
+        let mut extra_field = actual.clone();
+        extra_field["result"]["logs"][0]["logIndex"] = json!("0x0");
+        assert_eq!(
+            "result.logs[0].logIndex",
+            compare(&expected, &extra_field).unwrap_err().path
+        );
+    }
+}
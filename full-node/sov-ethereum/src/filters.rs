@@ -0,0 +1,152 @@
+//! Log filters installed with `eth_newFilter` and polled with `eth_getFilterChanges`.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use reth_primitives::{BlockNumberOrTag, U64};
+use reth_rpc_types::{Filter, FilterBlockOption};
+
+/// Filters that haven't been polled for this long are uninstalled.
+pub const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+struct ActiveFilter {
+    filter: Filter,
+    /// The first block that hasn't been reported to the client yet.
+    next_block: u64,
+    last_poll: Instant,
+}
+
+/// The log filters of the RPC clients. They only live in memory, so they are lost on restart.
+#[derive(Default)]
+pub struct LogFilters {
+    next_id: u64,
+    filters: HashMap<U64, ActiveFilter>,
+}
+
+impl LogFilters {
+    /// Installs `filter`, which will report logs starting from `next_block`.
+    pub fn install(&mut self, filter: Filter, next_block: u64) -> U64 {
+        self.remove_expired();
+
+        self.next_id += 1;
+        let id = U64::from(self.next_id);
+        self.filters.insert(
+            id,
+            ActiveFilter {
+                filter,
+                next_block,
+                last_poll: Instant::now(),
+            },
+        );
+        id
+    }
+
+    /// Returns the filter with `id` and the first block it hasn't reported yet. The blocks are
+    /// only marked as reported by [`LogFilters::mark_reported`], once their logs were queried.
+    pub fn poll(&mut self, id: U64) -> Option<(Filter, u64)> {
+        let active_filter = self.filters.get_mut(&id)?;
+        active_filter.last_poll = Instant::now();
+        Some((active_filter.filter.clone(), active_filter.next_block))
+    }
+
+    /// Marks every block up to `latest_block` as reported by the filter with `id`.
+    pub fn mark_reported(&mut self, id: U64, latest_block: u64) {
+        if let Some(active_filter) = self.filters.get_mut(&id) {
+            active_filter.next_block = active_filter.next_block.max(latest_block + 1);
+        }
+    }
+
+    /// Returns the filter with `id`, without changing what was reported.
+    pub fn get(&mut self, id: U64) -> Option<Filter> {
+        let active_filter = self.filters.get_mut(&id)?;
+        active_filter.last_poll = Instant::now();
+        Some(active_filter.filter.clone())
+    }
+
+    /// Uninstalls the filter with `id`. Returns `false` if there was no such filter.
+    pub fn uninstall(&mut self, id: U64) -> bool {
+        self.filters.remove(&id).is_some()
+    }
+
+    fn remove_expired(&mut self) {
+        self.filters
+            .retain(|_, active_filter| active_filter.last_poll.elapsed() < FILTER_TIMEOUT);
+    }
+}
+
+/// Returns the first block to report when `filter` is polled. `block_range` are the blocks
+/// covered by the filter, and `next_block` is the first block that hasn't been reported yet.
+///
+/// Unless the filter starts at a specific block, it only reports the blocks produced after it
+/// was installed.
+pub fn first_block_to_report(
+    filter: &Filter,
+    block_range: &RangeInclusive<u64>,
+    next_block: u64,
+) -> u64 {
+    match filter.block_option {
+        FilterBlockOption::Range {
+            from_block: Some(BlockNumberOrTag::Number(from_block)),
+            ..
+        } => from_block.max(next_block),
+        FilterBlockOption::Range { .. } => next_block,
+        FilterBlockOption::AtBlockHash(_) => (*block_range.start()).max(next_block),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_reports_every_block_once() {
+        let mut filters = LogFilters::default();
+        let id = filters.install(Filter::new(), 3);
+
+        assert_eq!(Some(3), filters.poll(id).map(|(_, from)| from));
+        filters.mark_reported(id, 5);
+        assert_eq!(Some(6), filters.poll(id).map(|(_, from)| from));
+        filters.mark_reported(id, 5);
+        assert_eq!(Some(6), filters.poll(id).map(|(_, from)| from));
+        filters.mark_reported(id, 7);
+        assert_eq!(Some(8), filters.poll(id).map(|(_, from)| from));
+    }
+
+    #[test]
+    fn failed_poll_reports_blocks_again() {
+        let mut filters = LogFilters::default();
+        let id = filters.install(Filter::new(), 3);
+
+        // The query of the first poll failed, so the blocks weren't marked as reported.
+        assert_eq!(Some(3), filters.poll(id).map(|(_, from)| from));
+        assert_eq!(Some(3), filters.poll(id).map(|(_, from)| from));
+    }
+
+    #[test]
+    fn filter_reports_blocks_from_its_start() {
+        let block_range = 0..=10;
+        assert_eq!(4, first_block_to_report(&Filter::new(), &block_range, 4));
+        assert_eq!(
+            4,
+            first_block_to_report(&Filter::new().from_block(2), &block_range, 4)
+        );
+        assert_eq!(
+            6,
+            first_block_to_report(&Filter::new().from_block(6), &block_range, 4)
+        );
+    }
+
+    #[test]
+    fn uninstalled_filter_is_gone() {
+        let mut filters = LogFilters::default();
+        let first = filters.install(Filter::new(), 0);
+        let second = filters.install(Filter::new(), 0);
+        assert_ne!(first, second);
+
+        assert!(filters.uninstall(first));
+        assert!(!filters.uninstall(first));
+        assert!(filters.poll(first).is_none());
+        assert!(filters.get(second).is_some());
+    }
+}
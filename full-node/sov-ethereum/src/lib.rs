@@ -1,36 +1,63 @@
 #[cfg(feature = "experimental")]
 mod batch_builder;
+#[cfg(feature = "test-utils")]
+pub mod conformance;
+#[cfg(feature = "experimental")]
+mod filters;
 #[cfg(feature = "experimental")]
 mod gas_price;
 #[cfg(feature = "experimental")]
+mod mempool;
+#[cfg(feature = "experimental")]
+mod subscriptions;
+#[cfg(feature = "experimental")]
 pub use experimental::{get_ethereum_rpc, Ethereum};
 #[cfg(feature = "experimental")]
 pub use gas_price::gas_oracle::GasPriceOracleConfig;
 #[cfg(feature = "experimental")]
+pub use mempool::MempoolConfig;
+#[cfg(all(feature = "experimental", feature = "local"))]
 pub use sov_evm::DevSigner;
 
 #[cfg(feature = "experimental")]
 pub mod experimental {
+    #[cfg(feature = "local")]
     use std::array::TryFromSliceError;
+    use std::marker::PhantomData;
     use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     use borsh::ser::BorshSerialize;
     use demo_stf::runtime::Runtime;
     use ethers::types::{Bytes, H256};
+    use futures::future::Either;
+    use jsonrpsee::core::SubscriptionResult;
     use jsonrpsee::types::ErrorObjectOwned;
-    use jsonrpsee::RpcModule;
-    use reth_primitives::{TransactionSignedNoHash as RethTransactionSignedNoHash, U128, U256};
+    use jsonrpsee::{PendingSubscriptionSink, RpcModule, SubscriptionMessage};
+    #[cfg(feature = "local")]
+    use reth_primitives::U128;
+    use reth_primitives::{Address, TransactionSignedEcRecovered, U256, U64};
+    use reth_rpc_types::pubsub::{Params, SubscriptionKind};
+    use reth_rpc_types::Filter;
+    #[cfg(feature = "local")]
     use reth_rpc_types::{CallRequest, TransactionRequest, TypedTransactionRequest};
-    use sov_evm::{CallMessage, Evm, RlpEvmTransaction};
+    use sov_evm::{log_matches_filter, CallMessage, Evm, RlpEvmTransaction};
     use sov_modules_api::utils::to_jsonrpsee_error_object;
     use sov_modules_api::{EncodeCall, PrivateKey, WorkingSet};
     use sov_rollup_interface::services::da::DaService;
+    use sov_rollup_interface::zk::Zkvm;
+    use tokio::sync::broadcast;
+    use tokio::sync::broadcast::error::RecvError;
+    use tracing::warn;
 
     use super::batch_builder::EthBatchBuilder;
+    use super::filters::{first_block_to_report, LogFilters};
+    use super::mempool::PooledTransaction;
+    use super::subscriptions::SubscriptionManager;
     #[cfg(feature = "local")]
     use super::DevSigner;
     use crate::gas_price::gas_oracle::GasPriceOracle;
-    use crate::GasPriceOracleConfig;
+    use crate::{GasPriceOracleConfig, MempoolConfig};
 
     const ETH_RPC_ERROR: &str = "ETH_RPC_ERROR";
 
@@ -39,15 +66,24 @@ pub mod experimental {
         pub min_blob_size: Option<usize>,
         pub sov_tx_signer_priv_key: C::PrivateKey,
         pub gas_price_oracle_config: GasPriceOracleConfig,
+        pub mempool_config: MempoolConfig,
+        /// If set, the pending transactions are published in a blob at this interval.
+        /// Otherwise they wait for `eth_publishBatch`.
+        pub blob_interval: Option<Duration>,
         #[cfg(feature = "local")]
         pub eth_signer: DevSigner,
     }
 
-    pub fn get_ethereum_rpc<C: sov_modules_api::Context, Da: DaService>(
+    /// Creates the Ethereum RPC module. `slot_subscriptions` notifies the module of every
+    /// processed slot, which feeds the `eth_subscribe` subscriptions.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn get_ethereum_rpc<C: sov_modules_api::Context, Da: DaService, Vm: Zkvm + 'static>(
         da_service: Da,
         eth_rpc_config: EthRpcConfig<C>,
         storage: C::Storage,
-    ) -> RpcModule<Ethereum<C, Da>> {
+        slot_subscriptions: broadcast::Receiver<u64>,
+    ) -> RpcModule<Ethereum<C, Da, Vm>> {
         // Unpack config
         let EthRpcConfig {
             min_blob_size,
@@ -55,6 +91,8 @@ pub mod experimental {
             #[cfg(feature = "local")]
             eth_signer,
             gas_price_oracle_config,
+            mempool_config,
+            blob_interval,
         } = eth_rpc_config;
 
         // Fetch nonce from storage
@@ -70,66 +108,156 @@ pub mod experimental {
             sov_accounts::Response::AccountEmpty { .. } => 0,
         };
 
+        let da_service = Arc::new(da_service);
+        let batch_builder = Arc::new(Mutex::new(EthBatchBuilder::new(
+            sov_tx_signer_priv_key,
+            sov_tx_signer_nonce,
+            min_blob_size,
+            mempool_config,
+        )));
+
+        if let Some(blob_interval) = blob_interval {
+            tokio::spawn(publish_blobs_periodically(
+                da_service.clone(),
+                batch_builder.clone(),
+                storage.clone(),
+                blob_interval,
+            ));
+        }
+
         let mut rpc = RpcModule::new(Ethereum::new(
             da_service,
-            Arc::new(Mutex::new(EthBatchBuilder::new(
-                sov_tx_signer_priv_key,
-                sov_tx_signer_nonce,
-                min_blob_size,
-            ))),
+            batch_builder,
             gas_price_oracle_config,
             #[cfg(feature = "local")]
             eth_signer,
             storage,
+            slot_subscriptions,
         ));
 
         register_rpc_methods(&mut rpc).expect("Failed to register sequencer RPC methods");
         rpc
     }
 
-    pub struct Ethereum<C: sov_modules_api::Context, Da: DaService> {
-        da_service: Da,
+    /// Publishes the transactions that are ready every `interval`.
+    async fn publish_blobs_periodically<C: sov_modules_api::Context, Da: DaService>(
+        da_service: Arc<Da>,
+        batch_builder: Arc<Mutex<EthBatchBuilder<C>>>,
+        storage: C::Storage,
+        interval: Duration,
+    ) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+
+            let blob = batch_builder
+                .lock()
+                .unwrap()
+                .take_next_blob(Some(1), evm_account_nonces::<C>(&storage));
+            let Some(blob) = blob else {
+                continue;
+            };
+            if let Err(error) = submit_batch(da_service.as_ref(), &blob.txs).await {
+                warn!(%error, "Failed to publish a blob of EVM transactions");
+                // The transactions are retried in the next blob.
+                batch_builder.lock().unwrap().requeue(blob);
+            }
+        }
+    }
+
+    /// Returns the current nonces of the EVM accounts.
+    fn evm_account_nonces<C: sov_modules_api::Context>(
+        storage: &C::Storage,
+    ) -> impl FnMut(&Address) -> u64 {
+        let evm = Evm::<C>::default();
+        let mut working_set = WorkingSet::<C>::new(storage.clone());
+        move |address| {
+            evm.get_transaction_count(*address, None, &mut working_set)
+                .map(|nonce| nonce.as_u64())
+                .unwrap_or_default()
+        }
+    }
+
+    async fn submit_batch<Da: DaService>(
+        da_service: &Da,
+        batch: &[Vec<u8>],
+    ) -> Result<(), jsonrpsee::core::Error> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let blob = batch
+            .try_to_vec()
+            .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+
+        da_service
+            .send_transaction(&blob)
+            .await
+            .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+
+        Ok(())
+    }
+
+    pub struct Ethereum<C: sov_modules_api::Context, Da: DaService, Vm: Zkvm + 'static> {
+        da_service: Arc<Da>,
         batch_builder: Arc<Mutex<EthBatchBuilder<C>>>,
         gas_price_oracle: GasPriceOracle<C>,
+        log_filters: Mutex<LogFilters>,
+        subscriptions: SubscriptionManager,
         #[cfg(feature = "local")]
         eth_signer: DevSigner,
         storage: C::Storage,
+        _phantom: PhantomData<fn() -> Vm>,
     }
 
-    impl<C: sov_modules_api::Context, Da: DaService> Ethereum<C, Da> {
+    impl<C: sov_modules_api::Context, Da: DaService, Vm: Zkvm + 'static> Ethereum<C, Da, Vm> {
         fn new(
-            da_service: Da,
+            da_service: Arc<Da>,
             batch_builder: Arc<Mutex<EthBatchBuilder<C>>>,
             gas_price_oracle_config: GasPriceOracleConfig,
             #[cfg(feature = "local")] eth_signer: DevSigner,
             storage: C::Storage,
+            slot_subscriptions: broadcast::Receiver<u64>,
         ) -> Self {
             let evm = Evm::<C>::default();
             let gas_price_oracle = GasPriceOracle::new(evm, gas_price_oracle_config);
+            let subscriptions = SubscriptionManager::new::<C>(storage.clone(), slot_subscriptions);
             Self {
                 da_service,
                 batch_builder,
                 gas_price_oracle,
+                log_filters: Mutex::new(LogFilters::default()),
+                subscriptions,
                 #[cfg(feature = "local")]
                 eth_signer,
                 storage,
+                _phantom: PhantomData,
             }
         }
     }
 
-    impl<C: sov_modules_api::Context, Da: DaService> Ethereum<C, Da> {
+    impl<C: sov_modules_api::Context, Da: DaService, Vm: Zkvm + 'static> Ethereum<C, Da, Vm> {
         fn make_raw_tx(
             &self,
             raw_tx: RlpEvmTransaction,
-        ) -> Result<(H256, Vec<u8>), jsonrpsee::core::Error> {
-            let signed_transaction: RethTransactionSignedNoHash = raw_tx.clone().try_into()?;
-
-            let tx_hash = signed_transaction.hash();
+        ) -> Result<PooledTransaction, jsonrpsee::core::Error> {
+            let signed_transaction: TransactionSignedEcRecovered = raw_tx.clone().try_into()?;
 
             let tx = CallMessage { tx: raw_tx };
-            let message = <Runtime<C, Da::Spec> as EncodeCall<sov_evm::Evm<C>>>::encode_call(tx);
-
-            Ok((H256::from(tx_hash), message))
+            let message = <Runtime<C, Da::Spec, Vm> as EncodeCall<
+                sov_evm::Evm<C, Runtime<C, Da::Spec, Vm>>,
+            >>::encode_call(tx);
+
+            Ok(PooledTransaction {
+                hash: signed_transaction.hash(),
+                sender: signed_transaction.signer(),
+                nonce: signed_transaction.nonce(),
+                max_fee_per_gas: signed_transaction.max_fee_per_gas(),
+                max_priority_fee_per_gas: signed_transaction
+                    .max_priority_fee_per_gas()
+                    .unwrap_or_else(|| signed_transaction.max_fee_per_gas()),
+                message,
+            })
         }
 
         async fn build_and_submit_batch(
@@ -147,20 +275,7 @@ pub mod experimental {
         }
 
         async fn submit_batch(&self, batch: Vec<Vec<u8>>) -> Result<(), jsonrpsee::core::Error> {
-            if batch.is_empty() {
-                return Ok(());
-            }
-
-            let blob = batch
-                .try_to_vec()
-                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
-
-            self.da_service
-                .send_transaction(&blob)
-                .await
-                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
-
-            Ok(())
+            submit_batch(self.da_service.as_ref(), &batch).await
         }
 
         fn build_batch(
@@ -172,18 +287,33 @@ pub mod experimental {
                 .batch_builder
                 .lock()
                 .unwrap()
-                .add_messages_and_get_next_blob(min_blob_size, messages);
+                .add_messages_and_get_next_blob(
+                    min_blob_size,
+                    messages,
+                    evm_account_nonces::<C>(&self.storage),
+                );
 
             Ok(batch)
         }
 
-        fn add_messages(&self, messages: Vec<Vec<u8>>) {
-            self.batch_builder.lock().unwrap().add_messages(messages);
+        /// Adds `tx` to the mempool and returns its hash.
+        fn add_transaction(&self, tx: PooledTransaction) -> Result<H256, ErrorObjectOwned> {
+            let tx_hash = tx.hash;
+            let account_nonce = evm_account_nonces::<C>(&self.storage)(&tx.sender);
+
+            self.batch_builder
+                .lock()
+                .unwrap()
+                .add_evm_transaction(tx, account_nonce)
+                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+            self.subscriptions.publish_pending_transaction(tx_hash);
+
+            Ok(H256::from(tx_hash.0))
         }
     }
 
-    fn register_rpc_methods<C: sov_modules_api::Context, Da: DaService>(
-        rpc: &mut RpcModule<Ethereum<C, Da>>,
+    fn register_rpc_methods<C: sov_modules_api::Context, Da: DaService, Vm: Zkvm + 'static>(
+        rpc: &mut RpcModule<Ethereum<C, Da, Vm>>,
     ) -> Result<(), jsonrpsee::core::Error> {
         rpc.register_async_method("eth_gasPrice", |_, ethereum| async move {
             let price = {
@@ -210,6 +340,139 @@ pub mod experimental {
             Ok::<U256, ErrorObjectOwned>(price)
         })?;
 
+        rpc.register_async_method("eth_maxPriorityFeePerGas", |_, ethereum| async move {
+            let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+
+            let suggested_tip = ethereum
+                .gas_price_oracle
+                .suggest_tip_cap(&mut working_set)
+                .await?;
+
+            Ok::<U256, ErrorObjectOwned>(suggested_tip)
+        })?;
+
+        rpc.register_async_method("eth_newFilter", |parameters, ethereum| async move {
+            let filter: Filter = parameters.one()?;
+
+            let evm = Evm::<C>::default();
+            let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+            let next_block = evm.latest_block_number(&mut working_set) + 1;
+
+            let id = ethereum
+                .log_filters
+                .lock()
+                .unwrap()
+                .install(filter, next_block);
+
+            Ok::<U64, ErrorObjectOwned>(id)
+        })?;
+
+        rpc.register_async_method("eth_getFilterChanges", |parameters, ethereum| async move {
+            let id: U64 = parameters.one()?;
+
+            let evm = Evm::<C>::default();
+            let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+            let latest_block = evm.latest_block_number(&mut working_set);
+
+            let (filter, next_block) = ethereum
+                .log_filters
+                .lock()
+                .unwrap()
+                .poll(id)
+                .ok_or_else(|| filter_not_found(id))?;
+
+            let block_range = evm.filter_block_range(&filter, &mut working_set)?;
+
+            // No block was produced since the last poll.
+            if next_block > latest_block {
+                return Ok(Vec::new());
+            }
+
+            let from_block = first_block_to_report(&filter, &block_range, next_block);
+            let logs = evm.logs_in_block_range(
+                &filter,
+                from_block..=*block_range.end(),
+                &mut working_set,
+            )?;
+
+            // The blocks are only reported once their logs were queried, so that a failed query
+            // is retried by the next poll.
+            ethereum
+                .log_filters
+                .lock()
+                .unwrap()
+                .mark_reported(id, latest_block);
+
+            Ok::<_, ErrorObjectOwned>(logs)
+        })?;
+
+        rpc.register_async_method("eth_getFilterLogs", |parameters, ethereum| async move {
+            let id: U64 = parameters.one()?;
+
+            let filter = ethereum
+                .log_filters
+                .lock()
+                .unwrap()
+                .get(id)
+                .ok_or_else(|| filter_not_found(id))?;
+
+            let evm = Evm::<C>::default();
+            let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+            let logs = evm.eth_get_logs(filter, &mut working_set)?;
+
+            Ok::<_, ErrorObjectOwned>(logs)
+        })?;
+
+        rpc.register_async_method("eth_uninstallFilter", |parameters, ethereum| async move {
+            let id: U64 = parameters.one()?;
+
+            let uninstalled = ethereum.log_filters.lock().unwrap().uninstall(id);
+
+            Ok::<bool, ErrorObjectOwned>(uninstalled)
+        })?;
+
+        rpc.register_subscription(
+            "eth_subscribe",
+            "eth_subscription",
+            "eth_unsubscribe",
+            |parameters, pending_subscription, ethereum| async move {
+                let mut parameters = parameters.sequence();
+                let kind: SubscriptionKind = parameters.next()?;
+                let params: Option<Params> = parameters.optional_next()?;
+
+                match kind {
+                    SubscriptionKind::NewHeads => {
+                        let rx = ethereum.subscriptions.subscribe_new_heads();
+                        pipe_from_channel(pending_subscription, rx, Some).await
+                    }
+                    SubscriptionKind::Logs => {
+                        let filter = match params {
+                            Some(Params::Logs(filter)) => *filter,
+                            _ => Filter::default(),
+                        };
+                        let rx = ethereum.subscriptions.subscribe_logs();
+                        pipe_from_channel(pending_subscription, rx, move |log| {
+                            log_matches_filter(&log.address, &log.topics, &filter).then_some(log)
+                        })
+                        .await
+                    }
+                    SubscriptionKind::NewPendingTransactions => {
+                        let rx = ethereum.subscriptions.subscribe_pending_transactions();
+                        pipe_from_channel(pending_subscription, rx, Some).await
+                    }
+                    SubscriptionKind::Syncing => {
+                        pending_subscription
+                            .reject(to_jsonrpsee_error_object(
+                                "Syncing subscriptions are not supported",
+                                ETH_RPC_ERROR,
+                            ))
+                            .await;
+                        Ok(())
+                    }
+                }
+            },
+        )?;
+
         rpc.register_async_method("eth_publishBatch", |params, ethereum| async move {
             let mut params_iter = params.sequence();
 
@@ -233,13 +496,11 @@ pub mod experimental {
 
                 let raw_evm_tx = RlpEvmTransaction { rlp: data.to_vec() };
 
-                let (tx_hash, raw_message) = ethereum
+                let tx = ethereum
                     .make_raw_tx(raw_evm_tx)
                     .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
 
-                ethereum.add_messages(vec![raw_message]);
-
-                Ok::<_, ErrorObjectOwned>(tx_hash)
+                ethereum.add_transaction(tx)
             },
         )?;
 
@@ -252,7 +513,8 @@ pub mod experimental {
         rpc.register_async_method("eth_sendTransaction", |parameters, ethereum| async move {
             let mut transaction_request: TransactionRequest = parameters.one().unwrap();
 
-            let evm = Evm::<C>::default();
+            // The gas estimation dispatches the module calls through the runtime.
+            let evm = Evm::<C, Runtime<C, Da::Spec, Vm>>::default();
 
             // get from, return error if none
             let from = transaction_request
@@ -342,18 +604,60 @@ pub mod experimental {
                     rlp: signed_tx.envelope_encoded().to_vec(),
                 }
             };
-            let (tx_hash, raw_message) = ethereum
+            let tx = ethereum
                 .make_raw_tx(raw_evm_tx)
                 .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
 
-            ethereum.add_messages(vec![raw_message]);
-
-            Ok::<_, ErrorObjectOwned>(tx_hash)
+            ethereum.add_transaction(tx)
         })?;
 
         Ok(())
     }
 
+    /// Accepts `pending_subscription` and forwards it the items received from `rx`, transformed
+    /// by `map`. Items mapped to [`None`] are skipped.
+    async fn pipe_from_channel<T: Clone, R: serde::Serialize>(
+        pending_subscription: PendingSubscriptionSink,
+        mut rx: broadcast::Receiver<T>,
+        map: impl Fn(T) -> Option<R>,
+    ) -> SubscriptionResult {
+        let subscription = pending_subscription.accept().await?;
+        let closed = subscription.closed();
+        futures::pin_mut!(closed);
+
+        // This loop continues running until the subscription ends.
+        loop {
+            let next_item = rx.recv();
+            futures::pin_mut!(next_item);
+            match futures::future::select(closed, next_item).await {
+                // If the subscription closed, we're done
+                Either::Left(_) => break Ok(()),
+                Either::Right((item, channel_closing_future)) => {
+                    match item {
+                        Ok(item) => {
+                            if let Some(item) = map(item) {
+                                let msg = SubscriptionMessage::from_json(&item)?;
+                                // Sending only fails if the subscriber has canceled, so we can stop sending messages
+                                if subscription.send(msg).await.is_err() {
+                                    break Ok(());
+                                }
+                            }
+                        }
+                        // A slow subscriber misses the items it lagged behind on.
+                        Err(RecvError::Lagged(_)) => {}
+                        Err(RecvError::Closed) => break Ok(()),
+                    }
+                    closed = channel_closing_future;
+                }
+            }
+        }
+    }
+
+    fn filter_not_found(id: U64) -> ErrorObjectOwned {
+        to_jsonrpsee_error_object(format!("Filter {id} not found"), ETH_RPC_ERROR)
+    }
+
+    #[cfg(feature = "local")]
     // Temporary solution until https://github.com/paradigmxyz/reth/issues/4704 is resolved
     // The problem is having wrong length nonce/gas_limt/value fields in the transaction request
     fn into_transaction(
@@ -401,18 +705,21 @@ pub mod experimental {
         })
     }
 
+    #[cfg(feature = "local")]
     fn convert_u256_to_u64(u256: reth_primitives::U256) -> Result<u64, TryFromSliceError> {
         let bytes: [u8; 32] = u256.to_be_bytes();
         let bytes: [u8; 8] = bytes[24..].try_into()?;
         Ok(u64::from_be_bytes(bytes))
     }
 
+    #[cfg(feature = "local")]
     fn convert_u256_to_u128(u256: reth_primitives::U256) -> Result<u128, TryFromSliceError> {
         let bytes: [u8; 32] = u256.to_be_bytes();
         let bytes: [u8; 16] = bytes[16..].try_into()?;
         Ok(u128::from_be_bytes(bytes))
     }
 
+    #[cfg(feature = "local")]
     fn get_call_request_and_params(
         from: reth_primitives::H160,
         chain_id: u64,
@@ -0,0 +1,372 @@
+//! The pool of the EVM transactions received with `eth_sendRawTransaction`, waiting to be
+//! published in a blob.
+
+use std::cmp::Ordering;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+use reth_primitives::{Address, H256};
+
+/// Limits of the [`EthMempool`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct MempoolConfig {
+    /// Maximum number of transactions in the pool.
+    pub max_txs: usize,
+    /// Maximum number of transactions of a single sender in the pool.
+    pub max_txs_per_sender: usize,
+    /// A transaction replaces a pooled transaction with the same sender and nonce if its fees
+    /// are higher by at least this percentage.
+    pub price_bump_percent: u128,
+    /// Maximum number of pooled transactions published in a single blob.
+    pub max_txs_per_blob: usize,
+}
+
+impl Default for MempoolConfig {
+    fn default() -> Self {
+        Self {
+            max_txs: 4096,
+            max_txs_per_sender: 64,
+            price_bump_percent: 10,
+            max_txs_per_blob: 512,
+        }
+    }
+}
+
+/// A transaction waiting in the [`EthMempool`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PooledTransaction {
+    pub hash: H256,
+    pub sender: Address,
+    pub nonce: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    /// The runtime message executing the transaction.
+    pub message: Vec<u8>,
+}
+
+impl PooledTransaction {
+    fn is_replaced_by(&self, other: &PooledTransaction, price_bump_percent: u128) -> bool {
+        let bumped = |fee: u128| fee.saturating_mul(100 + price_bump_percent) / 100;
+        other.max_fee_per_gas >= bumped(self.max_fee_per_gas)
+            && other.max_priority_fee_per_gas >= bumped(self.max_priority_fee_per_gas)
+    }
+}
+
+/// Why a transaction wasn't accepted into the [`EthMempool`].
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum MempoolError {
+    #[error("nonce too low: next nonce {expected}, tx nonce {actual}")]
+    NonceTooLow { expected: u64, actual: u64 },
+    #[error("replacement transaction underpriced")]
+    ReplacementUnderpriced,
+    #[error("sender {0} has too many pending transactions")]
+    SenderPoolFull(Address),
+    #[error("transaction pool is full")]
+    PoolFull,
+    #[error("already known")]
+    AlreadyKnown,
+}
+
+/// The transactions of every sender, ordered by nonce. Transactions are published once their
+/// nonce follows the nonce of the sender account without gaps.
+#[derive(Default)]
+pub struct EthMempool {
+    config: MempoolConfig,
+    senders: HashMap<Address, BTreeMap<u64, PooledTransaction>>,
+    /// The nonce following the last published transaction of each sender. The account nonces
+    /// only catch up once the blob is executed, so they can't tell what was published.
+    published_nonces: HashMap<Address, u64>,
+    len: usize,
+}
+
+impl EthMempool {
+    pub fn new(config: MempoolConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Number of transactions in the pool.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds `tx` to the pool. `account_nonce` is the current nonce of the sender account.
+    pub fn insert(
+        &mut self,
+        tx: PooledTransaction,
+        account_nonce: u64,
+    ) -> Result<(), MempoolError> {
+        if tx.nonce < account_nonce {
+            return Err(MempoolError::NonceTooLow {
+                expected: account_nonce,
+                actual: tx.nonce,
+            });
+        }
+
+        let txs = self.senders.entry(tx.sender).or_default();
+        match txs.get(&tx.nonce) {
+            Some(pooled) if pooled.hash == tx.hash => return Err(MempoolError::AlreadyKnown),
+            Some(pooled) => {
+                if !pooled.is_replaced_by(&tx, self.config.price_bump_percent) {
+                    return Err(MempoolError::ReplacementUnderpriced);
+                }
+            }
+            None => {
+                if txs.len() >= self.config.max_txs_per_sender {
+                    return Err(MempoolError::SenderPoolFull(tx.sender));
+                }
+                if self.len >= self.config.max_txs {
+                    return Err(MempoolError::PoolFull);
+                }
+                self.len += 1;
+            }
+        }
+
+        // A published transaction with this nonce was never executed, so this one fills the gap.
+        if let Some(published_nonce) = self.published_nonces.get_mut(&tx.sender) {
+            *published_nonce = (*published_nonce).min(tx.nonce);
+        }
+
+        txs.insert(tx.nonce, tx);
+        Ok(())
+    }
+
+    /// Removes up to [`MempoolConfig::max_txs_per_blob`] transactions that can be executed in
+    /// order and returns them. Senders are served by decreasing priority fee of their next
+    /// transaction. `account_nonce` returns the current nonce of an account.
+    pub fn take_ready(
+        &mut self,
+        mut account_nonce: impl FnMut(&Address) -> u64,
+    ) -> Vec<PooledTransaction> {
+        let account_nonces: HashMap<Address, u64> = self
+            .senders
+            .keys()
+            .chain(self.published_nonces.keys())
+            .map(|sender| (*sender, account_nonce(sender)))
+            .collect();
+        self.remove_executed(&account_nonces);
+
+        let mut candidates = BinaryHeap::new();
+        for (sender, txs) in &self.senders {
+            let next_nonce = self.next_nonce(sender, &account_nonces);
+            if let Some(tx) = txs.get(&next_nonce) {
+                candidates.push(Candidate {
+                    tip: tx.max_priority_fee_per_gas,
+                    sender: *sender,
+                    nonce: next_nonce,
+                });
+            }
+        }
+
+        let mut ready = Vec::new();
+        while ready.len() < self.config.max_txs_per_blob {
+            let Some(Candidate { sender, nonce, .. }) = candidates.pop() else {
+                break;
+            };
+            let txs = self
+                .senders
+                .get_mut(&sender)
+                .expect("Candidates have pooled txs");
+            let tx = txs.remove(&nonce).expect("Candidates are pooled");
+            self.len -= 1;
+            self.published_nonces.insert(sender, nonce + 1);
+
+            if let Some(next_tx) = txs.get(&(nonce + 1)) {
+                candidates.push(Candidate {
+                    tip: next_tx.max_priority_fee_per_gas,
+                    sender,
+                    nonce: nonce + 1,
+                });
+            }
+            ready.push(tx);
+        }
+
+        self.senders.retain(|_, txs| !txs.is_empty());
+        ready
+    }
+
+    /// Puts back transactions returned by [`EthMempool::take_ready`] whose blob wasn't
+    /// published. The pool limits don't apply, since the transactions were already pooled, and
+    /// transactions replaced in the meantime are dropped.
+    pub fn requeue(&mut self, txs: Vec<PooledTransaction>) {
+        for tx in txs {
+            if let Some(published_nonce) = self.published_nonces.get_mut(&tx.sender) {
+                *published_nonce = (*published_nonce).min(tx.nonce);
+            }
+
+            let txs = self.senders.entry(tx.sender).or_default();
+            if let Entry::Vacant(entry) = txs.entry(tx.nonce) {
+                entry.insert(tx);
+                self.len += 1;
+            }
+        }
+    }
+
+    fn next_nonce(&self, sender: &Address, account_nonces: &HashMap<Address, u64>) -> u64 {
+        let account_nonce = account_nonces[sender];
+        self.published_nonces
+            .get(sender)
+            .map_or(account_nonce, |published| account_nonce.max(*published))
+    }
+
+    /// Drops the transactions whose nonce was used by an executed transaction.
+    fn remove_executed(&mut self, account_nonces: &HashMap<Address, u64>) {
+        for (sender, txs) in self.senders.iter_mut() {
+            let pending = txs.split_off(&account_nonces[sender]);
+            self.len -= txs.len();
+            *txs = pending;
+        }
+        self.senders.retain(|_, txs| !txs.is_empty());
+        self.published_nonces
+            .retain(|sender, published| *published > account_nonces[sender]);
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct Candidate {
+    tip: u128,
+    sender: Address,
+    nonce: u64,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tip
+            .cmp(&other.tip)
+            // Breaks ties deterministically.
+            .then_with(|| other.sender.cmp(&self.sender))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(sender: u8, nonce: u64, tip: u128) -> PooledTransaction {
+        PooledTransaction {
+            hash: H256::from_low_u64_be(((sender as u64) << 32) + (nonce << 16) + tip as u64),
+            sender: Address::from_low_u64_be(sender as u64),
+            nonce,
+            max_fee_per_gas: 2 * tip,
+            max_priority_fee_per_gas: tip,
+            message: vec![sender, nonce as u8],
+        }
+    }
+
+    fn nonces(txs: &[PooledTransaction]) -> Vec<(u8, u64)> {
+        txs.iter().map(|tx| (tx.message[0], tx.nonce)).collect()
+    }
+
+    #[test]
+    fn takes_contiguous_nonces_by_tip() {
+        let mut mempool = EthMempool::default();
+        mempool.insert(tx(1, 1, 5), 0).unwrap();
+        mempool.insert(tx(1, 0, 5), 0).unwrap();
+        mempool.insert(tx(2, 0, 10), 0).unwrap();
+        // Sender 3 has a nonce gap.
+        mempool.insert(tx(3, 1, 100), 0).unwrap();
+
+        let ready = mempool.take_ready(|_| 0);
+        assert_eq!(vec![(2, 0), (1, 0), (1, 1)], nonces(&ready));
+        assert_eq!(1, mempool.len());
+
+        // The published nonces are skipped until the blob is executed.
+        mempool.insert(tx(1, 2, 5), 0).unwrap();
+        let ready = mempool.take_ready(|_| 0);
+        assert_eq!(vec![(1, 2)], nonces(&ready));
+
+        // Executing the transactions of sender 3 drops its stale transaction.
+        let ready = mempool.take_ready(|sender| {
+            if *sender == Address::from_low_u64_be(3) {
+                2
+            } else {
+                0
+            }
+        });
+        assert!(ready.is_empty());
+        assert!(mempool.is_empty());
+    }
+
+    #[test]
+    fn requeues_unpublished_transactions() {
+        let mut mempool = EthMempool::new(MempoolConfig {
+            max_txs: 2,
+            ..Default::default()
+        });
+        mempool.insert(tx(1, 0, 5), 0).unwrap();
+        mempool.insert(tx(1, 1, 5), 0).unwrap();
+
+        let ready = mempool.take_ready(|_| 0);
+        assert_eq!(vec![(1, 0), (1, 1)], nonces(&ready));
+        // A replacement of the second transaction arrives before the blob fails.
+        mempool.insert(tx(1, 1, 10), 0).unwrap();
+        mempool.insert(tx(2, 0, 1), 0).unwrap();
+
+        // The pool is full, but the taken transactions are put back anyway.
+        mempool.requeue(ready);
+        assert_eq!(3, mempool.len());
+
+        let ready = mempool.take_ready(|_| 0);
+        assert_eq!(vec![(1, 0), (1, 1), (2, 0)], nonces(&ready));
+        assert_eq!(10, ready[1].max_priority_fee_per_gas);
+        assert!(mempool.is_empty());
+    }
+
+    #[test]
+    fn replaces_with_bumped_fees() {
+        let mut mempool = EthMempool::default();
+        mempool.insert(tx(1, 0, 100), 0).unwrap();
+
+        assert_eq!(
+            Err(MempoolError::AlreadyKnown),
+            mempool.insert(tx(1, 0, 100), 0)
+        );
+        assert_eq!(
+            Err(MempoolError::ReplacementUnderpriced),
+            mempool.insert(tx(1, 0, 105), 0)
+        );
+        mempool.insert(tx(1, 0, 110), 0).unwrap();
+        assert_eq!(1, mempool.len());
+
+        let ready = mempool.take_ready(|_| 0);
+        assert_eq!(110, ready[0].max_priority_fee_per_gas);
+    }
+
+    #[test]
+    fn enforces_limits() {
+        let mut mempool = EthMempool::new(MempoolConfig {
+            max_txs: 3,
+            max_txs_per_sender: 2,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            Err(MempoolError::NonceTooLow {
+                expected: 1,
+                actual: 0
+            }),
+            mempool.insert(tx(1, 0, 1), 1)
+        );
+        mempool.insert(tx(1, 0, 1), 0).unwrap();
+        mempool.insert(tx(1, 1, 1), 0).unwrap();
+        assert!(matches!(
+            mempool.insert(tx(1, 2, 1), 0),
+            Err(MempoolError::SenderPoolFull(_))
+        ));
+        mempool.insert(tx(2, 0, 1), 0).unwrap();
+        assert_eq!(Err(MempoolError::PoolFull), mempool.insert(tx(3, 0, 1), 0));
+    }
+}
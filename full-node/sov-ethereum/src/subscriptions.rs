@@ -0,0 +1,104 @@
+//! Feeds of the `eth_subscribe` subscriptions.
+
+use reth_primitives::H256;
+use reth_rpc_types::{Header, Log};
+use sov_evm::Evm;
+use sov_modules_api::WorkingSet;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// How many items a slow subscriber can lag behind before it starts missing them.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Broadcasts the blocks sealed by the EVM, their logs and the transactions added to the mempool.
+#[derive(Clone)]
+pub struct SubscriptionManager {
+    new_heads: broadcast::Sender<Header>,
+    logs: broadcast::Sender<Log>,
+    pending_transactions: broadcast::Sender<H256>,
+}
+
+impl SubscriptionManager {
+    /// Follows the blocks sealed by the EVM in `storage`. `slot_subscriptions` notifies the
+    /// manager every time a slot is processed, which is when new blocks are sealed.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new<C: sov_modules_api::Context>(
+        storage: C::Storage,
+        mut slot_subscriptions: broadcast::Receiver<u64>,
+    ) -> Self {
+        let manager = Self {
+            new_heads: broadcast::channel(CHANNEL_CAPACITY).0,
+            logs: broadcast::channel(CHANNEL_CAPACITY).0,
+            pending_transactions: broadcast::channel(CHANNEL_CAPACITY).0,
+        };
+
+        let feed = manager.clone();
+        tokio::spawn(async move {
+            let mut next_block = {
+                let mut working_set = WorkingSet::<C>::new(storage.clone());
+                Evm::<C>::default().latest_block_number(&mut working_set) + 1
+            };
+
+            loop {
+                match slot_subscriptions.recv().await {
+                    // Missed notifications don't matter, the blocks are read from the storage.
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+                next_block = feed.publish_blocks_from::<C>(next_block, &storage);
+            }
+        });
+
+        manager
+    }
+
+    /// Returns a receiver of the headers of the sealed blocks.
+    pub fn subscribe_new_heads(&self) -> broadcast::Receiver<Header> {
+        self.new_heads.subscribe()
+    }
+
+    /// Returns a receiver of the logs emitted in the sealed blocks.
+    pub fn subscribe_logs(&self) -> broadcast::Receiver<Log> {
+        self.logs.subscribe()
+    }
+
+    /// Returns a receiver of the hashes of the transactions added to the mempool.
+    pub fn subscribe_pending_transactions(&self) -> broadcast::Receiver<H256> {
+        self.pending_transactions.subscribe()
+    }
+
+    /// Notifies the subscribers that the transaction `tx_hash` was added to the mempool.
+    pub fn publish_pending_transaction(&self, tx_hash: H256) {
+        // Sending only fails if there are no subscribers.
+        let _ = self.pending_transactions.send(tx_hash);
+    }
+
+    /// Publishes the blocks sealed since `next_block` and returns the next block to publish.
+    fn publish_blocks_from<C: sov_modules_api::Context>(
+        &self,
+        next_block: u64,
+        storage: &C::Storage,
+    ) -> u64 {
+        let evm = Evm::<C>::default();
+        let mut working_set = WorkingSet::<C>::new(storage.clone());
+        let latest_block = evm.latest_block_number(&mut working_set);
+
+        for block_number in next_block..=latest_block {
+            let (Some(header), Some(logs)) = (
+                evm.sealed_block_header(block_number, &mut working_set),
+                evm.sealed_block_logs(block_number, &mut working_set),
+            ) else {
+                return block_number;
+            };
+
+            // Sending only fails if there are no subscribers.
+            let _ = self.new_heads.send(header);
+            for log in logs {
+                let _ = self.logs.send(log);
+            }
+        }
+
+        next_block.max(latest_block + 1)
+    }
+}
@@ -5,7 +5,7 @@ use anyhow::{bail, Context as ErrorContext};
 use borsh::BorshDeserialize;
 use sov_modules_api::digest::Digest;
 use sov_modules_api::transaction::Transaction;
-use sov_modules_api::{Context, DispatchCall, PublicKey, Spec, WorkingSet};
+use sov_modules_api::{Context, DispatchCall, Spec, WorkingSet};
 use sov_rollup_interface::services::batch_builder::BatchBuilder;
 use tracing::{info, warn};
 
@@ -140,7 +140,7 @@ where
             // Execute
             {
                 // TODO: Bug(!), because potential discrepancy. Should be resolved by https://github.com/Sovereign-Labs/sovereign-sdk/issues/434
-                let sender_address: C::Address = pooled.tx.pub_key().to_address();
+                let sender_address: C::Address = pooled.tx.default_sender_address();
                 // FIXME! This should use the correct height
                 let ctx = C::new(sender_address, self.sequencer.clone(), 0);
 
@@ -186,7 +186,7 @@ mod tests {
     use sov_modules_api::macros::DefaultRuntime;
     use sov_modules_api::transaction::Transaction;
     use sov_modules_api::{
-        Address, Context, DispatchCall, EncodeCall, Genesis, MessageCodec, PrivateKey,
+        Address, Context, DispatchCall, EncodeCall, Genesis, MessageCodec, PrivateKey, PublicKey,
     };
     use sov_prover_storage_manager::{new_orphan_storage, SnapshotManager};
     use sov_rollup_interface::services::batch_builder::BatchBuilder;
@@ -116,6 +116,8 @@ mod tests {
                 celestia_rpc_address: "http://localhost:11111/".into(),
                 max_celestia_response_body_size: 980,
                 celestia_rpc_timeout_seconds: 60,
+                gas_price_multiplier: 1.1,
+                max_gas_price: 0.1,
             },
             storage: StorageConfig {
                 path: PathBuf::from("/tmp"),
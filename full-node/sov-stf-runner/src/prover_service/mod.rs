@@ -57,6 +57,16 @@ pub enum ProverServiceError {
     Other(#[from] anyhow::Error),
 }
 
+/// Wraps a serialized ZK proof into a blob which can be posted to the DA layer,
+/// so that the rollup can verify it on-chain.
+///
+/// Implementations usually build and sign a rollup transaction carrying the proof
+/// (e.g. a `VerifyProof` call to the prover incentives module) and wrap it into a batch.
+pub trait ProofBlobEncoder: Send + Sync {
+    /// Encodes the serialized `proof` into a DA blob.
+    fn encode_proof_blob(&self, proof: Vec<u8>) -> anyhow::Result<Vec<u8>>;
+}
+
 /// This service is responsible for ZK proof generation.
 /// The proof generation process involves the following stages:
 ///     1. Submitting a witness using the `submit_witness` method to a prover service.
@@ -89,8 +99,7 @@ pub trait ProverService {
         block_header_hash: <<Self::DaService as DaService>::Spec as DaSpec>::SlotHash,
     ) -> Result<ProofProcessingStatus, ProverServiceError>;
 
-    /// Sends the ZK proof to the DA.
-    /// This method is noy yet fully implemented: see #1185
+    /// Sends the ZK proof to the DA, once it has been generated.
     async fn send_proof_to_da(
         &self,
         block_header_hash: <<Self::DaService as DaService>::Spec as DaSpec>::SlotHash,
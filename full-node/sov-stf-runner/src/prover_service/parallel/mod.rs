@@ -8,9 +8,9 @@ use serde::Serialize;
 use sov_rollup_interface::da::DaSpec;
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::stf::StateTransitionFunction;
-use sov_rollup_interface::zk::{StateTransitionData, ZkvmHost};
+use sov_rollup_interface::zk::{Proof, StateTransitionData, ZkvmHost};
 
-use super::{ProverService, ProverServiceError};
+use super::{ProofBlobEncoder, ProverService, ProverServiceError};
 use crate::config::ProverServiceConfig;
 use crate::verifier::StateTransitionVerifier;
 use crate::{
@@ -32,6 +32,7 @@ where
 
     zk_storage: V::PreState,
     prover_state: Prover<StateRoot, Witness, Da>,
+    proof_submitter: Option<(Da, Box<dyn ProofBlobEncoder>)>,
 }
 
 impl<StateRoot, Witness, Da, Vm, V> ParallelProverService<StateRoot, Witness, Da, Vm, V>
@@ -73,9 +74,24 @@ where
                 prover_service_config.aggregated_proof_block_jump,
             ),
             zk_storage,
+            proof_submitter: None,
         }
     }
 
+    /// Posts the generated proofs to `da_service`, encoded by `encoder`.
+    /// Without it, proofs are dropped once generated.
+    ///
+    /// Only full proofs are posted: the public inputs of
+    /// [`RollupProverConfig::Execute`] can't be verified on-chain.
+    pub fn with_proof_submission(
+        mut self,
+        da_service: Da,
+        encoder: impl ProofBlobEncoder + 'static,
+    ) -> Self {
+        self.proof_submitter = Some((da_service, Box::new(encoder)));
+        self
+    }
+
     /// Creates a new prover.
     pub fn new_with_default_workers(
         vm: Vm,
@@ -147,7 +163,22 @@ where
         &self,
         block_header_hash: <Da::Spec as DaSpec>::SlotHash,
     ) -> Result<ProofSubmissionStatus, anyhow::Error> {
-        self.prover_state
-            .get_proof_submission_status_and_remove_on_success(block_header_hash)
+        let Some(proof) = self.prover_state.get_proof(block_header_hash.clone())? else {
+            return Ok(ProofSubmissionStatus::ProofGenerationInProgress);
+        };
+
+        if let (Some((da_service, encoder)), Proof::Full(proof)) = (&self.proof_submitter, proof) {
+            let blob = encoder.encode_proof_blob(proof)?;
+            da_service.send_transaction(&blob).await.map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to submit the proof for {:?} to the DA: {}",
+                    block_header_hash,
+                    e
+                )
+            })?;
+        }
+
+        self.prover_state.remove_proof(&block_header_hash);
+        Ok(ProofSubmissionStatus::Success)
     }
 }
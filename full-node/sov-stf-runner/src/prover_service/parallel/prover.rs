@@ -11,9 +11,7 @@ use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::zk::{Proof, StateTransitionData, ZkvmHost};
 
 use super::ProverServiceError;
-use crate::{
-    ProofGenConfig, ProofProcessingStatus, ProofSubmissionStatus, WitnessSubmissionStatus,
-};
+use crate::{ProofGenConfig, ProofProcessingStatus, WitnessSubmissionStatus};
 
 enum ProverStatus<StateRoot, Witness, Da: DaSpec> {
     WitnessSubmitted(StateTransitionData<StateRoot, Witness, Da>),
@@ -182,21 +180,18 @@ where
         }
     }
 
-    pub(crate) fn get_proof_submission_status_and_remove_on_success(
+    /// Returns the proof generated for `block_header_hash`, or [`None`] if its generation
+    /// is still in progress. The proof is kept until [`Prover::remove_proof`] is called.
+    pub(crate) fn get_proof(
         &self,
         block_header_hash: <Da::Spec as DaSpec>::SlotHash,
-    ) -> Result<ProofSubmissionStatus, anyhow::Error> {
-        let mut prover_state = self.prover_state.write().unwrap();
+    ) -> Result<Option<Proof>, anyhow::Error> {
+        let prover_state = self.prover_state.read().expect("Lock was poisoned");
         let status = prover_state.get_prover_status(block_header_hash.clone());
 
         match status {
-            Some(ProverStatus::ProvingInProgress) => {
-                Ok(ProofSubmissionStatus::ProofGenerationInProgress)
-            }
-            Some(ProverStatus::Proved(_)) => {
-                prover_state.remove(&block_header_hash);
-                Ok(ProofSubmissionStatus::Success)
-            }
+            Some(ProverStatus::ProvingInProgress) => Ok(None),
+            Some(ProverStatus::Proved(proof)) => Ok(Some(proof.clone())),
             Some(ProverStatus::WitnessSubmitted(_)) => Err(anyhow::anyhow!(
                 "Witness for {:?} was submitted, but the proof generation is not triggered.",
                 block_header_hash
@@ -208,6 +203,11 @@ where
             )),
         }
     }
+
+    pub(crate) fn remove_proof(&self, block_header_hash: &<Da::Spec as DaSpec>::SlotHash) {
+        let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
+        prover_state.remove(block_header_hash);
+    }
 }
 
 fn make_proof<V, Vm, Da>(
@@ -1,8 +1,10 @@
 use std::collections::VecDeque;
 use std::net::SocketAddr;
 
+use futures::{FutureExt, StreamExt};
 use jsonrpsee::RpcModule;
 use sov_db::ledger_db::{LedgerDB, SlotCommit};
+use sov_db::schema::types::SlotNumber;
 use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::services::da::{DaService, SlotData};
 use sov_rollup_interface::stf::StateTransitionFunction;
@@ -168,8 +170,13 @@ where
     /// Runs the rollup.
     pub async fn run_in_process(&mut self) -> Result<(), anyhow::Error> {
         let mut seen_block_headers: VecDeque<<Da::Spec as DaSpec>::BlockHeader> = VecDeque::new();
-        let mut seen_receipts: VecDeque<_> = VecDeque::new();
+        // The pending ledger slots of the blocks in `seen_block_headers`.
+        let mut seen_slots: VecDeque<SlotNumber> = VecDeque::new();
         let mut height = self.start_height;
+
+        let max_reorg_depth = self.da_service.max_reorg_depth();
+        let mut head_headers = Box::pin(self.da_service.subscribe_head_header().await?);
+        let mut head_height = self.da_service.get_head_block_header().await?.height();
         loop {
             debug!("Requesting data for height {}", height);
             let mut filtered_block = self.da_service.get_block_at(height).await?;
@@ -178,8 +185,19 @@ where
             if let Some(prev_block_header) = seen_block_headers.back() {
                 if prev_block_header.hash() != filtered_block.header().prev_hash() {
                     tracing::warn!("Block at height={} does not belong in current chain. Chain has forked. Traversing backwards", height);
+                    let mut reverted_blocks = 0;
+                    let mut first_reverted_slot = None;
+                    let mut fork_point_found = false;
                     while let Some(seen_block_header) = seen_block_headers.pop_back() {
-                        seen_receipts.pop_back();
+                        first_reverted_slot = seen_slots.pop_back();
+                        reverted_blocks += 1;
+                        // The slots of deeper blocks were already reported as finalized.
+                        anyhow::ensure!(
+                            reverted_blocks <= max_reorg_depth,
+                            "Reorg at height={} reverts more than {} blocks, the max reorg depth of the DA layer",
+                            height,
+                            max_reorg_depth
+                        );
                         let block = self
                             .da_service
                             .get_block_at(seen_block_header.height())
@@ -187,9 +205,18 @@ where
                         if block.header().prev_hash() == seen_block_header.prev_hash() {
                             height = seen_block_header.height();
                             filtered_block = block;
+                            fork_point_found = true;
                             break;
                         }
                     }
+                    anyhow::ensure!(
+                        fork_point_found,
+                        "Reorg at height={} reverts blocks finalized by the DA layer",
+                        height
+                    );
+                    if let Some(first_reverted_slot) = first_reverted_slot {
+                        self.ledger_db.revert_slots_from(first_reverted_slot)?;
+                    }
                     tracing::info!("Resuming execution on height={}", height);
                 }
             }
@@ -282,7 +309,7 @@ where
             }
             let next_state_root = slot_result.state_root;
 
-            seen_receipts.push_back(data_to_commit);
+            seen_slots.push_back(self.ledger_db.commit_pending_slot(data_to_commit)?);
 
             self.state_root = next_state_root;
             seen_block_headers.push_back(filtered_block.header().clone());
@@ -292,11 +319,33 @@ where
             // Finalization. Done after seen block for proper handling of instant finality
             // Can be moved to another thread to improve throughput
             let last_finalized = self.da_service.get_last_finalized_block_header().await?;
+            while let Some(Some(head)) = head_headers.next().now_or_never() {
+                match head {
+                    Ok(head) => head_height = head.height(),
+                    Err(e) => tracing::warn!("Failed to receive the DA head: {:?}", e),
+                }
+            }
             // For safety we finalize blocks one by one
             tracing::info!(
-                "Last finalized header height is {}, ",
-                last_finalized.height()
+                "Last finalized header height is {}, head height is {}",
+                last_finalized.height(),
+                head_height
             );
+            // Blocks more than `max_reorg_depth` blocks below the head are reported as finalized,
+            // but only the blocks finalized by the DA layer are finalized in storage.
+            let reported_finalized_height = last_finalized
+                .height()
+                .max(head_height.saturating_sub(max_reorg_depth));
+            let last_reported_finalized_slot = seen_block_headers
+                .iter()
+                .zip(seen_slots.iter())
+                .take_while(|(header, _)| header.height() <= reported_finalized_height)
+                .last()
+                .map(|(_, slot)| *slot);
+            if let Some(last_reported_finalized_slot) = last_reported_finalized_slot {
+                self.ledger_db
+                    .finalize_slots(last_reported_finalized_slot)?;
+            }
             // Checking all seen blocks, in case if there was delay in getting last finalized header.
             while let Some(earliest_seen_header) = seen_block_headers.front() {
                 tracing::debug!(
@@ -310,13 +359,19 @@ where
                     );
                     self.storage_manager.finalize(earliest_seen_header)?;
                     seen_block_headers.pop_front();
-                    let receipts = seen_receipts.pop_front().unwrap();
-                    self.ledger_db.commit_slot(receipts)?;
+                    seen_slots.pop_front();
                     continue;
                 }
 
                 break;
             }
+            // Blocks above the reported finalized height are executed but can still be reverted,
+            // so their slots are reported as pending.
+            tracing::debug!(
+                "{} executed blocks are not finalized by the DA layer yet, reorgs up to {} blocks deep are handled",
+                seen_block_headers.len(),
+                max_reorg_depth
+            );
         }
     }
 
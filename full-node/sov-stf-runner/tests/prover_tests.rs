@@ -1,13 +1,16 @@
 use sov_mock_da::{
-    MockBlockHeader, MockDaService, MockDaSpec, MockDaVerifier, MockHash, MockValidityCond,
+    MockAddress, MockBlockHeader, MockDaService, MockDaSpec, MockDaVerifier, MockHash,
+    MockValidityCond,
 };
-use sov_mock_zkvm::MockZkvm;
-use sov_rollup_interface::da::Time;
-use sov_rollup_interface::zk::StateTransitionData;
+use sov_mock_zkvm::{MockCodeCommitment, MockZkvm};
+use sov_rollup_interface::da::{BlobReaderTrait, Time};
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::zk::{StateTransitionData, Zkvm};
 use sov_stf_runner::mock::MockStf;
 use sov_stf_runner::{
-    ParallelProverService, ProofProcessingStatus, ProofSubmissionStatus, ProverService,
-    ProverServiceConfig, ProverServiceError, RollupProverConfig, WitnessSubmissionStatus,
+    ParallelProverService, ProofBlobEncoder, ProofProcessingStatus, ProofSubmissionStatus,
+    ProverService, ProverServiceConfig, ProverServiceError, RollupProverConfig,
+    WitnessSubmissionStatus,
 };
 
 #[tokio::test]
@@ -38,6 +41,41 @@ async fn test_successful_prover_execution() -> Result<(), ProverServiceError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_proof_submission_to_da() -> Result<(), anyhow::Error> {
+    let da_service = MockDaService::new(MockAddress::new([1; 32]));
+    let code_commitment = MockCodeCommitment([7; 32]);
+    let vm =
+        MockZkvm::new(MockValidityCond::default()).with_code_commitment(code_commitment.clone());
+    let prover_service = ParallelProverService::new(
+        vm.clone(),
+        MockStf::<MockValidityCond>::default(),
+        MockDaVerifier::default(),
+        RollupProverConfig::Prove,
+        (),
+        1,
+        ProverServiceConfig {
+            aggregated_proof_block_jump: 1,
+        },
+    )
+    .with_proof_submission(da_service.clone(), RawProofEncoder);
+
+    let header_hash = MockHash::from([0; 32]);
+    prover_service
+        .submit_witness(make_transition_data(header_hash))
+        .await;
+    prover_service.prove(header_hash).await?;
+    vm.make_proof();
+    wait_for_proof_proof_da_submission(header_hash, &prover_service).await;
+
+    // The proof was posted to the DA, and verifies against the code commitment of the prover.
+    let mut block = da_service.get_block_at(1).await?;
+    let proof = block.blobs[0].full_data().to_vec();
+    assert!(MockZkvm::<MockValidityCond>::verify(&proof, &code_commitment).is_ok());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_prover_status_busy() -> Result<(), anyhow::Error> {
     let TestProver {
@@ -162,6 +200,14 @@ async fn test_generate_multiple_proofs_for_the_same_witness() -> Result<(), anyh
     Ok(())
 }
 
+struct RawProofEncoder;
+
+impl ProofBlobEncoder for RawProofEncoder {
+    fn encode_proof_blob(&self, proof: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        Ok(proof)
+    }
+}
+
 struct TestProver {
     prover_service: ParallelProverService<
         [u8; 0],
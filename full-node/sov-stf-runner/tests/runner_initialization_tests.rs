@@ -76,6 +76,7 @@ fn initialize_runner(
         },
         da: MockDaConfig {
             sender_address: address,
+            ..Default::default()
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
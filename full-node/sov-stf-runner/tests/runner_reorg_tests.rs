@@ -13,6 +13,7 @@ mod hash_stf;
 use hash_stf::{get_result_from_blocks, HashStf, Q, S};
 use sov_db::ledger_db::LedgerDB;
 use sov_prover_storage_manager::ProverStorageManager;
+use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode, SlotIdentifier, SlotStatus};
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_state::storage::NativeStorage;
@@ -67,10 +68,34 @@ async fn test_simple_reorg_case() {
         genesis_params,
     };
 
-    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service).await;
+    let (before, after, ledger_db) =
+        runner_execution(tmpdir.path(), init_variant, da_service).await;
     assert_ne!(before, after);
     assert_eq!(expected_state_root, after);
 
+    // Only the first block is finalized, the slots of the fork are pending.
+    let slots = ledger_db
+        .get_slots::<(), ()>(
+            &(1..=5).map(SlotIdentifier::Number).collect::<Vec<_>>(),
+            QueryMode::Compact,
+        )
+        .unwrap();
+    let statuses: Vec<_> = slots
+        .iter()
+        .map(|slot| slot.as_ref().unwrap().status)
+        .collect();
+    assert_eq!(
+        vec![
+            SlotStatus::Finalized,
+            SlotStatus::Pending,
+            SlotStatus::Pending,
+            SlotStatus::Pending,
+            SlotStatus::Pending,
+        ],
+        statuses
+    );
+    drop(ledger_db);
+
     let committed_root_hash = get_saved_root_hash(tmpdir.path()).unwrap().unwrap();
 
     assert_eq!(expected_committed_root_hash.unwrap(), committed_root_hash);
@@ -105,9 +130,11 @@ async fn test_instant_finality_data_stored() {
         genesis_params,
     };
 
-    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service).await;
+    let (before, after, ledger_db) =
+        runner_execution(tmpdir.path(), init_variant, da_service).await;
     assert_ne!(before, after);
     assert_eq!(expected_state_root, after);
+    drop(ledger_db);
 
     let saved_root_hash = get_saved_root_hash(tmpdir.path()).unwrap().unwrap();
 
@@ -118,7 +145,7 @@ async fn runner_execution(
     path: &std::path::Path,
     init_variant: MockInitVariant,
     da_service: MockDaService,
-) -> ([u8; 32], [u8; 32]) {
+) -> ([u8; 32], [u8; 32], LedgerDB) {
     let rollup_config = RollupConfig::<MockDaConfig> {
         storage: StorageConfig {
             path: path.to_path_buf(),
@@ -132,6 +159,7 @@ async fn runner_execution(
         },
         da: MockDaConfig {
             sender_address: da_service.get_sequencer_address(),
+            ..Default::default()
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
@@ -165,7 +193,7 @@ async fn runner_execution(
     let mut runner = StateTransitionRunner::new(
         rollup_config.runner,
         da_service,
-        ledger_db,
+        ledger_db.clone(),
         stf,
         storage_manager,
         init_variant,
@@ -178,7 +206,7 @@ async fn runner_execution(
     assert!(end.is_err());
     let after = *runner.get_state_root();
 
-    (before, after)
+    (before, after, ledger_db)
 }
 
 fn get_saved_root_hash(
@@ -32,9 +32,13 @@ impl<C: Context> TxHooks for TestRuntime<C> {
         _working_set: &mut sov_modules_api::WorkingSet<C>,
         arg: &RuntimeTxHook<C>,
     ) -> anyhow::Result<C> {
-        let RuntimeTxHook { height, sequencer } = arg;
-        let sender = tx.pub_key().to_address();
-        let sequencer = sequencer.to_address();
+        let RuntimeTxHook {
+            height, sequencer, ..
+        } = arg;
+        let sender = tx.default_sender_address();
+        let sequencer = sequencer
+            .as_ref()
+            .map_or_else(|| sender.clone(), |sequencer| sequencer.to_address());
 
         Ok(C::new(sender, sequencer, *height))
     }
@@ -1,4 +1,5 @@
 use anyhow::{bail, Result};
+use sov_modules_api::ethereum::{self, EthereumAddress};
 use sov_modules_api::{Context, PublicKey, StateMapAccessor, WorkingSet};
 
 use crate::{Account, Accounts};
@@ -47,6 +48,24 @@ impl<C: sov_modules_api::Context> Accounts<C> {
         Ok(new_account)
     }
 
+    pub(crate) fn create_default_ethereum_account(
+        &self,
+        address: &EthereumAddress,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<Account<C>> {
+        let default_address = ethereum::module_address::<C>(address);
+        self.exit_if_address_exists(&default_address, working_set)?;
+
+        let new_account = Account {
+            addr: default_address,
+            nonce: 0,
+        };
+
+        self.ethereum_accounts
+            .set(address, &new_account, working_set);
+        Ok(new_account)
+    }
+
     fn exit_if_address_exists(
         &self,
         address: &C::Address,
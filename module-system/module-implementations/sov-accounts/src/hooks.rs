@@ -1,6 +1,6 @@
 use sov_modules_api::hooks::TxHooks;
-use sov_modules_api::transaction::Transaction;
-use sov_modules_api::{Context, StateMapAccessor, WorkingSet};
+use sov_modules_api::transaction::{Authenticator, Transaction};
+use sov_modules_api::{ethereum, Context, PublicKey, StateMapAccessor, WorkingSet};
 
 use crate::{Account, Accounts};
 
@@ -8,8 +8,8 @@ use crate::{Account, Accounts};
 pub struct AccountsTxHook<C: Context> {
     /// The tx sender address
     pub sender: C::Address,
-    /// The sequencer address
-    pub sequencer: C::Address,
+    /// The sequencer address, if the sequencer public key is known
+    pub sequencer: Option<C::Address>,
 }
 
 impl<C: Context> Accounts<C> {
@@ -23,21 +23,62 @@ impl<C: Context> Accounts<C> {
             .map(Ok)
             .unwrap_or_else(|| self.create_default_account(pubkey, working_set))
     }
+
+    fn get_or_create_default_sender(
+        &self,
+        tx: &Transaction<C>,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<Account<C>> {
+        match tx.authenticator() {
+            Authenticator::Standard { pub_key, .. } => {
+                self.get_or_create_default(pub_key, working_set)
+            }
+            Authenticator::Ethereum { address, .. } => self
+                .ethereum_accounts
+                .get(address, working_set)
+                .map(Ok)
+                .unwrap_or_else(|| self.create_default_ethereum_account(address, working_set)),
+        }
+    }
+
+    /// Returns the address of the account sending `tx`, or the address its account will get
+    /// when it's created.
+    pub fn get_sender_address(
+        &self,
+        tx: &Transaction<C>,
+        working_set: &mut WorkingSet<C>,
+    ) -> C::Address {
+        match tx.authenticator() {
+            Authenticator::Standard { pub_key, .. } => self
+                .accounts
+                .get(pub_key, working_set)
+                .map(|account| account.addr)
+                .unwrap_or_else(|| pub_key.to_address()),
+            Authenticator::Ethereum { address, .. } => self
+                .ethereum_accounts
+                .get(address, working_set)
+                .map(|account| account.addr)
+                .unwrap_or_else(|| ethereum::module_address::<C>(address)),
+        }
+    }
 }
 
 impl<C: Context> TxHooks for Accounts<C> {
     type Context = C;
-    type PreArg = C::PublicKey;
+    type PreArg = Option<C::PublicKey>;
     type PreResult = AccountsTxHook<C>;
 
     fn pre_dispatch_tx_hook(
         &self,
         tx: &Transaction<C>,
         working_set: &mut WorkingSet<C>,
-        sequencer: &C::PublicKey,
+        sequencer: &Option<C::PublicKey>,
     ) -> anyhow::Result<AccountsTxHook<C>> {
-        let sender = self.get_or_create_default(tx.pub_key(), working_set)?;
-        let sequencer = self.get_or_create_default(sequencer, working_set)?;
+        let sender = self.get_or_create_default_sender(tx, working_set)?;
+        let sequencer = sequencer
+            .as_ref()
+            .map(|sequencer| self.get_or_create_default(sequencer, working_set))
+            .transpose()?;
         let tx_nonce = tx.nonce();
 
         anyhow::ensure!(
@@ -49,7 +90,7 @@ impl<C: Context> TxHooks for Accounts<C> {
 
         Ok(AccountsTxHook {
             sender: sender.addr,
-            sequencer: sequencer.addr,
+            sequencer: sequencer.map(|sequencer| sequencer.addr),
         })
     }
 
@@ -59,9 +100,18 @@ impl<C: Context> TxHooks for Accounts<C> {
         _ctx: &C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
-        let mut account = self.accounts.get_or_err(tx.pub_key(), working_set)?;
-        account.nonce += 1;
-        self.accounts.set(tx.pub_key(), &account, working_set);
+        match tx.authenticator() {
+            Authenticator::Standard { pub_key, .. } => {
+                let mut account = self.accounts.get_or_err(pub_key, working_set)?;
+                account.nonce += 1;
+                self.accounts.set(pub_key, &account, working_set);
+            }
+            Authenticator::Ethereum { address, .. } => {
+                let mut account = self.ethereum_accounts.get_or_err(address, working_set)?;
+                account.nonce += 1;
+                self.ethereum_accounts.set(address, &account, working_set);
+            }
+        }
         Ok(())
     }
 }
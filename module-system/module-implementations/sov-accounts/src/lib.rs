@@ -15,6 +15,7 @@ mod tests;
 
 pub use call::{CallMessage, UPDATE_ACCOUNT_MSG};
 pub use hooks::AccountsTxHook;
+use sov_modules_api::ethereum::EthereumAddress;
 use sov_modules_api::{Context, Error, ModuleInfo, WorkingSet};
 
 impl<C: Context> FromIterator<C::PublicKey> for AccountConfig<C> {
@@ -50,6 +51,10 @@ pub struct Accounts<C: Context> {
     /// Mapping from a public key to a corresponding account.
     #[state]
     pub(crate) accounts: sov_modules_api::StateMap<C::PublicKey, Account<C>>,
+
+    /// Mapping from an Ethereum address to a corresponding account.
+    #[state]
+    pub(crate) ethereum_accounts: sov_modules_api::StateMap<EthereumAddress, Account<C>>,
 }
 
 impl<C: Context> sov_modules_api::Module for Accounts<C> {
@@ -1,5 +1,8 @@
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::ethereum;
+use sov_modules_api::hooks::TxHooks;
+use sov_modules_api::transaction::Transaction;
 use sov_modules_api::{
     AddressBech32, Context, Module, PrivateKey, PublicKey, Spec, StateMapAccessor, WorkingSet,
 };
@@ -205,3 +208,44 @@ fn test_response_deserialization_on_wrong_hrp() {
         }
     }
 }
+
+#[test]
+fn test_ethereum_account_hooks() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<C>::default();
+
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&[7; 32]).unwrap();
+    let ethereum_address = ethereum::address_of(signing_key.verifying_key());
+    let sender_addr = ethereum::module_address::<C>(&ethereum_address);
+
+    for nonce in 0..2 {
+        let tx =
+            Transaction::<C>::new_signed_ethereum_tx(&signing_key, vec![1, 2, 3], 0, 0, 0, nonce);
+        tx.verify().unwrap();
+
+        let hook = accounts
+            .pre_dispatch_tx_hook(&tx, working_set, &None)
+            .unwrap();
+        assert_eq!(hook.sender, sender_addr);
+        assert_eq!(hook.sequencer, None);
+
+        let ctx = C::new(hook.sender, sender_addr, 1);
+        accounts
+            .post_dispatch_tx_hook(&tx, &ctx, working_set)
+            .unwrap();
+    }
+
+    let account = accounts
+        .ethereum_accounts
+        .get(&ethereum_address, working_set)
+        .unwrap();
+    assert_eq!(account.addr, sender_addr);
+    assert_eq!(account.nonce, 2);
+
+    // Replaying a transaction fails the nonce check.
+    let tx = Transaction::<C>::new_signed_ethereum_tx(&signing_key, vec![1, 2, 3], 0, 0, 0, 1);
+    assert!(accounts
+        .pre_dispatch_tx_hook(&tx, working_set, &None)
+        .is_err());
+}
@@ -77,6 +77,11 @@ impl<
 }
 
 /// This enumeration represents the available call messages for interacting with the `AttesterIncentives` module.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
 #[derive(BorshDeserialize, BorshSerialize)]
 pub enum CallMessage<C: sov_modules_api::Context, Da: DaSpec> {
     /// Bonds an attester, the parameter is the bond amount
@@ -123,6 +128,13 @@ impl<C: sov_modules_api::Context, Da: DaSpec> Debug for CallMessage<C, Da> {
     }
 }
 
+// Manually implement PartialEq to remove spurious PartialEq bounds on C::Storage
+impl<C: sov_modules_api::Context, Da: DaSpec> PartialEq for CallMessage<C, Da> {
+    fn eq(&self, other: &Self) -> bool {
+        self.try_to_vec().ok() == other.try_to_vec().ok()
+    }
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
 /// Error type that explains why a user is slashed
 pub enum SlashingReason {
@@ -195,7 +207,7 @@ pub enum AttesterIncentiveErrors {
     MintFailure,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 /// A role in the attestation process
 pub enum Role {
     /// A user who attests to new state transitions
@@ -239,7 +251,7 @@ where
     }
 
     /// A helper function that simply slashes an attester and returns a reward value
-    fn slash_user(&self, user: &C::Address, role: Role, working_set: &mut WorkingSet<C>) -> u64 {
+    fn slash_user(&self, user: &C::Address, role: Role, working_set: &mut WorkingSet<C>) -> Amount {
         let bonded_set = match role {
             Role::Attester => {
                 // We have to remove the attester from the unbonding set
@@ -297,7 +309,7 @@ where
     fn reward_sender(
         &self,
         context: &C,
-        amount: u64,
+        amount: Amount,
         working_set: &mut WorkingSet<C>,
     ) -> Result<CallResponse, AttesterIncentiveErrors> {
         let reward_address = self
@@ -325,7 +337,7 @@ where
     /// during genesis when no context is available.
     pub(super) fn bond_user_helper(
         &self,
-        bond_amount: u64,
+        bond_amount: Amount,
         user_address: &C::Address,
         role: Role,
         working_set: &mut WorkingSet<C>,
@@ -522,7 +534,7 @@ where
             .map_err(|_err| AttesterIncentiveErrors::InvalidBondingProof)?;
 
         let bond = bond_opt.ok_or(AttesterIncentiveErrors::UserNotBonded)?;
-        let bond: u64 = BorshDeserialize::deserialize(&mut bond.value())
+        let bond: Amount = BorshDeserialize::deserialize(&mut bond.value())
             .map_err(|_err| AttesterIncentiveErrors::InvalidBondFormat)?;
 
         let minimum_bond = self
@@ -828,7 +840,7 @@ where
             .expect("Should be set at genesis");
 
         // Find the faulty attestation pool and get the associated reward
-        let attestation_reward: u64 = self
+        let attestation_reward: Amount = self
             .bad_transition_pool
             .get_or_err(transition_num, working_set)
             .map_err(|_| {
@@ -2,6 +2,8 @@ use core::marker::PhantomData;
 
 use anyhow::Result;
 use borsh::{BorshDeserialize, BorshSerialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use sov_bank::Amount;
 use sov_chain_state::TransitionHeight;
 use sov_modules_api::prelude::*;
@@ -11,6 +13,11 @@ use sov_state::Storage;
 use crate::{AttesterIncentives, Role};
 
 /// Configuration of the attester incentives module
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "Checker: Serialize",
+    deserialize = "Checker: DeserializeOwned"
+))]
 pub struct AttesterIncentivesConfig<C, Vm, Da, Checker>
 where
     C: Context,
@@ -39,6 +46,7 @@ where
     /// The validity condition checker used to check validity conditions
     pub validity_condition_checker: Checker,
     /// Phantom data that contains the validity condition
+    #[serde(skip)]
     pub(crate) phantom_data: PhantomData<Da::ValidityCondition>,
 }
 
@@ -1,6 +1,11 @@
 //! Defines the query methods for the attester incentives module
+use jsonrpsee::core::RpcResult;
 use serde::{Deserialize, Serialize};
-use sov_modules_api::{Spec, StateMapAccessor, ValidityConditionChecker, WorkingSet};
+use sov_bank::Amount;
+use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{
+    Context, DaSpec, Spec, StateMapAccessor, ValidityConditionChecker, WorkingSet, Zkvm,
+};
 use sov_state::storage::{NativeStorage, Storage, StorageKey, StorageProof};
 
 use super::AttesterIncentives;
@@ -10,10 +15,9 @@ use crate::call::Role;
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct BondAmountResponse {
     /// The value of the bond
-    pub value: u64,
+    pub value: Amount,
 }
 
-// TODO: implement rpc_gen macro
 impl<C, Vm, Da, Checker> AttesterIncentives<C, Vm, Da, Checker>
 where
     C: sov_modules_api::Context,
@@ -71,7 +75,28 @@ where
         _address: C::Address,
         _witness: &<<C as Spec>::Storage as Storage>::Witness,
         _working_set: &mut WorkingSet<C>,
-    ) -> u64 {
+    ) -> Amount {
         todo!("Make the unbonding amount queryable: https://github.com/Sovereign-Labs/sovereign-sdk/issues/675")
     }
 }
+
+#[rpc_gen(client, server, namespace = "attesterIncentives")]
+impl<
+        C: Context,
+        Vm: Zkvm + 'static,
+        Da: DaSpec,
+        Checker: ValidityConditionChecker<Da::ValidityCondition> + Send + Sync + 'static,
+    > AttesterIncentives<C, Vm, Da, Checker>
+{
+    /// Returns the bond amount of the user with the given address and role.
+    /// If the user is not bonded, the bond is zero.
+    #[rpc_method(name = "getBondAmount")]
+    pub fn bond_amount(
+        &self,
+        address: C::Address,
+        role: Role,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<BondAmountResponse> {
+        Ok(self.get_bond_amount(address, role, working_set))
+    }
+}
@@ -1,5 +1,5 @@
 use jmt::proof::SparseMerkleProof;
-use sov_bank::{BankConfig, TokenConfig};
+use sov_bank::{Amount, BankConfig, TokenConfig};
 use sov_mock_da::{
     MockBlock, MockBlockHeader, MockDaSpec, MockValidityCond, MockValidityCondChecker,
 };
@@ -20,8 +20,8 @@ use crate::AttesterIncentives;
 type C = DefaultContext;
 
 pub const TOKEN_NAME: &str = "TEST_TOKEN";
-pub const BOND_AMOUNT: u64 = 1000;
-pub const INITIAL_BOND_AMOUNT: u64 = 5 * BOND_AMOUNT;
+pub const BOND_AMOUNT: Amount = 1000;
+pub const INITIAL_BOND_AMOUNT: Amount = 5 * BOND_AMOUNT;
 pub const SALT: u64 = 5;
 pub const DEFAULT_ROLLUP_FINALITY: u64 = 3;
 pub const INIT_HEIGHT: u64 = 0;
@@ -45,9 +45,9 @@ pub(crate) fn create_bank_config_with_token(
     token_name: String,
     salt: u64,
     addresses_count: usize,
-    initial_balance: u64,
+    initial_balance: Amount,
 ) -> (BankConfig<C>, Vec<Address>) {
-    let address_and_balances: Vec<(Address, u64)> = (0..addresses_count)
+    let address_and_balances: Vec<(Address, Amount)> = (0..addresses_count)
         .map(|i| {
             let key = format!("key_{}", i);
             let addr = generate_address::<C>(&key);
@@ -57,6 +57,9 @@ pub(crate) fn create_bank_config_with_token(
 
     let token_config = TokenConfig {
         token_name,
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         address_and_balances: address_and_balances.clone(),
         authorized_minters: vec![address_and_balances.first().unwrap().0],
         salt,
@@ -3,6 +3,7 @@ use anyhow::{bail, Context, Result};
 use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::{CallResponse, StateMapAccessor, WorkingSet};
 
+use crate::token::TokenMetadata;
 use crate::{Amount, Bank, Coins, Token};
 
 /// This enumeration represents the available call messages for interacting with the sov-bank module.
@@ -25,10 +26,16 @@ pub enum CallMessage<C: sov_modules_api::Context> {
         salt: u64,
         /// The name of the new token.
         token_name: String,
+        /// The ticker symbol of the new token.
+        symbol: String,
+        /// The number of decimals used to display amounts of the new token.
+        decimals: u8,
         /// The initial balance of the new token.
         initial_balance: Amount,
         /// The address of the account that the new tokens are minted to.
         minter_address: C::Address,
+        /// The maximum total supply of the new token. No limit if not set.
+        max_supply: Option<Amount>,
         /// Authorized minter list.
         authorized_minters: Vec<C::Address>,
     },
@@ -62,6 +69,21 @@ pub enum CallMessage<C: sov_modules_api::Context> {
     },
 }
 
+/// Metadata and supply information of a token.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TokenInfo {
+    /// The name of the token.
+    pub name: String,
+    /// The ticker symbol of the token.
+    pub symbol: String,
+    /// The number of decimals used to display amounts of the token.
+    pub decimals: u8,
+    /// The current total supply of the token.
+    pub total_supply: Amount,
+    /// The maximum total supply of the token, if capped.
+    pub max_supply: Option<Amount>,
+}
+
 impl<C: sov_modules_api::Context> Bank<C> {
     /// Creates a token from a set of configuration parameters.
     /// Checks if a token already exists at that address. If so return an error.
@@ -69,6 +91,9 @@ impl<C: sov_modules_api::Context> Bank<C> {
     pub fn create_token(
         &self,
         token_name: String,
+        symbol: String,
+        decimals: u8,
+        max_supply: Option<Amount>,
         salt: u64,
         initial_balance: Amount,
         minter_address: C::Address,
@@ -76,8 +101,14 @@ impl<C: sov_modules_api::Context> Bank<C> {
         context: &C,
         working_set: &mut WorkingSet<C>,
     ) -> Result<C::Address> {
+        let metadata = TokenMetadata {
+            symbol,
+            decimals,
+            max_supply,
+        };
         let (token_address, token) = Token::<C>::create(
             &token_name,
+            &metadata,
             &[(minter_address, initial_balance)],
             &authorized_minters,
             context.sender().as_ref(),
@@ -253,7 +284,7 @@ impl<C: sov_modules_api::Context> Bank<C> {
         user_address: C::Address,
         token_address: C::Address,
         working_set: &mut WorkingSet<C>,
-    ) -> Option<u64> {
+    ) -> Option<Amount> {
         self.tokens
             .get(&token_address, working_set)
             .and_then(|token| token.balances.get(&user_address, working_set))
@@ -274,11 +305,29 @@ impl<C: sov_modules_api::Context> Bank<C> {
         &self,
         token_address: &C::Address,
         working_set: &mut WorkingSet<C>,
-    ) -> Option<u64> {
+    ) -> Option<Amount> {
         self.tokens
             .get(token_address, working_set)
             .map(|token| token.total_supply)
     }
+
+    /// Returns the metadata and supply information of the token stored at `token_address`,
+    /// or `None` if there is no such token.
+    pub fn get_token_info(
+        &self,
+        token_address: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<TokenInfo> {
+        self.tokens
+            .get(token_address, working_set)
+            .map(|token| TokenInfo {
+                name: token.name,
+                symbol: token.symbol,
+                decimals: token.decimals,
+                total_supply: token.total_supply,
+                max_supply: token.max_supply,
+            })
+    }
 }
 
 /// Creates a new prefix from an already existing prefix `parent_prefix` and a `token_address`
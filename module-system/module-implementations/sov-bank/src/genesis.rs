@@ -3,8 +3,8 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::{StateMapAccessor, WorkingSet};
 
-use crate::token::Token;
-use crate::Bank;
+use crate::token::{Token, TokenMetadata};
+use crate::{Amount, Bank};
 
 /// Initial configuration for sov-bank module.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -21,8 +21,15 @@ pub struct BankConfig<C: sov_modules_api::Context> {
 pub struct TokenConfig<C: sov_modules_api::Context> {
     /// The name of the token.
     pub token_name: String,
-    /// A vector of tuples containing the initial addresses and balances (as u64)
-    pub address_and_balances: Vec<(C::Address, u64)>,
+    /// The ticker symbol of the token.
+    pub symbol: String,
+    /// The number of decimals used to display amounts of the token.
+    pub decimals: u8,
+    /// The maximum total supply of the token. No limit if omitted.
+    #[serde(default)]
+    pub max_supply: Option<Amount>,
+    /// A vector of tuples containing the initial addresses and balances (as [`Amount`])
+    pub address_and_balances: Vec<(C::Address, Amount)>,
     /// The addresses that are authorized to mint the token.
     pub authorized_minters: Vec<C::Address>,
     /// A salt used to encrypt the token address.
@@ -43,8 +50,14 @@ impl<C: sov_modules_api::Context> Bank<C> {
     ) -> Result<()> {
         let parent_prefix = self.tokens.prefix();
         for token_config in config.tokens.iter() {
+            let metadata = TokenMetadata {
+                symbol: token_config.symbol.clone(),
+                decimals: token_config.decimals,
+                max_supply: token_config.max_supply,
+            };
             let (token_address, token) = Token::<C>::create(
                 &token_config.token_name,
+                &metadata,
                 &token_config.address_and_balances,
                 &token_config.authorized_minters,
                 &DEPLOYER,
@@ -83,6 +96,9 @@ mod tests {
         let config = BankConfig::<DefaultContext> {
             tokens: vec![TokenConfig {
                 token_name: "sov-demo-token".to_owned(),
+                symbol: "DEMO".to_owned(),
+                decimals: 18,
+                max_supply: None,
                 address_and_balances: vec![(address, 100000000)],
                 authorized_minters: vec![address],
                 salt: 0,
@@ -94,6 +110,8 @@ mod tests {
             "tokens":[
                 {
                     "token_name":"sov-demo-token",
+                    "symbol":"DEMO",
+                    "decimals":18,
                     "address_and_balances":[["sov1l6n2cku82yfqld30lanm2nfw43n2auc8clw7r5u5m6s7p8jrm4zqrr8r94",100000000]],
                     "authorized_minters":["sov1l6n2cku82yfqld30lanm2nfw43n2auc8clw7r5u5m6s7p8jrm4zqrr8r94"]
                     ,"salt":0
@@ -1,32 +1,29 @@
-use core::str::FromStr;
-
 use sov_modules_api::hooks::TxHooks;
-use sov_modules_api::macros::config_constant;
 use sov_modules_api::transaction::Transaction;
-use sov_modules_api::{Context, GasUnit, WorkingSet};
-
-use crate::{Bank, Coins};
+use sov_modules_api::{BasicAddress, Context, GasUnit, WorkingSet};
 
-#[config_constant]
-// This constant is a fixed value, expected to be generated as
-//
-// ```rust
-// let token_name = "sov-gas-token";
-// let deployer = DEPLOYER;
-// let salt = 0;
-// let computed = super::get_token_address::<DefaultContext>(token_name, &deployer, salt);
-// ```
-//
-// TODO: fetch address as constant
-// https://github.com/Sovereign-Labs/sovereign-sdk/issues/1234
-const GAS_TOKEN_ADDRESS: &'static str;
+use crate::{get_gas_token_address, Amount, Bank, Coins};
 
 /// The computed addresses of a pre-dispatch tx hook.
 pub struct BankTxHook<C: Context> {
     /// The tx sender address
     pub sender: C::Address,
-    /// The sequencer address
+    /// The address that pays for the base cost of the transaction if the sender can't
     pub sequencer: C::Address,
+    /// The address that holds the gas fees of the transaction on behalf of the sequencer, and
+    /// refunds the unused gas to the sender
+    pub gas_escrow: C::Address,
+}
+
+/// The error returned by the pre-dispatch tx hook when neither the sender nor the sequencer can
+/// pay for the base cost of a transaction.
+#[derive(Debug, thiserror::Error)]
+#[error("The sequencer {sequencer} can't pay for the transaction base cost: {reason}")]
+pub struct UnpaidBaseCostError<A: BasicAddress> {
+    /// The address of the sequencer that couldn't pay
+    pub sequencer: A,
+    /// Why the sequencer couldn't pay
+    pub reason: String,
 }
 
 impl<C: Context> TxHooks for Bank<C> {
@@ -40,14 +37,17 @@ impl<C: Context> TxHooks for Bank<C> {
         working_set: &mut WorkingSet<C>,
         hook: &BankTxHook<C>,
     ) -> anyhow::Result<()> {
-        let BankTxHook { sender, sequencer } = hook;
+        let BankTxHook {
+            sender,
+            sequencer,
+            gas_escrow,
+        } = hook;
 
         // Charge the base tx gas cost
         let gas_fixed_cost = tx.gas_fixed_cost();
         if working_set.charge_gas(&gas_fixed_cost).is_err() {
-            let amount = gas_fixed_cost.value(working_set.gas_price());
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
+            let amount = Amount::from(gas_fixed_cost.value(working_set.gas_price()));
+            let token_address = get_gas_token_address::<C>()?;
             let coins = Coins {
                 amount,
                 token_address,
@@ -55,23 +55,24 @@ impl<C: Context> TxHooks for Bank<C> {
 
             // If the sender's account balance is insufficient to cover the base global cost, the
             // transaction execution should be halted and the deficiency should be deducted from
-            // the sequencer's account. It is expected that a sequencer would have adequate funds,
-            // as the staked amount ought to be sufficient for executing any transaction count they
-            // choose. However, if a sequencer lacks sufficient staked funds, it indicates a
-            // critical design flaw in the sequencer registry.
-            self.burn(coins, sequencer, working_set).expect("Unrecoverable error: the sequencer doesn't have enough funds to pay for the transaction base cost.");
+            // the sequencer's account. If the sequencer doesn't have enough funds either, an
+            // `UnpaidBaseCostError` is returned so that the runtime can slash it.
+            self.burn(coins, sequencer, working_set)
+                .map_err(|e| UnpaidBaseCostError {
+                    sequencer: sequencer.clone(),
+                    reason: e.to_string(),
+                })?;
 
             anyhow::bail!(
                 "Transaction sender doesn't have enough funds to pay for the transaction base cost"
             );
         }
 
-        let amount = tx.gas_limit().saturating_add(tx.gas_tip());
+        let amount = Amount::from(tx.gas_limit()).saturating_add(Amount::from(tx.gas_tip()));
         if amount > 0 {
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
+            let token_address = get_gas_token_address::<C>()?;
             let from = sender;
-            let to = sequencer;
+            let to = gas_escrow;
             let coins = Coins {
                 amount,
                 token_address,
@@ -88,18 +89,28 @@ impl<C: Context> TxHooks for Bank<C> {
         ctx: &C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
-        let amount = working_set.gas_remaining_funds();
+        self.refund_remaining_gas(ctx.sequencer(), ctx.sender(), working_set)
+    }
+}
+
+impl<C: Context> Bank<C> {
+    /// Refunds the gas left unused by a transaction from the `gas_escrow` that holds its gas
+    /// fees to its `sender`.
+    pub fn refund_remaining_gas(
+        &self,
+        gas_escrow: &C::Address,
+        sender: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        let amount = Amount::from(working_set.gas_remaining_funds());
 
         if amount > 0 {
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
-            let from = ctx.sequencer();
-            let to = ctx.sender();
+            let token_address = get_gas_token_address::<C>()?;
             let coins = Coins {
                 amount,
                 token_address,
             };
-            self.transfer_from(from, to, coins, working_set)?;
+            self.transfer_from(gas_escrow, sender, coins, working_set)?;
         }
 
         Ok(())
@@ -12,13 +12,13 @@ mod token;
 pub mod utils;
 pub use call::*;
 pub use genesis::*;
-pub use hooks::BankTxHook;
+pub use hooks::{BankTxHook, UnpaidBaseCostError};
 use sov_modules_api::{CallResponse, Error, GasUnit, ModuleInfo, WorkingSet};
 use token::Token;
 /// Specifies an interface to interact with tokens.
 pub use token::{Amount, Coins};
 /// Methods to get a token address.
-pub use utils::{get_genesis_token_address, get_token_address};
+pub use utils::{get_gas_token_address, get_genesis_token_address, get_token_address};
 
 /// Gas configuration for the bank module
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -82,6 +82,9 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
             call::CallMessage::CreateToken {
                 salt,
                 token_name,
+                symbol,
+                decimals,
+                max_supply,
                 initial_balance,
                 minter_address,
                 authorized_minters,
@@ -89,6 +92,9 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
                 self.charge_gas(working_set, &self.gas.create_token)?;
                 self.create_token(
                     token_name,
+                    symbol,
+                    decimals,
+                    max_supply,
                     salt,
                     initial_balance,
                     minter_address,
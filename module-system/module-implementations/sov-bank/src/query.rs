@@ -3,22 +3,29 @@ use jsonrpsee::core::RpcResult;
 use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::WorkingSet;
 
-use crate::{Amount, Bank};
+use crate::{Amount, Bank, TokenInfo};
 
 /// Structure returned by the `balance_of` rpc method.
 #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
 pub struct BalanceResponse {
-    /// The balance amount of a given user for a given token. Equivalent to u64.
+    /// The balance amount of a given user for a given token. Equivalent to u128.
     pub amount: Option<Amount>,
 }
 
 /// Structure returned by the `supply_of` rpc method.
 #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
 pub struct TotalSupplyResponse {
-    /// The amount of token supply for a given token address. Equivalent to u64.
+    /// The amount of token supply for a given token address. Equivalent to u128.
     pub amount: Option<Amount>,
 }
 
+/// Structure returned by the `token_info` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct TokenInfoResponse {
+    /// The metadata and supply of a given token, if it exists.
+    pub info: Option<TokenInfo>,
+}
+
 #[rpc_gen(client, server, namespace = "bank")]
 impl<C: sov_modules_api::Context> Bank<C> {
     #[rpc_method(name = "balanceOf")]
@@ -54,4 +61,21 @@ impl<C: sov_modules_api::Context> Bank<C> {
             amount: self.get_total_supply_of(&token_address, working_set),
         })
     }
+
+    #[rpc_method(name = "tokenInfo")]
+    /// Rpc method that returns the name, symbol, decimals and supply of the token stored at the
+    /// address `token_address`.
+    pub fn token_info(
+        &self,
+        version: Option<u64>,
+        token_address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<TokenInfoResponse> {
+        if let Some(v) = version {
+            working_set.set_archival_version(v)
+        }
+        Ok(TokenInfoResponse {
+            info: self.get_token_info(&token_address, working_set),
+        })
+    }
 }
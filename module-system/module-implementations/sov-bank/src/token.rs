@@ -15,7 +15,10 @@ use thiserror::Error;
 use crate::call::prefix_from_address_with_parent;
 
 /// Type alias to store an amount of token.
-pub type Amount = u64;
+///
+/// Amounts are denominated in the smallest unit of a token, so an asset with 18 decimals
+/// needs the full `u128` range to represent realistic balances.
+pub type Amount = u128;
 
 /// Structure that stores information specifying
 /// a given `amount` (type [`Amount`]) of coins stored at a `token_address`
@@ -47,7 +50,7 @@ pub struct Coins<C: sov_modules_api::Context> {
 #[cfg(feature = "native")]
 #[derive(Debug, Error)]
 pub enum CoinsFromStrError {
-    /// The amount could not be parsed as a u64.
+    /// The amount could not be parsed as a u128.
     #[error("Could not parse {input} as a valid amount: {err}")]
     InvalidAmount { input: String, err: ParseIntError },
     /// The input string was malformed, so the `amount` substring could not be extracted.
@@ -104,13 +107,31 @@ impl<C: sov_modules_api::Context> std::fmt::Display for Coins<C> {
     }
 }
 
+/// Descriptive information about a token, supplied when the token is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TokenMetadata {
+    /// Ticker symbol of the token.
+    pub(crate) symbol: String,
+    /// Number of decimals used to display the token amounts.
+    pub(crate) decimals: u8,
+    /// Optional cap on the total supply.
+    pub(crate) max_supply: Option<Amount>,
+}
+
 /// This struct represents a token in the sov-bank module.
 #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq, Clone)]
 pub(crate) struct Token<C: sov_modules_api::Context> {
     /// Name of the token.
     pub(crate) name: String,
+    /// Ticker symbol of the token, e.g. `ETH`.
+    pub(crate) symbol: String,
+    /// Number of decimals used to display the token amounts.
+    pub(crate) decimals: u8,
     /// Total supply of the coins.
-    pub(crate) total_supply: u64,
+    pub(crate) total_supply: Amount,
+    /// Upper bound on the total supply, enforced on every mint.
+    /// `None` means that the supply is not capped.
+    pub(crate) max_supply: Option<Amount>,
     /// Mapping from user address to user balance.
     pub(crate) balances: sov_modules_api::StateMap<C::Address, Amount>,
 
@@ -140,7 +161,7 @@ impl<C: sov_modules_api::Context> Token<C> {
             .check_balance(from, amount, working_set)
             .with_context(|| format!("Incorrect balance on={} for token={}", from, self.name))?;
 
-        // We can't overflow here because the sum must be smaller or eq to `total_supply` which is u64.
+        // We can't overflow here because the sum must be smaller or eq to `total_supply` which is an `Amount`.
         let to_balance = self.balances.get(to, working_set).unwrap_or_default() + amount;
 
         self.balances.set(from, &from_balance, working_set);
@@ -176,7 +197,8 @@ impl<C: sov_modules_api::Context> Token<C> {
     /// Mints a given `amount` of token sent by `sender` to the specified `mint_to_address`.
     /// Checks that the `authorized_minters` set is not empty for the token and that the `sender`
     /// is an `authorized_minter`. If so, update the balances of token for the `mint_to_address` by
-    /// adding the minted tokens. Updates the `total_supply` of that token.
+    /// adding the minted tokens. Updates the `total_supply` of that token, which can't exceed
+    /// the `max_supply` if one is set.
     pub(crate) fn mint(
         &mut self,
         authorizer: &C::Address,
@@ -197,14 +219,25 @@ impl<C: sov_modules_api::Context> Token<C> {
             .ok_or(anyhow::Error::msg(
                 "Account balance overflow in the mint method of bank module",
             ))?;
-
-        self.balances.set(mint_to_address, &to_balance, working_set);
-        self.total_supply = self
+        let total_supply = self
             .total_supply
             .checked_add(amount)
             .ok_or(anyhow::Error::msg(
                 "Total Supply overflow in the mint method of bank module",
             ))?;
+        if let Some(max_supply) = self.max_supply {
+            if total_supply > max_supply {
+                bail!(
+                    "Minting {} of token {} would exceed its max supply {}",
+                    amount,
+                    self.name,
+                    max_supply
+                )
+            }
+        }
+
+        self.balances.set(mint_to_address, &to_balance, working_set);
+        self.total_supply = total_supply;
         Ok(())
     }
 
@@ -239,10 +272,13 @@ impl<C: sov_modules_api::Context> Token<C> {
     /// The `token_name`, `sender` address (as a `u8` slice), and the `salt` (`u64` number) are used as an input
     /// to an hash function that computes the token address. Then the initial accounts and balances are populated
     /// from the `address_and_balances` slice and the `total_supply` of tokens is updated each time.
+    /// The token metadata (`symbol` and `decimals`) doesn't affect the token address.
     /// Returns a tuple containing the computed `token_address` and the created `token` object.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn create(
         token_name: &str,
-        address_and_balances: &[(C::Address, u64)],
+        metadata: &TokenMetadata,
+        address_and_balances: &[(C::Address, Amount)],
         authorized_minters: &[C::Address],
         sender: &[u8],
         salt: u64,
@@ -253,7 +289,7 @@ impl<C: sov_modules_api::Context> Token<C> {
         let token_prefix = prefix_from_address_with_parent::<C>(parent_prefix, &token_address);
         let balances = sov_modules_api::StateMap::new(token_prefix);
 
-        let mut total_supply: Option<u64> = Some(0);
+        let mut total_supply: Option<Amount> = Some(0);
         for (address, balance) in address_and_balances.iter() {
             balances.set(address, balance, working_set);
             total_supply = total_supply.and_then(|ts| ts.checked_add(*balance));
@@ -264,6 +300,17 @@ impl<C: sov_modules_api::Context> Token<C> {
             None => bail!("Total supply overflow"),
         };
 
+        if let Some(max_supply) = metadata.max_supply {
+            if total_supply > max_supply {
+                bail!(
+                    "Initial supply {} of token {} exceeds its max supply {}",
+                    total_supply,
+                    token_name,
+                    max_supply
+                )
+            }
+        }
+
         let mut indices = HashSet::new();
         let mut auth_minter_list = Vec::new();
 
@@ -275,7 +322,10 @@ impl<C: sov_modules_api::Context> Token<C> {
 
         let token = Token::<C> {
             name: token_name.to_owned(),
+            symbol: metadata.symbol.clone(),
+            decimals: metadata.decimals,
             total_supply,
+            max_supply: metadata.max_supply,
             balances,
             authorized_minters: auth_minter_list,
         };
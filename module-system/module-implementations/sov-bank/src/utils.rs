@@ -1,7 +1,33 @@
+use core::str::FromStr;
+
 use sov_modules_api::digest::Digest;
+use sov_modules_api::macros::config_constant;
 
 use crate::genesis::DEPLOYER;
 
+#[config_constant]
+// This constant is a fixed value, expected to be generated as
+//
+// ```rust
+// let token_name = "sov-gas-token";
+// let deployer = DEPLOYER;
+// let salt = 0;
+// let computed = super::get_token_address::<DefaultContext>(token_name, &deployer, salt);
+// ```
+//
+// The token metadata (symbol, decimals, max supply) is not part of the address derivation, so
+// the genesis config of the gas token can be extended without changing this constant.
+//
+// TODO: fetch address as constant
+// https://github.com/Sovereign-Labs/sovereign-sdk/issues/1234
+const GAS_TOKEN_ADDRESS: &'static str;
+
+/// Returns the address of the token used to pay for gas, as configured in `constants.json`.
+pub fn get_gas_token_address<C: sov_modules_api::Context>() -> anyhow::Result<C::Address> {
+    C::Address::from_str(GAS_TOKEN_ADDRESS)
+        .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))
+}
+
 /// Derives token address from `token_name`, `sender` and `salt`.
 pub fn get_token_address<C: sov_modules_api::Context>(
     token_name: &str,
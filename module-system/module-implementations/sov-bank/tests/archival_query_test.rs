@@ -237,7 +237,7 @@ fn query_sender_receiver_balances(
     sender_address: Address,
     receiver_address: Address,
     working_set: &mut WorkingSet<DefaultContext>,
-) -> (u64, u64) {
+) -> (Amount, Amount) {
     let sender_balance = bank
         .get_balance_of(sender_address, token_address, working_set)
         .unwrap();
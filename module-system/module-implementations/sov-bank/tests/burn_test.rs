@@ -1,6 +1,6 @@
 use helpers::{generate_address, C};
 use sov_bank::{
-    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins,
+    get_genesis_token_address, get_token_address, Amount, Bank, BankConfig, CallMessage, Coins,
     TotalSupplyResponse,
 };
 use sov_modules_api::default_context::DefaultContext;
@@ -38,6 +38,9 @@ fn burn_deployed_tokens() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name,
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
@@ -47,14 +50,14 @@ fn burn_deployed_tokens() {
     // No events at the moment. If there are, needs to be checked
     assert!(working_set.events().is_empty());
 
-    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
         let total_supply: TotalSupplyResponse =
             bank.supply_of(None, token_address, working_set).unwrap();
         total_supply.amount
     };
 
     let query_user_balance =
-        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             bank.get_balance_of(user_address, token_address, working_set)
         };
 
@@ -200,7 +203,7 @@ fn burn_initial_tokens() {
     let sequencer_address = bank_config.tokens[0].address_and_balances[1].0;
 
     let query_user_balance =
-        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             bank.get_balance_of(user_address, token_address, working_set)
         };
 
@@ -1,5 +1,5 @@
 use helpers::*;
-use sov_bank::{get_token_address, Bank, CallMessage};
+use sov_bank::{get_token_address, Amount, Bank, CallMessage, TokenInfo};
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -25,6 +25,9 @@ fn initial_and_deployed_token() {
     let create_token_message = CallMessage::CreateToken::<C> {
         salt,
         token_name: token_name.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
@@ -52,6 +55,77 @@ fn initial_and_deployed_token() {
     assert_eq!(initial_balance, total_supply);
 }
 
+#[test]
+fn token_info_is_stored() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    bank.genesis(&create_bank_config_with_token(0, 0), &mut working_set)
+        .unwrap();
+
+    let sender_address = generate_address::<C>("sender");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let sender_context = C::new(sender_address, sequencer_address, 1);
+    let token_name = "Wrapped Ether".to_owned();
+    let salt = 2;
+    let token_address = get_token_address::<C>(&token_name, sender_address.as_ref(), salt);
+    // 1000 ETH with 18 decimals doesn't fit in a u64.
+    let initial_balance: Amount = 1_000 * 10u128.pow(18);
+    let max_supply = Some(initial_balance * 2);
+
+    let create_token_message = CallMessage::CreateToken::<C> {
+        salt,
+        token_name: token_name.clone(),
+        symbol: "WETH".to_owned(),
+        decimals: 18,
+        max_supply,
+        initial_balance,
+        minter_address: sender_address,
+        authorized_minters: vec![sender_address],
+    };
+    bank.call(create_token_message, &sender_context, &mut working_set)
+        .expect("Failed to create token");
+
+    let token_info = bank
+        .token_info(None, token_address, &mut working_set)
+        .unwrap()
+        .info;
+    assert_eq!(
+        Some(TokenInfo {
+            name: token_name,
+            symbol: "WETH".to_owned(),
+            decimals: 18,
+            total_supply: initial_balance,
+            max_supply,
+        }),
+        token_info
+    );
+
+    let unknown_token = generate_address::<C>("unknown");
+    let token_info = bank
+        .token_info(None, unknown_token, &mut working_set)
+        .unwrap()
+        .info;
+    assert!(token_info.is_none());
+}
+
+#[test]
+fn initial_balance_above_max_supply() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let mut bank_config = create_bank_config_with_token(2, 100);
+    bank_config.tokens[0].max_supply = Some(150);
+
+    let genesis_result = bank.genesis(&bank_config, &mut working_set);
+    assert!(genesis_result.is_err());
+    assert_eq!(
+        "Initial supply 200 of token InitialToken exceeds its max supply 150",
+        genesis_result.unwrap_err().to_string()
+    );
+}
+
 #[test]
 /// Currently integer overflow happens on bank genesis
 fn overflow_max_supply() {
@@ -59,7 +133,7 @@ fn overflow_max_supply() {
     let tmpdir = tempfile::tempdir().unwrap();
     let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
 
-    let bank_config = create_bank_config_with_token(2, u64::MAX - 2);
+    let bank_config = create_bank_config_with_token(2, Amount::MAX - 2);
 
     let genesis_result = bank.genesis(&bank_config, &mut working_set);
     assert!(genesis_result.is_err());
@@ -1,5 +1,7 @@
 use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use sov_bank::{
+    get_token_address, Amount, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -32,6 +34,9 @@ fn freeze_token() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name: token_name.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
@@ -81,6 +86,9 @@ fn freeze_token() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name: token_name_2.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
@@ -133,7 +141,7 @@ fn freeze_token() {
     };
 
     let query_total_supply =
-        |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             let total_supply: TotalSupplyResponse =
                 bank.supply_of(None, token_address, working_set).unwrap();
             total_supply.amount
@@ -178,11 +186,12 @@ fn freeze_token() {
     let total_supply = query_total_supply(token_address_2, &mut working_set);
     assert_eq!(Some(initial_balance + mint_amount), total_supply);
 
-    let query_user_balance =
-        |token_address: Address,
-         user_address: Address,
-         working_set: &mut WorkingSet<DefaultContext>|
-         -> Option<u64> { bank.get_balance_of(user_address, token_address, working_set) };
+    let query_user_balance = |token_address: Address,
+                              user_address: Address,
+                              working_set: &mut WorkingSet<DefaultContext>|
+     -> Option<Amount> {
+        bank.get_balance_of(user_address, token_address, working_set)
+    };
     let bal = query_user_balance(token_address_2, minter_address, &mut working_set);
 
     assert_eq!(Some(110), bal);
@@ -1,6 +1,6 @@
 use helpers::*;
 use sov_bank::{
-    get_genesis_token_address, Bank, BankConfig, BankGasConfig, CallMessage, TokenConfig,
+    get_genesis_token_address, Amount, Bank, BankConfig, BankGasConfig, CallMessage, TokenConfig,
 };
 use sov_modules_api::macros::config_constant;
 use sov_modules_api::utils::generate_address;
@@ -129,11 +129,14 @@ impl BankGasTestCase {
 
         // generate a token configuration with the provided arguments
         let sender_address = generate_address::<C>("sender");
-        let address_and_balances = vec![(sender_address, sender_balance)];
+        let address_and_balances = vec![(sender_address, Amount::from(sender_balance))];
         let authorized_minters = vec![];
         let bank_config: BankConfig<C> = BankConfig {
             tokens: vec![TokenConfig {
                 token_name: base_token_name.to_string(),
+                symbol: "SOV".to_owned(),
+                decimals: 18,
+                max_supply: None,
                 address_and_balances,
                 authorized_minters,
                 salt,
@@ -154,7 +157,7 @@ impl BankGasTestCase {
 
         // sanity test the sender balance
         let balance = bank.get_balance_of(sender_address, base_token_address, &mut ws);
-        assert_eq!(balance, Some(sender_balance));
+        assert_eq!(balance, Some(Amount::from(sender_balance)));
 
         // generate a create dummy token message
         let token_name = "dummy".to_string();
@@ -162,6 +165,9 @@ impl BankGasTestCase {
         let message = CallMessage::CreateToken::<C> {
             salt,
             token_name,
+            symbol: "TKN".to_owned(),
+            decimals: 18,
+            max_supply: None,
             initial_balance,
             minter_address,
             authorized_minters: vec![minter_address],
@@ -1,4 +1,4 @@
-use sov_bank::{BankConfig, TokenConfig};
+use sov_bank::{Amount, BankConfig, TokenConfig};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address as gen_address_generic;
 use sov_modules_api::Address;
@@ -15,7 +15,7 @@ pub fn generate_address(name: &str) -> Address {
 #[allow(dead_code)]
 pub fn create_bank_config_with_token(
     addresses_count: usize,
-    initial_balance: u64,
+    initial_balance: Amount,
 ) -> BankConfig<C> {
     let address_and_balances = (0..addresses_count)
         .map(|i| {
@@ -27,6 +27,9 @@ pub fn create_bank_config_with_token(
 
     let token_config = TokenConfig {
         token_name: "InitialToken".to_owned(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         address_and_balances,
         authorized_minters: vec![],
         salt: 5,
@@ -1,5 +1,7 @@
 use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use sov_bank::{
+    get_token_address, Amount, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -32,6 +34,9 @@ fn mint_token() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name: token_name.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
@@ -43,14 +48,14 @@ fn mint_token() {
     assert!(working_set.events().is_empty());
 
     let query_total_supply =
-        |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             let total_supply: TotalSupplyResponse =
                 bank.supply_of(None, token_address, working_set).unwrap();
             total_supply.amount
         };
 
     let query_user_balance =
-        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             bank.get_balance_of(user_address, token_address, working_set)
         };
 
@@ -128,6 +133,9 @@ fn mint_token() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name: token_name.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address,
         authorized_minters: vec![authorized_minter_address_1, authorized_minter_address_2],
@@ -208,7 +216,7 @@ fn mint_token() {
     // Overflow test - account balance
     let overflow_mint_message = CallMessage::Mint {
         coins: Coins {
-            amount: u64::MAX,
+            amount: Amount::MAX,
             token_address,
         },
         minter_address: new_holder,
@@ -229,7 +237,7 @@ fn mint_token() {
         format!(
             "Failed mint coins(token_address={} amount={}) to {} by authorizer {}",
             token_address,
-            u64::MAX,
+            Amount::MAX,
             new_holder,
             authorized_minter_address_1,
         ),
@@ -247,7 +255,7 @@ fn mint_token() {
     let new_holder = generate_address::<C>("new_holder_3");
     let overflow_mint_message = CallMessage::Mint {
         coins: Coins {
-            amount: u64::MAX - 1,
+            amount: Amount::MAX - 1,
             token_address,
         },
         minter_address: new_holder,
@@ -268,7 +276,7 @@ fn mint_token() {
         format!(
             "Failed mint coins(token_address={} amount={}) to {} by authorizer {}",
             token_address,
-            u64::MAX - 1,
+            Amount::MAX - 1,
             new_holder,
             authorized_minter_address_1,
         ),
@@ -283,3 +291,77 @@ fn mint_token() {
     let supply = query_total_supply(token_address, &mut working_set);
     assert_eq!(Some(120), supply);
 }
+
+#[test]
+fn mint_above_max_supply() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let minter_address = generate_address::<C>("minter");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let minter_context = C::new(minter_address, sequencer_address, 1);
+
+    let salt = 0;
+    let token_name = "CappedToken".to_owned();
+    let initial_balance = 100;
+    let max_supply = 150;
+    let token_address = get_token_address::<C>(&token_name, minter_address.as_ref(), salt);
+
+    let create_token_message = CallMessage::CreateToken {
+        salt,
+        token_name,
+        symbol: "CAP".to_owned(),
+        decimals: 6,
+        max_supply: Some(max_supply),
+        initial_balance,
+        minter_address,
+        authorized_minters: vec![minter_address],
+    };
+    bank.call(create_token_message, &minter_context, &mut working_set)
+        .expect("Failed to create token");
+
+    let mint = |amount: Amount, working_set: &mut WorkingSet<DefaultContext>| {
+        let mint_message = CallMessage::Mint {
+            coins: Coins {
+                amount,
+                token_address,
+            },
+            minter_address,
+        };
+        bank.call(mint_message, &minter_context, working_set)
+    };
+
+    // Minting up to the cap is allowed.
+    mint(max_supply - initial_balance, &mut working_set).expect("Failed to mint token");
+    assert_eq!(
+        Some(max_supply),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+
+    // Any further mint exceeds the cap.
+    let result = mint(1, &mut working_set);
+    assert!(result.is_err());
+    let Error::ModuleError(err) = result.err().unwrap();
+    let mut chain = err.chain();
+    let message_1 = chain.next().unwrap().to_string();
+    let message_2 = chain.next().unwrap().to_string();
+    assert!(chain.next().is_none());
+    assert_eq!(
+        format!(
+            "Failed mint coins(token_address={} amount=1) to {} by authorizer {}",
+            token_address, minter_address, minter_address
+        ),
+        message_1
+    );
+    assert_eq!(
+        "Minting 1 of token CappedToken would exceed its max supply 150",
+        message_2
+    );
+    assert_eq!(
+        Some(max_supply),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+}
@@ -2,7 +2,7 @@ mod helpers;
 
 use helpers::*;
 use sov_bank::{
-    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins,
+    get_genesis_token_address, get_token_address, Amount, Bank, BankConfig, CallMessage, Coins,
     TotalSupplyResponse,
 };
 use sov_modules_api::default_context::DefaultContext;
@@ -35,11 +35,11 @@ fn transfer_initial_token() {
 
     // Preparation
     let query_user_balance =
-        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             bank.get_balance_of(user_address, token_address, working_set)
         };
 
-    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
         let total_supply: TotalSupplyResponse =
             bank.supply_of(None, token_address, working_set).unwrap();
         total_supply.amount
@@ -278,11 +278,11 @@ fn transfer_deployed_token() {
 
     // Preparation
     let query_user_balance =
-        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+        |user_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
             bank.get_balance_of(user_address, token_address, working_set)
         };
 
-    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
+    let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<Amount> {
         let total_supply: TotalSupplyResponse =
             bank.supply_of(None, token_address, working_set).unwrap();
         total_supply.amount
@@ -300,6 +300,9 @@ fn transfer_deployed_token() {
     let mint_message = CallMessage::CreateToken {
         salt,
         token_name,
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance,
         minter_address: sender_address,
         authorized_minters: vec![sender_address],
@@ -1,13 +1,27 @@
+use std::cmp::Reverse;
+
+use borsh::BorshDeserialize;
+use sov_bank::{get_gas_token_address, Amount};
 use sov_chain_state::TransitionHeight;
+use sov_modules_api::da::direct::{
+    decode_direct_blob, is_direct_blob, MAX_DIRECT_BLOBS_PER_SLOT, MAX_DIRECT_BLOB_SIZE,
+    MIN_DIRECT_BLOB_GAS_LIMIT,
+};
+use sov_modules_api::macros::config_constant;
 use sov_modules_api::prelude::*;
 use sov_modules_api::runtime::capabilities::{BlobRefOrOwned, BlobSelector};
-use sov_modules_api::{BlobReaderTrait, Context, DaSpec, KernelWorkingSet, WorkingSet};
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{BlobReaderTrait, Context, DaSpec, GasUnit, KernelWorkingSet, WorkingSet};
 use tracing::info;
 
 use crate::{BlobStorage, DEFERRED_SLOTS_COUNT};
 
+/// The gas price the transactions are executed at. It must match the price used by the STF.
+#[config_constant]
+const GAS_ELASTIC_PRICE: &[u64];
+
 impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
-    fn filter_by_allowed_sender(
+    pub(crate) fn filter_by_allowed_sender(
         &self,
         b: &Da::BlobTransaction,
         working_set: &mut WorkingSet<C>,
@@ -29,15 +43,134 @@ impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
             is_allowed
         }
     }
+
+    // Direct blobs are authorized by the signature of their transaction rather than by their
+    // sender, so they were checked by `select_direct_blobs` instead.
+    fn filter_blob(&self, b: &Da::BlobTransaction, working_set: &mut WorkingSet<C>) -> bool {
+        is_direct_blob(b.verified_data()) || self.filter_by_allowed_sender(b, working_set)
+    }
+
+    // Discards the direct blobs rejected by `check_direct_blob`, and keeps the
+    // `MAX_DIRECT_BLOBS_PER_SLOT` remaining ones paying the highest tips. Direct blobs paying the
+    // same tip are kept in the order they appeared on the DA layer. Other blobs are left as they
+    // are. Small direct blobs were read in full while reassembling the chunked blobs.
+    fn select_direct_blobs<'a>(
+        &self,
+        blobs: Vec<BlobRefOrOwned<'a, Da::BlobTransaction>>,
+        working_set: &mut WorkingSet<C>,
+    ) -> Vec<BlobRefOrOwned<'a, Da::BlobTransaction>> {
+        let discard = |b: &Da::BlobTransaction, reason: &dyn std::fmt::Display| {
+            info!(
+                "Direct blob hash=0x{} from sender {} is going to be discarded: {}",
+                hex::encode(b.hash()),
+                b.sender(),
+                reason
+            );
+        };
+
+        let mut is_kept = vec![true; blobs.len()];
+        let mut tips = Vec::new();
+        for (index, blob) in blobs.iter().enumerate() {
+            let b = blob.as_ref();
+            if !is_direct_blob(b.verified_data()) {
+                continue;
+            }
+            match self.check_direct_blob(b, working_set) {
+                Ok(tip) => tips.push((index, tip)),
+                Err(e) => {
+                    discard(b, &e);
+                    is_kept[index] = false;
+                }
+            }
+        }
+
+        // The sort is stable, so direct blobs paying the same tip keep their DA order.
+        tips.sort_by_key(|(_, tip)| Reverse(*tip));
+        for (index, _) in tips.into_iter().skip(MAX_DIRECT_BLOBS_PER_SLOT) {
+            discard(
+                blobs[index].as_ref(),
+                &format!(
+                    "the slot already has {} direct blobs paying higher tips",
+                    MAX_DIRECT_BLOBS_PER_SLOT
+                ),
+            );
+            is_kept[index] = false;
+        }
+
+        blobs
+            .into_iter()
+            .zip(is_kept)
+            .filter_map(|(blob, is_kept)| is_kept.then_some(blob))
+            .collect()
+    }
+
+    // A direct blob is kept if it's small, its transaction is correctly signed, its gas limit is
+    // at least `MIN_DIRECT_BLOB_GAS_LIMIT` and covers the fixed cost of the transaction at the
+    // current gas price, and the sender of the transaction can pay for its gas. Returns the tip
+    // paid by the transaction.
+    fn check_direct_blob(
+        &self,
+        b: &Da::BlobTransaction,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<u64> {
+        anyhow::ensure!(
+            b.total_len() <= MAX_DIRECT_BLOB_SIZE,
+            "it's larger than {} bytes",
+            MAX_DIRECT_BLOB_SIZE
+        );
+
+        let mut tx_data = decode_direct_blob(b.verified_data())
+            .expect("The blob was checked to be a direct blob");
+        let tx = Transaction::<C>::deserialize(&mut tx_data)?;
+        tx.verify()?;
+
+        anyhow::ensure!(
+            tx.gas_limit() >= MIN_DIRECT_BLOB_GAS_LIMIT,
+            "its gas limit {} is lower than {}",
+            tx.gas_limit(),
+            MIN_DIRECT_BLOB_GAS_LIMIT
+        );
+        let gas_price = C::GasUnit::from_arbitrary_dimensions(GAS_ELASTIC_PRICE);
+        let fixed_cost = tx.gas_fixed_cost().value(&gas_price);
+        anyhow::ensure!(
+            tx.gas_limit() >= fixed_cost,
+            "its gas limit {} doesn't cover the fixed cost {} of its transaction",
+            tx.gas_limit(),
+            fixed_cost
+        );
+
+        let sender = self.accounts.get_sender_address(&tx, working_set);
+        let gas_token_address = get_gas_token_address::<C>()?;
+        let balance = self
+            .bank
+            .get_balance_of(sender.clone(), gas_token_address, working_set)
+            .unwrap_or_default();
+        let gas = Amount::from(tx.gas_limit()).saturating_add(Amount::from(tx.gas_tip()));
+        anyhow::ensure!(
+            balance >= gas,
+            "its sender {} can't pay {} for its gas",
+            sender,
+            gas
+        );
+
+        Ok(tx.gas_tip())
+    }
 }
 
 impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
     type Context = C;
 
     // This implementation returns three categories of blobs:
-    // 1. Any blobs sent by the preferred sequencer ("prority blobs")
+    // 1. Any blobs sent by the priority sequencer ("prority blobs"). This is the leader of the slot if the
+    //    sequencer registry rotates leaders, or the preferred sequencer otherwise.
     // 2. Any non-priority blobs which were sent `DEFERRED_SLOTS_COUNT` slots ago ("expiring deferred blobs")
     // 3. Some additional deferred blobs needed to fill the total requested by the sequencer, if applicable. ("bonus blobs")
+    // Direct blobs posted by users are deferred like any non-priority blob, so they are executed
+    // within `DEFERRED_SLOTS_COUNT` slots even if the priority sequencer ignores them. At most
+    // `MAX_DIRECT_BLOBS_PER_SLOT` of them are accepted per slot, preferring the ones paying the
+    // highest tips, and only if their transaction is signed by an account which can pay for its gas.
+    // Blobs split into chunks are buffered until all their chunks appeared, and then handled as if
+    // they were sent in full in the slot of their last chunk.
     fn get_blobs_for_this_slot<'a, 'k, I>(
         &self,
         current_blobs: I,
@@ -46,24 +179,26 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
     {
+        let current_blobs = self.reassemble_chunked_blobs(current_blobs, working_set);
+        let current_blobs = self.select_direct_blobs(current_blobs, working_set.inner);
+
         // If `DEFERRED_SLOTS_COUNT` is 0, we don't never to do any deferred blob processing and this
         // function just sorts and filters the current blobs before returning
         if DEFERRED_SLOTS_COUNT == 0 {
             let mut blobs = current_blobs
                 .into_iter()
-                .filter(|b| self.filter_by_allowed_sender(b, working_set.inner))
-                .map(Into::into)
+                .filter(|b| self.filter_blob(b.as_ref(), working_set.inner))
                 .collect::<Vec<_>>();
-            if let Some(sequencer) = self.get_preferred_sequencer(working_set.inner) {
+            if let Some(sequencer) = self.get_priority_sequencer(working_set) {
                 blobs.sort_by_key(|b: &BlobRefOrOwned<Da::BlobTransaction>| {
                     b.as_ref().sender() != sequencer
                 });
             }
-            return Ok(blobs.into_iter().map(Into::into).collect());
+            return Ok(blobs);
         }
 
         // Calculate any expiring deferred blobs first, since these have to be processed no matter what (Case 2 above).
-        // Note that we have to handle this case even if there is no preferred sequencer, since that sequencer might have
+        // Note that we have to handle this case even if there is no priority sequencer, since that sequencer might have
         // exited while there were deferred blobs waiting to be processed
         let current_slot: TransitionHeight = self.get_true_slot_height(working_set);
         let slot_for_expiring_blobs =
@@ -71,21 +206,22 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
         let expiring_deferred_blobs: Vec<Da::BlobTransaction> =
             self.take_blobs_for_slot_height(slot_for_expiring_blobs, working_set.inner);
 
-        // If there is no preferred sequencer, that's all we need to do
-        let preferred_sequencer =
-            if let Some(sequencer) = self.get_preferred_sequencer(working_set.inner) {
-                sequencer
-            } else {
-                // TODO: https://github.com/Sovereign-Labs/sovereign-sdk/issues/654
-                // Prevent double number of blobs being executed
-                return Ok(expiring_deferred_blobs
-                    .into_iter()
-                    .map(Into::into)
-                    .chain(current_blobs.into_iter().map(Into::into))
-                    .collect());
-            };
+        // If there is no priority sequencer, that's all we need to do
+        let priority_sequencer = if let Some(sequencer) = self.get_priority_sequencer(working_set) {
+            sequencer
+        } else {
+            // TODO: https://github.com/Sovereign-Labs/sovereign-sdk/issues/654
+            // Prevent double number of blobs being executed
+            // Batches from senders which aren't allowed are ignored when they are applied, and
+            // direct blobs were already selected.
+            return Ok(expiring_deferred_blobs
+                .into_iter()
+                .map(Into::into)
+                .chain(current_blobs)
+                .collect());
+        };
 
-        // If we reach this point, there is a preferred sequencer, so we need to handle cases 1 and 3.
+        // If we reach this point, there is a priority sequencer, so we need to handle cases 1 and 3.
 
         // First, compute any "bonus blobs" requested
         // to be processed early.
@@ -129,16 +265,16 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
 
         // Finally handle any new blobs which appeared on the DA layer in this slot
         let mut priority_blobs = Vec::new();
-        let mut to_defer: Vec<&mut Da::BlobTransaction> = Vec::new();
+        let mut to_defer: Vec<BlobRefOrOwned<Da::BlobTransaction>> = Vec::new();
         for blob in current_blobs {
-            // Blobs from the preferred sequencer get priority
-            if blob.sender() == preferred_sequencer {
+            // Blobs from the priority sequencer get priority
+            if blob.as_ref().sender() == priority_sequencer {
                 priority_blobs.push(blob);
             } else {
                 // Other blobs get deferred unless the sequencer has requested otherwise
                 if remaining_blobs_requested > 0 {
                     remaining_blobs_requested -= 1;
-                    bonus_blobs.push(blob.into())
+                    bonus_blobs.push(blob)
                 } else {
                     to_defer.push(blob);
                 }
@@ -151,15 +287,14 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
             // Gas metering suppose to prevent saving blobs from not allowed senders if they exit mid-slot
             let to_defer: Vec<&Da::BlobTransaction> = to_defer
                 .iter()
-                .filter(|b| self.filter_by_allowed_sender(b, working_set.inner))
-                .map(|b| &**b)
+                .map(|b| b.as_ref())
+                .filter(|b| self.filter_blob(b, working_set.inner))
                 .collect();
             self.store_blobs(current_slot, &to_defer, working_set.inner)?
         }
 
         Ok(priority_blobs
             .into_iter()
-            .map(Into::into)
             .chain(expiring_deferred_blobs.into_iter().map(Into::into))
             .chain(bonus_blobs)
             .collect())
@@ -0,0 +1,192 @@
+//! Reassembly of the blobs split into [`BlobChunk`]s across several DA transactions.
+
+use std::collections::BTreeMap;
+
+use sov_chain_state::TransitionHeight;
+use sov_modules_api::da::chunks::{blob_hash, BlobChunk, CHUNK_MAGIC};
+use sov_modules_api::da::direct::{is_direct_blob, MAX_DIRECT_BLOB_SIZE};
+use sov_modules_api::prelude::*;
+use sov_modules_api::runtime::capabilities::BlobRefOrOwned;
+use sov_modules_api::{BlobReaderTrait, Context, DaSpec, KernelWorkingSet, WorkingSet};
+use tracing::info;
+
+use crate::{BlobStorage, DEFERRED_SLOTS_COUNT};
+
+/// The sender of a chunked blob and its sequence id.
+pub(crate) type ChunkedBlobKey = (Vec<u8>, u64);
+
+/// The chunks of a blob received so far.
+#[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq, Clone)]
+pub(crate) struct ChunkedBlob {
+    /// The number of chunks of the blob.
+    count: u16,
+    /// The hash of the whole blob.
+    blob_hash: [u8; 32],
+    /// The data of the received chunks, by index.
+    chunks: BTreeMap<u16, Vec<u8>>,
+}
+
+impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
+    /// Adds `chunk`, sent by `sender` in slot `slot_height`, to the chunked blob it belongs to.
+    /// Returns the data of the blob once all its chunks were received and its hash matches.
+    ///
+    /// Chunks that disagree with the first chunk of their blob on the count or the hash, and
+    /// chunks that were already received, are dropped. An incomplete blob is dropped by
+    /// [`Self::expire_chunked_blobs`].
+    pub fn add_chunk(
+        &self,
+        slot_height: TransitionHeight,
+        sender: &Da::Address,
+        chunk: BlobChunk,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Vec<u8>> {
+        let key: ChunkedBlobKey = (sender.as_ref().to_vec(), chunk.sequence_id);
+        let mut chunked_blob = match self.chunked_blobs.get(&key, working_set) {
+            Some(chunked_blob) => chunked_blob,
+            None => {
+                let mut started = self
+                    .chunked_blobs_by_slot
+                    .get(&slot_height, working_set)
+                    .unwrap_or_default();
+                started.push(key.clone());
+                self.chunked_blobs_by_slot
+                    .set(&slot_height, &started, working_set);
+
+                ChunkedBlob {
+                    count: chunk.count,
+                    blob_hash: chunk.blob_hash,
+                    chunks: BTreeMap::new(),
+                }
+            }
+        };
+
+        if chunked_blob.count != chunk.count || chunked_blob.blob_hash != chunk.blob_hash {
+            info!(
+                "Chunk {} of sequence {} from sender {} conflicts with the previous chunks and is going to be discarded",
+                chunk.index, chunk.sequence_id, sender
+            );
+            return None;
+        }
+        if chunked_blob.chunks.contains_key(&chunk.index) {
+            info!(
+                "Chunk {} of sequence {} from sender {} was already received and is going to be discarded",
+                chunk.index, chunk.sequence_id, sender
+            );
+            return None;
+        }
+        chunked_blob.chunks.insert(chunk.index, chunk.data);
+
+        if chunked_blob.chunks.len() < chunked_blob.count as usize {
+            self.chunked_blobs.set(&key, &chunked_blob, working_set);
+            return None;
+        }
+
+        self.chunked_blobs.remove(&key, working_set);
+        let data: Vec<u8> = chunked_blob.chunks.into_values().flatten().collect();
+        if blob_hash(&data) != chunked_blob.blob_hash {
+            info!(
+                "Blob reassembled from sequence {} of sender {} doesn't match its hash and is going to be discarded",
+                chunk.sequence_id, sender
+            );
+            return None;
+        }
+        Some(data)
+    }
+
+    /// Drops the chunked blobs whose first chunk appeared in slot `slot_height` and that are
+    /// still incomplete.
+    pub fn expire_chunked_blobs(
+        &self,
+        slot_height: TransitionHeight,
+        working_set: &mut WorkingSet<C>,
+    ) {
+        let started = self
+            .chunked_blobs_by_slot
+            .remove(&slot_height, working_set)
+            .unwrap_or_default();
+        for key in started {
+            if self.chunked_blobs.remove(&key, working_set).is_some() {
+                info!(
+                    "Incomplete blob of sequence {} from sender 0x{} expired and is going to be discarded",
+                    key.1,
+                    hex::encode(&key.0)
+                );
+            }
+        }
+    }
+
+    /// Buffers the chunks among `current_blobs` and returns the other blobs, along with the
+    /// blobs completed by these chunks in place of their last chunk. Chunked blobs which are
+    /// still incomplete `DEFERRED_SLOTS_COUNT` slots after their first chunk are dropped, so
+    /// missing chunks can't hold blobs forever.
+    pub(crate) fn reassemble_chunked_blobs<'a, I>(
+        &self,
+        current_blobs: I,
+        working_set: &mut KernelWorkingSet<'_, C>,
+    ) -> Vec<BlobRefOrOwned<'a, Da::BlobTransaction>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        let current_slot = self.get_true_slot_height(working_set);
+        if let Some(expired_slot) = current_slot.checked_sub(DEFERRED_SLOTS_COUNT + 1) {
+            self.expire_chunked_blobs(expired_slot, working_set.inner);
+        }
+
+        let mut blobs = Vec::new();
+        for blob in current_blobs {
+            let data = read_data(blob, CHUNK_MAGIC.len());
+            if !BlobChunk::is_chunk(data) {
+                // Small direct blobs are read in full, so that their transaction can be checked.
+                if is_direct_blob(data) && blob.total_len() <= MAX_DIRECT_BLOB_SIZE {
+                    let total_len = blob.total_len();
+                    read_data(blob, total_len);
+                }
+                blobs.push(blob.into());
+                continue;
+            }
+
+            let sender = blob.sender();
+            if !self.filter_by_allowed_sender(blob, working_set.inner) {
+                continue;
+            }
+            let total_len = blob.total_len();
+            let Some(chunk) = BlobChunk::decode(read_data(blob, total_len)) else {
+                info!(
+                    "Malformed chunk hash=0x{} from sender {} is going to be discarded",
+                    hex::encode(blob.hash()),
+                    sender
+                );
+                continue;
+            };
+
+            let blob_hash = chunk.blob_hash;
+            if let Some(data) = self.add_chunk(current_slot, &sender, chunk, working_set.inner) {
+                blobs.push(Da::BlobTransaction::from_verified_data(sender, blob_hash, data).into());
+            }
+        }
+        blobs
+    }
+}
+
+/// Reads the first `num_bytes` of the data of `blob`, or all of it if it's shorter.
+fn read_data<B: BlobReaderTrait>(blob: &mut B, num_bytes: usize) -> &[u8] {
+    let expected_len = num_bytes.min(blob.total_len());
+    let data = data_for_reading(blob, num_bytes);
+    // In zk mode, the prover provides the data that was read in native mode. If it's missing,
+    // the prover is hiding a chunk.
+    assert!(
+        data.len() >= expected_len,
+        "Blob data was not provided. The prover might be malicious"
+    );
+    data
+}
+
+#[cfg(feature = "native")]
+fn data_for_reading(blob: &mut impl BlobReaderTrait, num_bytes: usize) -> &[u8] {
+    blob.advance(num_bytes)
+}
+
+#[cfg(not(feature = "native"))]
+fn data_for_reading(blob: &mut impl BlobReaderTrait, _num_bytes: usize) -> &[u8] {
+    blob.verified_data()
+}
@@ -4,12 +4,15 @@
 mod call;
 pub use call::CallMessage;
 mod capabilities;
+mod chunks;
 #[cfg(feature = "native")]
 mod query;
 
+use chunks::{ChunkedBlob, ChunkedBlobKey};
 #[cfg(feature = "native")]
 pub use query::*;
 use sov_chain_state::TransitionHeight;
+use sov_modules_api::da::BlockHeaderTrait;
 use sov_modules_api::macros::config_constant;
 use sov_modules_api::{
     KernelModuleInfo, KernelWorkingSet, Module, StateMap, StateMapAccessor, StateValue, WorkingSet,
@@ -41,9 +44,28 @@ pub struct BlobStorage<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
     #[state]
     pub(crate) deferred_blobs_requested_for_execution_next_slot: StateValue<u16>,
 
+    /// Blobs split into chunks, waiting for the rest of their chunks
+    /// (sender, sequence id) => chunks received so far
+    #[state]
+    pub(crate) chunked_blobs: StateMap<ChunkedBlobKey, ChunkedBlob>,
+
+    /// The chunked blobs whose first chunk appeared in each slot, dropped if they are still
+    /// incomplete `DEFERRED_SLOTS_COUNT` slots later
+    /// DA block number => (sender, sequence id)
+    #[state]
+    pub(crate) chunked_blobs_by_slot: StateMap<u64, Vec<ChunkedBlobKey>>,
+
     #[module]
     pub(crate) sequencer_registry: sov_sequencer_registry::SequencerRegistry<C, Da>,
 
+    /// Resolves the senders of the direct blobs
+    #[module]
+    pub(crate) accounts: sov_accounts::Accounts<C>,
+
+    /// Checks that the senders of the direct blobs can pay for their gas
+    #[module]
+    pub(crate) bank: sov_bank::Bank<C>,
+
     #[kernel_module]
     chain_state: sov_chain_state::ChainState<C, Da>,
 }
@@ -81,11 +103,31 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> BlobStorage<C, Da
             .collect()
     }
 
-    pub(crate) fn get_preferred_sequencer(
+    /// Stores the leader schedule of the current epoch if leader rotation is
+    /// enabled. Kernels must call this at the start of every slot, before
+    /// selecting its blobs.
+    pub fn begin_slot_hook(
         &self,
-        working_set: &mut WorkingSet<C>,
+        slot_header: &Da::BlockHeader,
+        working_set: &mut KernelWorkingSet<'_, C>,
+    ) {
+        let slot_height = self.get_true_slot_height(working_set);
+        self.sequencer_registry.update_leader_schedule(
+            slot_height,
+            slot_header.prev_hash().into(),
+            working_set.inner,
+        );
+    }
+
+    /// Returns the sequencer whose blobs are processed first in the current slot:
+    /// the slot leader if leader rotation is enabled, or the preferred sequencer otherwise.
+    pub(crate) fn get_priority_sequencer(
+        &self,
+        working_set: &mut KernelWorkingSet<'_, C>,
     ) -> Option<Da::Address> {
-        self.sequencer_registry.get_preferred_sequencer(working_set)
+        let slot_height = self.get_true_slot_height(working_set);
+        self.sequencer_registry
+            .get_priority_sequencer(slot_height, working_set.inner)
     }
 
     pub(crate) fn get_true_slot_height(
@@ -1,6 +1,7 @@
 use sov_blob_storage::BlobStorage;
 use sov_chain_state::{ChainState, ChainStateConfig};
 use sov_mock_da::{MockAddress, MockBlob, MockDaSpec};
+use sov_modules_api::da::chunks::{blob_hash, split_blob, BlobChunk, CHUNK_OVERHEAD};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::{KernelModule, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -112,3 +113,91 @@ fn store_and_retrieve_standard() {
         .take_blobs_for_slot_height(4, &mut working_set)
         .is_empty());
 }
+
+#[test]
+fn reassemble_chunked_blobs() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let blob_storage = BlobStorage::<C, Da>::default();
+
+    let sender = MockAddress::from([1u8; 32]);
+    let other_sender = MockAddress::from([2u8; 32]);
+    let blob: Vec<u8> = (0..=255).cycle().take(1000).collect();
+    let chunks: Vec<BlobChunk> = split_blob(&blob, CHUNK_OVERHEAD + 300, 7)
+        .unwrap()
+        .iter()
+        .map(|encoded| BlobChunk::decode(encoded).unwrap())
+        .collect();
+    assert_eq!(4, chunks.len());
+
+    // Chunks of the same sequence from another sender belong to another blob.
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(2, &other_sender, chunks[3].clone(), &mut working_set)
+    );
+
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(2, &sender, chunks[2].clone(), &mut working_set)
+    );
+    // Duplicates and chunks conflicting with the previous chunks are discarded.
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(2, &sender, chunks[2].clone(), &mut working_set)
+    );
+    let conflicting = BlobChunk {
+        count: 3,
+        ..chunks[0].clone()
+    };
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(2, &sender, conflicting, &mut working_set)
+    );
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(3, &sender, chunks[0].clone(), &mut working_set)
+    );
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(3, &sender, chunks[1].clone(), &mut working_set)
+    );
+    assert_eq!(
+        Some(blob.clone()),
+        blob_storage.add_chunk(4, &sender, chunks[3].clone(), &mut working_set)
+    );
+
+    // The chunks of the other sender are still incomplete when they expire.
+    blob_storage.expire_chunked_blobs(2, &mut working_set);
+    for chunk in &chunks[..3] {
+        assert_eq!(
+            None,
+            blob_storage.add_chunk(5, &other_sender, chunk.clone(), &mut working_set)
+        );
+    }
+}
+
+#[test]
+fn discard_chunked_blob_with_wrong_hash() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let blob_storage = BlobStorage::<C, Da>::default();
+
+    let sender = MockAddress::from([1u8; 32]);
+    let chunks = [vec![1, 2, 3], vec![4, 5, 6]].map(|data| BlobChunk {
+        sequence_id: 0,
+        index: u16::from(data[0] / 3),
+        count: 2,
+        blob_hash: blob_hash(&[1, 2, 3, 4, 5]),
+        data,
+    });
+
+    let [first, second] = chunks;
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(1, &sender, first, &mut working_set)
+    );
+    assert_eq!(
+        None,
+        blob_storage.add_chunk(1, &sender, second, &mut working_set)
+    );
+}
@@ -32,6 +32,9 @@
 // ) -> sov_bank::BankConfig<C> {
 //     let token_config: TokenConfig<C> = TokenConfig {
 //         token_name: "InitialToken".to_owned(),
+//         symbol: "TKN".to_owned(),
+//         decimals: 18,
+//         max_supply: None,
 //         address_and_balances: vec![
 //             (preferred_sequencer, LOCKED_AMOUNT * 3),
 //             (regular_sequencer, LOCKED_AMOUNT * 3),
@@ -24,6 +24,8 @@ where
         transition: StateTransitionId<Da, <C::Storage as Storage>::Root>,
         working_set: &mut WorkingSet<C>,
     ) {
+        self.transition_heights
+            .set(&transition.da_block_hash, &height, working_set);
         self.historical_transitions
             .set(&height, &transition, working_set);
     }
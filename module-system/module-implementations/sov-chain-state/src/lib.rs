@@ -147,6 +147,11 @@ pub struct ChainState<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
         BcsCodec,
     >,
 
+    /// The height of every transition in [`ChainState::historical_transitions`], indexed by the hash
+    /// of its DA block.
+    #[state]
+    transition_heights: sov_modules_api::StateMap<Da::SlotHash, TransitionHeight, BcsCodec>,
+
     /// The transition that is currently processed
     #[state]
     in_progress_transition: sov_modules_api::KernelStateValue<TransitionInProgress<Da>, BcsCodec>,
@@ -211,6 +216,15 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
         self.historical_transitions
             .get(&transition_num, working_set)
     }
+
+    /// Returns the height of the completed transition of the DA block `da_block_hash`, if any.
+    pub fn get_transition_height(
+        &self,
+        da_block_hash: &Da::SlotHash,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<TransitionHeight> {
+        self.transition_heights.get(da_block_hash, working_set)
+    }
 }
 
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::KernelModule
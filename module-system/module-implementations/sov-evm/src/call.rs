@@ -1,14 +1,16 @@
 use anyhow::Result;
 use reth_primitives::TransactionSignedEcRecovered;
 use reth_revm::into_reth_log;
-use revm::primitives::{CfgEnv, EVMError, SpecId};
+use revm::primitives::{Bytes, CfgEnv, EVMError, ExecutionResult, SpecId, U256};
 use sov_modules_api::prelude::*;
 use sov_modules_api::{CallResponse, WorkingSet};
 
+use crate::evm::conversions::create_tx_env;
 use crate::evm::db::EvmDb;
 use crate::evm::executor::{self};
+use crate::evm::module_precompile::EvmRuntime;
 use crate::evm::primitive_types::{BlockEnv, Receipt, TransactionSignedAndRecovered};
-use crate::evm::{EvmChainConfig, RlpEvmTransaction};
+use crate::evm::{DbAccount, EvmChainConfig, RlpEvmTransaction};
 use crate::experimental::PendingTransaction;
 use crate::Evm;
 
@@ -25,11 +27,11 @@ pub struct CallMessage {
     pub tx: RlpEvmTransaction,
 }
 
-impl<C: sov_modules_api::Context> Evm<C> {
+impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R> {
     pub(crate) fn execute_call(
         &self,
         tx: RlpEvmTransaction,
-        _context: &C,
+        context: &C,
         working_set: &mut WorkingSet<C>,
     ) -> Result<CallResponse> {
         let evm_tx_recovered: TransactionSignedEcRecovered = tx.try_into()?;
@@ -41,8 +43,42 @@ impl<C: sov_modules_api::Context> Evm<C> {
         let cfg = self.cfg.get(working_set).expect("Evm config must be set");
         let cfg_env = get_cfg_env(&block_env, cfg, None);
 
+        let mut module_precompile =
+            self.get_module_precompile(context.sequencer().clone(), context.slot_height());
         let evm_db: EvmDb<'_, C> = self.get_db(working_set);
-        let result = executor::execute_tx(evm_db, &block_env, &evm_tx_recovered, cfg_env);
+        let spec_id = cfg_env.spec_id;
+        let mut result = executor::inspect_commit(
+            evm_db,
+            &block_env,
+            create_tx_env(&evm_tx_recovered),
+            cfg_env,
+            &mut module_precompile,
+        );
+        // The module calls were applied to the working set and can only be undone together with
+        // the rest of the transaction. The runtime checkpoints the working set before dispatching
+        // a transaction, so reverting it discards this transaction alone. As for any EVM revert,
+        // the sender still pays for the gas used.
+        if module_precompile.reverted_module_calls() {
+            tracing::debug!(
+                "EVM transaction with hash={} reverted after calling the modules",
+                evm_tx_recovered.hash()
+            );
+            working_set.revert_in_place();
+            if let Ok(reverted) = &result {
+                let gas_used = reverted.gas_used();
+                self.charge_reverted_transaction(
+                    &evm_tx_recovered,
+                    &block_env,
+                    spec_id,
+                    gas_used,
+                    working_set,
+                );
+                result = Ok(ExecutionResult::Revert {
+                    gas_used,
+                    output: Bytes::new(),
+                });
+            }
+        }
         let previous_transaction = self.pending_transactions.last(working_set);
         let previous_transaction_cumulative_gas_used = previous_transaction
             .as_ref()
@@ -101,6 +137,44 @@ impl<C: sov_modules_api::Context> Evm<C> {
 
         Ok(CallResponse::default())
     }
+
+    /// Applies the effects revm gives a reverted transaction: the nonce of the sender is
+    /// incremented, the sender pays `gas_used` at the effective gas price and the coinbase
+    /// receives the priority fee.
+    fn charge_reverted_transaction(
+        &self,
+        tx: &TransactionSignedEcRecovered,
+        block_env: &BlockEnv,
+        spec_id: SpecId,
+        gas_used: u64,
+        working_set: &mut WorkingSet<C>,
+    ) {
+        let accounts_prefix = self.accounts.prefix();
+        let gas_price = tx.effective_gas_price(Some(block_env.basefee));
+        let fee = U256::from(gas_used) * U256::from(gas_price);
+
+        let mut sender = self
+            .accounts
+            .get(&tx.signer(), working_set)
+            .unwrap_or_else(|| DbAccount::new(accounts_prefix, tx.signer()));
+        sender.info.nonce += 1;
+        sender.info.balance = sender.info.balance.saturating_sub(fee);
+        self.accounts.set(&tx.signer(), &sender, working_set);
+
+        // Since London, the base fee is burnt.
+        let coinbase_fee = if SpecId::enabled(spec_id, SpecId::LONDON) {
+            U256::from(gas_used) * U256::from(gas_price.saturating_sub(block_env.basefee as u128))
+        } else {
+            fee
+        };
+        let mut coinbase = self
+            .accounts
+            .get(&block_env.coinbase, working_set)
+            .unwrap_or_else(|| DbAccount::new(accounts_prefix, block_env.coinbase));
+        coinbase.info.balance = coinbase.info.balance.saturating_add(coinbase_fee);
+        self.accounts
+            .set(&block_env.coinbase, &coinbase, working_set);
+    }
 }
 
 /// Get cfg env for a given block number
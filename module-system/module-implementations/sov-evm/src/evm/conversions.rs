@@ -1,6 +1,7 @@
 use bytes::Bytes;
 use reth_primitives::{
-    Bytes as RethBytes, TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash,
+    AccessList, Bytes as RethBytes, TransactionSigned, TransactionSignedEcRecovered,
+    TransactionSignedNoHash, TxType,
 };
 use revm::primitives::{
     AccountInfo as ReVmAccountInfo, BlockEnv as ReVmBlockEnv, CreateScheme, TransactTo, TxEnv, U256,
@@ -8,7 +9,7 @@ use revm::primitives::{
 
 use super::primitive_types::{BlockEnv, RlpEvmTransaction, TransactionSignedAndRecovered};
 use super::AccountInfo;
-use crate::error::rpc::EthApiError;
+use crate::error::rpc::{EthApiError, RpcInvalidTransactionError};
 
 impl From<AccountInfo> for ReVmAccountInfo {
     fn from(info: AccountInfo) -> Self {
@@ -42,7 +43,6 @@ impl From<&BlockEnv> for ReVmBlockEnv {
             basefee: U256::from(block_env.basefee),
             gas_limit: U256::from(block_env.gas_limit),
             // EIP-4844 related field
-            // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
             blob_excess_gas_and_price: None,
         }
     }
@@ -64,10 +64,12 @@ pub(crate) fn create_tx_env(tx: &TransactionSignedEcRecovered) -> TxEnv {
         data: Bytes::from(tx.input().to_vec()),
         chain_id: tx.chain_id(),
         nonce: Some(tx.nonce()),
-        // TODO handle access list
-        access_list: vec![],
+        access_list: tx
+            .access_list()
+            .cloned()
+            .map(AccessList::flattened)
+            .unwrap_or_default(),
         // EIP-4844 related fields
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
         blob_hashes: vec![],
         max_fee_per_blob_gas: None,
     }
@@ -85,6 +87,11 @@ impl TryFrom<RlpEvmTransaction> for TransactionSignedNoHash {
         let transaction = TransactionSigned::decode_enveloped(data)
             .map_err(|_| EthApiError::FailedToDecodeSignedTransaction)?;
 
+        // Blobs can't be published on the rollup DA layer.
+        if transaction.tx_type() == TxType::EIP4844 {
+            return Err(RpcInvalidTransactionError::TxTypeNotSupported.into());
+        }
+
         Ok(transaction.into())
     }
 }
@@ -26,9 +26,10 @@ impl<'a, C: sov_modules_api::Context> DatabaseCommit for EvmDb<'a, C> {
             if let Some(ref code) = account_info.code {
                 if !code.is_empty() {
                     // TODO: would be good to have a contains_key method on the StateMap that would be optimized, so we can check the hash before storing the code
+                    // The analysed bytecode is padded, only the deployed code is stored.
                     self.code.set(
                         &account_info.code_hash,
-                        &code.bytecode.as_ref().into(),
+                        &code.original_bytes().as_ref().into(),
                         self.working_set,
                     );
                 }
@@ -1,17 +1,18 @@
 use std::convert::Infallible;
 
-use reth_primitives::TransactionSignedEcRecovered;
-use reth_revm::tracing::{TracingInspector, TracingInspectorConfig};
 use revm::primitives::{CfgEnv, EVMError, Env, ExecutionResult, ResultAndState, TxEnv};
-use revm::{self, Database, DatabaseCommit};
+use revm::{self, Database, DatabaseCommit, Inspector};
 
-use super::conversions::create_tx_env;
+use super::db::EvmDb;
+use super::module_precompile::{EvmRuntime, ModulePrecompile};
 use super::primitive_types::BlockEnv;
 
+/// Executes `tx` without the module precompile and commits its changes to `db`.
+#[cfg(test)]
 pub(crate) fn execute_tx<DB: Database<Error = Infallible> + DatabaseCommit>(
     db: DB,
     block_env: &BlockEnv,
-    tx: &TransactionSignedEcRecovered,
+    tx: &reth_primitives::TransactionSignedEcRecovered,
     config_env: CfgEnv,
 ) -> Result<ExecutionResult, EVMError<Infallible>> {
     let mut evm = revm::new();
@@ -19,7 +20,7 @@ pub(crate) fn execute_tx<DB: Database<Error = Infallible> + DatabaseCommit>(
     let env = Env {
         block: block_env.into(),
         cfg: config_env,
-        tx: create_tx_env(tx),
+        tx: super::conversions::create_tx_env(tx),
     };
 
     evm.env = env;
@@ -27,11 +28,25 @@ pub(crate) fn execute_tx<DB: Database<Error = Infallible> + DatabaseCommit>(
     evm.transact_commit()
 }
 
-pub(crate) fn inspect<DB: Database<Error = Infallible> + DatabaseCommit>(
+/// Executes `tx` without committing its changes. The calls to the modules are executed by
+/// `module_precompile`.
+pub(crate) fn inspect<C: sov_modules_api::Context, R: EvmRuntime<C>>(
+    db: EvmDb<'_, C>,
+    block_env: &BlockEnv,
+    tx: TxEnv,
+    config_env: CfgEnv,
+    module_precompile: ModulePrecompile<'_, C, R>,
+) -> Result<ResultAndState, EVMError<Infallible>> {
+    inspect_with(db, block_env, tx, config_env, module_precompile)
+}
+
+/// Executes `tx` with `inspector` without committing its changes.
+pub(crate) fn inspect_with<DB: Database<Error = Infallible>, I: Inspector<DB>>(
     db: DB,
     block_env: &BlockEnv,
     tx: TxEnv,
     config_env: CfgEnv,
+    inspector: I,
 ) -> Result<ResultAndState, EVMError<Infallible>> {
     let mut evm = revm::new();
 
@@ -43,10 +58,29 @@ pub(crate) fn inspect<DB: Database<Error = Infallible> + DatabaseCommit>(
 
     evm.env = env;
     evm.database(db);
+    evm.inspect(inspector)
+}
 
-    let config = TracingInspectorConfig::all();
+/// Executes `tx` with `inspector` and commits its changes to `db`.
+pub(crate) fn inspect_commit<
+    DB: Database<Error = Infallible> + DatabaseCommit,
+    I: Inspector<DB>,
+>(
+    db: DB,
+    block_env: &BlockEnv,
+    tx: TxEnv,
+    config_env: CfgEnv,
+    inspector: I,
+) -> Result<ExecutionResult, EVMError<Infallible>> {
+    let mut evm = revm::new();
 
-    let mut inspector = TracingInspector::new(config);
+    let env = Env {
+        cfg: config_env,
+        block: block_env.into(),
+        tx,
+    };
 
-    evm.inspect(&mut inspector)
+    evm.env = env;
+    evm.database(db);
+    evm.inspect_commit(inspector)
 }
@@ -11,11 +11,16 @@ mod db_commit;
 pub(crate) mod db_init;
 pub(crate) mod error;
 pub(crate) mod executor;
+pub(crate) mod module_precompile;
 pub(crate) mod primitive_types;
 #[cfg(test)]
 mod tests;
 
 pub(crate) use call::prepare_call_env;
+pub use module_precompile::{
+    module_address_of, EvmRuntime, NoRuntime, EVM_GAS_PER_MODULE_GAS, MODULE_CALL_GAS,
+    MODULE_PRECOMPILE_ADDRESS, MODULE_QUERY_GAS,
+};
 pub use primitive_types::RlpEvmTransaction;
 use sov_state::codec::BcsCodec;
 
@@ -35,7 +40,7 @@ pub(crate) struct DbAccount {
 }
 
 impl DbAccount {
-    fn new(parent_prefix: &Prefix, address: Address) -> Self {
+    pub(crate) fn new(parent_prefix: &Prefix, address: Address) -> Self {
         let prefix = Self::create_storage_prefix(parent_prefix, address);
         Self {
             info: Default::default(),
@@ -0,0 +1,544 @@
+//! A precompile through which EVM contracts call the native modules.
+//!
+//! The precompile lives at [`MODULE_PRECOMPILE_ADDRESS`] and understands the following
+//! Solidity interface:
+//!
+//! ```solidity
+//! interface IModules {
+//!     // Queries, allowed in static calls.
+//!     function balanceOf(bytes32 token, bytes32 user) external view returns (uint256);
+//!     function totalSupply(bytes32 token) external view returns (uint256);
+//!     function moduleAddressOf(address account) external view returns (bytes32);
+//!     // Calls, dispatched through the runtime as if the caller had sent `message`.
+//!     function call(bytes message) external;
+//! }
+//! ```
+//!
+//! The `message` of a call is a borsh-encoded runtime call message, as found in the
+//! transactions of the rollup.
+//!
+//! revm precompiles can't access the module state, so the precompile is an [`Inspector`]
+//! intercepting the calls to its address.
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+use anyhow::{anyhow, bail};
+use ethers_core::abi::{self, ParamType, Token};
+use ethers_core::utils::{id, keccak256};
+use reth_primitives::hex_literal::hex;
+use revm::interpreter::{
+    return_ok, CallInputs, CallScheme, CreateInputs, Gas, InstructionResult, Interpreter,
+};
+use revm::primitives::{Bytes, Log, B160, B256, U256};
+use revm::{EVMData, Inspector};
+use sov_modules_api::{CallResponse, DispatchCall, GasUnit, WorkingSet};
+
+use super::db::EvmDb;
+
+/// The address of the precompile calling the modules.
+pub const MODULE_PRECOMPILE_ADDRESS: B160 = B160(hex!("0000000000000000000000000000000000000100"));
+
+/// EVM gas charged for a module query.
+pub const MODULE_QUERY_GAS: u64 = 2_600;
+
+/// EVM gas charged for a module call, on top of the gas used by the dispatched call.
+pub const MODULE_CALL_GAS: u64 = 25_000;
+
+/// EVM gas charged for every unit of gas used by a dispatched module call, in any of its
+/// dimensions.
+pub const EVM_GAS_PER_MODULE_GAS: u64 = 1;
+
+/// Selector of the `Error(string)` revert reason.
+const ERROR_SELECTOR: [u8; 4] = hex!("08c379a0");
+
+/// Returns the module address of the EVM account `address`. Module calls made by an EVM
+/// account are sent from this address, which is also the sender of the module transactions
+/// signed by the same Ethereum key.
+pub fn module_address_of<C: sov_modules_api::Context>(address: &B160) -> C::Address {
+    sov_modules_api::ethereum::module_address::<C>(&address.0)
+}
+
+/// The runtime through which the module precompile dispatches the calls of the EVM contracts.
+pub trait EvmRuntime<C: sov_modules_api::Context>:
+    DispatchCall<Context = C> + Default + 'static
+{
+}
+
+impl<C, R> EvmRuntime<C> for R
+where
+    C: sov_modules_api::Context,
+    R: DispatchCall<Context = C> + Default + 'static,
+{
+}
+
+/// The runtime of an EVM module used on its own, e.g. by the RPC services. It doesn't decode any
+/// call message, so the module calls made through the precompile fail. Module queries are still
+/// answered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoRuntime<C: sov_modules_api::Context> {
+    phantom: PhantomData<fn() -> C>,
+}
+
+impl<C: sov_modules_api::Context> Default for NoRuntime<C> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: sov_modules_api::Context> DispatchCall for NoRuntime<C> {
+    type Context = C;
+    type Decodable = Infallible;
+
+    fn decode_call(_serialized_message: &[u8]) -> Result<Self::Decodable, std::io::Error> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "The EVM module isn't part of a runtime",
+        ))
+    }
+
+    fn dispatch_call(
+        &self,
+        message: Self::Decodable,
+        _working_set: &mut WorkingSet<Self::Context>,
+        _context: &Self::Context,
+    ) -> Result<CallResponse, sov_modules_api::Error> {
+        match message {}
+    }
+
+    fn module_address(&self, message: &Self::Decodable) -> &C::Address {
+        match *message {}
+    }
+}
+
+/// A request to the modules decoded from the calldata of the precompile.
+enum ModuleRequest<C: sov_modules_api::Context> {
+    BalanceOf {
+        token: C::Address,
+        user: C::Address,
+    },
+    TotalSupply {
+        token: C::Address,
+    },
+    ModuleAddressOf {
+        account: B160,
+    },
+    /// A serialized runtime call message.
+    Call(Vec<u8>),
+}
+
+impl<C: sov_modules_api::Context> ModuleRequest<C> {
+    fn decode(input: &[u8]) -> anyhow::Result<Self> {
+        if input.len() < 4 {
+            bail!("Missing function selector");
+        }
+        let (selector, args) = input.split_at(4);
+
+        let request = if selector == id("balanceOf(bytes32,bytes32)") {
+            let args = abi::decode(
+                &[ParamType::FixedBytes(32), ParamType::FixedBytes(32)],
+                args,
+            )?;
+            Self::BalanceOf {
+                token: address_arg::<C>(&args[0])?,
+                user: address_arg::<C>(&args[1])?,
+            }
+        } else if selector == id("totalSupply(bytes32)") {
+            let args = abi::decode(&[ParamType::FixedBytes(32)], args)?;
+            Self::TotalSupply {
+                token: address_arg::<C>(&args[0])?,
+            }
+        } else if selector == id("moduleAddressOf(address)") {
+            let args = abi::decode(&[ParamType::Address], args)?;
+            let Some(Token::Address(account)) = args.into_iter().next() else {
+                bail!("Invalid address argument");
+            };
+            Self::ModuleAddressOf {
+                account: B160(account.0),
+            }
+        } else if selector == id("call(bytes)") {
+            let args = abi::decode(&[ParamType::Bytes], args)?;
+            let Some(Token::Bytes(message)) = args.into_iter().next() else {
+                bail!("Invalid message argument");
+            };
+            Self::Call(message)
+        } else {
+            bail!("Unknown function selector 0x{}", hex::encode(selector));
+        };
+
+        Ok(request)
+    }
+
+    fn gas_cost(&self) -> u64 {
+        match self {
+            Self::Call(_) => MODULE_CALL_GAS,
+            _ => MODULE_QUERY_GAS,
+        }
+    }
+}
+
+fn address_arg<C: sov_modules_api::Context>(token: &Token) -> anyhow::Result<C::Address> {
+    let bytes: [u8; 32] = token
+        .clone()
+        .into_fixed_bytes()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("Invalid address argument"))?;
+    Ok(C::Address::from(bytes))
+}
+
+/// Converts the gas used by a module call, from `before` to `after`, into EVM gas.
+fn evm_gas_of<GU: GasUnit>(before: &GU, after: &GU) -> u64 {
+    after
+        .to_dimensions()
+        .into_iter()
+        .zip(before.to_dimensions())
+        .map(|(after, before)| after.saturating_sub(before))
+        .fold(0, u64::saturating_add)
+        .saturating_mul(EVM_GAS_PER_MODULE_GAS)
+}
+
+fn encode_amount(amount: Option<sov_bank::Amount>) -> Bytes {
+    abi::encode(&[Token::Uint(amount.unwrap_or_default().into())]).into()
+}
+
+/// Encodes `error` as an `Error(string)` revert reason.
+fn encode_revert_reason(error: &anyhow::Error) -> Bytes {
+    let mut output = ERROR_SELECTOR.to_vec();
+    output.extend(abi::encode(&[Token::String(error.to_string())]));
+    output.into()
+}
+
+/// Executes the calls made to [`MODULE_PRECOMPILE_ADDRESS`] during an EVM execution.
+///
+/// The module calls are applied to the working set right away, so they can't be undone if a
+/// frame calling the modules reverts later on, or if the caller can't pay for the gas used by
+/// the module call. When that happens [`ModulePrecompile::reverted_module_calls`] returns `true`
+/// and the whole transaction must be reverted.
+pub(crate) struct ModulePrecompile<'a, C: sov_modules_api::Context, R: EvmRuntime<C>> {
+    bank: &'a sov_bank::Bank<C>,
+    evm_address: &'a C::Address,
+    runtime: R,
+    sequencer: C::Address,
+    slot_height: u64,
+    /// For every frame being executed, whether it has called the modules.
+    frames: Vec<bool>,
+    reverted_module_calls: bool,
+}
+
+impl<'a, C: sov_modules_api::Context, R: EvmRuntime<C>> ModulePrecompile<'a, C, R> {
+    /// Module calls are made with `sequencer` and `slot_height` in their context. Calls to the
+    /// module at `evm_address` are rejected, since the EVM can't be re-entered.
+    pub(crate) fn new(
+        bank: &'a sov_bank::Bank<C>,
+        evm_address: &'a C::Address,
+        sequencer: C::Address,
+        slot_height: u64,
+    ) -> Self {
+        Self {
+            bank,
+            evm_address,
+            runtime: R::default(),
+            sequencer,
+            slot_height,
+            frames: Vec::new(),
+            reverted_module_calls: false,
+        }
+    }
+
+    /// Whether a frame that called the modules was reverted.
+    pub(crate) fn reverted_module_calls(&self) -> bool {
+        self.reverted_module_calls
+    }
+
+    fn begin_frame(&mut self) {
+        self.frames.push(false);
+    }
+
+    fn end_frame(&mut self, result: InstructionResult) {
+        let called_modules = self.frames.pop().unwrap_or_default();
+        if !called_modules {
+            return;
+        }
+
+        if matches!(result, return_ok!()) {
+            if let Some(parent) = self.frames.last_mut() {
+                *parent = true;
+            }
+        } else {
+            self.reverted_module_calls = true;
+        }
+    }
+
+    /// Executes `request`, adding the EVM gas used by the dispatched module call to
+    /// `module_gas`.
+    fn execute(
+        &mut self,
+        request: ModuleRequest<C>,
+        inputs: &CallInputs,
+        working_set: &mut WorkingSet<C>,
+        logs: &mut Vec<Log>,
+        module_gas: &mut u64,
+    ) -> anyhow::Result<Bytes> {
+        let output = match request {
+            ModuleRequest::BalanceOf { token, user } => {
+                encode_amount(self.bank.get_balance_of(user, token, working_set))
+            }
+            ModuleRequest::TotalSupply { token } => {
+                encode_amount(self.bank.get_total_supply_of(&token, working_set))
+            }
+            ModuleRequest::ModuleAddressOf { account } => {
+                let address = module_address_of::<C>(&account);
+                abi::encode(&[Token::FixedBytes(address.as_ref().to_vec())]).into()
+            }
+            ModuleRequest::Call(message) => {
+                if inputs.is_static {
+                    bail!("Module calls are not allowed in static calls");
+                }
+                if inputs.context.scheme != CallScheme::Call {
+                    bail!("Modules can only be called with CALL");
+                }
+                if inputs.transfer.value != U256::ZERO {
+                    bail!("Module calls can't transfer value");
+                }
+
+                let message = R::decode_call(&message)
+                    .map_err(|e| anyhow!("Invalid runtime call message: {}", e))?;
+                if self.runtime.module_address(&message) == self.evm_address {
+                    bail!("The EVM module can't be called through the module precompile");
+                }
+
+                let sender = module_address_of::<C>(&inputs.context.caller);
+                let context = C::new(sender, self.sequencer.clone(), self.slot_height);
+
+                let first_event = working_set.events().len();
+                let gas_before = working_set.gas_used().clone();
+                let dispatched = self.runtime.dispatch_call(message, working_set, &context);
+                *module_gas = evm_gas_of(&gas_before, working_set.gas_used());
+                dispatched?;
+                if let Some(frame) = self.frames.last_mut() {
+                    *frame = true;
+                }
+
+                // Surfaces the module events as logs of the precompile.
+                let module_event_topic = B256::from(keccak256("ModuleEvent(bytes,bytes)"));
+                logs.extend(working_set.events()[first_event..].iter().map(|event| {
+                    Log {
+                        address: MODULE_PRECOMPILE_ADDRESS,
+                        topics: vec![module_event_topic],
+                        data: abi::encode(&[
+                            Token::Bytes(event.key().inner().clone()),
+                            Token::Bytes(event.value().inner().clone()),
+                        ])
+                        .into(),
+                    }
+                }));
+                let mut caller_topic = [0u8; 32];
+                caller_topic[12..].copy_from_slice(inputs.context.caller.as_bytes());
+                logs.push(Log {
+                    address: MODULE_PRECOMPILE_ADDRESS,
+                    topics: vec![
+                        B256::from(keccak256("ModuleCall(address)")),
+                        B256::from(caller_topic),
+                    ],
+                    data: Bytes::new(),
+                });
+
+                Bytes::new()
+            }
+        };
+
+        Ok(output)
+    }
+}
+
+impl<'a, 'db, C, R> Inspector<EvmDb<'db, C>> for ModulePrecompile<'a, C, R>
+where
+    C: sov_modules_api::Context,
+    R: EvmRuntime<C>,
+{
+    fn call(
+        &mut self,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &mut CallInputs,
+    ) -> (InstructionResult, Gas, Bytes) {
+        if inputs.contract != MODULE_PRECOMPILE_ADDRESS {
+            self.begin_frame();
+            return (InstructionResult::Continue, Gas::new(0), Bytes::new());
+        }
+
+        let mut gas = Gas::new(inputs.gas_limit);
+        let request = match ModuleRequest::<C>::decode(&inputs.input) {
+            Ok(request) => request,
+            Err(e) => return (InstructionResult::Revert, gas, encode_revert_reason(&e)),
+        };
+        if !gas.record_cost(request.gas_cost()) {
+            return (InstructionResult::OutOfGas, gas, Bytes::new());
+        }
+
+        let mut logs = Vec::new();
+        let mut module_gas = 0;
+        let result = self.execute(
+            request,
+            inputs,
+            &mut *data.db.working_set,
+            &mut logs,
+            &mut module_gas,
+        );
+        if !gas.record_cost(module_gas) {
+            // The module call can't be undone, so the whole transaction is reverted.
+            self.reverted_module_calls = true;
+            return (InstructionResult::OutOfGas, gas, Bytes::new());
+        }
+        match result {
+            Ok(output) => {
+                for log in logs {
+                    data.journaled_state.log(log);
+                }
+                (InstructionResult::Return, gas, output)
+            }
+            Err(e) => (InstructionResult::Revert, gas, encode_revert_reason(&e)),
+        }
+    }
+
+    fn call_end(
+        &mut self,
+        _data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &CallInputs,
+        remaining_gas: Gas,
+        ret: InstructionResult,
+        out: Bytes,
+    ) -> (InstructionResult, Gas, Bytes) {
+        if inputs.contract != MODULE_PRECOMPILE_ADDRESS {
+            self.end_frame(ret);
+        }
+        (ret, remaining_gas, out)
+    }
+
+    fn create(
+        &mut self,
+        _data: &mut EVMData<'_, EvmDb<'db, C>>,
+        _inputs: &mut CreateInputs,
+    ) -> (InstructionResult, Option<B160>, Gas, Bytes) {
+        self.begin_frame();
+        (InstructionResult::Continue, None, Gas::new(0), Bytes::new())
+    }
+
+    fn create_end(
+        &mut self,
+        _data: &mut EVMData<'_, EvmDb<'db, C>>,
+        _inputs: &CreateInputs,
+        ret: InstructionResult,
+        address: Option<B160>,
+        remaining_gas: Gas,
+        out: Bytes,
+    ) -> (InstructionResult, Option<B160>, Gas, Bytes) {
+        self.end_frame(ret);
+        (ret, address, remaining_gas, out)
+    }
+}
+
+/// Runs `inspector` alongside the module precompile, so that the transactions calling the modules
+/// can be traced.
+pub(crate) struct WithModulePrecompile<'a, C: sov_modules_api::Context, R: EvmRuntime<C>, I> {
+    pub(crate) precompile: ModulePrecompile<'a, C, R>,
+    pub(crate) inspector: I,
+}
+
+impl<'a, 'db, C, R, I> Inspector<EvmDb<'db, C>> for WithModulePrecompile<'a, C, R, I>
+where
+    C: sov_modules_api::Context,
+    R: EvmRuntime<C>,
+    I: Inspector<EvmDb<'db, C>>,
+{
+    fn initialize_interp(
+        &mut self,
+        interp: &mut Interpreter,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+    ) -> InstructionResult {
+        self.inspector.initialize_interp(interp, data)
+    }
+
+    fn step(
+        &mut self,
+        interp: &mut Interpreter,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+    ) -> InstructionResult {
+        self.inspector.step(interp, data)
+    }
+
+    fn log(
+        &mut self,
+        evm_data: &mut EVMData<'_, EvmDb<'db, C>>,
+        address: &B160,
+        topics: &[B256],
+        data: &Bytes,
+    ) {
+        self.inspector.log(evm_data, address, topics, data)
+    }
+
+    fn step_end(
+        &mut self,
+        interp: &mut Interpreter,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        eval: InstructionResult,
+    ) -> InstructionResult {
+        self.inspector.step_end(interp, data, eval)
+    }
+
+    fn call(
+        &mut self,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &mut CallInputs,
+    ) -> (InstructionResult, Gas, Bytes) {
+        let result = self.inspector.call(data, inputs);
+        if result.0 != InstructionResult::Continue {
+            return result;
+        }
+        self.precompile.call(data, inputs)
+    }
+
+    fn call_end(
+        &mut self,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &CallInputs,
+        remaining_gas: Gas,
+        ret: InstructionResult,
+        out: Bytes,
+    ) -> (InstructionResult, Gas, Bytes) {
+        let (ret, remaining_gas, out) =
+            self.precompile
+                .call_end(data, inputs, remaining_gas, ret, out);
+        self.inspector
+            .call_end(data, inputs, remaining_gas, ret, out)
+    }
+
+    fn create(
+        &mut self,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &mut CreateInputs,
+    ) -> (InstructionResult, Option<B160>, Gas, Bytes) {
+        let result = self.inspector.create(data, inputs);
+        if result.0 != InstructionResult::Continue {
+            return result;
+        }
+        self.precompile.create(data, inputs)
+    }
+
+    fn create_end(
+        &mut self,
+        data: &mut EVMData<'_, EvmDb<'db, C>>,
+        inputs: &CreateInputs,
+        ret: InstructionResult,
+        address: Option<B160>,
+        remaining_gas: Gas,
+        out: Bytes,
+    ) -> (InstructionResult, Option<B160>, Gas, Bytes) {
+        let (ret, address, remaining_gas, out) =
+            self.precompile
+                .create_end(data, inputs, ret, address, remaining_gas, out);
+        self.inspector
+            .create_end(data, inputs, ret, address, remaining_gas, out)
+    }
+}
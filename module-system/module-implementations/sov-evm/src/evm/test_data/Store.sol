@@ -4,9 +4,12 @@
 pragma solidity ^0.8.0;
 contract SimpleStorage {
     uint256 public num;
+
+    event NumberSet(uint256 indexed value);
     
     function set(uint256 _num) public {
         num = _num;
+        emit NumberSet(_num);
     }
     
     function get() public view returns (uint) {
@@ -8,6 +8,7 @@ use sov_modules_api::prelude::*;
 use sov_modules_api::WorkingSet;
 
 use crate::evm::db_init::InitEvmDb;
+use crate::evm::module_precompile::EvmRuntime;
 use crate::evm::primitive_types::Block;
 use crate::evm::{AccountInfo, EvmChainConfig};
 use crate::Evm;
@@ -81,7 +82,7 @@ impl Default for EvmConfig {
     }
 }
 
-impl<C: sov_modules_api::Context> Evm<C> {
+impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R> {
     pub(crate) fn init_module(
         &self,
         config: &<Self as sov_modules_api::Module>::Config,
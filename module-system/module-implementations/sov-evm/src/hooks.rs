@@ -3,11 +3,12 @@ use sov_modules_api::prelude::*;
 use sov_modules_api::{AccessoryWorkingSet, Spec, WorkingSet};
 use sov_state::Storage;
 
+use crate::evm::module_precompile::EvmRuntime;
 use crate::evm::primitive_types::{Block, BlockEnv};
 use crate::experimental::PendingTransaction;
 use crate::Evm;
 
-impl<C: sov_modules_api::Context> Evm<C>
+impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R>
 where
     <C::Storage as Storage>::Root: Into<[u8; 32]>,
 {
@@ -187,6 +188,14 @@ where
             &sealed_block.header.number,
             accessory_working_set,
         );
+        // The block is sealed with the state of the slot, which is committed at this version.
+        if let Some(version) = accessory_working_set.next_version() {
+            self.block_state_versions.set(
+                &sealed_block.header.number,
+                &version,
+                accessory_working_set,
+            );
+        }
         self.pending_head.delete(accessory_working_set);
     }
 }
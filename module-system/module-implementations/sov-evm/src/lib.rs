@@ -35,11 +35,14 @@ pub use revm::primitives::SpecId;
 #[cfg(feature = "experimental")]
 mod experimental {
 
+    use std::marker::PhantomData;
+
     use reth_primitives::Address;
     use sov_modules_api::{Error, ModuleInfo, WorkingSet};
     use sov_state::codec::BcsCodec;
 
     use super::evm::db::EvmDb;
+    use super::evm::module_precompile::{EvmRuntime, ModulePrecompile, NoRuntime};
     use super::evm::{DbAccount, EvmChainConfig};
     use crate::evm::primitive_types::{
         Block, BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered,
@@ -57,14 +60,25 @@ mod experimental {
     }
 
     /// The sov-evm module provides compatibility with the EVM.
+    ///
+    /// `R` is the runtime the module is part of, through which the module precompile dispatches
+    /// the calls of the EVM contracts.
     #[allow(dead_code)]
     // #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
     #[derive(ModuleInfo, Clone)]
-    pub struct Evm<C: sov_modules_api::Context> {
+    pub struct Evm<C: sov_modules_api::Context, R: EvmRuntime<C> = NoRuntime<C>> {
         /// The address of the evm module.
         #[address]
         pub(crate) address: C::Address,
 
+        /// The runtime the module is part of.
+        #[phantom]
+        pub(crate) runtime: PhantomData<R>,
+
+        /// Reference to the Bank module, called by the EVM contracts through the module precompile.
+        #[module]
+        pub(crate) bank: sov_bank::Bank<C>,
+
         /// Mapping from account address to account state.
         #[state]
         pub(crate) accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
@@ -103,6 +117,11 @@ mod experimental {
         #[state]
         pub(crate) blocks: sov_modules_api::AccessoryStateVec<SealedBlock, BcsCodec>,
 
+        /// Used only by the RPC: block_number => version of the state committed with the block,
+        /// read by the queries of past blocks.
+        #[state]
+        pub(crate) block_state_versions: sov_modules_api::AccessoryStateMap<u64, u64, BcsCodec>,
+
         /// Used only by the RPC: block_hash => block_number mapping,
         #[state]
         pub(crate) block_hashes:
@@ -123,7 +142,7 @@ mod experimental {
         pub(crate) receipts: sov_modules_api::AccessoryStateVec<Receipt, BcsCodec>,
     }
 
-    impl<C: sov_modules_api::Context> sov_modules_api::Module for Evm<C> {
+    impl<C: sov_modules_api::Context, R: EvmRuntime<C>> sov_modules_api::Module for Evm<C, R> {
         type Context = C;
 
         type Config = EvmConfig;
@@ -150,9 +169,17 @@ mod experimental {
         }
     }
 
-    impl<C: sov_modules_api::Context> Evm<C> {
+    impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R> {
         pub(crate) fn get_db<'a>(&self, working_set: &'a mut WorkingSet<C>) -> EvmDb<'a, C> {
             EvmDb::new(self.accounts.clone(), self.code.clone(), working_set)
         }
+
+        pub(crate) fn get_module_precompile(
+            &self,
+            sequencer: C::Address,
+            slot_height: u64,
+        ) -> ModulePrecompile<'_, C, R> {
+            ModulePrecompile::new(&self.bank, &self.address, sequencer, slot_height)
+        }
     }
 }
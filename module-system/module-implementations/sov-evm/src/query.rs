@@ -1,10 +1,18 @@
 use std::array::TryFromSliceError;
+use std::ops::RangeInclusive;
 
 use ethereum_types::U64;
 use jsonrpsee::core::RpcResult;
 use reth_primitives::contract::create_address;
 use reth_primitives::TransactionKind::{Call, Create};
-use reth_primitives::{TransactionSignedEcRecovered, U128, U256};
+use reth_primitives::{BlockId, BlockNumberOrTag, TransactionSignedEcRecovered, U128, U256};
+use reth_revm::tracing::{TracingInspector, TracingInspectorConfig};
+use reth_rlp::Encodable;
+use reth_rpc_types::trace::geth::{
+    GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace,
+};
+use reth_rpc_types::FilterBlockOption;
 use revm::primitives::{
     EVMError, ExecutionResult, Halt, InvalidTransaction, TransactTo, KECCAK_EMPTY,
 };
@@ -15,14 +23,37 @@ use tracing::info;
 
 use crate::call::get_cfg_env;
 use crate::error::rpc::{ensure_success, RevertError, RpcInvalidTransactionError};
+use crate::evm::conversions::create_tx_env;
 use crate::evm::db::EvmDb;
+use crate::evm::module_precompile::{
+    module_address_of, EvmRuntime, ModulePrecompile, WithModulePrecompile,
+};
 use crate::evm::primitive_types::{BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered};
 use crate::evm::{executor, prepare_call_env};
 use crate::experimental::{MIN_CREATE_GAS, MIN_TRANSACTION_GAS};
-use crate::{EthApiError, Evm};
+use crate::{EthApiError, EthResult, Evm};
+
+/// The maximum number of blocks reported by `eth_feeHistory`.
+pub const MAX_FEE_HISTORY_BLOCK_COUNT: u64 = 1024;
+
+/// The maximum number of blocks `eth_getLogs` and log filters scan in a single query.
+pub const MAX_LOGS_BLOCK_RANGE: u64 = 10_000;
+
+/// The maximum number of logs returned by `eth_getLogs` and log filters in a single query.
+pub const MAX_LOGS_PER_QUERY: usize = 10_000;
+
+/// The trace of a transaction, as returned by `debug_traceBlockByNumber`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTrace {
+    /// Hash of the traced transaction.
+    pub tx_hash: reth_primitives::H256,
+    /// The trace produced by the requested tracer.
+    pub result: GethTrace,
+}
 
 #[rpc_gen(client, server)]
-impl<C: sov_modules_api::Context> Evm<C> {
+impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R> {
     /// Handler for `net_version`
     #[rpc_method(name = "net_version")]
     pub fn net_version(&self, working_set: &mut WorkingSet<C>) -> RpcResult<String> {
@@ -67,10 +98,14 @@ impl<C: sov_modules_api::Context> Evm<C> {
         let block_number_hex = self
             .block_hashes
             .get(&block_hash, &mut working_set.accessory_state())
-            .map(|number| hex::encode(number.to_be_bytes()))
-            .expect("Block number for known block hash must be set");
+            .map(|number| hex::encode(number.to_be_bytes()));
 
-        self.get_block_by_number(Some(block_number_hex), details, working_set)
+        match block_number_hex {
+            Some(block_number_hex) => {
+                self.get_block_by_number(Some(block_number_hex), details, working_set)
+            }
+            None => Ok(None),
+        }
     }
 
     /// Handler for: `eth_getBlockByNumber`
@@ -83,24 +118,43 @@ impl<C: sov_modules_api::Context> Evm<C> {
     ) -> RpcResult<Option<reth_rpc_types::RichBlock>> {
         info!("evm module: eth_getBlockByNumber");
 
-        let block = self.get_sealed_block_by_number(block_number, working_set);
+        let Some(block) = self.get_sealed_block_by_number(block_number, working_set)? else {
+            return Ok(None);
+        };
 
         // Build rpc header response
         let header = reth_rpc_types::Header::from_primitive_with_hash(block.header.clone());
 
         // Collect transactions with ids from db
-        let transactions_with_ids = block.transactions.clone().map(|id| {
-            let tx = self
-                .transactions
-                .get(id as usize, &mut working_set.accessory_state())
-                .expect("Transaction must be set");
-            (id, tx)
-        });
+        let transactions_with_ids: Vec<_> = block
+            .transactions
+            .clone()
+            .map(|id| {
+                let tx = self
+                    .transactions
+                    .get(id as usize, &mut working_set.accessory_state())
+                    .expect("Transaction must be set");
+                (id, tx)
+            })
+            .collect();
+
+        // The size of a block is the length of its RLP encoding.
+        let size = reth_primitives::Block {
+            header: block.header.header.clone(),
+            body: transactions_with_ids
+                .iter()
+                .map(|(_, tx)| tx.signed_transaction.clone())
+                .collect(),
+            ommers: Vec::new(),
+            withdrawals: None,
+        }
+        .length();
 
         // Build rpc transactions response
         let transactions = match details {
             Some(true) => reth_rpc_types::BlockTransactions::Full(
                 transactions_with_ids
+                    .into_iter()
                     .map(|(id, tx)| {
                         reth_rpc_types_compat::from_recovered_with_block_context(
                             tx.clone().into(),
@@ -114,6 +168,7 @@ impl<C: sov_modules_api::Context> Evm<C> {
             ),
             _ => reth_rpc_types::BlockTransactions::Hashes({
                 transactions_with_ids
+                    .into_iter()
                     .map(|(_, tx)| tx.signed_transaction.hash)
                     .collect::<Vec<_>>()
             }),
@@ -126,7 +181,7 @@ impl<C: sov_modules_api::Context> Evm<C> {
             total_difficulty,
             uncles: Default::default(),
             transactions,
-            size: Default::default(),
+            size: Some(U256::from(size)),
             withdrawals: Default::default(),
         };
 
@@ -138,19 +193,18 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_balance(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_id: Option<BlockId>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U256> {
         info!("evm module: eth_getBalance");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
-
-        let balance = self
-            .accounts
-            .get(&address, working_set)
-            .map(|account| account.info.balance)
-            .unwrap_or_default();
+        let block_number = self.resolve_block_id(block_id, working_set)?;
+        let balance = self.with_state_after_block(block_number, working_set, |working_set| {
+            self.accounts
+                .get(&address, working_set)
+                .map(|account| account.info.balance)
+                .unwrap_or_default()
+        })?;
 
         Ok(balance)
     }
@@ -161,19 +215,19 @@ impl<C: sov_modules_api::Context> Evm<C> {
         &self,
         address: reth_primitives::Address,
         index: reth_primitives::U256,
-        _block_number: Option<String>,
+        block_id: Option<BlockId>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U256> {
         info!("evm module: eth_getStorageAt");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
-
-        let storage_slot = self
-            .accounts
-            .get(&address, working_set)
-            .and_then(|account| account.storage.get(&index, working_set))
-            .unwrap_or_default();
+        let block_number = self.resolve_block_id(block_id, working_set)?;
+        let storage_slot =
+            self.with_state_after_block(block_number, working_set, |working_set| {
+                self.accounts
+                    .get(&address, working_set)
+                    .and_then(|account| account.storage.get(&index, working_set))
+                    .unwrap_or_default()
+            })?;
 
         Ok(storage_slot)
     }
@@ -183,19 +237,18 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_transaction_count(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_id: Option<BlockId>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U64> {
         info!("evm module: eth_getTransactionCount");
 
-        // TODO: Implement block_number once we have archival state #882
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/882
-
-        let nonce = self
-            .accounts
-            .get(&address, working_set)
-            .map(|account| account.info.nonce)
-            .unwrap_or_default();
+        let block_number = self.resolve_block_id(block_id, working_set)?;
+        let nonce = self.with_state_after_block(block_number, working_set, |working_set| {
+            self.accounts
+                .get(&address, working_set)
+                .map(|account| account.info.nonce)
+                .unwrap_or_default()
+        })?;
 
         Ok(nonce.into())
     }
@@ -205,19 +258,18 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_code(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_id: Option<BlockId>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::Bytes> {
         info!("evm module: eth_getCode");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
-
-        let code = self
-            .accounts
-            .get(&address, working_set)
-            .and_then(|account| self.code.get(&account.info.code_hash, working_set))
-            .unwrap_or_default();
+        let block_number = self.resolve_block_id(block_id, working_set)?;
+        let code = self.with_state_after_block(block_number, working_set, |working_set| {
+            self.accounts
+                .get(&address, working_set)
+                .and_then(|account| self.code.get(&account.info.code_hash, working_set))
+                .unwrap_or_default()
+        })?;
 
         Ok(code)
     }
@@ -227,15 +279,19 @@ impl<C: sov_modules_api::Context> Evm<C> {
     #[rpc_method(name = "eth_feeHistory")]
     pub fn fee_history(
         &self,
-        _working_set: &mut WorkingSet<C>,
+        block_count: reth_primitives::U64,
+        newest_block: BlockNumberOrTag,
+        reward_percentiles: Option<Vec<f64>>,
+        working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_rpc_types::FeeHistory> {
         info!("evm module: eth_feeHistory");
-        Ok(reth_rpc_types::FeeHistory {
-            base_fee_per_gas: Default::default(),
-            gas_used_ratio: Default::default(),
-            oldest_block: Default::default(),
-            reward: Default::default(),
-        })
+
+        Ok(self.fee_history_in_range(
+            block_count.to::<u64>(),
+            newest_block,
+            reward_percentiles.as_deref(),
+            working_set,
+        )?)
     }
 
     /// Handler for: `eth_getTransactionByHash`
@@ -326,30 +382,41 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_call(
         &self,
         request: reth_rpc_types::CallRequest,
-        block_number: Option<String>,
+        block_id: Option<BlockId>,
         _state_overrides: Option<reth_rpc_types::state::StateOverride>,
         _block_overrides: Option<Box<reth_rpc_types::BlockOverrides>>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::Bytes> {
         info!("evm module: eth_call");
-        let block_env = match block_number {
-            Some(ref block_number) if block_number == "pending" => {
-                self.block_env.get(working_set).unwrap_or_default().clone()
-            }
+        // The pending block is executed on top of the latest state.
+        let (block_env, block_number) = match block_id {
+            Some(BlockId::Number(BlockNumberOrTag::Pending)) => (
+                self.block_env.get(working_set).unwrap_or_default().clone(),
+                self.latest_block_number(working_set),
+            ),
             _ => {
-                let block = self.get_sealed_block_by_number(block_number, working_set);
-                BlockEnv::from(&block)
+                let block_number = self.resolve_block_id(block_id, working_set)?;
+                let block = self
+                    .blocks
+                    .get(block_number as usize, &mut working_set.accessory_state())
+                    .expect("Resolved blocks must be set");
+                (BlockEnv::from(&block), block_number)
             }
         };
 
         let tx_env = prepare_call_env(&block_env, request.clone()).unwrap();
 
-        let cfg = self.cfg.get(working_set).unwrap_or_default();
-        let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
+        let result = self.with_state_after_block(block_number, working_set, |working_set| {
+            let cfg = self.cfg.get(working_set).unwrap_or_default();
+            let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
+
+            let module_precompile = self.rpc_module_precompile(&block_env);
+            let evm_db: EvmDb<'_, C> = self.get_db(working_set);
 
-        let evm_db: EvmDb<'_, C> = self.get_db(working_set);
+            executor::inspect(evm_db, &block_env, tx_env, cfg_env, module_precompile)
+        })?;
 
-        let result = match executor::inspect(evm_db, &block_env, tx_env, cfg_env) {
+        let result = match result {
             Ok(result) => result.result,
             Err(err) => return Err(EthApiError::from(err).into()),
         };
@@ -388,7 +455,9 @@ impl<C: sov_modules_api::Context> Evm<C> {
                 self.block_env.get(working_set).unwrap_or_default().clone()
             }
             _ => {
-                let block = self.get_sealed_block_by_number(block_number, working_set);
+                let block = self
+                    .get_sealed_block_by_number(block_number, working_set)?
+                    .ok_or(EthApiError::UnknownBlockNumber)?;
                 BlockEnv::from(&block)
             }
         };
@@ -450,7 +519,13 @@ impl<C: sov_modules_api::Context> Evm<C> {
         let evm_db = self.get_db(working_set);
 
         // execute the call without writing to db
-        let result = executor::inspect(evm_db, &block_env, tx_env.clone(), cfg_env.clone());
+        let result = executor::inspect(
+            evm_db,
+            &block_env,
+            tx_env.clone(),
+            cfg_env.clone(),
+            self.rpc_module_precompile(&block_env),
+        );
 
         // Exceptional case: init used too much gas, we need to increase the gas limit and try
         // again
@@ -459,8 +534,16 @@ impl<C: sov_modules_api::Context> Evm<C> {
             // if price or limit was included in the request then we can execute the request
             // again with the block's gas limit to check if revert is gas related or not
             if request_gas.is_some() || request_gas_price.is_some() {
+                let module_precompile = self.rpc_module_precompile(&block_env);
                 let evm_db = self.get_db(working_set);
-                return Err(map_out_of_gas_err(block_env, tx_env, cfg_env, evm_db).into());
+                return Err(map_out_of_gas_err(
+                    block_env,
+                    tx_env,
+                    cfg_env,
+                    evm_db,
+                    module_precompile,
+                )
+                .into());
             }
         }
 
@@ -474,8 +557,16 @@ impl<C: sov_modules_api::Context> Evm<C> {
                     // if price or limit was included in the request then we can execute the request
                     // again with the block's gas limit to check if revert is gas related or not
                     return if request_gas.is_some() || request_gas_price.is_some() {
+                        let module_precompile = self.rpc_module_precompile(&block_env);
                         let evm_db = self.get_db(working_set);
-                        Err(map_out_of_gas_err(block_env, tx_env, cfg_env, evm_db).into())
+                        Err(map_out_of_gas_err(
+                            block_env,
+                            tx_env,
+                            cfg_env,
+                            evm_db,
+                            module_precompile,
+                        )
+                        .into())
                     } else {
                         // the transaction did revert
                         Err(RpcInvalidTransactionError::Revert(RevertError::new(output)).into())
@@ -508,7 +599,13 @@ impl<C: sov_modules_api::Context> Evm<C> {
             tx_env.gas_limit = mid_gas_limit;
 
             let evm_db = self.get_db(working_set);
-            let result = executor::inspect(evm_db, &block_env, tx_env.clone(), cfg_env.clone());
+            let result = executor::inspect(
+                evm_db,
+                &block_env,
+                tx_env.clone(),
+                cfg_env.clone(),
+                self.rpc_module_precompile(&block_env),
+            );
 
             // Exceptional case: init used too much gas, we need to increase the gas limit and try
             // again
@@ -557,35 +654,693 @@ impl<C: sov_modules_api::Context> Evm<C> {
         Ok(U64::from(highest_gas_limit))
     }
 
+    /// Handler for: `eth_getLogs`
+    #[rpc_method(name = "eth_getLogs")]
+    pub fn eth_get_logs(
+        &self,
+        filter: reth_rpc_types::Filter,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Vec<reth_rpc_types::Log>> {
+        info!("evm module: eth_getLogs");
+
+        let block_range = self.filter_block_range(&filter, working_set)?;
+        Ok(self.logs_in_block_range(&filter, block_range, working_set)?)
+    }
+
+    /// Handler for: `debug_traceTransaction`
+    #[rpc_method(name = "debug_traceTransaction")]
+    pub fn debug_trace_transaction(
+        &self,
+        tx_hash: reth_primitives::H256,
+        opts: Option<GethDebugTracingOptions>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<GethTrace> {
+        info!("evm module: debug_traceTransaction");
+
+        let mut accessory_state = working_set.accessory_state();
+        let tx_number = self
+            .transaction_hashes
+            .get(&tx_hash, &mut accessory_state)
+            .ok_or(EthApiError::TransactionNotFound)?;
+        let block_number = self
+            .transactions
+            .get(tx_number as usize, &mut accessory_state)
+            .expect("Transaction with known hash must be set")
+            .block_number;
+        let block = self
+            .blocks
+            .get(block_number as usize, &mut accessory_state)
+            .expect("Block of a sealed transaction must be set");
+
+        let mut traces = self.trace_block_transactions(
+            &block,
+            Some(tx_number),
+            &opts.unwrap_or_default(),
+            working_set,
+        )?;
+        let trace = traces
+            .pop()
+            .expect("The traced transaction is part of the block");
+
+        Ok(trace.result)
+    }
+
+    /// Handler for: `debug_traceBlockByNumber`
+    #[rpc_method(name = "debug_traceBlockByNumber")]
+    pub fn debug_trace_block_by_number(
+        &self,
+        block_number: BlockNumberOrTag,
+        opts: Option<GethDebugTracingOptions>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Vec<TransactionTrace>> {
+        info!("evm module: debug_traceBlockByNumber");
+
+        let latest = self.latest_block_number(working_set);
+        let block_number = resolve_block_number(Some(block_number), latest)?;
+        let block = self
+            .blocks
+            .get(block_number as usize, &mut working_set.accessory_state())
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+
+        Ok(self.trace_block_transactions(&block, None, &opts.unwrap_or_default(), working_set)?)
+    }
+
+    /// Handler for: `debug_traceCall`
+    #[rpc_method(name = "debug_traceCall")]
+    pub fn debug_trace_call(
+        &self,
+        request: reth_rpc_types::CallRequest,
+        block_number: Option<BlockNumberOrTag>,
+        opts: Option<GethDebugTracingCallOptions>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<GethTrace> {
+        info!("evm module: debug_traceCall");
+
+        let latest = self.latest_block_number(working_set);
+        let block_number = resolve_block_number(block_number, latest)?;
+        let block = self
+            .blocks
+            .get(block_number as usize, &mut working_set.accessory_state())
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+
+        let block_env = BlockEnv::from(&block);
+        let tx_env = prepare_call_env(&block_env, request)?;
+        let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
+        let opts = opts.unwrap_or_default().tracing_options;
+
+        // The call is executed on top of the state of the requested block.
+        let mut inspector = tracing_inspector(&opts)?;
+        let result = self
+            .with_state_after_block(block_number, working_set, |working_set| {
+                let inspector = WithModulePrecompile {
+                    precompile: self.rpc_module_precompile(&block_env),
+                    inspector: &mut inspector,
+                };
+                let evm_db: EvmDb<'_, C> = self.get_db(working_set);
+                executor::inspect_with(evm_db, &block_env, tx_env, cfg_env, inspector)
+            })?
+            .map_err(EthApiError::from)?;
+
+        Ok(geth_trace(inspector, &opts, result.result)?)
+    }
+
     fn get_sealed_block_by_number(
         &self,
         block_number: Option<String>,
         working_set: &mut WorkingSet<C>,
-    ) -> SealedBlock {
+    ) -> EthResult<Option<SealedBlock>> {
         // safe, finalized, and pending are not supported
-        match block_number {
-            Some(ref block_number) if block_number == "earliest" => self
-                .blocks
-                .get(0, &mut working_set.accessory_state())
-                .expect("Genesis block must be set"),
-            Some(ref block_number) if block_number == "latest" => self
-                .blocks
-                .last(&mut working_set.accessory_state())
-                .expect("Head block must be set"),
+        let block = match block_number {
+            Some(ref block_number) if block_number == "earliest" => Some(
+                self.blocks
+                    .get(0, &mut working_set.accessory_state())
+                    .expect("Genesis block must be set"),
+            ),
+            Some(ref block_number) if block_number == "latest" => Some(
+                self.blocks
+                    .last(&mut working_set.accessory_state())
+                    .expect("Head block must be set"),
+            ),
             Some(ref block_number) => {
                 // hex representation may have 0x prefix
                 let block_number = usize::from_str_radix(block_number.trim_start_matches("0x"), 16)
-                    .expect("Block number must be a valid hex number, with or without 0x prefix");
+                    .map_err(|_| {
+                        EthApiError::InvalidParams(format!(
+                            "invalid block number: {}",
+                            block_number
+                        ))
+                    })?;
+                // Blocks that don't exist yet are reported as `null`.
                 self.blocks
                     .get(block_number, &mut working_set.accessory_state())
-                    .expect("Block must be set")
             }
-            None => self
+            None => Some(
+                self.blocks
+                    .last(&mut working_set.accessory_state())
+                    .expect("Head block must be set"),
+            ),
+        };
+
+        Ok(block)
+    }
+}
+
+impl<C: sov_modules_api::Context, R: EvmRuntime<C>> Evm<C, R> {
+    /// Returns the number of the latest sealed block.
+    pub fn latest_block_number(&self, working_set: &mut WorkingSet<C>) -> u64 {
+        (self.blocks.len(&mut working_set.accessory_state()) as u64).saturating_sub(1)
+    }
+
+    /// Returns the number of the sealed block identified by `block_id`, by default the latest.
+    fn resolve_block_id(
+        &self,
+        block_id: Option<BlockId>,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<u64> {
+        let latest = self.latest_block_number(working_set);
+        match block_id {
+            Some(BlockId::Hash(hash)) => self
+                .block_hashes
+                .get(&hash.block_hash, &mut working_set.accessory_state())
+                .ok_or(EthApiError::UnknownBlockNumber),
+            Some(BlockId::Number(block_number)) => {
+                let block_number = resolve_block_number(Some(block_number), latest)?;
+                if block_number > latest {
+                    return Err(EthApiError::UnknownBlockNumber);
+                }
+                Ok(block_number)
+            }
+            None => Ok(latest),
+        }
+    }
+
+    /// Runs `query` against the state right after `block_number` was sealed. The state of past
+    /// blocks is read from the archival state, at the version recorded when they were sealed.
+    fn with_state_after_block<T>(
+        &self,
+        block_number: u64,
+        working_set: &mut WorkingSet<C>,
+        query: impl FnOnce(&mut WorkingSet<C>) -> T,
+    ) -> EthResult<T> {
+        if block_number >= self.latest_block_number(working_set) {
+            return Ok(query(working_set));
+        }
+
+        let version = self.state_version_after_block(block_number, working_set)?;
+        working_set.set_archival_version(version);
+        let result = query(working_set);
+        working_set.unset_archival_version();
+        Ok(result)
+    }
+
+    /// Returns the version of the state committed with the sealed block `block_number`.
+    fn state_version_after_block(
+        &self,
+        block_number: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<u64> {
+        self.block_state_versions
+            .get(&block_number, &mut working_set.accessory_state())
+            .ok_or_else(|| {
+                EthApiError::InvalidParams(format!(
+                    "The state of block {} is not available",
+                    block_number
+                ))
+            })
+    }
+
+    /// The module precompile of the calls simulated by the RPC. The module calls are made as if
+    /// the coinbase of `block_env` had sequenced them.
+    fn rpc_module_precompile(&self, block_env: &BlockEnv) -> ModulePrecompile<'_, C, R> {
+        self.get_module_precompile(
+            module_address_of::<C>(&block_env.coinbase),
+            block_env.number,
+        )
+    }
+
+    /// Returns the header of the sealed block `block_number`, if any.
+    pub fn sealed_block_header(
+        &self,
+        block_number: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<reth_rpc_types::Header> {
+        self.blocks
+            .get(block_number as usize, &mut working_set.accessory_state())
+            .map(|block| reth_rpc_types::Header::from_primitive_with_hash(block.header))
+    }
+
+    /// Re-executes the transactions of `block` on top of the state of its parent block, and
+    /// traces them with `opts`. If `last_tx` is set, only that transaction is traced and the
+    /// following ones are not executed. The module calls of the transactions are replayed
+    /// through the module precompile.
+    fn trace_block_transactions(
+        &self,
+        block: &SealedBlock,
+        last_tx: Option<u64>,
+        opts: &GethDebugTracingOptions,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<Vec<TransactionTrace>> {
+        // The genesis block doesn't have transactions nor a parent.
+        if block.header.number == 0 {
+            return Ok(Vec::new());
+        }
+
+        let tx_end = last_tx.map_or(block.transactions.end, |tx_number| tx_number + 1);
+        let transactions: Vec<TransactionSignedEcRecovered> = {
+            let mut accessory_state = working_set.accessory_state();
+            (block.transactions.start..tx_end)
+                .map(|tx_number| {
+                    self.transactions
+                        .get(tx_number as usize, &mut accessory_state)
+                        .expect("Transaction of a sealed block must be set")
+                        .into()
+                })
+                .collect()
+        };
+
+        let block_env = BlockEnv::from(block);
+        let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let cfg_env = get_cfg_env(&block_env, cfg, None);
+
+        let version = self.state_version_after_block(block.header.number - 1, working_set)?;
+        working_set.set_archival_version(version);
+        // The archival version must be unset even if a transaction fails to be replayed.
+        let traces = self.trace_transactions(
+            &transactions,
+            last_tx.is_some(),
+            &block_env,
+            cfg_env,
+            opts,
+            working_set,
+        );
+        working_set.unset_archival_version();
+
+        traces
+    }
+
+    /// Executes `transactions` in order on top of the current state, and traces them with
+    /// `opts`. If `only_last` is set, only the last transaction is traced.
+    fn trace_transactions(
+        &self,
+        transactions: &[TransactionSignedEcRecovered],
+        only_last: bool,
+        block_env: &BlockEnv,
+        cfg_env: revm::primitives::CfgEnv,
+        opts: &GethDebugTracingOptions,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<Vec<TransactionTrace>> {
+        let mut traces = Vec::new();
+        let last_index = transactions.len().saturating_sub(1);
+        for (index, tx) in transactions.iter().enumerate() {
+            let precompile = self.rpc_module_precompile(block_env);
+            let evm_db: EvmDb<'_, C> = self.get_db(working_set);
+
+            // Transactions preceding the traced one only need to be applied.
+            if only_last && index < last_index {
+                executor::inspect_commit(
+                    evm_db,
+                    block_env,
+                    create_tx_env(tx),
+                    cfg_env.clone(),
+                    precompile,
+                )?;
+                continue;
+            }
+
+            let mut inspector = tracing_inspector(opts)?;
+            let result = executor::inspect_commit(
+                evm_db,
+                block_env,
+                create_tx_env(tx),
+                cfg_env.clone(),
+                WithModulePrecompile {
+                    precompile,
+                    inspector: &mut inspector,
+                },
+            )?;
+            traces.push(TransactionTrace {
+                tx_hash: tx.hash(),
+                result: geth_trace(inspector, opts, result)?,
+            });
+        }
+
+        Ok(traces)
+    }
+
+    /// Computes the fee history of the `block_count` blocks ending at `newest_block`.
+    ///
+    /// The base fee of the block following `newest_block` is included, and at most
+    /// [`MAX_FEE_HISTORY_BLOCK_COUNT`] blocks are reported.
+    pub fn fee_history_in_range(
+        &self,
+        block_count: u64,
+        newest_block: BlockNumberOrTag,
+        reward_percentiles: Option<&[f64]>,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<reth_rpc_types::FeeHistory> {
+        if let Some(percentiles) = reward_percentiles {
+            let in_range = percentiles
+                .iter()
+                .all(|percentile| (0.0..=100.0).contains(percentile));
+            let increasing = percentiles.windows(2).all(|pair| pair[0] <= pair[1]);
+            if !in_range || !increasing {
+                return Err(EthApiError::InvalidRewardPercentiles);
+            }
+        }
+
+        let latest = self.latest_block_number(working_set);
+        let newest_block = resolve_block_number(Some(newest_block), latest)?;
+        if newest_block > latest {
+            return Err(EthApiError::UnknownBlockNumber);
+        }
+
+        let block_count = block_count.min(MAX_FEE_HISTORY_BLOCK_COUNT);
+        if block_count == 0 {
+            return Ok(reth_rpc_types::FeeHistory {
+                base_fee_per_gas: Vec::new(),
+                gas_used_ratio: Vec::new(),
+                oldest_block: U256::ZERO,
+                reward: None,
+            });
+        }
+        let oldest_block = (newest_block + 1).saturating_sub(block_count);
+
+        let base_fee_params = self
+            .cfg
+            .get(working_set)
+            .unwrap_or_default()
+            .base_fee_params;
+        let mut accessory_state = working_set.accessory_state();
+
+        let mut base_fee_per_gas = Vec::new();
+        let mut gas_used_ratio = Vec::new();
+        let mut rewards = Vec::new();
+        let mut next_base_fee = None;
+        for block_number in oldest_block..=newest_block {
+            let block = self
+                .blocks
+                .get(block_number as usize, &mut accessory_state)
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+            let header = &block.header;
+
+            base_fee_per_gas.push(U256::from(header.base_fee_per_gas.unwrap_or_default()));
+            gas_used_ratio.push(if header.gas_limit == 0 {
+                0.0
+            } else {
+                header.gas_used as f64 / header.gas_limit as f64
+            });
+            next_base_fee = header.next_block_base_fee(base_fee_params);
+
+            if let Some(percentiles) = reward_percentiles {
+                let mut transactions: Vec<(u128, u64)> = block
+                    .transactions
+                    .clone()
+                    .map(|tx_number| {
+                        let tx = self
+                            .transactions
+                            .get(tx_number as usize, &mut accessory_state)
+                            .expect("Transaction of a sealed block must be set");
+                        let receipt = self
+                            .receipts
+                            .get(tx_number as usize, &mut accessory_state)
+                            .expect("Receipt of a sealed block's transaction must be set");
+                        let tip = tx
+                            .signed_transaction
+                            .effective_gas_tip(header.base_fee_per_gas)
+                            .unwrap_or_default();
+                        (tip, receipt.gas_used)
+                    })
+                    .collect();
+                rewards.push(reward_percentiles_of_block(
+                    &mut transactions,
+                    header.gas_used,
+                    percentiles,
+                ));
+            }
+        }
+        base_fee_per_gas.push(U256::from(next_base_fee.unwrap_or_default()));
+
+        Ok(reth_rpc_types::FeeHistory {
+            base_fee_per_gas,
+            gas_used_ratio,
+            oldest_block: U256::from(oldest_block),
+            reward: reward_percentiles.map(|_| rewards),
+        })
+    }
+
+    /// Resolves the blocks covered by `filter`, capped at the latest sealed block.
+    pub fn filter_block_range(
+        &self,
+        filter: &reth_rpc_types::Filter,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<RangeInclusive<u64>> {
+        let latest = self.latest_block_number(working_set);
+
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::AtBlockHash(block_hash) => {
+                let block_number = self
+                    .block_hashes
+                    .get(&block_hash, &mut working_set.accessory_state())
+                    .ok_or(EthApiError::UnknownBlockNumber)?;
+                (block_number, block_number)
+            }
+            FilterBlockOption::Range {
+                from_block,
+                to_block,
+            } => (
+                resolve_block_number(from_block, latest)?,
+                resolve_block_number(to_block, latest)?,
+            ),
+        };
+
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange);
+        }
+
+        Ok(from_block..=to_block.min(latest))
+    }
+
+    /// Returns the logs matching the address and topics of `filter` emitted in `block_range`.
+    ///
+    /// Blocks whose logs bloom can't contain a match are skipped without reading their receipts.
+    /// Queries spanning more than [`MAX_LOGS_BLOCK_RANGE`] blocks or matching more than
+    /// [`MAX_LOGS_PER_QUERY`] logs are rejected.
+    pub fn logs_in_block_range(
+        &self,
+        filter: &reth_rpc_types::Filter,
+        block_range: RangeInclusive<u64>,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<Vec<reth_rpc_types::Log>> {
+        if block_range.end().saturating_sub(*block_range.start()) >= MAX_LOGS_BLOCK_RANGE {
+            return Err(EthApiError::InvalidParams(format!(
+                "query exceeds the max block range of {}",
+                MAX_LOGS_BLOCK_RANGE
+            )));
+        }
+
+        let mut logs = Vec::new();
+        for block_number in block_range {
+            let block = self
                 .blocks
-                .last(&mut working_set.accessory_state())
-                .expect("Head block must be set"),
+                .get(block_number as usize, &mut working_set.accessory_state())
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+            self.push_block_logs(&block, filter, MAX_LOGS_PER_QUERY, &mut logs, working_set)?;
         }
+
+        Ok(logs)
     }
+
+    /// Returns every log emitted in the sealed block `block_number`, if any. Unlike
+    /// [`Evm::logs_in_block_range`], the number of logs isn't capped, since it is already bounded
+    /// by the gas limit of the block.
+    pub fn sealed_block_logs(
+        &self,
+        block_number: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Vec<reth_rpc_types::Log>> {
+        let block = self
+            .blocks
+            .get(block_number as usize, &mut working_set.accessory_state())?;
+
+        let mut logs = Vec::new();
+        // The default filter matches every log.
+        self.push_block_logs(
+            &block,
+            &reth_rpc_types::Filter::default(),
+            usize::MAX,
+            &mut logs,
+            working_set,
+        )
+        .expect("Logs of a single block are not capped");
+        Some(logs)
+    }
+
+    /// Appends the logs of `block` matching the address and topics of `filter` to `logs`. Fails if
+    /// `logs` would hold more than `max_logs` logs.
+    fn push_block_logs(
+        &self,
+        block: &SealedBlock,
+        filter: &reth_rpc_types::Filter,
+        max_logs: usize,
+        logs: &mut Vec<reth_rpc_types::Log>,
+        working_set: &mut WorkingSet<C>,
+    ) -> EthResult<()> {
+        let bloom = block.header.logs_bloom;
+        if !filter.address.to_bloom_filter().matches(bloom)
+            || !filter
+                .topics
+                .iter()
+                .all(|topic| topic.to_bloom_filter().matches(bloom))
+        {
+            return Ok(());
+        }
+
+        let mut accessory_state = working_set.accessory_state();
+        for tx_number in block.transactions.clone() {
+            let tx = self
+                .transactions
+                .get(tx_number as usize, &mut accessory_state)
+                .expect("Transaction of a sealed block must be set");
+            let receipt = self
+                .receipts
+                .get(tx_number as usize, &mut accessory_state)
+                .expect("Receipt of a sealed block's transaction must be set");
+
+            for (log_number, log) in receipt.receipt.logs.into_iter().enumerate() {
+                if !log_matches_filter(&log.address, &log.topics, filter) {
+                    continue;
+                }
+                if logs.len() == max_logs {
+                    return Err(EthApiError::InvalidParams(format!(
+                        "query returns more than {} logs, narrow down the block range",
+                        max_logs
+                    )));
+                }
+
+                logs.push(reth_rpc_types::Log {
+                    address: log.address,
+                    topics: log.topics,
+                    data: log.data,
+                    block_hash: Some(block.header.hash),
+                    block_number: Some(U256::from(block.header.number)),
+                    transaction_hash: Some(tx.signed_transaction.hash),
+                    transaction_index: Some(U256::from(tx_number - block.transactions.start)),
+                    log_index: Some(U256::from(receipt.log_index_start + log_number as u64)),
+                    removed: false,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates the inspector collecting what the tracer requested in `opts` needs.
+fn tracing_inspector(opts: &GethDebugTracingOptions) -> EthResult<TracingInspector> {
+    match opts.tracer {
+        None | Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer)) => {
+            Ok(TracingInspector::new(
+                TracingInspectorConfig::from_geth_config(&opts.config),
+            ))
+        }
+        Some(_) => Err(EthApiError::Unsupported(
+            "only the default struct logger and callTracer are supported",
+        )),
+    }
+}
+
+/// Builds the trace requested in `opts` from an `inspector` that traced an execution.
+fn geth_trace(
+    inspector: TracingInspector,
+    opts: &GethDebugTracingOptions,
+    result: ExecutionResult,
+) -> EthResult<GethTrace> {
+    let gas_used = result.gas_used();
+    match opts.tracer {
+        Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer)) => {
+            let call_config = opts
+                .tracer_config
+                .clone()
+                .into_call_config()
+                .map_err(|_| EthApiError::InvalidTracerConfig)?;
+            let frame = inspector
+                .into_geth_builder()
+                .geth_call_traces(call_config, gas_used);
+            Ok(GethTrace::CallTracer(frame))
+        }
+        _ => {
+            let return_value = result.into_output().unwrap_or_default().into();
+            let frame = inspector.into_geth_builder().geth_traces(
+                gas_used,
+                return_value,
+                opts.config.clone(),
+            );
+            Ok(GethTrace::Default(frame))
+        }
+    }
+}
+
+/// Returns the tip paid at each of `percentiles` of the gas used in a block. `transactions` are the
+/// effective tip and gas used of every transaction of the block.
+///
+/// As in geth, the transactions are sorted by tip and each percentile is weighted by gas used.
+fn reward_percentiles_of_block(
+    transactions: &mut [(u128, u64)],
+    block_gas_used: u64,
+    percentiles: &[f64],
+) -> Vec<U256> {
+    if transactions.is_empty() {
+        return vec![U256::ZERO; percentiles.len()];
+    }
+    transactions.sort_unstable_by_key(|(tip, _)| *tip);
+
+    let mut rewards = Vec::with_capacity(percentiles.len());
+    let mut tx_index = 0;
+    let mut cumulative_gas_used = transactions[0].1;
+    for percentile in percentiles {
+        let threshold = (block_gas_used as f64 * percentile / 100.0) as u64;
+        while cumulative_gas_used < threshold && tx_index < transactions.len() - 1 {
+            tx_index += 1;
+            cumulative_gas_used += transactions[tx_index].1;
+        }
+        rewards.push(U256::from(transactions[tx_index].0));
+    }
+    rewards
+}
+
+fn resolve_block_number(block: Option<BlockNumberOrTag>, latest: u64) -> EthResult<u64> {
+    match block {
+        Some(BlockNumberOrTag::Number(block_number)) => Ok(block_number),
+        Some(BlockNumberOrTag::Earliest) => Ok(0),
+        Some(BlockNumberOrTag::Latest) | Some(BlockNumberOrTag::Pending) | None => Ok(latest),
+        // safe and finalized are not supported
+        Some(BlockNumberOrTag::Safe) | Some(BlockNumberOrTag::Finalized) => {
+            Err(EthApiError::UnknownSafeOrFinalizedBlock)
+        }
+    }
+}
+
+/// Returns `true` if a log emitted by `address` with `topics` matches the address and topics of
+/// `filter`. The block range of the filter is ignored.
+pub fn log_matches_filter(
+    address: &reth_primitives::Address,
+    topics: &[reth_primitives::H256],
+    filter: &reth_rpc_types::Filter,
+) -> bool {
+    if !filter.address.matches(address) {
+        return false;
+    }
+
+    // An empty topic set matches anything, but a log without the topic can't match a non-empty one.
+    filter
+        .topics
+        .iter()
+        .enumerate()
+        .all(|(index, topic_filter)| match topics.get(index) {
+            Some(topic) => topic_filter.matches(topic),
+            None => topic_filter.is_empty(),
+        })
 }
 
 fn get_cfg_env_template() -> revm::primitives::CfgEnv {
@@ -669,15 +1424,16 @@ pub(crate) fn build_rpc_receipt(
     }
 }
 
-fn map_out_of_gas_err<C: sov_modules_api::Context>(
+fn map_out_of_gas_err<C: sov_modules_api::Context, R: EvmRuntime<C>>(
     block_env: BlockEnv,
     mut tx_env: revm::primitives::TxEnv,
     cfg_env: revm::primitives::CfgEnv,
     db: EvmDb<'_, C>,
+    module_precompile: ModulePrecompile<'_, C, R>,
 ) -> EthApiError {
     let req_gas_limit = tx_env.gas_limit;
     tx_env.gas_limit = block_env.gas_limit;
-    let res = executor::inspect(db, &block_env, tx_env, cfg_env).unwrap();
+    let res = executor::inspect(db, &block_env, tx_env, cfg_env, module_precompile).unwrap();
     match res.result {
         ExecutionResult::Success { .. } => {
             // transaction succeeded by manually increasing the gas limit to
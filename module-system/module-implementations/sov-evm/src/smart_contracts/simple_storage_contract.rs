@@ -65,6 +65,15 @@ impl SimpleStorageContract {
         self.base_contract.encode("get", ()).unwrap()
     }
 
+    /// Topic of the `NumberSet` event, emitted by the setter.
+    pub fn number_set_topic(&self) -> ethereum_types::H256 {
+        self.base_contract
+            .abi()
+            .event("NumberSet")
+            .unwrap()
+            .signature()
+    }
+
     /// Failing call data to test revert.
     pub fn failing_function_call_data(&self) -> Bytes {
         // Some random function signature.
@@ -0,0 +1,157 @@
+use reth_primitives::{Address, BlockId, BlockNumberOrTag, Bytes, TransactionKind, H256};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{Module, StateMapAccessor, StateVecAccessor, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::utils::{apply_block, commit, set_message};
+use crate::{AccountData, Evm, EvmConfig};
+
+type C = DefaultContext;
+
+#[test]
+fn historical_state_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let evm = Evm::<C>::default();
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    evm.finalize_hook(&[10u8; 32].into(), &mut working_set.accessory_state());
+    commit(working_set, storage.clone());
+
+    let contract = SimpleStorageContract::default();
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+
+    let create_contract_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Create, contract.byte_code().to_vec(), 0)
+            .unwrap(),
+    };
+    apply_block(
+        &evm,
+        vec![
+            create_contract_message,
+            set_message(&dev_signer, contract_addr, 1, 5),
+        ],
+        &storage,
+    );
+    apply_block(
+        &evm,
+        vec![set_message(&dev_signer, contract_addr, 2, 7)],
+        &storage,
+    );
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    let block_hash = |block_number: usize, working_set: &mut WorkingSet<C>| -> H256 {
+        evm.blocks
+            .get(block_number, &mut working_set.accessory_state())
+            .unwrap()
+            .header
+            .hash
+    };
+
+    // Each block records the version of the state committed with it.
+    let versions: Vec<u64> = (0..3)
+        .map(|block_number| {
+            evm.block_state_versions
+                .get(&block_number, &mut working_set.accessory_state())
+                .unwrap()
+        })
+        .collect();
+    assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let genesis = Some(BlockId::Number(BlockNumberOrTag::Earliest));
+    let first_block = Some(BlockId::from(block_hash(1, working_set)));
+    let latest = Some(BlockId::Number(BlockNumberOrTag::Latest));
+
+    // The contract doesn't exist before it was deployed.
+    assert_eq!(
+        Bytes::default(),
+        evm.get_code(contract_addr, genesis, working_set).unwrap()
+    );
+    assert_eq!(
+        evm.get_code(contract_addr, first_block, working_set)
+            .unwrap(),
+        evm.get_code(contract_addr, latest, working_set).unwrap()
+    );
+
+    for (block_id, nonce, value) in [(first_block, 2, 5), (latest, 3, 7), (None, 3, 7)] {
+        assert_eq!(
+            nonce,
+            evm.get_transaction_count(dev_signer.address(), block_id, working_set)
+                .unwrap()
+                .as_u64()
+        );
+        assert_eq!(
+            U256::from(value),
+            evm.get_storage_at(contract_addr, U256::ZERO, block_id, working_set)
+                .unwrap()
+        );
+
+        let get_request = CallRequest {
+            from: Some(dev_signer.address()),
+            to: Some(contract_addr),
+            input: CallInput {
+                input: Some(contract.get_call_data().to_vec().into()),
+                data: None,
+            },
+            ..Default::default()
+        };
+        let output = evm
+            .get_call(get_request, block_id, None, None, working_set)
+            .unwrap();
+        assert_eq!(U256::from(value), U256::from_be_slice(&output));
+    }
+
+    // Fees paid after the first block don't show in its balance.
+    let genesis_balance = evm
+        .get_balance(dev_signer.address(), genesis, working_set)
+        .unwrap();
+    let first_block_balance = evm
+        .get_balance(dev_signer.address(), first_block, working_set)
+        .unwrap();
+    let latest_balance = evm
+        .get_balance(dev_signer.address(), latest, working_set)
+        .unwrap();
+    assert_eq!(U256::from(1000000000), genesis_balance);
+    assert!(first_block_balance < genesis_balance);
+    assert!(latest_balance < first_block_balance);
+
+    // Unknown blocks are rejected.
+    assert!(evm
+        .get_balance(
+            dev_signer.address(),
+            Some(BlockId::Number(BlockNumberOrTag::Number(3))),
+            working_set
+        )
+        .is_err());
+    assert!(evm
+        .get_balance(
+            dev_signer.address(),
+            Some(BlockId::from(H256::zero())),
+            working_set
+        )
+        .is_err());
+}
@@ -1,4 +1,4 @@
-use reth_primitives::{Address, Bytes, TransactionKind};
+use reth_primitives::{Address, Bytes, TransactionKind, H256};
 use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
@@ -62,6 +62,12 @@ fn call_test() {
         .unwrap();
 
     assert_eq!(U256::from(set_arg), storage_value);
+
+    let number_set_topic = H256::from_slice(
+        SimpleStorageContract::default()
+            .number_set_topic()
+            .as_bytes(),
+    );
     assert_eq!(
         evm.receipts
             .iter(&mut working_set.accessory_state())
@@ -71,10 +77,10 @@ fn call_test() {
                 receipt: reth_primitives::Receipt {
                     tx_type: reth_primitives::TxType::EIP1559,
                     success: true,
-                    cumulative_gas_used: 132943,
+                    cumulative_gas_used: 142887,
                     logs: vec![]
                 },
-                gas_used: 132943,
+                gas_used: 142887,
                 log_index_start: 0,
                 error: None
             },
@@ -82,10 +88,14 @@ fn call_test() {
                 receipt: reth_primitives::Receipt {
                     tx_type: reth_primitives::TxType::EIP1559,
                     success: true,
-                    cumulative_gas_used: 176673,
-                    logs: vec![]
+                    cumulative_gas_used: 187764,
+                    logs: vec![reth_primitives::Log {
+                        address: contract_addr,
+                        topics: vec![number_set_topic, H256::from_low_u64_be(set_arg as u64)],
+                        data: Bytes::default(),
+                    }]
                 },
-                gas_used: 43730,
+                gas_used: 44877,
                 log_index_start: 0,
                 error: None
             }
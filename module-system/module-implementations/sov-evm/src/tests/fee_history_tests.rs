@@ -0,0 +1,130 @@
+use reth_primitives::{Address, BlockNumberOrTag, Bytes, TransactionKind, U64};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::StateVecAccessor;
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::genesis_tests::get_evm;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::utils::{apply_block_in_working_set, set_message_with_priority_fee};
+use crate::{AccountData, EvmConfig};
+
+#[test]
+fn fee_history_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+    let (evm, mut working_set) = get_evm(&config);
+    let working_set = &mut working_set;
+
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+
+    let create_contract_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(
+                TransactionKind::Create,
+                SimpleStorageContract::default().byte_code().to_vec(),
+                0,
+            )
+            .unwrap(),
+    };
+    apply_block_in_working_set(
+        &evm,
+        vec![
+            create_contract_message,
+            set_message_with_priority_fee(&dev_signer, contract_addr, 1, 1, 1),
+        ],
+        working_set,
+    );
+    apply_block_in_working_set(
+        &evm,
+        vec![
+            set_message_with_priority_fee(&dev_signer, contract_addr, 2, 2, 3),
+            set_message_with_priority_fee(&dev_signer, contract_addr, 3, 3, 2),
+        ],
+        working_set,
+    );
+    // A block without any transaction.
+    apply_block_in_working_set(&evm, vec![], working_set);
+
+    let fee_history = evm
+        .fee_history(
+            U64::from(3),
+            BlockNumberOrTag::Latest,
+            Some(vec![0.0, 50.0, 100.0]),
+            working_set,
+        )
+        .unwrap();
+
+    assert_eq!(U256::from(1), fee_history.oldest_block);
+
+    // The base fee of every block, followed by the base fee of the next block.
+    assert_eq!(4, fee_history.base_fee_per_gas.len());
+    for (block_number, base_fee) in (1..=3).zip(&fee_history.base_fee_per_gas) {
+        let header = evm
+            .blocks
+            .get(block_number, &mut working_set.accessory_state())
+            .unwrap()
+            .header;
+        assert_eq!(U256::from(header.base_fee_per_gas.unwrap()), *base_fee);
+    }
+
+    assert_eq!(3, fee_history.gas_used_ratio.len());
+    assert!(fee_history.gas_used_ratio[0] > 0.0);
+    assert!(fee_history.gas_used_ratio[0] > fee_history.gas_used_ratio[1]);
+    assert_eq!(0.0, fee_history.gas_used_ratio[2]);
+
+    // The deployment uses most of the gas of the first block, without any tip.
+    let rewards = fee_history.reward.unwrap();
+    assert_eq!(
+        vec![U256::from(0), U256::from(0), U256::from(1)],
+        rewards[0]
+    );
+    assert_eq!(U256::from(2), rewards[1][0]);
+    assert_eq!(U256::from(3), rewards[1][2]);
+    assert_eq!(vec![U256::ZERO; 3], rewards[2]);
+
+    // The window is capped at the genesis block.
+    let fee_history = evm
+        .fee_history(
+            U64::from(10),
+            BlockNumberOrTag::Number(1),
+            None,
+            working_set,
+        )
+        .unwrap();
+    assert_eq!(U256::ZERO, fee_history.oldest_block);
+    assert_eq!(3, fee_history.base_fee_per_gas.len());
+    assert!(fee_history.reward.is_none());
+
+    let fee_history = evm
+        .fee_history(U64::from(0), BlockNumberOrTag::Latest, None, working_set)
+        .unwrap();
+    assert!(fee_history.base_fee_per_gas.is_empty());
+
+    // Percentiles must be increasing and at most 100.
+    for percentiles in [vec![50.0, 10.0], vec![101.0]] {
+        let result = evm.fee_history(
+            U64::from(3),
+            BlockNumberOrTag::Latest,
+            Some(percentiles),
+            working_set,
+        );
+        assert!(result.is_err());
+    }
+    let result = evm.fee_history(U64::from(3), BlockNumberOrTag::Number(4), None, working_set);
+    assert!(result.is_err());
+}
@@ -0,0 +1,152 @@
+use reth_primitives::{Address, Bytes, TransactionKind, H256};
+use reth_rpc_types::Filter;
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{StateVecAccessor, WorkingSet};
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::genesis_tests::get_evm;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::utils::{apply_block_in_working_set, set_message};
+use crate::{AccountData, EvmConfig, MAX_LOGS_BLOCK_RANGE};
+
+type C = DefaultContext;
+
+#[test]
+fn get_logs_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+    let (evm, mut working_set) = get_evm(&config);
+    let working_set = &mut working_set;
+
+    let contract = SimpleStorageContract::default();
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+    let number_set_topic = H256::from_slice(contract.number_set_topic().as_bytes());
+
+    let create_contract_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Create, contract.byte_code().to_vec(), 0)
+            .unwrap(),
+    };
+    apply_block_in_working_set(
+        &evm,
+        vec![
+            create_contract_message,
+            set_message(&dev_signer, contract_addr, 1, 1),
+        ],
+        working_set,
+    );
+    apply_block_in_working_set(
+        &evm,
+        vec![
+            set_message(&dev_signer, contract_addr, 2, 2),
+            set_message(&dev_signer, contract_addr, 3, 3),
+        ],
+        working_set,
+    );
+    // A block without any transaction.
+    apply_block_in_working_set(&evm, vec![], working_set);
+
+    let get_logs = |filter: Filter, working_set: &mut WorkingSet<C>| {
+        evm.eth_get_logs(filter, working_set).unwrap()
+    };
+
+    // All the events of the contract.
+    let logs = get_logs(
+        Filter::new()
+            .from_block(0)
+            .to_block(3)
+            .address(contract_addr),
+        working_set,
+    );
+    assert_eq!(3, logs.len());
+    for (log, set_arg) in logs.iter().zip(1u64..) {
+        assert_eq!(contract_addr, log.address);
+        assert_eq!(
+            vec![number_set_topic, H256::from_low_u64_be(set_arg)],
+            log.topics
+        );
+    }
+    assert_eq!(Some(U256::from(1)), logs[0].block_number);
+    assert_eq!(Some(U256::from(1)), logs[0].transaction_index);
+    assert_eq!(Some(U256::from(0)), logs[0].log_index);
+    assert_eq!(Some(U256::from(2)), logs[2].block_number);
+    assert_eq!(Some(U256::from(1)), logs[2].transaction_index);
+    assert_eq!(Some(U256::from(1)), logs[2].log_index);
+
+    // Topic filtering.
+    let logs = get_logs(
+        Filter::new()
+            .from_block(0)
+            .topic0(number_set_topic)
+            .topic1(H256::from_low_u64_be(2)),
+        working_set,
+    );
+    assert_eq!(1, logs.len());
+    assert_eq!(Some(U256::from(2)), logs[0].block_number);
+    assert_eq!(Some(U256::from(0)), logs[0].transaction_index);
+
+    // Address filtering.
+    let logs = get_logs(
+        Filter::new()
+            .from_block(0)
+            .address(Address::from_low_u64_be(1)),
+        working_set,
+    );
+    assert!(logs.is_empty());
+
+    // Block range filtering, defaulting to the latest block.
+    let logs = get_logs(Filter::new().from_block(2), working_set);
+    assert_eq!(2, logs.len());
+    let logs = get_logs(Filter::new(), working_set);
+    assert!(logs.is_empty());
+
+    // Block hash filtering.
+    let block_1_hash = evm
+        .blocks
+        .get(1, &mut working_set.accessory_state())
+        .unwrap()
+        .header
+        .hash;
+    assert_eq!(
+        Some(block_1_hash),
+        evm.sealed_block_header(1, working_set)
+            .and_then(|header| header.hash)
+    );
+    assert!(evm.sealed_block_header(4, working_set).is_none());
+
+    // Every log of a sealed block.
+    let block_logs = evm.sealed_block_logs(2, working_set).unwrap();
+    assert_eq!(
+        get_logs(Filter::new().from_block(2).to_block(2), working_set),
+        block_logs
+    );
+    assert_eq!(Some(vec![]), evm.sealed_block_logs(3, working_set));
+    assert!(evm.sealed_block_logs(4, working_set).is_none());
+    let logs = get_logs(Filter::new().at_block_hash(block_1_hash), working_set);
+    assert_eq!(1, logs.len());
+    assert_eq!(Some(block_1_hash), logs[0].block_hash);
+
+    // Invalid range.
+    let result = evm.eth_get_logs(Filter::new().from_block(2).to_block(1), working_set);
+    assert!(result.is_err());
+
+    // Ranges wider than the limit are rejected before any block is read.
+    let result = evm.logs_in_block_range(&Filter::new(), 0..=MAX_LOGS_BLOCK_RANGE, working_set);
+    assert!(result.is_err());
+}
@@ -1,6 +1,13 @@
+mod archival_tests;
 mod call_tests;
 mod cfg_tests;
+mod fee_history_tests;
 mod genesis_tests;
 mod hooks_tests;
+mod log_filter_tests;
+mod module_precompile_tests;
 pub(crate) mod test_signer;
+mod tracing_tests;
 mod tx_tests;
+mod tx_type_tests;
+mod utils;
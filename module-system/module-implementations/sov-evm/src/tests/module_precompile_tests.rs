@@ -0,0 +1,328 @@
+use ethers_core::abi::{encode, Token};
+use ethers_core::utils::id;
+use reth_primitives::contract::create_address;
+use reth_primitives::{Address, BlockNumberOrTag, Bytes, TransactionKind};
+use reth_rpc_types::trace::geth::{GethDebugTracingCallOptions, GethTrace};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_bank::{get_genesis_token_address, Amount, BankConfig, TokenConfig};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::macros::DefaultRuntime;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{
+    Context, DispatchCall, EncodeCall, MessageCodec, Module, StateMapAccessor, StateVecAccessor,
+    WorkingSet,
+};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::call::CallMessage;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::tracing_tests::call_tracer_options;
+use crate::tests::utils::{apply_block, commit};
+use crate::{
+    module_address_of, AccountData, Evm, EvmConfig, MODULE_CALL_GAS, MODULE_PRECOMPILE_ADDRESS,
+};
+
+type C = DefaultContext;
+
+/// A runtime through which the EVM contracts call the bank.
+#[derive(DispatchCall, MessageCodec, DefaultRuntime)]
+#[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
+struct TestRuntime<C: Context> {
+    bank: sov_bank::Bank<C>,
+    evm: Evm<C, TestRuntime<C>>,
+}
+
+type TestEvm = Evm<C, TestRuntime<C>>;
+
+const INITIAL_BALANCE: Amount = 1000;
+
+/// Forwards its calldata to the module precompile, then reverts.
+const REVERTING_FORWARDER_BYTE_CODE: &str = concat!(
+    // Constructor: returns the 26 bytes of runtime code that follow it.
+    "601a600c600039601a6000f3",
+    // CALLDATACOPY(0, 0, CALLDATASIZE)
+    "3660006000",
+    "37",
+    // CALL(GAS, 0x0100, 0, 0, CALLDATASIZE, 0, 0)
+    "6000600036600060006101005a",
+    "f150",
+    // REVERT(0, 0)
+    "60006000fd",
+);
+
+fn call_data(message: Vec<u8>) -> Vec<u8> {
+    let mut data = id("call(bytes)").to_vec();
+    data.extend(encode(&[Token::Bytes(message)]));
+    data
+}
+
+fn transfer_call_data(
+    token: &<C as sov_modules_api::Spec>::Address,
+    to: &<C as sov_modules_api::Spec>::Address,
+    amount: Amount,
+) -> Vec<u8> {
+    call_data(
+        <TestRuntime<C> as EncodeCall<sov_bank::Bank<C>>>::encode_call(
+            sov_bank::CallMessage::Transfer {
+                to: *to,
+                coins: sov_bank::Coins {
+                    amount,
+                    token_address: *token,
+                },
+            },
+        ),
+    )
+}
+
+fn balance_of_request(
+    token: &<C as sov_modules_api::Spec>::Address,
+    user: &<C as sov_modules_api::Spec>::Address,
+) -> CallRequest {
+    let mut data = id("balanceOf(bytes32,bytes32)").to_vec();
+    data.extend(encode(&[
+        Token::FixedBytes(token.as_ref().to_vec()),
+        Token::FixedBytes(user.as_ref().to_vec()),
+    ]));
+
+    CallRequest {
+        to: Some(MODULE_PRECOMPILE_ADDRESS),
+        input: CallInput {
+            input: Some(data.into()),
+            data: None,
+        },
+        ..Default::default()
+    }
+}
+
+fn balance_of(
+    evm: &TestEvm,
+    token: &<C as sov_modules_api::Spec>::Address,
+    user: &<C as sov_modules_api::Spec>::Address,
+    working_set: &mut WorkingSet<C>,
+) -> U256 {
+    let output = evm
+        .get_call(
+            balance_of_request(token, user),
+            None,
+            None,
+            None,
+            working_set,
+        )
+        .unwrap();
+    U256::from_be_slice(&output)
+}
+
+#[test]
+fn module_precompile_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let forwarder_addr: Address = create_address(dev_signer.address(), 0);
+    let signer_module_address = module_address_of::<C>(&dev_signer.address());
+    let forwarder_module_address = module_address_of::<C>(&forwarder_addr);
+    let receiver = generate_address::<C>("receiver");
+
+    let bank_config = BankConfig::<C> {
+        tokens: vec![TokenConfig {
+            token_name: "evm-token".to_owned(),
+            symbol: "EVM".to_owned(),
+            decimals: 0,
+            max_supply: None,
+            address_and_balances: vec![
+                (signer_module_address, INITIAL_BALANCE),
+                (forwarder_module_address, INITIAL_BALANCE),
+            ],
+            authorized_minters: vec![],
+            salt: 0,
+        }],
+    };
+    let token = get_genesis_token_address::<C>("evm-token", 0);
+
+    let runtime = TestRuntime::<C>::default();
+    let evm = &runtime.evm;
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    runtime
+        .bank
+        .genesis(&bank_config, &mut working_set)
+        .unwrap();
+    evm.finalize_hook(&[10u8; 32].into(), &mut working_set.accessory_state());
+    commit(working_set, storage.clone());
+
+    // The signer deploys the forwarder, then transfers tokens by calling the precompile
+    // directly.
+    let deploy_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(
+                TransactionKind::Create,
+                hex::decode(REVERTING_FORWARDER_BYTE_CODE).unwrap(),
+                0,
+            )
+            .unwrap(),
+    };
+    let transfer_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(
+                TransactionKind::Call(MODULE_PRECOMPILE_ADDRESS),
+                transfer_call_data(&token, &receiver, 10),
+                1,
+            )
+            .unwrap(),
+    };
+    apply_block(evm, vec![deploy_message, transfer_message], &storage);
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    assert_eq!(
+        Some(INITIAL_BALANCE - 10),
+        runtime
+            .bank
+            .get_balance_of(signer_module_address, token, working_set)
+    );
+    assert_eq!(
+        Some(10),
+        runtime.bank.get_balance_of(receiver, token, working_set)
+    );
+
+    let receipts: Vec<_> = evm
+        .receipts
+        .iter(&mut working_set.accessory_state())
+        .collect();
+    assert_eq!(2, receipts.len());
+    assert!(receipts[1].receipt.success);
+    assert_eq!(1, receipts[1].receipt.logs.len());
+    assert_eq!(
+        MODULE_PRECOMPILE_ADDRESS,
+        receipts[1].receipt.logs[0].address
+    );
+
+    // Queries are served by `eth_call`.
+    assert_eq!(
+        U256::from(10),
+        balance_of(evm, &token, &receiver, working_set)
+    );
+    assert_eq!(
+        U256::from(INITIAL_BALANCE),
+        balance_of(evm, &token, &forwarder_module_address, working_set)
+    );
+
+    // The EVM can't be re-entered through the precompile.
+    let evm_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Call(forwarder_addr), vec![], 2)
+            .unwrap(),
+    };
+    let request = CallRequest {
+        from: Some(dev_signer.address()),
+        to: Some(MODULE_PRECOMPILE_ADDRESS),
+        input: CallInput {
+            input: Some(
+                call_data(<TestRuntime<C> as EncodeCall<TestEvm>>::encode_call(
+                    evm_message,
+                ))
+                .into(),
+            ),
+            data: None,
+        },
+        ..Default::default()
+    };
+    assert!(evm
+        .get_call(request, None, None, None, working_set)
+        .is_err());
+
+    // The module calls are replayed when the block is traced.
+    let traces = evm
+        .debug_trace_block_by_number(
+            BlockNumberOrTag::Number(1),
+            Some(call_tracer_options()),
+            working_set,
+        )
+        .unwrap();
+    assert_eq!(2, traces.len());
+    let GethTrace::CallTracer(ref transfer_frame) = traces[1].result else {
+        panic!("Expected a call trace");
+    };
+    assert_eq!(Some(MODULE_PRECOMPILE_ADDRESS), transfer_frame.to);
+    assert!(transfer_frame.error.is_none());
+
+    let trace = evm
+        .debug_trace_call(
+            balance_of_request(&token, &receiver),
+            Some(BlockNumberOrTag::Number(1)),
+            Some(GethDebugTracingCallOptions {
+                tracing_options: call_tracer_options(),
+                ..Default::default()
+            }),
+            working_set,
+        )
+        .unwrap();
+    let GethTrace::CallTracer(frame) = trace else {
+        panic!("Expected a call trace");
+    };
+    assert_eq!(U256::from(10), U256::from_be_slice(&frame.output.unwrap()));
+
+    // A transaction reverting a frame that called the modules is reverted as a whole, but its
+    // sender still pays for the gas used.
+    let context = C::new(
+        generate_address::<C>("sender"),
+        generate_address::<C>("sequencer"),
+        2,
+    );
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    let mut working_set = working_set.checkpoint().to_revertable();
+    let sender_before = evm
+        .accounts
+        .get(&dev_signer.address(), &mut working_set)
+        .unwrap()
+        .info;
+    let forwarded_transfer_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(
+                TransactionKind::Call(forwarder_addr),
+                transfer_call_data(&token, &receiver, 10),
+                2,
+            )
+            .unwrap(),
+    };
+    evm.call(forwarded_transfer_message, &context, &mut working_set)
+        .unwrap();
+
+    let sender_after = evm
+        .accounts
+        .get(&dev_signer.address(), &mut working_set)
+        .unwrap()
+        .info;
+    assert_eq!(sender_before.nonce + 1, sender_after.nonce);
+    assert!(sender_after.balance < sender_before.balance);
+    assert_eq!(
+        Some(INITIAL_BALANCE),
+        runtime
+            .bank
+            .get_balance_of(forwarder_module_address, token, &mut working_set)
+    );
+    assert_eq!(
+        Some(10),
+        runtime
+            .bank
+            .get_balance_of(receiver, token, &mut working_set)
+    );
+
+    let reverted = evm.pending_transactions.last(&mut working_set).unwrap();
+    assert!(!reverted.receipt.receipt.success);
+    assert!(reverted.receipt.receipt.logs.is_empty());
+    assert!(reverted.receipt.gas_used > MODULE_CALL_GAS);
+}
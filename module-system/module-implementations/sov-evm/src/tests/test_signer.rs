@@ -1,8 +1,8 @@
 use ethers_core::rand::rngs::StdRng;
 use ethers_core::rand::SeedableRng;
 use reth_primitives::{
-    Address, Bytes as RethBytes, Transaction as RethTransaction, TransactionKind,
-    TxEip1559 as RethTxEip1559,
+    AccessList, Address, Bytes as RethBytes, Transaction as RethTransaction, TransactionKind,
+    TxEip1559 as RethTxEip1559, TxEip2930 as RethTxEip2930,
 };
 use secp256k1::{PublicKey, SecretKey};
 
@@ -44,6 +44,17 @@ impl TestSigner {
         to: TransactionKind,
         data: Vec<u8>,
         nonce: u64,
+    ) -> Result<RlpEvmTransaction, SignError> {
+        self.sign_transaction_with_priority_fee(to, data, nonce, 0)
+    }
+
+    /// Signs default Eip1559 transaction with to, data, nonce and priority fee overridden.
+    pub(crate) fn sign_transaction_with_priority_fee(
+        &self,
+        to: TransactionKind,
+        data: Vec<u8>,
+        nonce: u64,
+        max_priority_fee_per_gas: u128,
     ) -> Result<RlpEvmTransaction, SignError> {
         let reth_tx = RethTxEip1559 {
             to,
@@ -52,11 +63,41 @@ impl TestSigner {
             chain_id: 1,
             gas_limit: 1_000_000u64,
             max_fee_per_gas: u128::from(reth_primitives::constants::MIN_PROTOCOL_BASE_FEE * 2),
+            max_priority_fee_per_gas,
+            ..Default::default()
+        };
+
+        self.sign_transaction(RethTransaction::Eip1559(reth_tx))
+    }
+
+    /// Signs default Eip2930 transaction with to, data, nonce and access list overridden.
+    pub(crate) fn sign_access_list_transaction(
+        &self,
+        to: TransactionKind,
+        data: Vec<u8>,
+        nonce: u64,
+        access_list: AccessList,
+    ) -> Result<RlpEvmTransaction, SignError> {
+        let reth_tx = RethTxEip2930 {
+            to,
+            input: RethBytes::from(data),
+            nonce,
+            chain_id: 1,
+            gas_limit: 1_000_000u64,
+            gas_price: u128::from(reth_primitives::constants::MIN_PROTOCOL_BASE_FEE * 2),
+            access_list,
             ..Default::default()
         };
 
-        let reth_tx = RethTransaction::Eip1559(reth_tx);
-        let signed = self.signer.sign_transaction(reth_tx, self.address)?;
+        self.sign_transaction(RethTransaction::Eip2930(reth_tx))
+    }
+
+    /// Signs an arbitrary transaction.
+    pub(crate) fn sign_transaction(
+        &self,
+        transaction: RethTransaction,
+    ) -> Result<RlpEvmTransaction, SignError> {
+        let signed = self.signer.sign_transaction(transaction, self.address)?;
 
         Ok(RlpEvmTransaction {
             rlp: signed.envelope_encoded().to_vec(),
@@ -0,0 +1,163 @@
+use reth_primitives::{Address, BlockNumberOrTag, Bytes, TransactionKind, H256};
+use reth_rpc_types::trace::geth::{
+    GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace,
+};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{Module, StateVecAccessor, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::utils::{apply_block, commit, set_message};
+use crate::{AccountData, Evm, EvmConfig};
+
+type C = DefaultContext;
+
+pub(crate) fn call_tracer_options() -> GethDebugTracingOptions {
+    GethDebugTracingOptions {
+        tracer: Some(GethDebugTracerType::BuiltInTracer(
+            GethDebugBuiltInTracerType::CallTracer,
+        )),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn debug_trace_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let evm = Evm::<C>::default();
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    evm.finalize_hook(&[10u8; 32].into(), &mut working_set.accessory_state());
+    commit(working_set, storage.clone());
+
+    let contract = SimpleStorageContract::default();
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+
+    let create_contract_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Create, contract.byte_code().to_vec(), 0)
+            .unwrap(),
+    };
+    apply_block(
+        &evm,
+        vec![
+            create_contract_message,
+            set_message(&dev_signer, contract_addr, 1, 5),
+        ],
+        &storage,
+    );
+    apply_block(
+        &evm,
+        vec![set_message(&dev_signer, contract_addr, 2, 7)],
+        &storage,
+    );
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    let tx_hash = |tx_number: usize, working_set: &mut WorkingSet<C>| -> H256 {
+        evm.transactions
+            .get(tx_number, &mut working_set.accessory_state())
+            .unwrap()
+            .signed_transaction
+            .hash
+    };
+
+    // The call tracer reports the call to the contract.
+    let set_7_hash = tx_hash(2, working_set);
+    let trace = evm
+        .debug_trace_transaction(set_7_hash, Some(call_tracer_options()), working_set)
+        .unwrap();
+    let GethTrace::CallTracer(frame) = trace else {
+        panic!("Expected a call trace");
+    };
+    assert_eq!(Some(contract_addr), frame.to);
+    assert_eq!(dev_signer.address(), frame.from);
+    assert_eq!(contract.set_call_data(7).to_vec(), frame.input.to_vec());
+    assert!(frame.error.is_none());
+
+    // The struct logger reports the executed opcodes.
+    let trace = evm
+        .debug_trace_transaction(set_7_hash, None, working_set)
+        .unwrap();
+    let GethTrace::Default(frame) = trace else {
+        panic!("Expected struct logs");
+    };
+    assert!(!frame.failed);
+    assert!(frame.struct_logs.iter().any(|log| log.op == "SSTORE"));
+    assert!(frame.struct_logs.iter().any(|log| log.op == "LOG2"));
+
+    // Every transaction of the block is traced.
+    let traces = evm
+        .debug_trace_block_by_number(
+            BlockNumberOrTag::Number(1),
+            Some(call_tracer_options()),
+            working_set,
+        )
+        .unwrap();
+    assert_eq!(2, traces.len());
+    assert_eq!(tx_hash(0, working_set), traces[0].tx_hash);
+    assert_eq!(tx_hash(1, working_set), traces[1].tx_hash);
+    let GethTrace::CallTracer(ref deploy_frame) = traces[0].result else {
+        panic!("Expected a call trace");
+    };
+    assert_eq!("CREATE", deploy_frame.typ);
+
+    // Calls are executed on top of the state of the requested block.
+    let get_request = CallRequest {
+        from: Some(dev_signer.address()),
+        to: Some(contract_addr),
+        input: CallInput {
+            input: Some(contract.get_call_data().to_vec().into()),
+            data: None,
+        },
+        ..Default::default()
+    };
+    for (block_number, value) in [
+        (BlockNumberOrTag::Number(1), 5),
+        (BlockNumberOrTag::Latest, 7),
+    ] {
+        let trace = evm
+            .debug_trace_call(
+                get_request.clone(),
+                Some(block_number),
+                Some(GethDebugTracingCallOptions {
+                    tracing_options: call_tracer_options(),
+                    ..Default::default()
+                }),
+                working_set,
+            )
+            .unwrap();
+        let GethTrace::CallTracer(frame) = trace else {
+            panic!("Expected a call trace");
+        };
+        let output = frame.output.unwrap();
+        assert_eq!(U256::from(value), U256::from_be_slice(&output));
+    }
+
+    // Unknown transactions can't be traced.
+    let result = evm.debug_trace_transaction(H256::zero(), None, working_set);
+    assert!(result.is_err());
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use reth_primitives::contract::create_address;
+use reth_primitives::{
+    keccak256, AccessList, AccessListItem, Address, Bytes, Transaction as RethTransaction,
+    TransactionKind, TransactionSignedNoHash, TxEip4844, H256,
+};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module, StateVecAccessor, WorkingSet};
+use sov_prover_storage_manager::{new_orphan_storage, SnapshotManager};
+use sov_state::{DefaultStorageSpec, ProverStorage};
+use tempfile::TempDir;
+
+use crate::call::CallMessage;
+use crate::evm::primitive_types::Receipt;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::test_signer::TestSigner;
+use crate::tests::utils::{apply_block, commit, set_message};
+use crate::{AccountData, Evm, EvmConfig};
+
+type C = DefaultContext;
+
+/// Deploys its calldata with CREATE2 and a zero salt, then returns the created address.
+const CREATE2_FACTORY_BYTE_CODE: &str = concat!(
+    // Constructor: returns the 22 bytes of runtime code that follow it.
+    "6016600c60003960166000f3",
+    // CALLDATACOPY(0, 0, CALLDATASIZE)
+    "3660006000",
+    "37",
+    // CREATE2(0, 0, CALLDATASIZE, 0)
+    "6000366000",
+    "6000f5",
+    // MSTORE(0, address), RETURN(0, 32)
+    "600052",
+    "60206000f3",
+);
+
+/// Init code of a contract returning 42 to any call.
+const ANSWER_BYTE_CODE: &str = concat!(
+    // Constructor: returns the 10 bytes of runtime code that follow it.
+    "600a600c600039600a6000f3",
+    // MSTORE(0, 42), RETURN(0, 32)
+    "602a600052",
+    "60206000f3",
+);
+
+fn init_evm(
+    tmpdir: &TempDir,
+    dev_signer: &TestSigner,
+    spec: HashMap<u64, SpecId>,
+) -> (Evm<C>, ProverStorage<DefaultStorageSpec, SnapshotManager>) {
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec,
+        ..Default::default()
+    };
+
+    let evm = Evm::<C>::default();
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    evm.finalize_hook(&[10u8; 32].into(), &mut working_set.accessory_state());
+    commit(working_set, storage.clone());
+
+    (evm, storage)
+}
+
+fn receipts(evm: &Evm<C>, working_set: &mut WorkingSet<C>) -> Vec<Receipt> {
+    evm.receipts
+        .iter(&mut working_set.accessory_state())
+        .collect()
+}
+
+fn create_message(dev_signer: &TestSigner, byte_code: &[u8], nonce: u64) -> CallMessage {
+    CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Create, byte_code.to_vec(), nonce)
+            .unwrap(),
+    }
+}
+
+#[test]
+fn create2_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let dev_signer = TestSigner::new_random();
+    let (evm, storage) = init_evm(
+        &tmpdir,
+        &dev_signer,
+        vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+    );
+
+    let factory_addr = create_address(dev_signer.address(), 0);
+    let init_code = hex::decode(ANSWER_BYTE_CODE).unwrap();
+    let deploy_message = CallMessage {
+        tx: dev_signer
+            .sign_default_transaction(TransactionKind::Call(factory_addr), init_code.clone(), 1)
+            .unwrap(),
+    };
+    apply_block(
+        &evm,
+        vec![
+            create_message(
+                &dev_signer,
+                &hex::decode(CREATE2_FACTORY_BYTE_CODE).unwrap(),
+                0,
+            ),
+            deploy_message,
+        ],
+        &storage,
+    );
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    assert!(receipts(&evm, working_set)
+        .iter()
+        .all(|receipt| receipt.receipt.success));
+
+    // keccak256(0xff ++ factory ++ salt ++ keccak256(init_code))[12..]
+    let mut preimage = vec![0xff];
+    preimage.extend_from_slice(factory_addr.as_bytes());
+    preimage.extend_from_slice(H256::zero().as_bytes());
+    preimage.extend_from_slice(keccak256(&init_code).as_bytes());
+    let answer_addr = Address::from_slice(&keccak256(preimage)[12..]);
+
+    assert_eq!(
+        Bytes::from(hex::decode("602a60005260206000f3").unwrap()),
+        evm.get_code(answer_addr, None, working_set).unwrap()
+    );
+
+    let answer = evm
+        .get_call(
+            CallRequest {
+                to: Some(answer_addr),
+                input: CallInput {
+                    input: None,
+                    data: None,
+                },
+                ..Default::default()
+            },
+            None,
+            None,
+            None,
+            working_set,
+        )
+        .unwrap();
+    assert_eq!(U256::from(42), U256::from_be_slice(&answer));
+}
+
+#[test]
+fn access_list_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let dev_signer = TestSigner::new_random();
+    let (evm, storage) = init_evm(
+        &tmpdir,
+        &dev_signer,
+        vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+    );
+
+    let contract = SimpleStorageContract::default();
+    let first_contract_addr = create_address(dev_signer.address(), 0);
+    let second_contract_addr = create_address(dev_signer.address(), 1);
+
+    // The same call, with and without the written slot in the access list.
+    let access_list = AccessList(vec![AccessListItem {
+        address: second_contract_addr,
+        storage_keys: vec![H256::zero()],
+    }]);
+    let access_list_message = CallMessage {
+        tx: dev_signer
+            .sign_access_list_transaction(
+                TransactionKind::Call(second_contract_addr),
+                contract.set_call_data(5).to_vec(),
+                3,
+                access_list,
+            )
+            .unwrap(),
+    };
+    apply_block(
+        &evm,
+        vec![
+            create_message(&dev_signer, &contract.byte_code(), 0),
+            create_message(&dev_signer, &contract.byte_code(), 1),
+            set_message(&dev_signer, first_contract_addr, 2, 5),
+            access_list_message,
+        ],
+        &storage,
+    );
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    let receipts = receipts(&evm, working_set);
+    assert_eq!(4, receipts.len());
+    assert!(receipts.iter().all(|receipt| receipt.receipt.success));
+    assert_eq!(
+        U256::from(5),
+        evm.get_storage_at(second_contract_addr, U256::ZERO, None, working_set)
+            .unwrap()
+    );
+
+    // The access list costs 2400 gas for the address and 1900 gas for the slot, and saves the
+    // 2100 gas of the cold slot access.
+    assert_eq!(receipts[2].gas_used + 2200, receipts[3].gas_used);
+}
+
+#[test]
+fn spec_schedule_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let dev_signer = TestSigner::new_random();
+    let (evm, storage) = init_evm(
+        &tmpdir,
+        &dev_signer,
+        vec![(0, SpecId::MERGE), (2, SpecId::SHANGHAI)]
+            .into_iter()
+            .collect(),
+    );
+
+    // PUSH0 PUSH0 RETURN: PUSH0 was introduced by Shanghai.
+    let push0_byte_code = hex::decode("5f5ff3").unwrap();
+    apply_block(
+        &evm,
+        vec![create_message(&dev_signer, &push0_byte_code, 0)],
+        &storage,
+    );
+    apply_block(
+        &evm,
+        vec![create_message(&dev_signer, &push0_byte_code, 1)],
+        &storage,
+    );
+
+    let working_set = &mut WorkingSet::new(storage.clone());
+    let receipts = receipts(&evm, working_set);
+    assert_eq!(2, receipts.len());
+    assert!(!receipts[0].receipt.success);
+    assert!(receipts[1].receipt.success);
+}
+
+#[test]
+fn blob_transaction_test() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let dev_signer = TestSigner::new_random();
+    let (evm, storage) = init_evm(
+        &tmpdir,
+        &dev_signer,
+        vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+    );
+
+    let blob_tx = dev_signer
+        .sign_transaction(RethTransaction::Eip4844(TxEip4844 {
+            chain_id: 1,
+            nonce: 0,
+            gas_limit: 1_000_000,
+            max_fee_per_gas: u128::from(reth_primitives::constants::MIN_PROTOCOL_BASE_FEE * 2),
+            blob_versioned_hashes: vec![H256::repeat_byte(1)],
+            ..Default::default()
+        }))
+        .unwrap();
+
+    let error = TransactionSignedNoHash::try_from(blob_tx.clone()).unwrap_err();
+    assert_eq!("transaction type not supported", error.to_string());
+
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    let context = C::new(
+        generate_address::<C>("sender"),
+        generate_address::<C>("sequencer"),
+        1,
+    );
+    assert!(evm
+        .call(CallMessage { tx: blob_tx }, &context, &mut working_set)
+        .is_err());
+}
@@ -0,0 +1,91 @@
+//! Helpers shared by the tests of the module.
+
+use reth_primitives::{Address, TransactionKind};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module, WorkingSet};
+use sov_prover_storage_manager::SnapshotManager;
+use sov_state::{DefaultStorageSpec, ProverStorage, Storage};
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::test_signer::TestSigner;
+use crate::{Evm, EvmRuntime};
+
+type C = DefaultContext;
+
+pub(crate) fn commit(
+    working_set: WorkingSet<C>,
+    storage: ProverStorage<DefaultStorageSpec, SnapshotManager>,
+) {
+    let mut checkpoint = working_set.checkpoint();
+    let (cache_log, witness) = checkpoint.freeze();
+    let (_, authenticated_node_batch) = storage
+        .compute_state_update(cache_log, &witness)
+        .expect("jellyfish merkle tree update must succeed");
+
+    let working_set = checkpoint.to_revertable();
+    let accessory_log = working_set.checkpoint().freeze_non_provable();
+    storage.commit(&authenticated_node_batch, &accessory_log);
+}
+
+/// Seals a block with `messages` and commits it to `storage`.
+pub(crate) fn apply_block<R: EvmRuntime<C>>(
+    evm: &Evm<C, R>,
+    messages: Vec<CallMessage>,
+    storage: &ProverStorage<DefaultStorageSpec, SnapshotManager>,
+) {
+    let mut working_set = WorkingSet::new(storage.clone());
+    apply_block_in_working_set(evm, messages, &mut working_set);
+    commit(working_set, storage.clone());
+}
+
+/// Seals a block with `messages` in `working_set`, without committing it.
+pub(crate) fn apply_block_in_working_set<R: EvmRuntime<C>>(
+    evm: &Evm<C, R>,
+    messages: Vec<CallMessage>,
+    working_set: &mut WorkingSet<C>,
+) {
+    let sender_address = generate_address::<C>("sender");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let context = C::new(sender_address, sequencer_address, 1);
+
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), working_set);
+    for tx in messages {
+        evm.call(tx, &context, working_set).unwrap();
+    }
+    evm.end_slot_hook(working_set);
+    evm.finalize_hook(&[10u8; 32].into(), &mut working_set.accessory_state());
+}
+
+/// A call of the setter of the [`SimpleStorageContract`] at `contract_addr`.
+pub(crate) fn set_message(
+    dev_signer: &TestSigner,
+    contract_addr: Address,
+    nonce: u64,
+    set_arg: u32,
+) -> CallMessage {
+    set_message_with_priority_fee(dev_signer, contract_addr, nonce, set_arg, 0)
+}
+
+/// A call of the setter of the [`SimpleStorageContract`] at `contract_addr`, paying
+/// `priority_fee`.
+pub(crate) fn set_message_with_priority_fee(
+    dev_signer: &TestSigner,
+    contract_addr: Address,
+    nonce: u64,
+    set_arg: u32,
+    priority_fee: u128,
+) -> CallMessage {
+    let signed_tx = dev_signer
+        .sign_transaction_with_priority_fee(
+            TransactionKind::Call(contract_addr),
+            SimpleStorageContract::default()
+                .set_call_data(set_arg)
+                .to_vec(),
+            nonce,
+            priority_fee,
+        )
+        .unwrap();
+    CallMessage { tx: signed_tx }
+}
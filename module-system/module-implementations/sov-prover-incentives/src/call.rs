@@ -2,32 +2,43 @@ use std::fmt::Debug;
 
 use anyhow::Result;
 use borsh::{BorshDeserialize, BorshSerialize};
-use sov_bank::Coins;
+use sov_bank::{Amount, Coins};
+use sov_chain_state::TransitionHeight;
+#[cfg(feature = "native")]
+use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::prelude::*;
-use sov_modules_api::{CallResponse, WorkingSet};
+use sov_modules_api::{CallResponse, DaSpec, StateTransition, WorkingSet};
+use sov_state::Storage;
 
 use crate::ProverIncentives;
 
 /// This enumeration represents the available call messages for interacting with the `ExampleModule` module.
-#[cfg_attr(feature = "native", derive(schemars::JsonSchema))]
-#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+#[cfg_attr(feature = "native", derive(schemars::JsonSchema), derive(CliWalletArg))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone)]
 // TODO: allow call messages to borrow data
 //     https://github.com/Sovereign-Labs/sovereign-sdk/issues/274
 pub enum CallMessage {
     /// Bonds the prover with provided bond.
-    BondProver(u64),
+    BondProver(Amount),
     /// Unbonds the prover.
     UnbondProver,
     /// Verifies the provided proof (of format `Vec<u8>`)
     VerifyProof(Vec<u8>),
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm, Da: DaSpec>
+    ProverIncentives<C, Vm, Da>
+{
     /// A helper function for the `bond_prover` call. Also used to bond provers
     /// during genesis when no context is available.
     pub(super) fn bond_prover_helper(
         &self,
-        bond_amount: u64,
+        bond_amount: Amount,
         prover: &C::Address,
         working_set: &mut WorkingSet<C>,
     ) -> Result<CallResponse> {
@@ -64,7 +75,7 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
     /// Try to bond the requested amount of coins from context.sender()
     pub(crate) fn bond_prover(
         &self,
-        bond_amount: u64,
+        bond_amount: Amount,
         context: &C,
         working_set: &mut WorkingSet<C>,
     ) -> Result<sov_modules_api::CallResponse> {
@@ -132,15 +143,13 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
             .set(context.sender(), &(old_balance - minimum_bond), working_set);
 
         // Don't return an error for invalid proofs - those are expected and shouldn't cause reverts.
-        if let Ok(_public_outputs) =
-            Vm::verify(proof, &code_commitment).map_err(|e| anyhow::format_err!("{:?}", e))
+        if let Ok(transition) = Vm::verify_and_extract_output::<Da, <C::Storage as Storage>::Root>(
+            proof,
+            &code_commitment,
+        )
+        .map_err(|e| anyhow::format_err!("{:?}", e))
         {
-            // TODO: decide what the proof output is and do something with it
-            //     https://github.com/Sovereign-Labs/sovereign-sdk/issues/272
-
             // Unlock the prover's bond
-            // TODO: reward the prover with newly minted tokens as appropriate based on gas fees.
-            //     https://github.com/Sovereign-Labs/sovereign-sdk/issues/271
             self.bonded_provers
                 .set(context.sender(), &old_balance, working_set);
 
@@ -148,6 +157,8 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
                 "processed_valid_proof",
                 &format!("prover: {:?}", context.sender()),
             );
+
+            self.reward_prover(&transition, context.sender(), working_set)?;
         } else {
             working_set.add_event(
                 "processed_invalid_proof",
@@ -157,4 +168,73 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
 
         Ok(CallResponse::default())
     }
+
+    /// Mints the proof reward to the `prover` if `transition` is a state transition recorded by
+    /// the rollup, unless it was already rewarded. Otherwise, anybody could claim the reward
+    /// again by resubmitting a proof, or by proving transitions the rollup never made.
+    fn reward_prover(
+        &self,
+        transition: &StateTransition<Da, <C::Storage as Storage>::Root>,
+        prover: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<()> {
+        let Some(height) = self.recorded_transition_height(transition, working_set) else {
+            working_set.add_event("unknown_transition", &format!("prover: {prover:?}"));
+            return Ok(());
+        };
+
+        if let Some(rewarded_prover) = self.rewarded_proofs.get(&height, working_set) {
+            working_set.add_event(
+                "duplicate_proof",
+                &format!("prover: {prover:?}, rewarded_prover: {rewarded_prover:?}"),
+            );
+            return Ok(());
+        }
+
+        let reward = self.proof_reward.get_or_err(working_set)?;
+        let coins = Coins {
+            token_address: self.bonding_token_address.get_or_err(working_set)?,
+            amount: reward,
+        };
+        // The module mints the reward itself, so that rewards don't depend on the balance of
+        // an account outside of its control.
+        self.bank.mint(&coins, prover, &self.address, working_set)?;
+        self.rewarded_proofs.set(&height, prover, working_set);
+
+        working_set.add_event(
+            "rewarded_prover",
+            &format!("prover: {prover:?}, reward: {reward:?}"),
+        );
+
+        Ok(())
+    }
+
+    /// Returns the height of the state transition recorded for the DA slot of `transition`,
+    /// if its initial and final state roots match the recorded ones.
+    fn recorded_transition_height(
+        &self,
+        transition: &StateTransition<Da, <C::Storage as Storage>::Root>,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<TransitionHeight> {
+        let height = self
+            .chain_state
+            .get_transition_height(&transition.slot_hash, working_set)?;
+        let recorded = self
+            .chain_state
+            .get_historical_transitions(height, working_set)?;
+
+        // The first transition after genesis starts from the genesis state.
+        let initial_state_root = match height.checked_sub(1).and_then(|prev_height| {
+            self.chain_state
+                .get_historical_transitions(prev_height, working_set)
+        }) {
+            Some(prev_transition) => prev_transition.post_state_root().clone(),
+            None => self.chain_state.get_genesis_hash(working_set)?,
+        };
+
+        let matches = transition.initial_state_root == initial_state_root
+            && &transition.final_state_root == recorded.post_state_root()
+            && &transition.validity_condition == recorded.validity_condition();
+        matches.then_some(height)
+    }
 }
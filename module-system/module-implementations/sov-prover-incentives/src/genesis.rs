@@ -1,5 +1,6 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use sov_bank::Amount;
 use sov_modules_api::prelude::*;
 use sov_modules_api::{WorkingSet, Zkvm};
 
@@ -7,24 +8,29 @@ use crate::ProverIncentives;
 
 /// Configuration of the prover incentives module. Specifies the
 /// address of the bonding token, the minimum bond, the commitment to
-/// the allowed verifier method and a set of initial provers with their
-/// bonding amount.
+/// the allowed verifier method, the reward minted for valid proofs
+/// and a set of initial provers with their bonding amount.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProverIncentivesConfig<C: sov_modules_api::Context, Vm: Zkvm> {
     /// The address of the token to be used for bonding.
     pub bonding_token_address: C::Address,
     /// The minimum bond for a prover.
-    pub minimum_bond: u64,
+    pub minimum_bond: Amount,
     /// A code commitment to be used for verifying proofs
     pub commitment_of_allowed_verifier_method: Vm::CodeCommitment,
+    /// The amount of bonding tokens minted for every valid proof. The module must be an
+    /// authorized minter of the bonding token.
+    pub proof_reward: Amount,
     /// A list of initial provers and their bonded amount.
-    pub initial_provers: Vec<(C::Address, u64)>,
+    pub initial_provers: Vec<(C::Address, Amount)>,
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm, Da: sov_modules_api::DaSpec>
+    ProverIncentives<C, Vm, Da>
+{
     /// Init the [`ProverIncentives`] module using the provided `config`.
-    /// Sets the minimum amount necessary to bond, the commitment to the verifier circuit
-    /// the bonding token address and builds the set of initial provers.
+    /// Sets the minimum amount necessary to bond, the commitment to the verifier circuit,
+    /// the bonding token address, the proof reward and builds the set of initial provers.
     pub(crate) fn init_module(
         &self,
         config: &<Self as sov_modules_api::Module>::Config,
@@ -40,6 +46,7 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
             .set(&config.commitment_of_allowed_verifier_method, working_set);
         self.bonding_token_address
             .set(&config.bonding_token_address, working_set);
+        self.proof_reward.set(&config.proof_reward, working_set);
 
         for (prover, bond) in config.initial_provers.iter() {
             self.bond_prover_helper(*bond, prover, working_set)?;
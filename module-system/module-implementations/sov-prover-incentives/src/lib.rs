@@ -14,7 +14,9 @@ pub use genesis::*;
 /// The response type used by RPC queries.
 #[cfg(feature = "native")]
 pub use query::*;
-use sov_modules_api::{Context, Error, ModuleInfo, WorkingSet, Zkvm};
+use sov_bank::Amount;
+use sov_chain_state::TransitionHeight;
+use sov_modules_api::{Context, DaSpec, Error, KernelModuleInfo, WorkingSet, Zkvm};
 use sov_state::codec::BcsCodec;
 
 /// A new module:
@@ -22,8 +24,8 @@ use sov_state::codec::BcsCodec;
 /// - Must contain `[address]` field
 /// - Can contain any number of ` #[state]` or `[module]` fields
 #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
-#[derive(ModuleInfo)]
-pub struct ProverIncentives<C: Context, Vm: Zkvm> {
+#[derive(KernelModuleInfo)]
+pub struct ProverIncentives<C: Context, Vm: Zkvm, Da: DaSpec> {
     /// Address of the module.
     #[address]
     pub address: C::Address,
@@ -39,18 +41,33 @@ pub struct ProverIncentives<C: Context, Vm: Zkvm> {
 
     /// The set of registered provers and their bonded amount.
     #[state]
-    pub bonded_provers: sov_modules_api::StateMap<C::Address, u64>,
+    pub bonded_provers: sov_modules_api::StateMap<C::Address, Amount>,
 
     /// The minimum bond for a prover to be eligible for onchain verification
     #[state]
-    pub minimum_bond: sov_modules_api::StateValue<u64>,
+    pub minimum_bond: sov_modules_api::StateValue<Amount>,
+
+    /// The amount of bonding tokens minted for every valid proof. The module must be an
+    /// authorized minter of the bonding token
+    #[state]
+    pub proof_reward: sov_modules_api::StateValue<Amount>,
+
+    /// The heights of the state transitions whose proofs were already rewarded,
+    /// mapped to the prover that got the reward.
+    #[state]
+    pub rewarded_proofs: sov_modules_api::StateMap<TransitionHeight, C::Address>,
 
     /// Reference to the Bank module.
     #[module]
     pub(crate) bank: sov_bank::Bank<C>,
+
+    /// Reference to the chain state module, used to check the proven state transitions against
+    /// the recorded ones.
+    #[kernel_module]
+    pub(crate) chain_state: sov_chain_state::ChainState<C, Da>,
 }
 
-impl<C: Context, Vm: Zkvm> sov_modules_api::Module for ProverIncentives<C, Vm> {
+impl<C: Context, Vm: Zkvm, Da: DaSpec> sov_modules_api::Module for ProverIncentives<C, Vm, Da> {
     type Context = C;
 
     type Config = ProverIncentivesConfig<C, Vm>;
@@ -1,16 +1,29 @@
+//! Defines rpc queries exposed by the prover incentives module, along with the relevant types
+use jsonrpsee::core::RpcResult;
 use serde::{Deserialize, Serialize};
-use sov_modules_api::{StateMapAccessor, WorkingSet};
+use sov_bank::Amount;
+use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{Context, DaSpec, StateMapAccessor, StateValueAccessor, WorkingSet, Zkvm};
 
 use super::ProverIncentives;
 
 /// The structure containing the response returned by the `get_bond_amount` query.
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
 pub struct Response {
-    /// The bond value stored as a `u64`.
-    pub value: u64,
+    /// The bond value stored as an [`Amount`].
+    pub value: Amount,
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+/// The response type to the `getProofReward` RPC method.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct ProofRewardResponse {
+    /// The amount of bonding tokens paid out for every valid proof.
+    pub amount: Option<Amount>,
+}
+
+impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm, Da: sov_modules_api::DaSpec>
+    ProverIncentives<C, Vm, Da>
+{
     /// Queries the state of the module and returns the bond amount of the address `address`.
     /// If the `address` is not bonded, returns a default value.
     pub fn get_bond_amount(
@@ -26,3 +39,28 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
         }
     }
 }
+
+#[rpc_gen(client, server, namespace = "proverIncentives")]
+impl<C: Context, Vm: Zkvm + 'static, Da: DaSpec> ProverIncentives<C, Vm, Da> {
+    /// Returns the bond amount of the prover with the given address.
+    /// If the prover is not bonded, the bond is zero.
+    #[rpc_method(name = "getBondAmount")]
+    pub fn bond_amount(
+        &self,
+        address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Response> {
+        Ok(self.get_bond_amount(address, working_set))
+    }
+
+    /// Returns the amount of bonding tokens paid out for every valid proof.
+    #[rpc_method(name = "getProofReward")]
+    pub fn reward_per_proof(
+        &self,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<ProofRewardResponse> {
+        Ok(ProofRewardResponse {
+            amount: self.proof_reward.get(working_set),
+        })
+    }
+}
@@ -1,16 +1,26 @@
-use sov_mock_da::MockValidityCond;
+use sov_mock_da::{MockBlockHeader, MockDaSpec, MockValidityCond};
 use sov_mock_zkvm::{MockCodeCommitment, MockProof, MockZkvm};
+use sov_modules_api::da::Time;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::digest::Digest;
 use sov_modules_api::prelude::*;
-use sov_modules_api::{Address, Context, Module, Spec, WorkingSet};
+use sov_modules_api::{
+    Address, Context, KernelModule, KernelWorkingSet, Module, ModuleInfo, Spec, StateTransition,
+    WorkingSet,
+};
+use sov_modules_core::runtime::capabilities::mocks::MockKernel;
 use sov_prover_storage_manager::new_orphan_storage;
 
+use sov_bank::Amount;
+
 use crate::ProverIncentives;
 
 type C = DefaultContext;
+type TestProverIncentives = ProverIncentives<C, MockZkvm<MockValidityCond>, MockDaSpec>;
+type TestStateTransition = StateTransition<MockDaSpec, jmt::RootHash>;
 
-const BOND_AMOUNT: u64 = 1000;
+const BOND_AMOUNT: Amount = 1000;
+const PROOF_REWARD: Amount = 100;
 const MOCK_CODE_COMMITMENT: MockCodeCommitment = MockCodeCommitment([0u8; 32]);
 
 /// Generates an address by hashing the provided `key`.
@@ -26,11 +36,15 @@ fn create_bank_config() -> (
 ) {
     let prover_address = generate_address("prover_pub_key");
     let sequencer_address = generate_address("sequencer_pub_key");
+    let module_address = *TestProverIncentives::default().address();
 
     let token_config = sov_bank::TokenConfig {
         token_name: "InitialToken".to_owned(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         address_and_balances: vec![(prover_address, BOND_AMOUNT * 5)],
-        authorized_minters: vec![prover_address],
+        authorized_minters: vec![prover_address, module_address],
         salt: 2,
     };
 
@@ -43,13 +57,7 @@ fn create_bank_config() -> (
     )
 }
 
-fn setup(
-    working_set: &mut WorkingSet<C>,
-) -> (
-    ProverIncentives<C, MockZkvm<MockValidityCond>>,
-    Address,
-    Address,
-) {
+fn setup(working_set: &mut WorkingSet<C>) -> (TestProverIncentives, Address, Address) {
     // Initialize bank
     let (bank_config, prover_address, sequencer) = create_bank_config();
     let bank = sov_bank::Bank::<C>::default();
@@ -61,12 +69,23 @@ fn setup(
         bank_config.tokens[0].salt,
     );
 
+    // Initialize chain state
+    let chain_state_config = sov_chain_state::ChainStateConfig {
+        initial_slot_height: 0,
+        current_time: Default::default(),
+    };
+    let chain_state = sov_chain_state::ChainState::<C, MockDaSpec>::default();
+    chain_state
+        .genesis(&chain_state_config, working_set)
+        .expect("chain state genesis must succeed");
+
     // initialize prover incentives
-    let module = ProverIncentives::<C, MockZkvm<MockValidityCond>>::default();
+    let module = TestProverIncentives::default();
     let config = crate::ProverIncentivesConfig {
         bonding_token_address: token_address,
         minimum_bond: BOND_AMOUNT,
         commitment_of_allowed_verifier_method: MockCodeCommitment([0u8; 32]),
+        proof_reward: PROOF_REWARD,
         initial_provers: vec![(prover_address, BOND_AMOUNT)],
     };
 
@@ -76,6 +95,51 @@ fn setup(
     (module, prover_address, sequencer)
 }
 
+/// Runs the chain state slot hooks so that `count` state transitions are recorded,
+/// and returns them. The transition of a slot is recorded at the start of the next one.
+fn record_transitions(
+    module: &TestProverIncentives,
+    count: u8,
+    working_set: &mut WorkingSet<C>,
+) -> Vec<TestStateTransition> {
+    let validity_condition = MockValidityCond { is_valid: true };
+    for slot in 1..=count + 1 {
+        let header = MockBlockHeader {
+            prev_hash: [slot - 1; 32].into(),
+            hash: [slot; 32].into(),
+            height: u64::from(slot),
+            time: Time::now(),
+        };
+        let kernel = MockKernel::<C, MockDaSpec>::new(u64::from(slot), u64::from(slot));
+        module.chain_state.begin_slot_hook(
+            &header,
+            &validity_condition,
+            &jmt::RootHash([slot - 1; 32]),
+            &mut KernelWorkingSet::from_kernel(&kernel, working_set),
+        );
+    }
+
+    (1..=count)
+        .map(|slot| StateTransition {
+            initial_state_root: jmt::RootHash([slot - 1; 32]),
+            final_state_root: jmt::RootHash([slot; 32]),
+            slot_hash: [slot; 32].into(),
+            validity_condition,
+        })
+        .collect()
+}
+
+/// Encodes a valid mock proof of `transition`.
+fn prove(transition: &TestStateTransition) -> Vec<u8> {
+    let log = bincode::serialize(transition).unwrap();
+    MockProof {
+        program_id: MOCK_CODE_COMMITMENT,
+        is_valid: true,
+        log: &log,
+    }
+    .encode_to_vec()
+}
+
 #[test]
 fn test_burn_on_invalid_proof() {
     let tmpdir = tempfile::tempdir().unwrap();
@@ -128,14 +192,10 @@ fn test_valid_proof() {
 
     // Process a valid proof
     {
+        let transitions = record_transitions(&module, 1, &mut working_set);
         let context = DefaultContext::new(prover_address, sequencer, 1);
-        let proof = MockProof {
-            program_id: MOCK_CODE_COMMITMENT,
-            is_valid: true,
-            log: &[],
-        };
         module
-            .process_proof(proof.encode_to_vec().as_ref(), &context, &mut working_set)
+            .process_proof(&prove(&transitions[0]), &context, &mut working_set)
             .expect("An invalid proof is not an error");
     }
 
@@ -148,6 +208,163 @@ fn test_valid_proof() {
     );
 }
 
+#[test]
+fn test_valid_proof_is_rewarded() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup(&mut working_set);
+    let context = DefaultContext::new(prover_address, sequencer, 1);
+    let token_address = module
+        .bonding_token_address
+        .get(&mut working_set)
+        .expect("bonding token address was set at genesis");
+    let get_balance = |working_set: &mut WorkingSet<C>| {
+        module
+            .bank
+            .get_balance_of(prover_address, token_address, working_set)
+            .unwrap_or_default()
+    };
+
+    let initial_balance = get_balance(&mut working_set);
+    let initial_supply = module
+        .bank
+        .get_total_supply_of(&token_address, &mut working_set)
+        .unwrap();
+    let transitions = record_transitions(&module, 1, &mut working_set);
+    module
+        .process_proof(&prove(&transitions[0]), &context, &mut working_set)
+        .expect("A valid proof must be processed");
+
+    // The prover is paid the reward and keeps its bond
+    assert_eq!(
+        get_balance(&mut working_set),
+        initial_balance + PROOF_REWARD
+    );
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        BOND_AMOUNT
+    );
+    // The reward is minted by the module
+    assert_eq!(
+        module
+            .bank
+            .get_total_supply_of(&token_address, &mut working_set),
+        Some(initial_supply + PROOF_REWARD)
+    );
+}
+
+#[test]
+fn test_duplicate_proof_is_not_rewarded() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup(&mut working_set);
+    let context = DefaultContext::new(prover_address, sequencer, 1);
+    let token_address = module
+        .bonding_token_address
+        .get(&mut working_set)
+        .expect("bonding token address was set at genesis");
+    let get_balance = |working_set: &mut WorkingSet<C>| {
+        module
+            .bank
+            .get_balance_of(prover_address, token_address, working_set)
+            .unwrap_or_default()
+    };
+
+    let initial_balance = get_balance(&mut working_set);
+    let transitions = record_transitions(&module, 2, &mut working_set);
+
+    // Submit the same proof twice
+    for _ in 0..2 {
+        module
+            .process_proof(&prove(&transitions[0]), &context, &mut working_set)
+            .expect("A valid proof must be processed");
+    }
+
+    // The reward is only paid once, but the bond is still unlocked
+    assert_eq!(
+        get_balance(&mut working_set),
+        initial_balance + PROOF_REWARD
+    );
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        BOND_AMOUNT
+    );
+
+    // A proof of a different transition is rewarded again
+    module
+        .process_proof(&prove(&transitions[1]), &context, &mut working_set)
+        .expect("A valid proof must be processed");
+    assert_eq!(
+        get_balance(&mut working_set),
+        initial_balance + 2 * PROOF_REWARD
+    );
+}
+
+#[test]
+fn test_unrecorded_transition_is_not_rewarded() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup(&mut working_set);
+    let context = DefaultContext::new(prover_address, sequencer, 1);
+    let token_address = module
+        .bonding_token_address
+        .get(&mut working_set)
+        .expect("bonding token address was set at genesis");
+    let get_balance = |working_set: &mut WorkingSet<C>| {
+        module
+            .bank
+            .get_balance_of(prover_address, token_address, working_set)
+            .unwrap_or_default()
+    };
+
+    let initial_balance = get_balance(&mut working_set);
+    let transitions = record_transitions(&module, 2, &mut working_set);
+
+    let wrong_initial_root = TestStateTransition {
+        initial_state_root: jmt::RootHash([9; 32]),
+        ..transitions[1].clone()
+    };
+    let wrong_final_root = TestStateTransition {
+        final_state_root: jmt::RootHash([9; 32]),
+        ..transitions[1].clone()
+    };
+    let unknown_slot = TestStateTransition {
+        slot_hash: [9; 32].into(),
+        ..transitions[1].clone()
+    };
+    // The transition of the last slot is only recorded once the next slot starts.
+    let in_progress_slot = TestStateTransition {
+        initial_state_root: jmt::RootHash([2; 32]),
+        final_state_root: jmt::RootHash([3; 32]),
+        slot_hash: [3; 32].into(),
+        ..transitions[1].clone()
+    };
+
+    for transition in [
+        wrong_initial_root,
+        wrong_final_root,
+        unknown_slot,
+        in_progress_slot,
+    ] {
+        module
+            .process_proof(&prove(&transition), &context, &mut working_set)
+            .expect("A valid proof must be processed");
+    }
+
+    // The proofs are valid, so the bond is unlocked, but no reward is paid
+    assert_eq!(get_balance(&mut working_set), initial_balance);
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        BOND_AMOUNT
+    );
+}
+
 #[test]
 fn test_unbonding() {
     let tmpdir = tempfile::tempdir().unwrap();
@@ -221,14 +438,10 @@ fn test_prover_not_bonded() {
 
     // Process a valid proof
     {
-        let proof = MockProof {
-            program_id: MOCK_CODE_COMMITMENT,
-            is_valid: true,
-            log: &[],
-        };
+        let transitions = record_transitions(&module, 1, &mut working_set);
         // Assert that processing a valid proof fails
         assert!(module
-            .process_proof(proof.encode_to_vec().as_ref(), &context, &mut working_set)
+            .process_proof(&prove(&transitions[0]), &context, &mut working_set)
             .is_err())
     }
 }
@@ -1,4 +1,5 @@
 use anyhow::bail;
+use sov_bank::Amount;
 #[cfg(feature = "native")]
 use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::prelude::*;
@@ -25,11 +26,46 @@ pub enum CallMessage {
         /// The raw Da address of the sequencer you're registering.
         da_address: Vec<u8>,
     },
-    /// Remove a sequencer from the sequencer registry.
+    /// Remove a sequencer from the sequencer registry. Its bond can be
+    /// withdrawn once the unbonding period is over.
     Exit {
         /// The raw Da address of the sequencer you're removing.
         da_address: Vec<u8>,
     },
+    /// Withdraw the bond of a sequencer that has exited and finished unbonding.
+    Withdraw {
+        /// The raw Da address of the sequencer whose bond you're withdrawing.
+        da_address: Vec<u8>,
+    },
+    /// Add to the bond of a sequencer. A partially slashed sequencer has to
+    /// restore its bond to the required amount before submitting blobs again,
+    /// and a larger bond makes a sequencer more likely to lead a slot.
+    TopUp {
+        /// The raw Da address of the sequencer whose bond you're topping up.
+        da_address: Vec<u8>,
+        /// The amount added to the bond.
+        amount: Amount,
+    },
+}
+
+/// A sequencer that has exited and is waiting for its bond to be released.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+)]
+pub struct UnbondingSequencer<C: sov_modules_api::Context> {
+    /// The rollup address the bond is returned to.
+    pub rollup_address: C::Address,
+    /// The bond that is still locked, net of any slashing during unbonding.
+    pub amount: Amount,
+    /// The first slot height at which the bond can be withdrawn.
+    pub unlocks_at: u64,
 }
 
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
@@ -50,8 +86,6 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         context: &C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<CallResponse> {
-        let locker = &self.address;
-        let coins = self.coins_to_lock.get_or_err(working_set)?;
         let sequencer = context.sender();
 
         let belongs_to = self
@@ -62,16 +96,113 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
             bail!("Unauthorized exit attempt");
         }
 
+        let amount = self
+            .sequencer_bonds
+            .get(da_address, working_set)
+            .unwrap_or_default();
+        let unbonding_period = self.unbonding_period.get(working_set).unwrap_or_default();
+
         self.delete(da_address, working_set);
 
+        let unbonding = UnbondingSequencer {
+            rollup_address: belongs_to,
+            amount,
+            unlocks_at: context.slot_height().saturating_add(unbonding_period),
+        };
+        self.unbonding_sequencers
+            .set(da_address, &unbonding, working_set);
+
+        Ok(CallResponse::default())
+    }
+
+    pub(crate) fn withdraw(
+        &self,
+        da_address: &Da::Address,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<CallResponse> {
+        let locker = &self.address;
+        let sequencer = context.sender();
+
+        let unbonding = self
+            .unbonding_sequencers
+            .get_or_err(da_address, working_set)?;
+
+        if sequencer != &unbonding.rollup_address {
+            bail!("Unauthorized withdraw attempt");
+        }
+
+        if context.slot_height() < unbonding.unlocks_at {
+            bail!(
+                "Bond of sequencer {} is locked until slot {}",
+                sequencer,
+                unbonding.unlocks_at
+            );
+        }
+
+        self.unbonding_sequencers.delete(da_address, working_set);
+
+        let token_address = self.coins_to_lock.get_or_err(working_set)?.token_address;
+        let coins = sov_bank::Coins {
+            amount: unbonding.amount,
+            token_address,
+        };
         self.bank
             .transfer_from(locker, sequencer, coins, working_set)?;
 
         Ok(CallResponse::default())
     }
 
+    pub(crate) fn top_up(
+        &self,
+        da_address: &Da::Address,
+        amount: Amount,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<CallResponse> {
+        let locker = &self.address;
+        let sequencer = context.sender();
+
+        let belongs_to = self
+            .allowed_sequencers
+            .get_or_err(da_address, working_set)?;
+
+        if sequencer != &belongs_to {
+            bail!("Unauthorized top up attempt");
+        }
+
+        if amount == 0 {
+            bail!("Top up amount must be positive");
+        }
+
+        let bond = self
+            .sequencer_bonds
+            .get(da_address, working_set)
+            .unwrap_or_default();
+        let token_address = self.coins_to_lock.get_or_err(working_set)?.token_address;
+        let coins = sov_bank::Coins {
+            amount,
+            token_address,
+        };
+        self.bank
+            .transfer_from(sequencer, locker, coins, working_set)?;
+        self.sequencer_bonds
+            .set(da_address, &bond.saturating_add(amount), working_set);
+
+        Ok(CallResponse::default())
+    }
+
     pub(crate) fn delete(&self, da_address: &Da::Address, working_set: &mut WorkingSet<C>) {
         self.allowed_sequencers.delete(da_address, working_set);
+        self.sequencer_bonds.delete(da_address, working_set);
+
+        let mut registered_sequencers = self
+            .registered_sequencers
+            .get(working_set)
+            .unwrap_or_default();
+        registered_sequencers.retain(|sequencer| sequencer != da_address);
+        self.registered_sequencers
+            .set(&registered_sequencers, working_set);
 
         if let Some(preferred_sequencer) = self.preferred_sequencer.get(working_set) {
             if da_address == &preferred_sequencer {
@@ -1,9 +1,47 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use sov_modules_api::prelude::*;
 use sov_modules_api::WorkingSet;
 
-use crate::SequencerRegistry;
+use crate::{SequencerRegistry, SlashingReason, MAX_BPS};
+
+/// How much of a sequencer's bond is slashed for each [`SlashingReason`], and
+/// how much of the slashed amount goes to the reporter. All ratios are in basis
+/// points and must not exceed [`MAX_BPS`].
+#[derive(
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+)]
+pub struct SlashingConfig {
+    /// Share of the bond slashed for [`SlashingReason::InvalidBatchEncoding`].
+    pub invalid_batch_encoding_bps: u64,
+    /// Share of the bond slashed for [`SlashingReason::StatelessVerificationFailed`].
+    pub stateless_verification_failed_bps: u64,
+    /// Share of the bond slashed for [`SlashingReason::InvalidTransactionEncoding`].
+    pub invalid_transaction_encoding_bps: u64,
+    /// Share of the bond slashed for [`SlashingReason::UnpaidTransactionBaseCost`].
+    pub unpaid_transaction_base_cost_bps: u64,
+    /// Share of the slashed amount paid to the reporter of the misbehavior.
+    pub reporter_reward_bps: u64,
+}
+
+impl SlashingConfig {
+    /// Returns the share of the bond slashed for `reason`, in basis points.
+    pub fn slash_bps(&self, reason: SlashingReason) -> u64 {
+        match reason {
+            SlashingReason::InvalidBatchEncoding => self.invalid_batch_encoding_bps,
+            SlashingReason::StatelessVerificationFailed => self.stateless_verification_failed_bps,
+            SlashingReason::InvalidTransactionEncoding => self.invalid_transaction_encoding_bps,
+            SlashingReason::UnpaidTransactionBaseCost => self.unpaid_transaction_base_cost_bps,
+        }
+    }
+}
 
 /// Genesis configuration for the [`SequencerRegistry`] module.
 ///
@@ -34,6 +72,23 @@ pub struct SequencerConfig<C: sov_modules_api::Context, Da: sov_modules_api::DaS
     /// block, which means the preferred sequencer can guarantee soft
     /// confirmation time for transactions.
     pub is_preferred_sequencer: bool,
+    /// The address that receives the protocol's cut of the sequencer rewards.
+    pub treasury_address: C::Address,
+    /// The protocol's cut of the sequencer rewards, in basis points.
+    ///
+    /// Must not exceed [`MAX_BPS`].
+    pub protocol_fee_bps: u64,
+    /// How much of the bond is slashed when the sequencer misbehaves.
+    pub slashing: SlashingConfig,
+    /// Number of slots an exiting sequencer has to wait before withdrawing its
+    /// bond. The bond can still be slashed during that time.
+    pub unbonding_period: u64,
+    /// Number of slots in a leader schedule epoch. If set, the sequencers take
+    /// turns leading the slots, weighted by their bonds, and the leader's blobs
+    /// are processed first.
+    /// Otherwise, only the preferred sequencer gets priority.
+    #[serde(default)]
+    pub epoch_length: Option<u64>,
 }
 
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
@@ -42,6 +97,55 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         config: &<Self as sov_modules_api::Module>::Config,
         working_set: &mut WorkingSet<C>,
     ) -> Result<()> {
+        if config.protocol_fee_bps > MAX_BPS {
+            bail!(
+                "Protocol fee of {} bps exceeds the maximum of {} bps",
+                config.protocol_fee_bps,
+                MAX_BPS
+            );
+        }
+        let slashing = &config.slashing;
+        for (name, bps) in [
+            (
+                "invalid_batch_encoding_bps",
+                slashing.invalid_batch_encoding_bps,
+            ),
+            (
+                "stateless_verification_failed_bps",
+                slashing.stateless_verification_failed_bps,
+            ),
+            (
+                "invalid_transaction_encoding_bps",
+                slashing.invalid_transaction_encoding_bps,
+            ),
+            (
+                "unpaid_transaction_base_cost_bps",
+                slashing.unpaid_transaction_base_cost_bps,
+            ),
+            ("reporter_reward_bps", slashing.reporter_reward_bps),
+        ] {
+            if bps > MAX_BPS {
+                bail!(
+                    "Slashing ratio {} of {} bps exceeds the maximum of {} bps",
+                    name,
+                    bps,
+                    MAX_BPS
+                );
+            }
+        }
+        if let Some(epoch_length) = config.epoch_length {
+            if epoch_length == 0 {
+                bail!("Epoch length must be greater than zero");
+            }
+            self.epoch_length.set(&epoch_length, working_set);
+        }
+        self.slashing_config.set(slashing, working_set);
+        self.unbonding_period
+            .set(&config.unbonding_period, working_set);
+        self.treasury_address
+            .set(&config.treasury_address, working_set);
+        self.protocol_fee_bps
+            .set(&config.protocol_fee_bps, working_set);
         self.coins_to_lock.set(&config.coins_to_lock, working_set);
         self.register_sequencer(
             &config.seq_da_address,
@@ -66,7 +170,7 @@ mod tests {
     use sov_modules_api::default_context::DefaultContext;
     use sov_modules_api::{AddressBech32, Spec};
 
-    use crate::SequencerConfig;
+    use crate::{SequencerConfig, SlashingConfig};
 
     #[test]
     fn test_config_serialization() {
@@ -97,6 +201,17 @@ mod tests {
             seq_da_address: seq_da_addreess,
             coins_to_lock: coins,
             is_preferred_sequencer: true,
+            treasury_address: seq_rollup_address,
+            protocol_fee_bps: 1_000,
+            slashing: SlashingConfig {
+                invalid_batch_encoding_bps: 10_000,
+                stateless_verification_failed_bps: 5_000,
+                invalid_transaction_encoding_bps: 2_000,
+                unpaid_transaction_base_cost_bps: 5_000,
+                reporter_reward_bps: 1_000,
+            },
+            unbonding_period: 10,
+            epoch_length: None,
         };
 
         let data = r#"
@@ -107,7 +222,17 @@ mod tests {
                 "amount":50,
                 "token_address":"sov1zsnx7n2wjvtkr0ttscfgt06pjca3v2e6stxeu49qwynavmk7a8xqlxkkjp"
             },
-            "is_preferred_sequencer":true
+            "is_preferred_sequencer":true,
+            "treasury_address":"sov1l6n2cku82yfqld30lanm2nfw43n2auc8clw7r5u5m6s7p8jrm4zqrr8r94",
+            "protocol_fee_bps":1000,
+            "slashing":{
+                "invalid_batch_encoding_bps":10000,
+                "stateless_verification_failed_bps":5000,
+                "invalid_transaction_encoding_bps":2000,
+                "unpaid_transaction_base_cost_bps":5000,
+                "reporter_reward_bps":1000
+            },
+            "unbonding_period":10
         }"#;
 
         let parsed_config: SequencerConfig<DefaultContext, MockDaSpec> =
@@ -36,8 +36,15 @@ impl<C: Context, Da: sov_modules_api::DaSpec> ApplyBlobHooks<Da::BlobTransaction
     ) -> anyhow::Result<()> {
         match result {
             SequencerOutcome::Completed => (),
-            SequencerOutcome::Slashed { sequencer } => {
-                self.delete(&sequencer, working_set);
+            SequencerOutcome::Rewarded { sequencer, amount } => {
+                self.reward_sequencer(&sequencer, amount, working_set)?;
+            }
+            SequencerOutcome::Slashed {
+                sequencer,
+                reason,
+                reporter,
+            } => {
+                self.slash(&sequencer, reason, reporter.as_ref(), working_set)?;
             }
         }
         Ok(())
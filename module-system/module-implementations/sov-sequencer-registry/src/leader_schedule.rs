@@ -0,0 +1,147 @@
+use sov_bank::Amount;
+use sov_modules_api::digest::Digest;
+use sov_modules_api::prelude::*;
+use sov_modules_api::{Spec, WorkingSet};
+
+use crate::SequencerRegistry;
+
+/// Snapshot of the sequencers' bonds taken at the start of an epoch.
+///
+/// The leader of every slot of the epoch is drawn from it, with a probability
+/// proportional to each sequencer's bond. The draws are seeded with the hash of
+/// the DA block preceding the epoch, so they can't be known before that block
+/// is produced.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(bound = "")]
+pub struct LeaderSchedule<Da: sov_modules_api::DaSpec> {
+    /// The epoch the schedule applies to.
+    pub epoch: u64,
+    /// The first slot height of the epoch.
+    pub first_slot_height: u64,
+    /// The number of slots in the epoch.
+    pub epoch_length: u64,
+    /// The hash of the DA block preceding the first slot of the epoch.
+    pub seed: [u8; 32],
+    /// The bond of every sequencer eligible to lead, in registration order.
+    pub stakes: Vec<(Da::Address, Amount)>,
+}
+
+impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
+    /// Returns the sequencer whose blobs get priority at `slot_height`.
+    ///
+    /// If leader rotation is enabled, this is the leader of the slot, drawn
+    /// from the schedule stored by [`SequencerRegistry::update_leader_schedule`],
+    /// or [`None`] if the stored schedule doesn't cover the slot. Otherwise,
+    /// this is the preferred sequencer, if any. This method never writes to the
+    /// state.
+    pub fn get_priority_sequencer(
+        &self,
+        slot_height: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Da::Address> {
+        if self.epoch_length.get(working_set).is_none() {
+            return self.get_preferred_sequencer(working_set);
+        }
+        let schedule = self.leader_schedule.get(working_set)?;
+        self.leader_at(&schedule, slot_height)
+    }
+
+    /// Stores the leader schedule of the epoch of `slot_height` if it isn't
+    /// stored yet. Does nothing if leader rotation is disabled.
+    ///
+    /// This must be called once at the start of every slot, before the blobs of
+    /// the slot are selected, so that the schedule is a snapshot of the bonded
+    /// sequencers at the start of the epoch. `prev_slot_hash` is the hash of the
+    /// DA block preceding the slot, which seeds the schedule of a new epoch.
+    pub fn update_leader_schedule(
+        &self,
+        slot_height: u64,
+        prev_slot_hash: [u8; 32],
+        working_set: &mut WorkingSet<C>,
+    ) {
+        let Some(epoch_length) = self.epoch_length.get(working_set) else {
+            return;
+        };
+        let epoch = slot_height / epoch_length;
+        let stored_epoch = self
+            .leader_schedule
+            .get(working_set)
+            .map(|schedule| schedule.epoch);
+        if stored_epoch == Some(epoch) {
+            return;
+        }
+
+        let mut stakes = Vec::new();
+        for sequencer in self
+            .registered_sequencers
+            .get(working_set)
+            .unwrap_or_default()
+        {
+            // Underbonded sequencers can't submit blobs, so they can't lead either.
+            if !self.is_sender_allowed(&sequencer, working_set) {
+                continue;
+            }
+            if let Some(bond) = self.get_sequencer_bond(&sequencer, working_set) {
+                stakes.push((sequencer, bond));
+            }
+        }
+
+        let schedule = LeaderSchedule {
+            epoch,
+            first_slot_height: epoch * epoch_length,
+            epoch_length,
+            seed: prev_slot_hash,
+            stakes,
+        };
+        self.leader_schedule.set(&schedule, working_set);
+    }
+
+    /// Returns the leader schedule of the latest epoch, or [`None`] if leader
+    /// rotation is disabled or no epoch has started yet.
+    pub fn get_leader_schedule(
+        &self,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<LeaderSchedule<Da>> {
+        self.leader_schedule.get(working_set)
+    }
+
+    /// Returns the leader of `slot_height` according to `schedule`, or
+    /// [`None`] if the slot is outside of the epoch or no sequencer is bonded.
+    pub fn leader_at(
+        &self,
+        schedule: &LeaderSchedule<Da>,
+        slot_height: u64,
+    ) -> Option<Da::Address> {
+        if slot_height < schedule.first_slot_height
+            || slot_height - schedule.first_slot_height >= schedule.epoch_length
+        {
+            return None;
+        }
+
+        let total_stake = schedule
+            .stakes
+            .iter()
+            .fold(0, |total: Amount, (_, stake)| total.saturating_add(*stake));
+        if total_stake == 0 {
+            return None;
+        }
+
+        let mut hasher = <C as Spec>::Hasher::new();
+        hasher.update(self.address.as_ref());
+        hasher.update(schedule.seed);
+        hasher.update(slot_height.to_le_bytes());
+        let hash: [u8; 32] = hasher.finalize().into();
+        let mut ticket_bytes = [0u8; 16];
+        ticket_bytes.copy_from_slice(&hash[..16]);
+
+        // Walks the cumulative stakes until the ticket falls into a sequencer's range.
+        let mut ticket = Amount::from_le_bytes(ticket_bytes) % total_stake;
+        for (sequencer, stake) in &schedule.stakes {
+            if ticket < *stake {
+                return Some(sequencer.clone());
+            }
+            ticket -= stake;
+        }
+        None
+    }
+}
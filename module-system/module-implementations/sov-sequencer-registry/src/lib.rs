@@ -1,7 +1,25 @@
 //! The `sov-sequencer-registry` module is responsible for sequencer
-//! registration, slashing, and rewards. At the moment, only a centralized
-//! sequencer is supported. The sequencer's address and bond are registered
-//! during the rollup deployment.
+//! registration, slashing, and rewards. The first sequencer's address and bond
+//! are registered during the rollup deployment, and others can join by bonding
+//! the same amount.
+//!
+//! By default, only the optional preferred sequencer gets its blobs processed
+//! first. If an epoch length is configured, the registry instead rotates
+//! leadership between the bonded sequencers: every epoch, it draws a leader
+//! for each slot among the sequencers bonded when the epoch started, weighted
+//! by their bonds, see
+//! [`SequencerRegistry::get_priority_sequencer`].
+//!
+//! Gas fees paid by transactions are escrowed at the module's address while a
+//! blob is executed. Once the blob is done, the collected fees are paid out to
+//! the sequencer that submitted it, minus a protocol cut that goes to the
+//! treasury.
+//!
+//! A misbehaving sequencer loses a share of its bond that depends on the
+//! [`SlashingReason`]. Part of the slashed amount goes to whoever reported the
+//! misbehavior and the rest to the treasury. Exiting sequencers have to wait
+//! for an unbonding period before withdrawing their bond, so they can still be
+//! slashed in the meantime.
 //!
 //! The module implements the [`sov_modules_api::hooks::ApplyBlobHooks`] trait.
 
@@ -9,16 +27,30 @@
 mod call;
 mod genesis;
 mod hooks;
+mod leader_schedule;
 #[cfg(feature = "native")]
 mod query;
 pub use call::*;
 pub use genesis::*;
+pub use leader_schedule::*;
 #[cfg(feature = "native")]
 pub use query::*;
+use sov_bank::Amount;
 use sov_modules_api::prelude::*;
 use sov_modules_api::{CallResponse, Error, ModuleInfo, StateMap, StateValue, WorkingSet};
 use sov_state::codec::BcsCodec;
 
+/// Fees and slashing ratios are expressed in basis points, [`MAX_BPS`] being 100%.
+pub const MAX_BPS: u64 = 10_000;
+
+/// Returns `bps` basis points of `amount`, rounded down.
+pub(crate) fn apply_bps(amount: Amount, bps: u64) -> Amount {
+    let bps = Amount::from(bps);
+    let max_bps = Amount::from(MAX_BPS);
+    // Split the multiplication so it can't overflow for large amounts.
+    amount / max_bps * bps + amount % max_bps * bps / max_bps
+}
+
 /// The `sov-sequencer-registry` module `struct`.
 #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
 #[derive(Clone, ModuleInfo)]
@@ -53,17 +85,86 @@ pub struct SequencerRegistry<C: sov_modules_api::Context, Da: sov_modules_api::D
     /// allowed to exit.
     #[state]
     pub(crate) coins_to_lock: StateValue<sov_bank::Coins<C>>,
+
+    /// The address that receives the protocol's cut of the sequencer rewards.
+    #[state]
+    pub(crate) treasury_address: StateValue<C::Address>,
+
+    /// The protocol's cut of the sequencer rewards, in basis points.
+    #[state]
+    pub(crate) protocol_fee_bps: StateValue<u64>,
+
+    /// Total amount of gas tokens paid out to each sequencer so far.
+    #[state]
+    pub(crate) sequencer_rewards: StateMap<Da::Address, Amount, BcsCodec>,
+
+    /// The bond currently locked by each registered sequencer. It can fall
+    /// below [`SequencerRegistry::coins_to_lock`] after a partial slashing, in
+    /// which case the sequencer has to top it up before submitting blobs again.
+    #[state]
+    pub(crate) sequencer_bonds: StateMap<Da::Address, Amount, BcsCodec>,
+
+    /// Sequencers that have exited and are waiting for their bond to be released.
+    #[state]
+    pub(crate) unbonding_sequencers: StateMap<Da::Address, UnbondingSequencer<C>, BcsCodec>,
+
+    /// How much of the bond is slashed for each [`SlashingReason`].
+    #[state]
+    pub(crate) slashing_config: StateValue<SlashingConfig>,
+
+    /// Number of slots an exiting sequencer has to wait before withdrawing its bond.
+    #[state]
+    pub(crate) unbonding_period: StateValue<u64>,
+
+    /// All registered sequencers, in registration order.
+    #[state]
+    pub(crate) registered_sequencers: StateValue<Vec<Da::Address>, BcsCodec>,
+
+    /// Number of slots in a leader schedule epoch. Leader rotation is
+    /// disabled if it isn't set.
+    #[state]
+    pub(crate) epoch_length: StateValue<u64>,
+
+    /// The leader schedule of the latest epoch.
+    #[state]
+    pub(crate) leader_schedule: StateValue<LeaderSchedule<Da>, BcsCodec>,
+}
+
+/// The reason why a sequencer is slashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlashingReason {
+    /// The blob could not be deserialized into a batch.
+    InvalidBatchEncoding,
+    /// A transaction in the batch failed stateless verification.
+    StatelessVerificationFailed,
+    /// A transaction in the batch could not be decoded into a call message.
+    InvalidTransactionEncoding,
+    /// Neither the sender of a transaction in the batch nor the sequencer
+    /// could pay for its base cost.
+    UnpaidTransactionBaseCost,
 }
 
 /// Result of applying a blob, from sequencer's point of view.
 pub enum SequencerOutcome<Da: sov_modules_api::DaSpec> {
     /// The blob was applied successfully and the operation is concluded.
     Completed,
+    /// The blob was applied successfully and the sequencer is owed the fees
+    /// collected from its transactions.
+    Rewarded {
+        /// The address of the sequencer that submitted the blob.
+        sequencer: Da::Address,
+        /// The amount of gas tokens collected while executing the blob.
+        amount: Amount,
+    },
     /// The blob was *not* applied successfully. The sequencer has been slashed
     /// as a result of the invalid blob.
     Slashed {
         /// The address of the sequencer that was slashed.
         sequencer: Da::Address,
+        /// Why the sequencer was slashed.
+        reason: SlashingReason,
+        /// The registered sequencer that reported the misbehavior, if any.
+        reporter: Option<Da::Address>,
     },
 }
 
@@ -97,6 +198,14 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::
                 let da_address = Da::Address::try_from(&da_address)?;
                 self.exit(&da_address, context, working_set)?
             }
+            CallMessage::Withdraw { da_address } => {
+                let da_address = Da::Address::try_from(&da_address)?;
+                self.withdraw(&da_address, context, working_set)?
+            }
+            CallMessage::TopUp { da_address, amount } => {
+                let da_address = Da::Address::try_from(&da_address)?;
+                self.top_up(&da_address, amount, context, working_set)?
+            }
         })
     }
 }
@@ -120,13 +229,30 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         {
             anyhow::bail!("sequencer {} already registered", rollup_address)
         }
+        if self
+            .unbonding_sequencers
+            .get(da_address, working_set)
+            .is_some()
+        {
+            anyhow::bail!("sequencer {} is still unbonding", rollup_address)
+        }
         let locker = &self.address;
         let coins = self.coins_to_lock.get_or_err(working_set)?;
+        let bond = coins.amount;
         self.bank
             .transfer_from(rollup_address, locker, coins, working_set)?;
 
         self.allowed_sequencers
             .set(da_address, rollup_address, working_set);
+        self.sequencer_bonds.set(da_address, &bond, working_set);
+
+        let mut registered_sequencers = self
+            .registered_sequencers
+            .get(working_set)
+            .unwrap_or_default();
+        registered_sequencers.push(da_address.clone());
+        self.registered_sequencers
+            .set(&registered_sequencers, working_set);
 
         Ok(())
     }
@@ -154,8 +280,218 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
         })
     }
 
-    /// Checks whether `sender` is a registered sequencer.
+    /// Returns the rollup address of the sequencer with the given DA address,
+    /// or [`None`] if it isn't registered.
+    pub fn get_sequencer_rollup_address(
+        &self,
+        da_address: &Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<C::Address> {
+        self.allowed_sequencers.get(da_address, working_set)
+    }
+
+    /// Checks whether `sender` is a registered sequencer with a full bond.
     pub fn is_sender_allowed(&self, sender: &Da::Address, working_set: &mut WorkingSet<C>) -> bool {
-        self.allowed_sequencers.get(sender, working_set).is_some()
+        if self.allowed_sequencers.get(sender, working_set).is_none() {
+            return false;
+        }
+        let required_bond = self
+            .coins_to_lock
+            .get(working_set)
+            .map(|coins| coins.amount)
+            .unwrap_or_default();
+        self.get_sequencer_bond(sender, working_set)
+            .is_some_and(|bond| bond >= required_bond)
+    }
+
+    /// Returns the bond currently locked by the sequencer with the given DA
+    /// address, or [`None`] if it isn't registered.
+    pub fn get_sequencer_bond(
+        &self,
+        da_address: &Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Amount> {
+        self.sequencer_bonds.get(da_address, working_set)
+    }
+
+    /// Returns the unbonding state of the sequencer with the given DA address,
+    /// or [`None`] if it isn't exiting.
+    pub fn get_unbonding_sequencer(
+        &self,
+        da_address: &Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<UnbondingSequencer<C>> {
+        self.unbonding_sequencers.get(da_address, working_set)
+    }
+
+    /// Slashes the sequencer with the given DA address, whether it is
+    /// registered or unbonding.
+    ///
+    /// The share of the bond configured for `reason` is taken away. Part of it
+    /// is paid to the rollup address of the `reporter`, if it is another
+    /// registered sequencer, and the rest is sent to the treasury. A sequencer that loses
+    /// its whole bond is removed from the registry.
+    pub(crate) fn slash(
+        &self,
+        da_address: &Da::Address,
+        reason: SlashingReason,
+        reporter: Option<&Da::Address>,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        let slashing_config = self.slashing_config.get_or_err(working_set)?;
+        let slash_bps = slashing_config.slash_bps(reason);
+
+        let slashed = if let Some(bond) = self.sequencer_bonds.get(da_address, working_set) {
+            let slashed = apply_bps(bond, slash_bps);
+            let remaining = bond - slashed;
+            if remaining == 0 {
+                self.delete(da_address, working_set);
+            } else {
+                self.sequencer_bonds
+                    .set(da_address, &remaining, working_set);
+            }
+            slashed
+        } else if let Some(mut unbonding) = self.unbonding_sequencers.get(da_address, working_set) {
+            let slashed = apply_bps(unbonding.amount, slash_bps);
+            unbonding.amount -= slashed;
+            if unbonding.amount == 0 {
+                self.unbonding_sequencers.delete(da_address, working_set);
+            } else {
+                self.unbonding_sequencers
+                    .set(da_address, &unbonding, working_set);
+            }
+            slashed
+        } else {
+            // An unknown sequencer has no bond to slash.
+            return Ok(());
+        };
+
+        if slashed == 0 {
+            return Ok(());
+        }
+
+        let token_address = self.coins_to_lock.get_or_err(working_set)?.token_address;
+        // A sequencer can't claim a reward for reporting itself.
+        let reporter_address = reporter
+            .filter(|reporter| *reporter != da_address)
+            .and_then(|reporter| self.allowed_sequencers.get(reporter, working_set));
+        let reporter_reward = match reporter_address {
+            Some(reporter_address) => {
+                let reporter_reward = apply_bps(slashed, slashing_config.reporter_reward_bps);
+                if reporter_reward > 0 {
+                    let coins = sov_bank::Coins {
+                        amount: reporter_reward,
+                        token_address,
+                    };
+                    self.bank.transfer_from(
+                        &self.address,
+                        &reporter_address,
+                        coins,
+                        working_set,
+                    )?;
+                }
+                reporter_reward
+            }
+            None => 0,
+        };
+
+        let treasury_cut = slashed - reporter_reward;
+        if treasury_cut > 0 {
+            let treasury_address = self.treasury_address.get_or_err(working_set)?;
+            let coins = sov_bank::Coins {
+                amount: treasury_cut,
+                token_address,
+            };
+            self.bank
+                .transfer_from(&self.address, &treasury_address, coins, working_set)?;
+        }
+
+        working_set.add_event(
+            "sequencer_slashed",
+            &format!(
+                "sequencer: {da_address}, reason: {reason:?}, slashed: {slashed}, reporter_reward: {reporter_reward}"
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Returns the total amount of gas tokens paid out to the sequencer with
+    /// the given DA address, or [`None`] if it was never rewarded.
+    pub fn get_sequencer_rewards(
+        &self,
+        da_address: &Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Amount> {
+        self.sequencer_rewards.get(da_address, working_set)
+    }
+
+    /// Pays out `amount` of gas tokens escrowed at [`SequencerRegistry::address`].
+    ///
+    /// The protocol cut is sent to the treasury and the rest goes to the
+    /// rollup address of the sequencer. If the sequencer is not registered
+    /// anymore, the whole amount is sent to the treasury.
+    pub(crate) fn reward_sequencer(
+        &self,
+        da_address: &Da::Address,
+        amount: Amount,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let token_address = sov_bank::get_gas_token_address::<C>()?;
+        let treasury_address = self.treasury_address.get_or_err(working_set)?;
+
+        let (protocol_cut, sequencer_cut) = match self
+            .allowed_sequencers
+            .get(da_address, working_set)
+        {
+            Some(rollup_address) => {
+                let fee_bps = self.protocol_fee_bps.get_or_err(working_set)?;
+                let protocol_cut = apply_bps(amount, fee_bps);
+                let sequencer_cut = amount - protocol_cut;
+
+                if sequencer_cut > 0 {
+                    let coins = sov_bank::Coins {
+                        amount: sequencer_cut,
+                        token_address,
+                    };
+                    self.bank
+                        .transfer_from(&self.address, &rollup_address, coins, working_set)?;
+                }
+                (protocol_cut, sequencer_cut)
+            }
+            None => (amount, 0),
+        };
+
+        if protocol_cut > 0 {
+            let coins = sov_bank::Coins {
+                amount: protocol_cut,
+                token_address,
+            };
+            self.bank
+                .transfer_from(&self.address, &treasury_address, coins, working_set)?;
+        }
+
+        if sequencer_cut > 0 {
+            let total_rewards = self
+                .sequencer_rewards
+                .get(da_address, working_set)
+                .unwrap_or_default()
+                .saturating_add(sequencer_cut);
+            self.sequencer_rewards
+                .set(da_address, &total_rewards, working_set);
+        }
+
+        working_set.add_event(
+            "sequencer_rewarded",
+            &format!(
+                "sequencer: {da_address}, reward: {sequencer_cut}, protocol_fee: {protocol_cut}"
+            ),
+        );
+
+        Ok(())
     }
 }
@@ -1,5 +1,6 @@
 //! Defines rpc queries exposed by the sequencer registry module, along with the relevant types
 use jsonrpsee::core::RpcResult;
+use sov_bank::Amount;
 use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::{Context, StateMapAccessor, WorkingSet};
 
@@ -16,6 +17,47 @@ pub struct SequencerAddressResponse<C: Context> {
     pub address: Option<C::Address>,
 }
 
+/// The response type to the `getSequencerRewards` RPC method.
+#[cfg_attr(
+    feature = "native",
+    derive(serde::Deserialize, serde::Serialize, Clone)
+)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct SequencerRewardsResponse {
+    /// The total amount of gas tokens paid out to the requested sequencer.
+    pub amount: Option<Amount>,
+}
+
+/// The leaders of every slot of an epoch.
+#[cfg_attr(
+    feature = "native",
+    derive(serde::Deserialize, serde::Serialize, Clone),
+    serde(bound = "")
+)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct EpochLeaders<Da: sov_modules_api::DaSpec> {
+    /// The epoch the leaders were drawn for.
+    pub epoch: u64,
+    /// The slot height led by the first entry of [`EpochLeaders::leaders`].
+    pub first_slot_height: u64,
+    /// The leader of every slot of the epoch, in order. Empty if no sequencer
+    /// was bonded when the epoch started.
+    pub leaders: Vec<Da::Address>,
+}
+
+/// The response type to the `getLeaderSchedule` RPC method.
+#[cfg_attr(
+    feature = "native",
+    derive(serde::Deserialize, serde::Serialize, Clone),
+    serde(bound = "")
+)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct LeaderScheduleResponse<Da: sov_modules_api::DaSpec> {
+    /// The leaders of the latest epoch, or [`None`] if leader rotation is
+    /// disabled or no epoch has started yet.
+    pub schedule: Option<EpochLeaders<Da>>,
+}
+
 #[rpc_gen(client, server, namespace = "sequencer")]
 impl<C: Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
     /// Returns the rollup address of the sequencer with the given DA address.
@@ -31,4 +73,43 @@ impl<C: Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
             address: self.allowed_sequencers.get(&da_address, working_set),
         })
     }
+
+    /// Returns the total amount of gas tokens paid out to the sequencer with the
+    /// given DA address, after the protocol cut.
+    ///
+    /// The response only contains data if the sequencer was ever rewarded.
+    #[rpc_method(name = "getSequencerRewards")]
+    pub fn sequencer_rewards(
+        &self,
+        da_address: Da::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<SequencerRewardsResponse> {
+        Ok(SequencerRewardsResponse {
+            amount: self.get_sequencer_rewards(&da_address, working_set),
+        })
+    }
+
+    /// Returns the leader of every slot of the latest epoch.
+    ///
+    /// Sequencers should only publish blobs during the slots they lead, since
+    /// blobs from other sequencers are deferred.
+    #[rpc_method(name = "getLeaderSchedule")]
+    pub fn leader_schedule(
+        &self,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<LeaderScheduleResponse<Da>> {
+        let schedule = self.get_leader_schedule(working_set).map(|schedule| {
+            let slots =
+                schedule.first_slot_height..schedule.first_slot_height + schedule.epoch_length;
+            let leaders = slots
+                .map_while(|slot_height| self.leader_at(&schedule, slot_height))
+                .collect();
+            EpochLeaders {
+                epoch: schedule.epoch,
+                first_slot_height: schedule.first_slot_height,
+                leaders,
+            }
+        });
+        Ok(LeaderScheduleResponse { schedule })
+    }
 }
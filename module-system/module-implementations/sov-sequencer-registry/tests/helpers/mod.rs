@@ -1,9 +1,10 @@
 use jsonrpsee::core::RpcResult;
+use sov_bank::Amount;
 use sov_mock_da::{MockAddress, MockDaSpec};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::digest::Digest;
-use sov_modules_api::{Address, Module, Spec, WorkingSet};
-use sov_sequencer_registry::{SequencerConfig, SequencerRegistry};
+use sov_modules_api::{Address, Module, ModuleInfo, Spec, WorkingSet};
+use sov_sequencer_registry::{SequencerConfig, SequencerRegistry, SlashingConfig};
 
 pub type C = DefaultContext;
 pub type Da = MockDaSpec;
@@ -19,8 +20,12 @@ pub const REWARD_SEQUENCER_KEY: &str = "sequencer_4";
 #[allow(dead_code)]
 pub const UNKNOWN_SEQUENCER_DA_ADDRESS: [u8; 32] = [3; 32];
 pub const LOW_FUND_KEY: &str = "zero_funds";
-pub const INITIAL_BALANCE: u64 = 210;
-pub const LOCKED_AMOUNT: u64 = 200;
+pub const TREASURY_KEY: &str = "treasury";
+pub const INITIAL_BALANCE: Amount = 300;
+pub const LOCKED_AMOUNT: Amount = 200;
+pub const ESCROWED_FEES: Amount = 1_000;
+pub const PROTOCOL_FEE_BPS: u64 = 1_000;
+pub const UNBONDING_PERIOD: u64 = 5;
 
 pub struct TestSequencer {
     pub bank: sov_bank::Bank<C>,
@@ -72,6 +77,9 @@ pub fn create_bank_config() -> (sov_bank::BankConfig<C>, <C as Spec>::Address) {
 
     let token_config = sov_bank::TokenConfig {
         token_name: "InitialToken".to_owned(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         address_and_balances: vec![
             (seq_address, INITIAL_BALANCE),
             (generate_address(ANOTHER_SEQUENCER_KEY), INITIAL_BALANCE),
@@ -82,9 +90,23 @@ pub fn create_bank_config() -> (sov_bank::BankConfig<C>, <C as Spec>::Address) {
         salt: 8,
     };
 
+    // Gas fees are escrowed at the registry address until the end of the blob.
+    let gas_token_config = sov_bank::TokenConfig {
+        token_name: "sov-gas-token".to_owned(),
+        symbol: "SOV".to_owned(),
+        decimals: 9,
+        max_supply: None,
+        address_and_balances: vec![(
+            *SequencerRegistry::<C, Da>::default().address(),
+            ESCROWED_FEES,
+        )],
+        authorized_minters: vec![],
+        salt: 0,
+    };
+
     (
         sov_bank::BankConfig {
-            tokens: vec![token_config],
+            tokens: vec![token_config, gas_token_config],
         },
         seq_address,
     )
@@ -102,6 +124,17 @@ pub fn create_sequencer_config(
             token_address,
         },
         is_preferred_sequencer: false,
+        treasury_address: generate_address(TREASURY_KEY),
+        protocol_fee_bps: PROTOCOL_FEE_BPS,
+        slashing: SlashingConfig {
+            invalid_batch_encoding_bps: 10_000,
+            stateless_verification_failed_bps: 5_000,
+            invalid_transaction_encoding_bps: 2_000,
+            unpaid_transaction_base_cost_bps: 5_000,
+            reporter_reward_bps: 1_000,
+        },
+        unbonding_period: UNBONDING_PERIOD,
+        epoch_length: None,
     }
 }
 
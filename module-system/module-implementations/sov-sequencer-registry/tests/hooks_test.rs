@@ -1,9 +1,11 @@
 use helpers::*;
 use sov_mock_da::{MockAddress, MockBlob};
 use sov_modules_api::hooks::ApplyBlobHooks;
-use sov_modules_api::WorkingSet;
+use sov_modules_api::{Context, Module, ModuleInfo, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
-use sov_sequencer_registry::{SequencerOutcome, SequencerRegistry};
+use sov_sequencer_registry::{
+    CallMessage, SequencerOutcome, SequencerRegistry, SlashingReason, MAX_BPS,
+};
 
 mod helpers;
 
@@ -122,6 +124,8 @@ fn end_blob_hook_slash() {
 
     let result = SequencerOutcome::Slashed {
         sequencer: genesis_sequencer_da_address,
+        reason: SlashingReason::InvalidBatchEncoding,
+        reporter: None,
     };
     <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
         &test_sequencer.registry,
@@ -181,6 +185,8 @@ fn end_blob_hook_slash_preferred_sequencer() {
 
     let result = SequencerOutcome::Slashed {
         sequencer: genesis_sequencer_da_address,
+        reason: SlashingReason::InvalidBatchEncoding,
+        reporter: None,
     };
     <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
         &test_sequencer.registry,
@@ -231,6 +237,8 @@ fn end_blob_hook_slash_unknown_sequencer() {
 
     let result = SequencerOutcome::Slashed {
         sequencer: sequencer_address,
+        reason: SlashingReason::InvalidBatchEncoding,
+        reporter: None,
     };
     <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
         &test_sequencer.registry,
@@ -245,3 +253,275 @@ fn end_blob_hook_slash_unknown_sequencer() {
         .unwrap();
     assert!(resp.address.is_none());
 }
+
+#[test]
+fn end_blob_hook_partial_slash_and_top_up() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let seq_rollup_address = test_sequencer.sequencer_config.seq_rollup_address;
+    let reporter_da_address = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+    let reporter_address = generate_address(ANOTHER_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+
+    let register_message = CallMessage::Register {
+        da_address: ANOTHER_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(
+            register_message,
+            &C::new(reporter_address, reward_address, 1),
+            working_set,
+        )
+        .unwrap();
+
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Slashed {
+            sequencer: genesis_sequencer_da_address,
+            reason: SlashingReason::InvalidTransactionEncoding,
+            reporter: Some(reporter_da_address),
+        },
+        working_set,
+    )
+    .unwrap();
+
+    // 20% of the bond is slashed, and 10% of that goes to the reporter.
+    let slashed = LOCKED_AMOUNT / 5;
+    assert_eq!(
+        Some(LOCKED_AMOUNT - slashed),
+        test_sequencer
+            .registry
+            .get_sequencer_bond(&genesis_sequencer_da_address, working_set)
+    );
+    let reporter_balance = test_sequencer
+        .query_balance(reporter_address, working_set)
+        .unwrap()
+        .amount;
+    assert_eq!(
+        Some(INITIAL_BALANCE - LOCKED_AMOUNT + slashed / 10),
+        reporter_balance
+    );
+    let treasury_balance = test_sequencer
+        .query_balance(generate_address(TREASURY_KEY), working_set)
+        .unwrap()
+        .amount;
+    assert_eq!(Some(slashed - slashed / 10), treasury_balance);
+
+    // The sequencer is still registered, but can't submit blobs until it tops up its bond.
+    let resp = test_sequencer
+        .registry
+        .sequencer_address(genesis_sequencer_da_address, working_set)
+        .unwrap();
+    assert_eq!(Some(seq_rollup_address), resp.address);
+    let mut test_blob = MockBlob::new(Vec::new(), genesis_sequencer_da_address, [0_u8; 32]);
+    assert!(test_sequencer
+        .registry
+        .begin_blob_hook(&mut test_blob, working_set)
+        .is_err());
+
+    let top_up_message = CallMessage::TopUp {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+        amount: slashed,
+    };
+    test_sequencer
+        .registry
+        .call(
+            top_up_message,
+            &C::new(seq_rollup_address, reward_address, 1),
+            working_set,
+        )
+        .expect("Sequencer top up has failed");
+
+    let resp = test_sequencer.query_balance_via_bank(working_set).unwrap();
+    assert_eq!(Some(INITIAL_BALANCE - LOCKED_AMOUNT - slashed), resp.amount);
+    assert_eq!(
+        Some(LOCKED_AMOUNT),
+        test_sequencer
+            .registry
+            .get_sequencer_bond(&genesis_sequencer_da_address, working_set)
+    );
+    test_sequencer
+        .registry
+        .begin_blob_hook(&mut test_blob, working_set)
+        .unwrap();
+}
+
+#[test]
+fn end_blob_hook_slash_unbonding_sequencer() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let seq_rollup_address = test_sequencer.sequencer_config.seq_rollup_address;
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+
+    let exit_message = CallMessage::Exit {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(
+            exit_message,
+            &C::new(seq_rollup_address, reward_address, 1),
+            working_set,
+        )
+        .unwrap();
+
+    // Evidence of misbehavior lands while the sequencer is unbonding.
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Slashed {
+            sequencer: genesis_sequencer_da_address,
+            reason: SlashingReason::StatelessVerificationFailed,
+            reporter: None,
+        },
+        working_set,
+    )
+    .unwrap();
+
+    let unbonding = test_sequencer
+        .registry
+        .get_unbonding_sequencer(&genesis_sequencer_da_address, working_set)
+        .unwrap();
+    assert_eq!(LOCKED_AMOUNT / 2, unbonding.amount);
+
+    let withdraw_message = CallMessage::Withdraw {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(
+            withdraw_message,
+            &C::new(seq_rollup_address, reward_address, 1 + UNBONDING_PERIOD),
+            working_set,
+        )
+        .unwrap();
+
+    let resp = test_sequencer.query_balance_via_bank(working_set).unwrap();
+    assert_eq!(Some(INITIAL_BALANCE - LOCKED_AMOUNT / 2), resp.amount);
+}
+
+#[test]
+fn end_blob_hook_reward() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let seq_rollup_address = test_sequencer.sequencer_config.seq_rollup_address;
+    let treasury_address = generate_address(TREASURY_KEY);
+    let gas_token_address = sov_bank::get_gas_token_address::<C>().unwrap();
+    let gas_balance = |address, working_set: &mut WorkingSet<C>| {
+        test_sequencer
+            .bank
+            .get_balance_of(address, gas_token_address, working_set)
+    };
+
+    let resp = test_sequencer
+        .registry
+        .sequencer_rewards(genesis_sequencer_da_address, working_set)
+        .unwrap();
+    assert!(resp.amount.is_none());
+
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Rewarded {
+            sequencer: genesis_sequencer_da_address,
+            amount: 100,
+        },
+        working_set,
+    )
+    .unwrap();
+
+    // 10% of the reward goes to the treasury.
+    assert_eq!(Some(90), gas_balance(seq_rollup_address, working_set));
+    assert_eq!(Some(10), gas_balance(treasury_address, working_set));
+    assert_eq!(
+        Some(ESCROWED_FEES - 100),
+        gas_balance(*test_sequencer.registry.address(), working_set)
+    );
+
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Rewarded {
+            sequencer: genesis_sequencer_da_address,
+            amount: 55,
+        },
+        working_set,
+    )
+    .unwrap();
+
+    // The protocol cut is rounded down.
+    assert_eq!(Some(140), gas_balance(seq_rollup_address, working_set));
+    assert_eq!(Some(15), gas_balance(treasury_address, working_set));
+
+    let resp = test_sequencer
+        .registry
+        .sequencer_rewards(genesis_sequencer_da_address, working_set)
+        .unwrap();
+    assert_eq!(Some(140), resp.amount);
+}
+
+#[test]
+fn end_blob_hook_reward_unknown_sequencer() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let sequencer_address = MockAddress::from(UNKNOWN_SEQUENCER_DA_ADDRESS);
+    let treasury_address = generate_address(TREASURY_KEY);
+    let gas_token_address = sov_bank::get_gas_token_address::<C>().unwrap();
+
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Rewarded {
+            sequencer: sequencer_address,
+            amount: 100,
+        },
+        working_set,
+    )
+    .unwrap();
+
+    // Nobody can claim the reward, so it all goes to the treasury.
+    assert_eq!(
+        Some(100),
+        test_sequencer
+            .bank
+            .get_balance_of(treasury_address, gas_token_address, working_set)
+    );
+    let resp = test_sequencer
+        .registry
+        .sequencer_rewards(sequencer_address, working_set)
+        .unwrap();
+    assert!(resp.amount.is_none());
+}
+
+#[test]
+fn genesis_rejects_protocol_fee_above_max() {
+    let mut test_sequencer = create_test_sequencer();
+    test_sequencer.sequencer_config.protocol_fee_bps = MAX_BPS + 1;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    test_sequencer
+        .bank
+        .genesis(&test_sequencer.bank_config, working_set)
+        .unwrap();
+    let result = test_sequencer
+        .registry
+        .genesis(&test_sequencer.sequencer_config, working_set);
+    assert!(result.is_err());
+    assert_eq!(
+        "Protocol fee of 10001 bps exceeds the maximum of 10000 bps",
+        result.unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,303 @@
+use helpers::*;
+use sov_mock_da::MockAddress;
+use sov_modules_api::{Context, Module, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+use sov_sequencer_registry::{CallMessage, LeaderSchedule, SequencerRegistry};
+
+mod helpers;
+
+const EPOCH_LENGTH: u64 = 4;
+
+fn create_rotating_test_sequencer() -> TestSequencer {
+    let bank = sov_bank::Bank::<C>::default();
+    let (bank_config, seq_rollup_address) = create_bank_config();
+
+    let token_address = sov_bank::get_genesis_token_address::<C>(
+        &bank_config.tokens[0].token_name,
+        bank_config.tokens[0].salt,
+    );
+
+    let registry = SequencerRegistry::<C, Da>::default();
+    let mut sequencer_config = create_sequencer_config(seq_rollup_address, token_address);
+    sequencer_config.is_preferred_sequencer = true;
+    sequencer_config.epoch_length = Some(EPOCH_LENGTH);
+
+    TestSequencer {
+        bank,
+        bank_config,
+        registry,
+        sequencer_config,
+    }
+}
+
+fn register_another_sequencer(test_sequencer: &TestSequencer, working_set: &mut WorkingSet<C>) {
+    let sequencer_address = generate_address(ANOTHER_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let register_message = CallMessage::Register {
+        da_address: ANOTHER_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(
+            register_message,
+            &C::new(sequencer_address, reward_address, 1),
+            working_set,
+        )
+        .expect("Sequencer registration has failed");
+}
+
+/// The hash of the DA block preceding `slot_height` in these tests.
+fn prev_slot_hash(slot_height: u64) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash[..8].copy_from_slice(&slot_height.to_le_bytes());
+    hash
+}
+
+fn begin_slot(
+    test_sequencer: &TestSequencer,
+    slot_height: u64,
+    working_set: &mut WorkingSet<C>,
+) -> Option<MockAddress> {
+    test_sequencer.registry.update_leader_schedule(
+        slot_height,
+        prev_slot_hash(slot_height),
+        working_set,
+    );
+    test_sequencer
+        .registry
+        .get_priority_sequencer(slot_height, working_set)
+}
+
+#[test]
+fn leader_schedule_disabled() {
+    let mut test_sequencer = create_test_sequencer();
+    test_sequencer.sequencer_config.is_preferred_sequencer = true;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+    register_another_sequencer(&test_sequencer, working_set);
+
+    // Without an epoch length, the preferred sequencer always gets priority.
+    for slot_height in 0..2 * EPOCH_LENGTH {
+        assert_eq!(
+            Some(MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS)),
+            begin_slot(&test_sequencer, slot_height, working_set)
+        );
+    }
+
+    let resp = test_sequencer
+        .registry
+        .leader_schedule(working_set)
+        .unwrap();
+    assert!(resp.schedule.is_none());
+}
+
+#[test]
+fn leader_schedule_rotates_between_sequencers() {
+    let mut test_sequencer = create_rotating_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let genesis_sequencer = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let another_sequencer = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+
+    // The schedule of the first epoch only knows about the genesis sequencer.
+    assert_eq!(
+        Some(genesis_sequencer),
+        begin_slot(&test_sequencer, 0, working_set)
+    );
+
+    // Sequencers registered mid-epoch only lead from the next epoch on.
+    register_another_sequencer(&test_sequencer, working_set);
+    for slot_height in 1..EPOCH_LENGTH {
+        assert_eq!(
+            Some(genesis_sequencer),
+            begin_slot(&test_sequencer, slot_height, working_set)
+        );
+    }
+
+    let mut leaders = Vec::new();
+    for slot_height in EPOCH_LENGTH..16 * EPOCH_LENGTH {
+        let leader = begin_slot(&test_sequencer, slot_height, working_set).unwrap();
+        leaders.push(leader);
+    }
+    assert!(leaders.contains(&genesis_sequencer));
+    assert!(leaders.contains(&another_sequencer));
+
+    // The RPC reports the leaders of the latest epoch.
+    let resp = test_sequencer
+        .registry
+        .leader_schedule(working_set)
+        .unwrap();
+    let schedule = resp.schedule.unwrap();
+    assert_eq!(15, schedule.epoch);
+    assert_eq!(15 * EPOCH_LENGTH, schedule.first_slot_height);
+    assert_eq!(
+        &leaders[leaders.len() - EPOCH_LENGTH as usize..],
+        &schedule.leaders[..]
+    );
+}
+
+#[test]
+fn leader_schedule_skips_exited_sequencers() {
+    let mut test_sequencer = create_rotating_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+    register_another_sequencer(&test_sequencer, working_set);
+
+    let exit_message = CallMessage::Exit {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(
+            exit_message,
+            &C::new(
+                test_sequencer.sequencer_config.seq_rollup_address,
+                generate_address(REWARD_SEQUENCER_KEY),
+                1,
+            ),
+            working_set,
+        )
+        .expect("Sequencer exit has failed");
+
+    for slot_height in 0..4 * EPOCH_LENGTH {
+        assert_eq!(
+            Some(MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS)),
+            begin_slot(&test_sequencer, slot_height, working_set)
+        );
+    }
+}
+
+#[test]
+fn priority_sequencer_does_not_store_leader_schedule() {
+    let mut test_sequencer = create_rotating_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    // Without a stored schedule, no sequencer has priority.
+    assert_eq!(
+        None,
+        test_sequencer
+            .registry
+            .get_priority_sequencer(0, working_set)
+    );
+    assert!(test_sequencer
+        .registry
+        .get_leader_schedule(working_set)
+        .is_none());
+
+    test_sequencer
+        .registry
+        .update_leader_schedule(0, prev_slot_hash(0), working_set);
+    let schedule = test_sequencer
+        .registry
+        .get_leader_schedule(working_set)
+        .unwrap();
+    assert_eq!(0, schedule.epoch);
+    assert_eq!(prev_slot_hash(0), schedule.seed);
+    assert_eq!(
+        vec![(
+            MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS),
+            LOCKED_AMOUNT
+        )],
+        schedule.stakes
+    );
+    assert_eq!(
+        Some(MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS)),
+        test_sequencer
+            .registry
+            .get_priority_sequencer(0, working_set)
+    );
+}
+
+#[test]
+fn leader_schedule_snapshots_topped_up_bonds() {
+    let mut test_sequencer = create_rotating_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+    register_another_sequencer(&test_sequencer, working_set);
+
+    let top_up = INITIAL_BALANCE - LOCKED_AMOUNT;
+    let top_up_message = CallMessage::TopUp {
+        da_address: ANOTHER_SEQUENCER_DA_ADDRESS.to_vec(),
+        amount: top_up,
+    };
+    test_sequencer
+        .registry
+        .call(
+            top_up_message,
+            &C::new(
+                generate_address(ANOTHER_SEQUENCER_KEY),
+                generate_address(REWARD_SEQUENCER_KEY),
+                1,
+            ),
+            working_set,
+        )
+        .expect("Sequencer top up has failed");
+
+    begin_slot(&test_sequencer, 0, working_set);
+    let schedule = test_sequencer
+        .registry
+        .get_leader_schedule(working_set)
+        .unwrap();
+    assert_eq!(
+        vec![
+            (
+                MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS),
+                LOCKED_AMOUNT
+            ),
+            (
+                MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS),
+                LOCKED_AMOUNT + top_up
+            ),
+        ],
+        schedule.stakes
+    );
+}
+
+#[test]
+fn leaders_are_drawn_by_stake() {
+    let registry = SequencerRegistry::<C, Da>::default();
+    let genesis_sequencer = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let another_sequencer = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+    let schedule = |seed: [u8; 32], genesis_stake, another_stake| LeaderSchedule {
+        epoch: 0,
+        first_slot_height: 0,
+        epoch_length: 64,
+        seed,
+        stakes: vec![
+            (genesis_sequencer, genesis_stake),
+            (another_sequencer, another_stake),
+        ],
+    };
+    let leaders = |schedule: &LeaderSchedule<Da>| -> Vec<MockAddress> {
+        (0..schedule.epoch_length)
+            .map(|slot_height| registry.leader_at(schedule, slot_height).unwrap())
+            .collect()
+    };
+
+    // A sequencer without stake never leads.
+    assert!(leaders(&schedule([0; 32], 0, LOCKED_AMOUNT))
+        .iter()
+        .all(|leader| *leader == another_sequencer));
+    assert_eq!(None, registry.leader_at(&schedule([0; 32], 0, 0), 0));
+
+    // The sequencer holding almost all the stake leads almost every slot.
+    let skewed = leaders(&schedule([0; 32], 1, 1_000_000_000));
+    let led_by_another = skewed
+        .iter()
+        .filter(|leader| **leader == another_sequencer)
+        .count();
+    assert!(led_by_another >= 63);
+
+    // The draws depend on the seed.
+    assert_ne!(
+        leaders(&schedule([0; 32], LOCKED_AMOUNT, LOCKED_AMOUNT)),
+        leaders(&schedule([1; 32], LOCKED_AMOUNT, LOCKED_AMOUNT))
+    );
+}
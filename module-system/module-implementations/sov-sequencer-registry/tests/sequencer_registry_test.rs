@@ -10,7 +10,7 @@ mod helpers;
 // This test checks:
 //  - genesis sequencer is present after genesis
 //  - registration works, and funds are deducted
-//  - exit works and funds are returned
+//  - exit works and funds are returned after the unbonding period
 #[test]
 fn test_registration_lifecycle() {
     let mut test_sequencer = create_test_sequencer();
@@ -85,13 +85,51 @@ fn test_registration_lifecycle() {
         .unwrap()
         .amount
         .unwrap();
-    assert_eq!(balance_before, balance_after_exit);
+    assert_eq!(balance_after_registration, balance_after_exit);
 
     let registry_response_after_exit = test_sequencer
         .registry
         .sequencer_address(da_address, working_set)
         .unwrap();
     assert!(registry_response_after_exit.address.is_none());
+
+    // The bond is still locked during the unbonding period
+    let withdraw_message = CallMessage::Withdraw {
+        da_address: da_address.as_ref().to_vec(),
+    };
+    let response = test_sequencer.registry.call(
+        withdraw_message.clone(),
+        &C::new(sequencer_address, reward_address, UNBONDING_PERIOD),
+        working_set,
+    );
+    assert_eq!(
+        format!(
+            "Bond of sequencer {} is locked until slot {}",
+            sequencer_address,
+            1 + UNBONDING_PERIOD
+        ),
+        response.err().unwrap().to_string()
+    );
+
+    test_sequencer
+        .registry
+        .call(
+            withdraw_message,
+            &C::new(sequencer_address, reward_address, 1 + UNBONDING_PERIOD),
+            working_set,
+        )
+        .expect("Sequencer withdraw has failed");
+
+    let balance_after_withdraw = test_sequencer
+        .query_balance(sequencer_address, working_set)
+        .unwrap()
+        .amount
+        .unwrap();
+    assert_eq!(balance_before, balance_after_withdraw);
+    assert!(test_sequencer
+        .registry
+        .get_unbonding_sequencer(&da_address, working_set)
+        .is_none());
 }
 
 #[test]
@@ -212,6 +250,75 @@ fn test_exit_different_sender() {
     assert_eq!("Unauthorized exit attempt", actual_error_message);
 }
 
+#[test]
+fn test_withdraw_different_sender() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let sequencer_address = generate_address(GENESIS_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let sender_context = C::new(sequencer_address, reward_address, 1);
+    let attacker_address = generate_address("some_random_key");
+    let attacker_context = C::new(attacker_address, reward_address, 1 + UNBONDING_PERIOD);
+
+    let exit_message = CallMessage::Exit {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(exit_message, &sender_context, working_set)
+        .expect("Sequencer exit has failed");
+
+    let withdraw_message = CallMessage::Withdraw {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    let response = test_sequencer
+        .registry
+        .call(withdraw_message, &attacker_context, working_set);
+
+    assert!(
+        response.is_err(),
+        "withdraw by non authorized sender should fail"
+    );
+    let actual_error_message = response.err().unwrap().to_string();
+
+    assert_eq!("Unauthorized withdraw attempt", actual_error_message);
+}
+
+#[test]
+fn test_register_while_unbonding() {
+    let mut test_sequencer = create_test_sequencer();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let sequencer_address = generate_address(GENESIS_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let sender_context = C::new(sequencer_address, reward_address, 1);
+
+    let exit_message = CallMessage::Exit {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    test_sequencer
+        .registry
+        .call(exit_message, &sender_context, working_set)
+        .expect("Sequencer exit has failed");
+
+    let register_message = CallMessage::Register {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    let response = test_sequencer
+        .registry
+        .call(register_message, &sender_context, working_set);
+
+    assert_eq!(
+        format!("sequencer {} is still unbonding", sequencer_address),
+        response.err().unwrap().to_string()
+    );
+}
+
 #[test]
 fn test_allow_exit_last_sequencer() {
     let mut test_sequencer = create_test_sequencer();
@@ -11,9 +11,9 @@ fn main() -> io::Result<()> {
     store_json_schema::<sov_bank::Bank<C>>("sov-bank.json")?;
     store_json_schema::<sov_accounts::Accounts<C>>("sov-accounts.json")?;
     store_json_schema::<sov_value_setter::ValueSetter<C>>("sov-value-setter.json")?;
-    store_json_schema::<sov_prover_incentives::ProverIncentives<C, MockZkvm<MockValidityCond>>>(
-        "sov-prover-incentives.json",
-    )?;
+    store_json_schema::<
+        sov_prover_incentives::ProverIncentives<C, MockZkvm<MockValidityCond>, MockDaSpec>,
+    >("sov-prover-incentives.json")?;
     store_json_schema::<sov_sequencer_registry::SequencerRegistry<C, MockDaSpec>>(
         "sov-sequencer-registry.json",
     )?;
@@ -2,6 +2,7 @@ use demo_stf::runtime::RuntimeCall;
 use sov_cli::wallet_state::{KeyIdentifier, PrivateKeyAndAddress, WalletState};
 use sov_cli::workflows::keys::KeyWorkflow;
 use sov_mock_da::MockDaSpec;
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::{PrivateKey, PublicKey, Spec};
 
@@ -11,7 +12,7 @@ type Da = MockDaSpec;
 fn test_key_gen() {
     let app_dir = tempfile::tempdir().unwrap();
     let mut wallet_state =
-        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+        WalletState::<RuntimeCall<DefaultContext, Da, MockZkVerifier>, DefaultContext>::default();
     let workflow = KeyWorkflow::Generate { nickname: None };
     workflow.run(&mut wallet_state, app_dir).unwrap();
 
@@ -30,7 +31,7 @@ fn test_key_import() {
 
     // Initialize an empty wallet
     let mut wallet_state =
-        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+        WalletState::<RuntimeCall<DefaultContext, Da, MockZkVerifier>, DefaultContext>::default();
     let workflow = KeyWorkflow::Import {
         nickname: Some("my-test-key".to_string()),
         address_override: None,
@@ -59,7 +60,7 @@ fn test_activate() {
     // Setup a wallet with two keys
     let app_dir = tempfile::tempdir().unwrap();
     let mut wallet_state =
-        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+        WalletState::<RuntimeCall<DefaultContext, Da, MockZkVerifier>, DefaultContext>::default();
     let workflow = KeyWorkflow::Generate {
         nickname: Some("key1".into()),
     };
@@ -4,6 +4,7 @@ use demo_stf::runtime::{Runtime, RuntimeCall, RuntimeSubcommand};
 use sov_cli::wallet_state::WalletState;
 use sov_cli::workflows::transactions::{ImportTransaction, TransactionWorkflow};
 use sov_mock_da::MockDaSpec;
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::cli::{FileNameArg, JsonStringArg};
 use sov_modules_api::default_context::DefaultContext;
 
@@ -13,7 +14,7 @@ type Da = MockDaSpec;
 fn test_import_transaction_from_string() {
     let app_dir = tempfile::tempdir().unwrap();
     let mut wallet_state =
-        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+        WalletState::<RuntimeCall<DefaultContext, Da, MockZkVerifier>, DefaultContext>::default();
 
     let test_token_path = make_test_path("requests/create_token.json");
     let subcommand = RuntimeSubcommand::<JsonStringArg, DefaultContext, Da>::bank {
@@ -27,10 +28,13 @@ fn test_import_transaction_from_string() {
 
     let workflow = TransactionWorkflow::Import(ImportTransaction::<
         _,
-        RuntimeSubcommand<JsonStringArg, DefaultContext, Da>,
+        RuntimeSubcommand<JsonStringArg, DefaultContext, Da, MockZkVerifier>,
     >::FromFile(subcommand));
     workflow
-        .run::<Runtime<DefaultContext, Da>, _, _, _, _, _>(&mut wallet_state, app_dir)
+        .run::<Runtime<DefaultContext, Da, MockZkVerifier>, _, _, _, _, _>(
+            &mut wallet_state,
+            app_dir,
+        )
         .unwrap();
 
     assert_eq!(wallet_state.unsent_transactions.len(), 1);
@@ -40,7 +44,7 @@ fn test_import_transaction_from_string() {
 fn test_import_transaction_from_file() {
     let app_dir = tempfile::tempdir().unwrap();
     let mut wallet_state =
-        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+        WalletState::<RuntimeCall<DefaultContext, Da, MockZkVerifier>, DefaultContext>::default();
 
     let test_token_path = make_test_path("requests/create_token.json");
     let subcommand = RuntimeSubcommand::<FileNameArg, DefaultContext, Da>::bank {
@@ -54,10 +58,13 @@ fn test_import_transaction_from_file() {
 
     let workflow = TransactionWorkflow::Import(ImportTransaction::<
         _,
-        RuntimeSubcommand<JsonStringArg, DefaultContext, Da>,
+        RuntimeSubcommand<JsonStringArg, DefaultContext, Da, MockZkVerifier>,
     >::FromFile(subcommand));
     workflow
-        .run::<Runtime<DefaultContext, Da>, _, _, _, _, _>(&mut wallet_state, app_dir)
+        .run::<Runtime<DefaultContext, Da, MockZkVerifier>, _, _, _, _, _>(
+            &mut wallet_state,
+            app_dir,
+        )
         .unwrap();
 
     assert_eq!(wallet_state.unsent_transactions.len(), 1);
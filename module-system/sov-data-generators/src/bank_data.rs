@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use sov_bank::{get_token_address, Bank, CallMessage, Coins};
+use sov_bank::{get_token_address, Amount, Bank, CallMessage, Coins};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_api::transaction::Transaction;
@@ -13,13 +13,13 @@ pub struct TransferData<C: Context> {
     pub sender_pkey: Rc<C::PrivateKey>,
     pub receiver_address: <C as Spec>::Address,
     pub token_address: <C as Spec>::Address,
-    pub transfer_amount: u64,
+    pub transfer_amount: Amount,
 }
 
 pub struct MintData<C: Context> {
     pub token_name: String,
     pub salt: u64,
-    pub initial_balance: u64,
+    pub initial_balance: Amount,
     pub minter_address: <C as Spec>::Address,
     pub minter_pkey: Rc<C::PrivateKey>,
     pub authorized_minters: Vec<<C as Spec>::Address>,
@@ -36,7 +36,7 @@ const DEFAULT_PVT_KEY: &str = "236e80cb222c4ed0431b093b3ac53e6aa7a2273fe1f4351cd
 const DEFAULT_CHAIN_ID: u64 = 0;
 const DEFAULT_GAS_TIP: u64 = 0;
 const DEFAULT_GAS_LIMIT: u64 = 0;
-const DEFAULT_INIT_BALANCE: u64 = 1000000;
+const DEFAULT_INIT_BALANCE: Amount = 1000000;
 
 pub fn get_default_token_address() -> <DefaultContext as Spec>::Address {
     let minter_key = DefaultPrivateKey::from_hex(DEFAULT_PVT_KEY).unwrap();
@@ -146,7 +146,7 @@ impl BankMessageGenerator<DefaultContext> {
         salt: u64,
         minter_pkey: std::rc::Rc<DefaultPrivateKey>,
         authorized_minters: Vec<<DefaultContext as Spec>::Address>,
-        initial_balance: u64,
+        initial_balance: Amount,
     ) -> Self {
         Self {
             token_mint_txs: vec![MintData {
@@ -207,6 +207,9 @@ pub(crate) fn mint_token_tx<C: Context>(mint_data: &MintData<C>) -> CallMessage<
     CallMessage::CreateToken {
         salt: mint_data.salt,
         token_name: mint_data.token_name.clone(),
+        symbol: "TKN".to_owned(),
+        decimals: 18,
+        max_supply: None,
         initial_balance: mint_data.initial_balance,
         minter_address: mint_data.minter_address.clone(),
         authorized_minters: mint_data.authorized_minters.clone(),
@@ -303,6 +306,9 @@ impl MessageGenerator for BadSerializationBankCallMessages {
             CallMessage::CreateToken {
                 salt,
                 token_name,
+                symbol: "TKN".to_owned(),
+                decimals: 18,
+                max_supply: None,
                 initial_balance: 1000,
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
@@ -381,6 +387,9 @@ impl MessageGenerator for BadSignatureBankCallMessages {
             CallMessage::CreateToken {
                 salt,
                 token_name,
+                symbol: "TKN".to_owned(),
+                decimals: 18,
+                max_supply: None,
                 initial_balance: 1000,
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
@@ -417,7 +426,9 @@ impl MessageGenerator for BadSignatureBankCallMessages {
             Transaction::new(
                 DefaultPrivateKey::generate().pub_key(),
                 call_data,
-                tx.signature().clone(),
+                tx.signature()
+                    .expect("Transaction signed by a rollup key")
+                    .clone(),
                 chain_id,
                 gas_tip,
                 gas_limit,
@@ -460,6 +471,9 @@ impl MessageGenerator for BadNonceBankCallMessages {
             CallMessage::CreateToken {
                 salt,
                 token_name,
+                symbol: "TKN".to_owned(),
+                decimals: 18,
+                max_supply: None,
                 initial_balance: 1000,
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
@@ -0,0 +1,133 @@
+//! Authentication of transactions signed with Ethereum keys.
+//!
+//! Ethereum wallets sign a [`Transaction`](crate::transaction::Transaction) as the EIP-712 typed
+//! data
+//!
+//! ```text
+//! Transaction(bytes runtimeMsg,uint64 chainId,uint64 gasTip,uint64 gasLimit,uint64 nonce)
+//! ```
+//!
+//! in the domain `EIP712Domain(string name,string version,uint256 chainId)` named
+//! [`EIP712_DOMAIN_NAME`], at version [`EIP712_DOMAIN_VERSION`].
+
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use sov_modules_core::Context;
+use sov_rollup_interface::digest::Digest;
+use tiny_keccak::{Hasher, Keccak};
+
+/// The address of an Ethereum account.
+pub type EthereumAddress = [u8; 20];
+
+/// The name of the EIP-712 domain of the transactions.
+pub const EIP712_DOMAIN_NAME: &str = "Sovereign SDK";
+
+/// The version of the EIP-712 domain of the transactions.
+pub const EIP712_DOMAIN_VERSION: &str = "1";
+
+const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+
+const TRANSACTION_TYPE: &str =
+    "Transaction(bytes runtimeMsg,uint64 chainId,uint64 gasTip,uint64 gasLimit,uint64 nonce)";
+
+/// Length of a signature encoded as `r || s || v`.
+pub const ETHEREUM_SIGNATURE_LEN: usize = 65;
+
+fn keccak256(chunks: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    let mut output = [0; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// ABI encoding of an unsigned integer.
+fn word(value: u64) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Returns the EIP-712 hash signed by the Ethereum key authenticating a transaction.
+pub fn eip712_signing_hash(
+    runtime_msg: &[u8],
+    chain_id: u64,
+    gas_tip: u64,
+    gas_limit: u64,
+    nonce: u64,
+) -> [u8; 32] {
+    let domain_separator = keccak256(&[
+        &keccak256(&[EIP712_DOMAIN_TYPE.as_bytes()]),
+        &keccak256(&[EIP712_DOMAIN_NAME.as_bytes()]),
+        &keccak256(&[EIP712_DOMAIN_VERSION.as_bytes()]),
+        &word(chain_id),
+    ]);
+    let struct_hash = keccak256(&[
+        &keccak256(&[TRANSACTION_TYPE.as_bytes()]),
+        &keccak256(&[runtime_msg]),
+        &word(chain_id),
+        &word(gas_tip),
+        &word(gas_limit),
+        &word(nonce),
+    ]);
+
+    keccak256(&[&[0x19, 0x01], &domain_separator, &struct_hash])
+}
+
+/// Returns the address of the Ethereum account controlled by `key`.
+pub fn address_of(key: &VerifyingKey) -> EthereumAddress {
+    let point = key.to_encoded_point(false);
+    let hash = keccak256(&[&point.as_bytes()[1..]]);
+    let mut address = [0; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Returns the module address of the Ethereum account `address`. Transactions signed by the
+/// account are sent from this address, so the same key controls it from any Ethereum wallet.
+pub fn module_address<C: Context>(address: &EthereumAddress) -> C::Address {
+    let mut hasher = C::Hasher::new();
+    hasher.update(b"ethereum");
+    hasher.update(address);
+
+    let hash: [u8; 32] = hasher.finalize().into();
+    C::Address::from(hash)
+}
+
+/// Recovers the address of the Ethereum account that signed `hash`. The signature is
+/// encoded as `r || s || v`, where `v` is either 0/1 or 27/28.
+pub fn recover_signer(hash: &[u8; 32], signature: &[u8]) -> anyhow::Result<EthereumAddress> {
+    anyhow::ensure!(
+        signature.len() == ETHEREUM_SIGNATURE_LEN,
+        "Ethereum signatures are {} bytes long, got {}",
+        ETHEREUM_SIGNATURE_LEN,
+        signature.len()
+    );
+
+    let ecdsa_signature = Signature::from_slice(&signature[..64])?;
+    // A signature with a high `s` has a twin with a low `s`, only accept one of them.
+    anyhow::ensure!(
+        ecdsa_signature.normalize_s().is_none(),
+        "Ethereum signature is not normalized"
+    );
+
+    let v = signature[64];
+    let recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })
+        .ok_or_else(|| anyhow::anyhow!("Invalid recovery id {}", v))?;
+    let key = VerifyingKey::recover_from_prehash(hash, &ecdsa_signature, recovery_id)?;
+
+    Ok(address_of(&key))
+}
+
+/// Signs `hash` with an Ethereum `signing_key`, returning the signature as `r || s || v`.
+#[cfg(feature = "native")]
+pub fn sign(signing_key: &k256::ecdsa::SigningKey, hash: &[u8; 32]) -> Vec<u8> {
+    let (signature, recovery_id) = signing_key
+        .sign_prehash_recoverable(hash)
+        .expect("Signing a 32 bytes hash can't fail");
+
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(27 + recovery_id.to_byte());
+    bytes
+}
@@ -5,6 +5,7 @@ pub mod cli;
 mod containers;
 pub mod default_context;
 pub mod default_signature;
+pub mod ethereum;
 pub mod hooks;
 mod pub_key_hex;
 
@@ -54,7 +55,7 @@ pub mod optimistic {
 }
 
 pub mod da {
-    pub use sov_rollup_interface::da::{BlockHeaderTrait, NanoSeconds, Time};
+    pub use sov_rollup_interface::da::{chunks, direct, BlockHeaderTrait, NanoSeconds, Time};
 }
 
 pub mod storage {
@@ -180,3 +180,93 @@ fn test_default_signature_roundtrip() {
     sig.verify(&key.pub_key(), msg)
         .expect("Roundtrip verification failed");
 }
+
+fn ethereum_signing_key() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_slice(&[7; 32]).unwrap()
+}
+
+#[test]
+fn test_ethereum_transaction_roundtrip() {
+    use crate::transaction::{Authenticator, Transaction};
+
+    let signing_key = ethereum_signing_key();
+    let tx = Transaction::<DefaultContext>::new_signed_ethereum_tx(
+        &signing_key,
+        b"hello, world".to_vec(),
+        1,
+        2,
+        3,
+        4,
+    );
+    tx.verify().expect("Roundtrip verification failed");
+
+    let Authenticator::Ethereum { address, .. } = tx.authenticator() else {
+        panic!("Expected an Ethereum authenticator");
+    };
+    assert_eq!(
+        *address,
+        crate::ethereum::address_of(signing_key.verifying_key())
+    );
+    assert_eq!(None, tx.pub_key());
+
+    let deserialized_tx =
+        Transaction::<DefaultContext>::try_from_slice(&tx.try_to_vec().unwrap()).unwrap();
+    assert_eq!(tx, deserialized_tx);
+}
+
+#[test]
+fn test_standard_transaction_serialization_unchanged() {
+    use crate::transaction::Transaction;
+
+    let key = DefaultPrivateKey::generate();
+    let tx = Transaction::<DefaultContext>::new_signed_tx(&key, b"hello".to_vec(), 1, 2, 3, 4);
+    let serialized_tx = tx.try_to_vec().unwrap();
+
+    let mut expected = tx.signature().unwrap().try_to_vec().unwrap();
+    expected.extend(key.pub_key().try_to_vec().unwrap());
+    assert!(serialized_tx.starts_with(&expected));
+
+    let deserialized_tx = Transaction::<DefaultContext>::try_from_slice(&serialized_tx).unwrap();
+    assert_eq!(tx, deserialized_tx);
+    deserialized_tx.verify().unwrap();
+}
+
+#[test]
+fn test_tampered_ethereum_transaction() {
+    use crate::transaction::{Authenticator, Transaction};
+
+    let tx = Transaction::<DefaultContext>::new_signed_ethereum_tx(
+        &ethereum_signing_key(),
+        b"hello, world".to_vec(),
+        1,
+        2,
+        3,
+        4,
+    );
+    let Authenticator::Ethereum { address, signature } = tx.authenticator().clone() else {
+        panic!("Expected an Ethereum authenticator");
+    };
+
+    // A different nonce changes the signed hash, so another address is recovered.
+    let tampered_tx = Transaction::<DefaultContext>::new_ethereum(
+        address,
+        b"hello, world".to_vec(),
+        signature.clone(),
+        1,
+        2,
+        3,
+        5,
+    );
+    assert!(tampered_tx.verify().is_err());
+
+    let truncated_tx = Transaction::<DefaultContext>::new_ethereum(
+        address,
+        b"hello, world".to_vec(),
+        signature[..64].to_vec(),
+        1,
+        2,
+        3,
+        4,
+    );
+    assert!(truncated_tx.verify().is_err());
+}
@@ -3,20 +3,82 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "native")]
 use sov_modules_core::PrivateKey;
-use sov_modules_core::{Context, GasUnit, Signature};
+use sov_modules_core::{Context, GasUnit, PublicKey, Signature};
 use sov_modules_macros::config_constant;
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use sov_zk_cycle_macros::cycle_tracker;
 
+use crate::ethereum::{self, EthereumAddress};
+
 const EXTEND_MESSAGE_LEN: usize = 4 * core::mem::size_of::<u64>();
 
+/// Marks the Ethereum [`Authenticator`]s in the serialized transactions. It would be the
+/// beginning of a [`Authenticator::Standard`] signature otherwise, but it isn't a canonical
+/// ed25519 point, so valid signatures never start with it.
+const ETHEREUM_AUTHENTICATOR_TAG: [u8; 32] = [0xff; 32];
+
+/// Proves that the sender of a [`Transaction`] authorized it.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize)]
+pub enum Authenticator<C: Context> {
+    /// A signature of the transaction by a rollup key.
+    Standard {
+        /// Signature of the transaction.
+        signature: C::Signature,
+        /// Key of the sender.
+        pub_key: C::PublicKey,
+    },
+    /// A signature of the EIP-712 typed data of the transaction by an Ethereum key.
+    Ethereum {
+        /// Signature of the transaction, as `r || s || v`.
+        signature: Vec<u8>,
+        /// Address of the sender.
+        address: EthereumAddress,
+    },
+}
+
+impl<C: Context> BorshSerialize for Authenticator<C> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            // Serialized like the transactions predating the Ethereum authenticators.
+            Self::Standard { signature, pub_key } => {
+                signature.serialize(writer)?;
+                pub_key.serialize(writer)
+            }
+            Self::Ethereum { signature, address } => {
+                writer.write_all(&ETHEREUM_AUTHENTICATOR_TAG)?;
+                signature.serialize(writer)?;
+                address.serialize(writer)
+            }
+        }
+    }
+}
+
+impl<C: Context> BorshDeserialize for Authenticator<C> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut tag = [0; ETHEREUM_AUTHENTICATOR_TAG.len()];
+        reader.read_exact(&mut tag)?;
+
+        if tag == ETHEREUM_AUTHENTICATOR_TAG {
+            return Ok(Self::Ethereum {
+                signature: Vec::deserialize_reader(reader)?,
+                address: EthereumAddress::deserialize_reader(reader)?,
+            });
+        }
+
+        let mut reader = std::io::Read::chain(&tag[..], reader);
+        Ok(Self::Standard {
+            signature: C::Signature::deserialize_reader(&mut reader)?,
+            pub_key: C::PublicKey::deserialize_reader(&mut reader)?,
+        })
+    }
+}
+
 /// A Transaction object that is compatible with the module-system/sov-default-stf.
 #[derive(
     Debug, PartialEq, Eq, Clone, borsh::BorshDeserialize, borsh::BorshSerialize, serde::Serialize,
 )]
 pub struct Transaction<C: Context> {
-    signature: C::Signature,
-    pub_key: C::PublicKey,
+    authenticator: Authenticator<C>,
     runtime_msg: Vec<u8>,
     chain_id: u64,
     gas_tip: u64,
@@ -42,12 +104,33 @@ where
 }
 
 impl<C: Context> Transaction<C> {
-    pub fn signature(&self) -> &C::Signature {
-        &self.signature
+    pub fn authenticator(&self) -> &Authenticator<C> {
+        &self.authenticator
+    }
+
+    /// The address the transaction is sent from, unless the sender rotated their key with
+    /// `sov-accounts`.
+    pub fn default_sender_address(&self) -> C::Address {
+        match &self.authenticator {
+            Authenticator::Standard { pub_key, .. } => pub_key.to_address(),
+            Authenticator::Ethereum { address, .. } => ethereum::module_address::<C>(address),
+        }
     }
 
-    pub fn pub_key(&self) -> &C::PublicKey {
-        &self.pub_key
+    /// The signature of a transaction authenticated by a rollup key.
+    pub fn signature(&self) -> Option<&C::Signature> {
+        match &self.authenticator {
+            Authenticator::Standard { signature, .. } => Some(signature),
+            Authenticator::Ethereum { .. } => None,
+        }
+    }
+
+    /// The sender key of a transaction authenticated by a rollup key.
+    pub fn pub_key(&self) -> Option<&C::PublicKey> {
+        match &self.authenticator {
+            Authenticator::Standard { pub_key, .. } => Some(pub_key),
+            Authenticator::Ethereum { .. } => None,
+        }
     }
 
     pub fn runtime_msg(&self) -> &[u8] {
@@ -89,6 +172,20 @@ impl<C: Context> Transaction<C> {
     /// Check whether the transaction has been signed correctly.
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     pub fn verify(&self) -> anyhow::Result<()> {
+        let (signature, pub_key) = match &self.authenticator {
+            Authenticator::Standard { signature, pub_key } => (signature, pub_key),
+            Authenticator::Ethereum { signature, address } => {
+                let signer = ethereum::recover_signer(&self.eip712_signing_hash(), signature)?;
+                anyhow::ensure!(
+                    signer == *address,
+                    "Transaction signed by 0x{} instead of 0x{}",
+                    hex::encode(signer),
+                    hex::encode(address)
+                );
+                return Ok(());
+            }
+        };
+
         let mut serialized_tx = Vec::with_capacity(self.runtime_msg().len() + EXTEND_MESSAGE_LEN);
 
         serialized_tx.extend_from_slice(self.runtime_msg());
@@ -97,11 +194,22 @@ impl<C: Context> Transaction<C> {
         serialized_tx.extend_from_slice(&self.gas_limit().to_le_bytes());
         serialized_tx.extend_from_slice(&self.nonce().to_le_bytes());
 
-        self.signature().verify(&self.pub_key, &serialized_tx)?;
+        signature.verify(pub_key, &serialized_tx)?;
 
         Ok(())
     }
 
+    /// The EIP-712 hash signed by an Ethereum key authenticating the transaction.
+    pub fn eip712_signing_hash(&self) -> [u8; 32] {
+        ethereum::eip712_signing_hash(
+            &self.runtime_msg,
+            self.chain_id,
+            self.gas_tip,
+            self.gas_limit,
+            self.nonce,
+        )
+    }
+
     /// New transaction.
     pub fn new(
         pub_key: C::PublicKey,
@@ -113,9 +221,28 @@ impl<C: Context> Transaction<C> {
         nonce: u64,
     ) -> Self {
         Self {
-            signature,
+            authenticator: Authenticator::Standard { signature, pub_key },
+            runtime_msg: message,
+            chain_id,
+            gas_tip,
+            gas_limit,
+            nonce,
+        }
+    }
+
+    /// New transaction authenticated by an Ethereum key.
+    pub fn new_ethereum(
+        address: EthereumAddress,
+        message: Vec<u8>,
+        signature: Vec<u8>,
+        chain_id: u64,
+        gas_tip: u64,
+        gas_limit: u64,
+        nonce: u64,
+    ) -> Self {
+        Self {
+            authenticator: Authenticator::Ethereum { signature, address },
             runtime_msg: message,
-            pub_key,
             chain_id,
             gas_tip,
             gas_limit,
@@ -154,15 +281,32 @@ impl<C: Context> Transaction<C> {
         message.truncate(len);
 
         Self {
-            signature,
+            authenticator: Authenticator::Standard { signature, pub_key },
             runtime_msg: message,
-            pub_key,
             chain_id,
             gas_tip,
             gas_limit,
             nonce,
         }
     }
+
+    /// New transaction signed by an Ethereum key, as a wallet would sign its EIP-712 typed data.
+    pub fn new_signed_ethereum_tx(
+        signing_key: &k256::ecdsa::SigningKey,
+        message: Vec<u8>,
+        chain_id: u64,
+        gas_tip: u64,
+        gas_limit: u64,
+        nonce: u64,
+    ) -> Self {
+        let hash = ethereum::eip712_signing_hash(&message, chain_id, gas_tip, gas_limit, nonce);
+        let signature = ethereum::sign(signing_key, &hash);
+        let address = ethereum::address_of(signing_key.verifying_key());
+
+        Self::new_ethereum(
+            address, message, signature, chain_id, gas_tip, gas_limit, nonce,
+        )
+    }
 }
 
 impl<Tx> UnsignedTransaction<Tx>
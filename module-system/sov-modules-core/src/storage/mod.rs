@@ -277,6 +277,10 @@ pub trait Storage: Clone {
     /// Indicates if storage is empty or not.
     /// Useful during initialization.
     fn is_empty(&self) -> bool;
+
+    /// Returns the version the next state update is committed at, or `None` if the storage
+    /// isn't versioned.
+    fn next_version(&self) -> Option<Version>;
 }
 
 /// Used only in tests.
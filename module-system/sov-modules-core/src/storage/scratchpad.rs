@@ -375,6 +375,12 @@ impl<C: Context> WorkingSet<C> {
         ArchivalAccessoryWorkingSet::new(&self.accessory_delta.inner.storage, version)
     }
 
+    /// Returns the version the changes of the working set are committed at, or `None` if the
+    /// storage isn't versioned.
+    pub fn next_version(&self) -> Option<Version> {
+        self.delta.inner.inner.next_version()
+    }
+
     /// Sets archival version for a working set
     pub fn set_archival_version(&mut self, version: Version) {
         self.archival_working_set = Some(self.archival_state(version));
@@ -439,6 +445,17 @@ impl<C: Context> WorkingSet<C> {
         }
     }
 
+    /// Discards the changes and the events added to this [`WorkingSet`] since it was created
+    /// from a [`StateCheckpoint`], keeping its gas meter.
+    ///
+    /// Unlike [`WorkingSet::revert`], it doesn't consume the [`WorkingSet`], so modules can undo
+    /// the changes of the call they are dispatching.
+    pub fn revert_in_place(&mut self) {
+        self.delta.writes.clear();
+        self.accessory_delta.writes.clear();
+        self.events.clear();
+    }
+
     /// Adds an event to the working set.
     pub fn add_event(&mut self, key: &str, value: &str) {
         self.events.push(Event::new(key, value));
@@ -523,6 +540,14 @@ pub struct AccessoryWorkingSet<'a, C: Context> {
     ws: &'a mut WorkingSet<C>,
 }
 
+impl<'a, C: Context> AccessoryWorkingSet<'a, C> {
+    /// Returns the version the changes of the working set are committed at, or `None` if the
+    /// storage isn't versioned.
+    pub fn next_version(&self) -> Option<Version> {
+        self.ws.next_version()
+    }
+}
+
 impl<'a, C: Context> StateReaderAndWriter for AccessoryWorkingSet<'a, C> {
     fn get(&mut self, key: &StorageKey) -> Option<StorageValue> {
         if !cfg!(feature = "native") {
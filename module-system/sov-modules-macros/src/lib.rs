@@ -41,7 +41,7 @@ use proc_macro::TokenStream;
 use rpc::ExposeRpcMacro;
 use syn::{parse_macro_input, DeriveInput, ItemFn};
 
-#[proc_macro_derive(ModuleInfo, attributes(state, module, address, gas))]
+#[proc_macro_derive(ModuleInfo, attributes(state, module, address, gas, phantom))]
 pub fn module_info(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
 
@@ -50,7 +50,7 @@ pub fn module_info(input: TokenStream) -> TokenStream {
 
 #[proc_macro_derive(
     KernelModuleInfo,
-    attributes(state, module, kernel_module, address, gas)
+    attributes(state, module, kernel_module, address, gas, phantom)
 )]
 pub fn kernel_module_info(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
@@ -98,9 +98,10 @@ fn impl_module_info(
                         "The `#[kernel_module]` attribute is only allowed in kernel modules.",
                     ));
                 }
+                // Kernel modules are initialized by the kernel before the runtime, so they
+                // aren't listed as genesis dependencies of the module.
                 impl_self_init.push(make_init_module(field, ModuleType::Kernel)?);
                 impl_self_body.push(&field.ident);
-                modules.push(&field.ident);
             }
             ModuleFieldAttribute::Address => {
                 impl_self_init.push(make_init_address(field, ident, generic_param)?);
@@ -110,6 +111,10 @@ fn impl_module_info(
                 impl_self_init.push(make_init_gas_config(ident, field)?);
                 impl_self_body.push(&field.ident);
             }
+            ModuleFieldAttribute::Phantom => {
+                impl_self_init.push(make_init_phantom(field));
+                impl_self_body.push(&field.ident);
+            }
         };
     }
 
@@ -257,6 +262,14 @@ fn make_init_gas_config(
     Manifest::read_constants(parent)?.parse_gas_config(ty, field_ident)
 }
 
+fn make_init_phantom(field: &ModuleField) -> proc_macro2::TokenStream {
+    let field_ident = &field.ident;
+
+    quote::quote! {
+        let #field_ident = ::core::marker::PhantomData;
+    }
+}
+
 fn make_module_prefix_fn(struct_ident: &Ident) -> proc_macro2::TokenStream {
     let body = make_module_prefix_fn_body(struct_ident);
     quote::quote! {
@@ -348,6 +361,7 @@ pub mod parsing {
         State { codec_builder: Option<syn::Path> },
         Address,
         Gas,
+        Phantom,
     }
 
     impl ModuleFieldAttribute {
@@ -394,6 +408,16 @@ pub mod parsing {
                         ))
                     }
                 }
+                "phantom" => {
+                    if attr.tokens.is_empty() {
+                        Ok(Self::Phantom)
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            attr,
+                            "The `#[phantom]` attribute does not accept any arguments.",
+                        ))
+                    }
+                }
                 _ => unreachable!("attribute names were validated already; this is a bug"),
             }
         }
@@ -518,9 +542,9 @@ pub mod parsing {
         let mut attr = None;
         for a in field.attrs.iter() {
             match a.path.segments[0].ident.to_string().as_str() {
-                "state" | "module" | "address" | "gas" | "kernel_module" => {
+                "state" | "module" | "address" | "gas" | "kernel_module" | "phantom" => {
                     if attr.is_some() {
-                        return Err(syn::Error::new_spanned(ident, "Only one attribute out of `#[kernel_module]`, `#[module]`, `#[state]`, `#[address]`, and #[gas] is allowed per field."));
+                        return Err(syn::Error::new_spanned(ident, "Only one attribute out of `#[kernel_module]`, `#[module]`, `#[state]`, `#[address]`, #[gas], and `#[phantom]` is allowed per field."));
                     } else {
                         attr = Some(a);
                     }
@@ -534,7 +558,7 @@ pub mod parsing {
         } else {
             Err(syn::Error::new_spanned(
                 ident,
-                format!("The field `{}` is missing an attribute: add `#[kernel_module]`, `#[module]`, `#[state]`, `#[address]`, or #[gas].", ident),
+                format!("The field `{}` is missing an attribute: add `#[kernel_module]`, `#[module]`, `#[state]`, `#[address]`, #[gas], or `#[phantom]`.", ident),
             ))
         }
     }
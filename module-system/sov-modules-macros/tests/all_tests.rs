@@ -18,6 +18,7 @@ fn module_info_tests() {
     t.pass("tests/module_info/use_address_trait.rs");
     t.pass("tests/module_info/not_supported_attribute.rs");
     t.pass("tests/module_info/custom_codec_builder.rs");
+    t.pass("tests/module_info/phantom_field.rs");
     t.pass("tests/custom_codec_must_be_used.rs");
     t.compile_fail("tests/module_info/derive_on_enum_not_supported.rs");
     t.compile_fail("tests/module_info/field_missing_attribute.rs");
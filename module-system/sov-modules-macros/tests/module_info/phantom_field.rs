@@ -0,0 +1,23 @@
+use std::marker::PhantomData;
+
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::{Context, ModuleInfo, StateValue};
+
+#[derive(ModuleInfo)]
+struct TestModule<C: Context, T> {
+    #[address]
+    address: C::Address,
+
+    #[state]
+    value: StateValue<u32>,
+
+    #[phantom]
+    phantom: PhantomData<T>,
+}
+
+fn main() {
+    let module = TestModule::<ZkDefaultContext, String>::default();
+    let _ = module.address();
+    let _ = module.value;
+    let _ = module.phantom;
+}
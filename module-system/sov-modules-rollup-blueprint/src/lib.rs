@@ -105,12 +105,13 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         rollup_config: &RollupConfig<Self::DaConfig>,
     ) -> Self::DaService;
 
-    /// Creates instance of [`ProverService`].
+    /// Creates instance of [`ProverService`]. The `storage` follows the state of the rollup.
     async fn create_prover_service(
         &self,
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
         da_service: &Self::DaService,
+        storage: &<Self::NativeContext as Spec>::Storage,
     ) -> Self::ProverService;
 
     /// Creates instance of [`Self::StorageManager`].
@@ -144,8 +145,12 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         // Maybe whole "prev_root" can be initialized inside runner
         // Getting block here, so prover_service doesn't have to be `Send`
         let last_finalized_block_header = da_service.get_last_finalized_block_header().await?;
+
+        let mut storage_manager = self.create_storage_manager(&rollup_config)?;
+        let prover_storage = storage_manager.create_finalized_storage()?;
+
         let prover_service = self
-            .create_prover_service(prover_config, &rollup_config, &da_service)
+            .create_prover_service(prover_config, &rollup_config, &da_service, &prover_storage)
             .await;
 
         let ledger_db = self.create_ledger_db(&rollup_config);
@@ -155,9 +160,6 @@ pub trait RollupBlueprint: Sized + Send + Sync {
             &rollup_config,
         )?;
 
-        let mut storage_manager = self.create_storage_manager(&rollup_config)?;
-        let prover_storage = storage_manager.create_finalized_storage()?;
-
         let prev_root = ledger_db
             .get_head_slot()?
             .map(|(number, _)| prover_storage.get_root_hash(number.0))
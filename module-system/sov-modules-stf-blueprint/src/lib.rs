@@ -24,16 +24,20 @@ pub use tx_verifier::RawTx;
 
 /// The tx hook for a blueprint runtime
 pub struct RuntimeTxHook<C: Context> {
-    /// Height to initialize the context
+    /// The true height of the slot, used to initialize the context
     pub height: u64,
-    /// Sequencer public key
-    pub sequencer: C::PublicKey,
+    /// Sequencer public key, if any
+    pub sequencer: Option<C::PublicKey>,
+    /// DA address of the sequencer that included the transaction, or [`None`] for the
+    /// transaction of a direct blob
+    pub sequencer_da_address: Option<Vec<u8>>,
 }
 
 /// This trait has to be implemented by a runtime in order to be used in `StfBlueprint`.
 ///
 /// The `TxHooks` implementation sets up a transaction context based on the height at which it is
-/// to be executed.
+/// to be executed. If its pre-dispatch hook fails with a [`SlashingReason`] error, the sequencer
+/// that included the transaction is slashed and the rest of its batch is skipped.
 pub trait Runtime<C: Context, Da: DaSpec>:
     DispatchCall<Context = C>
     + Genesis<Context = C, Config = Self::GenesisConfig>
@@ -79,7 +83,13 @@ pub enum TxEffect {
 /// Represents the different outcomes that can occur for a sequencer after batch processing.
 pub enum SequencerOutcome<A: BasicAddress> {
     /// Sequencer receives reward amount in defined token and can withdraw its deposit
-    Rewarded(u64),
+    Rewarded {
+        /// Amount of gas collected from the batch that is owed to the sequencer.
+        amount: u64,
+        #[serde(bound(deserialize = ""))]
+        /// Sequencer address on DA.
+        sequencer_da_address: A,
+    },
     /// Sequencer loses its deposit and receives no reward
     Slashed {
         /// Reason why sequencer was slashed.
@@ -90,6 +100,15 @@ pub enum SequencerOutcome<A: BasicAddress> {
     },
     /// Batch was ignored, sequencer deposit left untouched.
     Ignored,
+    /// A direct blob posted by a user was executed. Its transaction paid its own gas, and no
+    /// sequencer is rewarded or slashed.
+    Direct {
+        /// Amount of gas paid by the transaction of the direct blob.
+        amount: u64,
+        #[serde(bound(deserialize = ""))]
+        /// Sender of the direct blob on DA.
+        sender_da_address: A,
+    },
 }
 
 /// Genesis parameters for a blueprint
@@ -101,14 +120,22 @@ pub struct GenesisParams<RT, K> {
 }
 
 /// Reason why sequencer was slashed.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, thiserror::Error,
+)]
 pub enum SlashingReason {
     /// This status indicates problem with batch deserialization.
+    #[error("the batch could not be deserialized")]
     InvalidBatchEncoding,
     /// Stateless verification failed, for example deserialized transactions have invalid signatures.
+    #[error("a transaction failed stateless verification")]
     StatelessVerificationFailed,
     /// This status indicates problem with transaction deserialization.
+    #[error("a transaction could not be deserialized")]
     InvalidTransactionEncoding,
+    /// Neither the sender of a transaction nor the sequencer could pay for its base cost.
+    #[error("neither the sender nor the sequencer could pay for the base cost of a transaction")]
+    UnpaidTransactionBaseCost,
 }
 
 impl<C, RT, Vm, Da, K> StfBlueprint<C, Da, Vm, RT, K>
@@ -265,6 +292,8 @@ where
         let mut batch_workspace = checkpoint.to_revertable();
         let mut kernel_working_set =
             KernelWorkingSet::from_kernel(&self.kernel, &mut batch_workspace);
+        // Transactions see the true height of the slot, as tracked by the kernel.
+        let slot_height = kernel_working_set.current_slot();
         let selected_blobs = self
             .kernel
             .get_blobs_for_this_slot(blobs, &mut kernel_working_set)
@@ -281,7 +310,7 @@ where
 
         for (blob_idx, mut blob) in selected_blobs.into_iter().enumerate() {
             let (apply_blob_result, checkpoint_after_blob) =
-                self.apply_blob(checkpoint, blob.as_mut_ref());
+                self.apply_blob(checkpoint, blob.as_mut_ref(), slot_height);
             checkpoint = checkpoint_after_blob;
             let batch_receipt = apply_blob_result.unwrap_or_else(Into::into);
             info!(
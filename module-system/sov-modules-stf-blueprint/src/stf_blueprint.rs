@@ -1,6 +1,8 @@
 use std::marker::PhantomData;
 
 use borsh::BorshDeserialize;
+use sov_modules_api::da::direct::{decode_direct_blob, is_direct_blob, DIRECT_BLOB_MAGIC};
+use sov_modules_api::macros::config_constant;
 use sov_modules_api::runtime::capabilities::KernelSlotHooks;
 use sov_modules_api::{
     BasicAddress, BlobReaderTrait, Context, DaSpec, DispatchCall, GasUnit, StateCheckpoint,
@@ -9,7 +11,7 @@ use sov_modules_core::WorkingSet;
 use sov_rollup_interface::stf::{BatchReceipt, TransactionReceipt};
 use tracing::{debug, error};
 
-use crate::tx_verifier::{verify_txs_stateless, TransactionAndRawHash};
+use crate::tx_verifier::{verify_txs_stateless, RawTx, TransactionAndRawHash};
 use crate::{Batch, Runtime, RuntimeTxHook, SequencerOutcome, SlashingReason, TxEffect};
 
 type ApplyBatchResult<T, A> = Result<T, ApplyBatchError<A>>;
@@ -22,6 +24,10 @@ type ApplyBatch<Da: DaSpec> = ApplyBatchResult<
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use sov_zk_cycle_macros::cycle_tracker;
 
+// TODO fetch gas price from chain state
+#[config_constant]
+const GAS_ELASTIC_PRICE: &[u64];
+
 /// An implementation of the
 /// [`StateTransitionFunction`](sov_rollup_interface::stf::StateTransitionFunction)
 /// that is specifically designed to work with the module-system.
@@ -105,7 +111,12 @@ where
         &self,
         checkpoint: StateCheckpoint<C>,
         blob: &mut Da::BlobTransaction,
+        slot_height: u64,
     ) -> (ApplyBatch<Da>, StateCheckpoint<C>) {
+        if is_direct_blob(read_magic(blob)) {
+            return self.apply_direct_blob(checkpoint, blob, slot_height);
+        }
+
         debug!(
             "Applying batch from sequencer: 0x{}",
             hex::encode(blob.sender())
@@ -174,23 +185,118 @@ where
             "Error in preprocessing batch, there should be same number of txs and messages"
         );
 
-        // TODO fetch gas price from chain state
-        let gas_elastic_price = [0, 0];
         let mut sequencer_reward = 0u64;
 
         let mut tx_receipts = Vec::with_capacity(txs.len());
 
-        let mut batch_workspace = self.apply_txs(
+        let (mut batch_workspace, slashing_reason) = self.apply_txs(
             txs,
             messages,
-            &gas_elastic_price,
+            GAS_ELASTIC_PRICE,
             &mut tx_receipts,
             batch_workspace,
             &mut sequencer_reward,
+            slot_height,
+            Some(blob.sender().as_ref().to_vec()),
         );
 
-        // TODO: calculate the amount based of gas and fees
-        let sequencer_outcome = SequencerOutcome::Rewarded(sequencer_reward);
+        // The transactions applied before the sequencer was slashed are kept, but the sequencer
+        // isn't rewarded for them.
+        let sequencer_outcome = match slashing_reason {
+            Some(reason) => SequencerOutcome::Slashed {
+                reason,
+                sequencer_da_address: blob.sender(),
+            },
+            None => SequencerOutcome::Rewarded {
+                amount: sequencer_reward,
+                sequencer_da_address: blob.sender(),
+            },
+        };
+
+        if let Err(e) = self
+            .runtime
+            .end_blob_hook(sequencer_outcome.clone(), &mut batch_workspace)
+        {
+            // TODO: will be covered in https://github.com/Sovereign-Labs/sovereign-sdk/issues/421
+            error!("Failed on `end_blob_hook`: {}", e);
+        };
+
+        (
+            Ok(BatchReceipt {
+                batch_hash: blob.hash(),
+                tx_receipts,
+                inner: sequencer_outcome,
+            }),
+            batch_workspace.checkpoint(),
+        )
+    }
+
+    // Direct blobs hold a single transaction posted by a user to force its inclusion. The
+    // signature of the transaction authorizes it instead of a sequencer registration, so the
+    // `begin_blob_hook` is skipped, and a malformed direct blob is ignored since there is nobody
+    // to slash. The `end_blob_hook` settles the gas paid by the transaction.
+    #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
+    fn apply_direct_blob(
+        &self,
+        checkpoint: StateCheckpoint<C>,
+        blob: &mut Da::BlobTransaction,
+        slot_height: u64,
+    ) -> (ApplyBatch<Da>, StateCheckpoint<C>) {
+        debug!(
+            "Applying direct blob from sender: 0x{}",
+            hex::encode(blob.sender())
+        );
+
+        let batch_workspace = checkpoint.to_revertable();
+        let data = decode_direct_blob(data_for_deserialization(blob))
+            .expect("The blob was checked to be a direct blob")
+            .to_vec();
+        let batch = Batch {
+            txs: vec![RawTx { data }],
+        };
+        let verified = self.verify_txs_stateless(batch).and_then(|txs| {
+            let messages = self.decode_txs(&txs)?;
+            Ok((txs, messages))
+        });
+        let (txs, messages) = match verified {
+            Ok((txs, messages)) => (txs, messages),
+            Err(reason) => {
+                // As for batches, make sure the prover didn't leave out some data of the blob.
+                assert_eq!(blob.verified_data().len(), blob.total_len(), "Direct blob verification failed and some data was not provided. The prover might be malicious");
+                error!(
+                    "Direct blob 0x{} from sender {} is ignored: {:?}",
+                    hex::encode(blob.hash()),
+                    blob.sender(),
+                    reason
+                );
+                return (
+                    Err(ApplyBatchError::Ignored(blob.hash())),
+                    batch_workspace.revert(),
+                );
+            }
+        };
+
+        // The gas is paid by the sender of the transaction, and the runtime decides what happens
+        // to it since no sequencer included the transaction.
+        let mut gas_paid = 0u64;
+        let mut tx_receipts = Vec::with_capacity(txs.len());
+        // There is no sequencer to slash, so a transaction failing its pre-dispatch hook is only
+        // rejected.
+        let (mut batch_workspace, _) = self.apply_txs(
+            txs,
+            messages,
+            GAS_ELASTIC_PRICE,
+            &mut tx_receipts,
+            batch_workspace,
+            &mut gas_paid,
+            slot_height,
+            None,
+        );
+
+        let sequencer_outcome = SequencerOutcome::Direct {
+            amount: gas_paid,
+            sender_da_address: blob.sender(),
+        };
 
         if let Err(e) = self
             .runtime
@@ -233,6 +339,9 @@ where
         Ok((txs, messages))
     }
 
+    // Applies the transactions of a batch, and stops early if the sequencer has to be slashed
+    // for one of them, returning the reason.
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     fn apply_txs(
         &self,
@@ -242,7 +351,9 @@ where
         tx_receipts: &mut Vec<TransactionReceipt<TxEffect>>,
         mut batch_workspace: WorkingSet<C>,
         sequencer_reward: &mut u64,
-    ) -> WorkingSet<C> {
+        slot_height: u64,
+        sequencer_da_address: Option<Vec<u8>>,
+    ) -> (WorkingSet<C>, Option<SlashingReason>) {
         // Dispatching transactions
         for (TransactionAndRawHash { tx, raw_tx_hash }, msg) in
             txs.into_iter().zip(messages.into_iter())
@@ -256,8 +367,9 @@ where
             // Pre dispatch hook
             // TODO set the sequencer pubkey
             let hook = RuntimeTxHook {
-                height: 1,
-                sequencer: tx.pub_key().clone(),
+                height: slot_height,
+                sequencer: tx.pub_key().cloned(),
+                sequencer_da_address: sequencer_da_address.clone(),
             };
             let ctx = match self
                 .runtime
@@ -278,6 +390,9 @@ where
                     };
 
                     tx_receipts.push(receipt);
+                    if let Some(reason) = e.downcast_ref::<SlashingReason>() {
+                        return (batch_workspace, Some(*reason));
+                    }
                     continue;
                 }
             };
@@ -335,7 +450,7 @@ where
                 .expect("inconsistent state: error in post_dispatch_tx_hook");
         }
 
-        batch_workspace
+        (batch_workspace, None)
     }
 
     // Attempt to deserialize batch, error results in sequencer slashing.
@@ -396,6 +511,28 @@ where
     }
 }
 
+// Reads only the magic of the blob, so the prover doesn't have to provide ignored batches in full.
+fn read_magic(blob: &mut impl BlobReaderTrait) -> &[u8] {
+    let expected_len = DIRECT_BLOB_MAGIC.len().min(blob.total_len());
+    let data = data_for_classification(blob);
+    // If the magic is missing, the prover might be passing a direct blob off as a batch.
+    assert!(
+        data.len() >= expected_len,
+        "Blob data was not provided. The prover might be malicious"
+    );
+    data
+}
+
+#[cfg(feature = "native")]
+fn data_for_classification(blob: &mut impl BlobReaderTrait) -> &[u8] {
+    blob.advance(DIRECT_BLOB_MAGIC.len())
+}
+
+#[cfg(not(feature = "native"))]
+fn data_for_classification(blob: &mut impl BlobReaderTrait) -> &[u8] {
+    blob.verified_data()
+}
+
 #[cfg(feature = "native")]
 fn data_for_deserialization(blob: &mut impl BlobReaderTrait) -> &[u8] {
     blob.full_data()
@@ -86,6 +86,7 @@ impl<C: Context, Da: DaSpec> KernelSlotHooks<C, Da> for SoftConfirmationsKernel<
         let mut ws = sov_modules_api::KernelWorkingSet::from_kernel(self, working_set);
         self.chain_state
             .begin_slot_hook(slot_header, validity_condition, pre_state_root, &mut ws);
+        self.blob_storage.begin_slot_hook(slot_header, &mut ws);
     }
 
     fn end_slot_hook(&self, working_set: &mut sov_modules_api::WorkingSet<Self::Context>) {
@@ -215,6 +215,10 @@ impl<S: MerkleProofSpec, Q: QueryManager> Storage for ProverStorage<S, Q> {
     fn is_empty(&self) -> bool {
         self.db.get_next_version() <= 1
     }
+
+    fn next_version(&self) -> Option<Version> {
+        Some(self.db.get_next_version())
+    }
 }
 
 impl<S: MerkleProofSpec, Q: QueryManager> NativeStorage for ProverStorage<S, Q> {
@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use jmt::KeyHash;
 use sov_modules_core::{
-    OrderedReadsAndWrites, Storage, StorageKey, StorageProof, StorageValue, Witness,
+    OrderedReadsAndWrites, Storage, StorageKey, StorageProof, StorageValue, Version, Witness,
 };
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use sov_zk_cycle_macros::cycle_tracker;
@@ -141,4 +141,9 @@ impl<S: MerkleProofSpec> Storage for ZkStorage<S> {
     fn is_empty(&self) -> bool {
         unimplemented!("Needs simplification in JellyfishMerkleTree: https://github.com/Sovereign-Labs/sovereign-sdk/issues/362")
     }
+
+    // The zk storage only sees the state of the slot being proven.
+    fn next_version(&self) -> Option<Version> {
+        None
+    }
 }
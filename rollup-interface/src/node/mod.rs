@@ -3,3 +3,5 @@
 //! inside of a zkVM, so they may be non-deterministic, have access to networking/disk, etc.
 pub mod rpc;
 pub mod services;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
@@ -133,6 +133,15 @@ impl Default for QueryMode {
     }
 }
 
+/// Whether a slot can still be reverted by a reorg of the DA layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SlotStatus {
+    /// The DA block of the slot can still be reverted, along with the batches of the slot.
+    Pending,
+    /// The DA block of the slot is final.
+    Finalized,
+}
+
 /// The body of a response to a JSON-RPC request for a particular slot.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct SlotResponse<B, Tx> {
@@ -141,6 +150,8 @@ pub struct SlotResponse<B, Tx> {
     /// The hex encoded slot hash.
     #[serde(with = "utils::rpc_hex")]
     pub hash: [u8; 32],
+    /// Whether the slot is finalized.
+    pub status: SlotStatus,
     /// The range of batches in this slot.
     pub batch_range: core::ops::Range<u64>,
     /// The batches in this slot, if the [`QueryMode`] of the request is not `Compact`
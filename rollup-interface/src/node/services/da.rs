@@ -58,13 +58,24 @@ pub trait DaService: Send + Sync + 'static {
     /// Optimized version of `get_last_finalized_block_header`.
     async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error>;
 
-    /// Fetch the head block of the most popular fork.
-    ///
-    /// More like utility method, to provide better user experience
+    /// Fetch the header of the head block of the best fork.
+    /// The head block may not be final: every block above the last finalized one
+    /// can still be reverted by a reorg of at most [`DaService::max_reorg_depth`] blocks.
     async fn get_head_block_header(
         &self,
     ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error>;
 
+    /// Subscribe to the headers of the head block of the best fork as it changes.
+    /// Expect only to receive heads which were produced after subscription.
+    /// Heads can be skipped when the head changes faster than it is reported, and a head
+    /// doesn't have to extend the previous one when the DA layer switches to another fork.
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error>;
+
+    /// The maximum number of blocks at the top of the best fork that a reorg can revert.
+    /// Blocks which are more than `max_reorg_depth` blocks below the head are never reverted,
+    /// and `0` means that the DA layer has instant finality.
+    fn max_reorg_depth(&self) -> u64;
+
     /// Extract the relevant transactions from a block. For example, this method might return
     /// all of the blob transactions in rollup's namespace on Celestia.
     fn extract_relevant_blobs(
@@ -110,16 +121,73 @@ pub trait DaService: Send + Sync + 'static {
     /// Returns nothing if the transaction was successfully sent.
     async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error>;
 
-    /// Sends am aggregated ZK proofs to the DA layer.
+    /// Sends am aggregated ZK proofs to the DA layer, wrapped in an [`AggregatedProofEnvelope`].
+    /// Returns the height of the block including the proof.
     async fn send_aggregated_zk_proof(
         &self,
         aggregated_proof_data: &[u8],
     ) -> Result<u64, Self::Error>;
 
     /// Fetches all aggregated ZK proofs at a specified block height.
+    /// Blobs which aren't valid [`AggregatedProofEnvelope`]s are skipped.
     async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error>;
 }
 
+/// An aggregated ZK proof as it is posted on the DA layer. The proof is prefixed with the version
+/// of the envelope, so that readers can tell the proofs they understand apart from the proofs of
+/// a newer format and from any other data posted in the proof namespace.
+#[cfg(feature = "native")]
+#[derive(Debug, Clone, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct AggregatedProofEnvelope {
+    version: u8,
+    proof: Vec<u8>,
+}
+
+#[cfg(feature = "native")]
+impl AggregatedProofEnvelope {
+    /// The version of the envelopes created by [`AggregatedProofEnvelope::new`].
+    pub const VERSION: u8 = 1;
+
+    /// Wraps an aggregated proof in an envelope of the current version.
+    pub fn new(proof: Vec<u8>) -> Self {
+        Self {
+            version: Self::VERSION,
+            proof,
+        }
+    }
+
+    /// The wrapped aggregated proof.
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    /// Serializes the envelope into the blob posted on the DA layer.
+    pub fn encode(&self) -> Vec<u8> {
+        borsh::BorshSerialize::try_to_vec(self).expect("Serialization to vec is infallible")
+    }
+
+    /// Deserializes an envelope from a blob of the DA layer.
+    pub fn decode(blob: &[u8]) -> anyhow::Result<Self> {
+        let envelope: Self = borsh::BorshDeserialize::try_from_slice(blob)?;
+        if envelope.version != Self::VERSION {
+            anyhow::bail!(
+                "Unsupported aggregated proof envelope version {}",
+                envelope.version
+            );
+        }
+        Ok(envelope)
+    }
+
+    /// Returns the proofs of the valid envelopes among the blobs of the proof namespace.
+    pub fn decode_proofs(blobs: impl IntoIterator<Item = Vec<u8>>) -> Vec<Vec<u8>> {
+        blobs
+            .into_iter()
+            .filter_map(|blob| Self::decode(&blob).ok())
+            .map(|envelope| envelope.proof)
+            .collect()
+    }
+}
+
 /// `SlotData` is the subset of a DA layer block which is stored in the rollup's database.
 /// At the very least, the rollup needs access to the hashes and headers of all DA layer blocks,
 /// but rollup may choose to store partial (or full) block data as well.
@@ -144,3 +212,34 @@ pub trait SlotData:
     /// Get the validity condition set associated with the slot
     fn validity_condition(&self) -> Self::Cond;
 }
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregated_proof_envelope_round_trip() {
+        let envelope = AggregatedProofEnvelope::new(vec![1, 2, 3]);
+        let decoded = AggregatedProofEnvelope::decode(&envelope.encode()).unwrap();
+        assert_eq!(envelope, decoded);
+        assert_eq!(&[1, 2, 3], decoded.proof());
+    }
+
+    #[test]
+    fn aggregated_proof_envelope_skips_unknown_blobs() {
+        let mut future_version = AggregatedProofEnvelope::new(vec![4]);
+        future_version.version = AggregatedProofEnvelope::VERSION + 1;
+
+        let blobs = vec![
+            AggregatedProofEnvelope::new(vec![1, 2, 3]).encode(),
+            future_version.encode(),
+            b"not a proof".to_vec(),
+            AggregatedProofEnvelope::new(vec![]).encode(),
+        ];
+
+        assert_eq!(
+            vec![vec![1, 2, 3], vec![]],
+            AggregatedProofEnvelope::decode_proofs(blobs)
+        );
+    }
+}
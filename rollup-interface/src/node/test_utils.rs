@@ -0,0 +1,273 @@
+//! Generic tests pinning down the contract of the [`DaService`], [`DaVerifier`] and
+//! [`BlobReaderTrait`] traits. DA adapters run them against their own implementations, backed by
+//! a mock transport or recorded fixtures.
+
+use core::time::Duration;
+
+use futures::StreamExt;
+
+use crate::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, DaVerifier};
+use crate::services::da::{DaService, SlotData};
+
+/// How long [`test_finality_monotonicity`] and [`test_head_tracking`] wait for each header of
+/// the subscriptions.
+const FINALIZED_HEADER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The relevant blobs of a block, with the proofs that they were extracted correctly.
+pub type Extraction<Spec> = (
+    Vec<<Spec as DaSpec>::BlobTransaction>,
+    <Spec as DaSpec>::InclusionMultiProof,
+    <Spec as DaSpec>::CompletenessProof,
+);
+
+/// Checks that `extraction` verifies against the header of `block`, to the validity condition
+/// of `block`.
+pub fn test_verification_round_trip<V, B>(verifier: &V, block: &B, extraction: Extraction<V::Spec>)
+where
+    V: DaVerifier,
+    B: SlotData<
+        BlockHeader = <V::Spec as DaSpec>::BlockHeader,
+        Cond = <V::Spec as DaSpec>::ValidityCondition,
+    >,
+{
+    let (blobs, inclusion_proof, completeness_proof) = extraction;
+    let validity_condition = verifier
+        .verify_relevant_tx_list(block.header(), &blobs, inclusion_proof, completeness_proof)
+        .expect("The extracted blobs must verify");
+
+    assert_eq!(block.validity_condition(), validity_condition);
+}
+
+/// Checks that the relevant blobs extracted by `da_service` from `block` verify, as in
+/// [`test_verification_round_trip`].
+pub async fn test_extraction_round_trip<Da: DaService>(
+    da_service: &Da,
+    verifier: &Da::Verifier,
+    block: &Da::FilteredBlock,
+) {
+    let extraction = da_service.extract_relevant_blobs_with_proof(block).await;
+    test_verification_round_trip(verifier, block, extraction);
+}
+
+/// Checks that the verifier rejects the relevant blobs of a block when one of them is omitted
+/// or when a blob is injected. `extract` returns the valid extraction of a block with
+/// `header` and at least one relevant blob.
+pub fn test_tamper_rejection<V: DaVerifier>(
+    verifier: &V,
+    header: &<V::Spec as DaSpec>::BlockHeader,
+    mut extract: impl FnMut() -> Extraction<V::Spec>,
+) {
+    let (mut blobs, inclusion_proof, completeness_proof) = extract();
+    assert!(!blobs.is_empty(), "The block must have relevant blobs");
+    blobs.pop();
+    assert!(
+        verifier
+            .verify_relevant_tx_list(header, &blobs, inclusion_proof, completeness_proof)
+            .is_err(),
+        "Omitting the last relevant blob must be rejected"
+    );
+
+    let (mut blobs, inclusion_proof, completeness_proof) = extract();
+    blobs.remove(0);
+    assert!(
+        verifier
+            .verify_relevant_tx_list(header, &blobs, inclusion_proof, completeness_proof)
+            .is_err(),
+        "Omitting the first relevant blob must be rejected"
+    );
+
+    let (mut blobs, inclusion_proof, completeness_proof) = extract();
+    let (mut duplicates, _, _) = extract();
+    blobs.push(duplicates.remove(0));
+    assert!(
+        verifier
+            .verify_relevant_tx_list(header, &blobs, inclusion_proof, completeness_proof)
+            .is_err(),
+        "Injecting a blob must be rejected"
+    );
+}
+
+/// Checks that the verifier rejects tampered extractions of `block` made by `da_service`, as in
+/// [`test_tamper_rejection`].
+pub async fn test_extraction_tamper_rejection<Da: DaService>(
+    da_service: &Da,
+    verifier: &Da::Verifier,
+    block: &Da::FilteredBlock,
+) {
+    let mut extractions = Vec::new();
+    for _ in 0..4 {
+        extractions.push(da_service.extract_relevant_blobs_with_proof(block).await);
+    }
+
+    test_tamper_rejection(verifier, block.header(), || {
+        extractions.pop().expect("Enough extractions are prepared")
+    });
+}
+
+/// Checks that the finalized headers of `da_service` never go backwards or ahead of the head
+/// while `blobs_count` blobs are sent, and that the subscription streams every header finalized
+/// after subscribing, in order. Each sent blob is expected to produce a block.
+pub async fn test_finality_monotonicity<Da: DaService>(da_service: &Da, blobs_count: usize) {
+    let mut finalized_headers = Box::pin(
+        da_service
+            .subscribe_finalized_header()
+            .await
+            .expect("Failed to subscribe to the finalized headers"),
+    );
+    // There may be no finalized block yet.
+    let first_finalized = da_service.get_last_finalized_block_header().await.ok();
+
+    let mut last_finalized = first_finalized.clone();
+    for i in 0..blobs_count {
+        da_service
+            .send_transaction(&[i as u8])
+            .await
+            .expect("Failed to send the blob");
+        let head = da_service
+            .get_head_block_header()
+            .await
+            .expect("Failed to get the head");
+        let Ok(finalized) = da_service.get_last_finalized_block_header().await else {
+            assert!(last_finalized.is_none(), "Finality must not be lost");
+            continue;
+        };
+
+        assert!(
+            finalized.height() <= head.height(),
+            "The finalized height {} is ahead of the head {}",
+            finalized.height(),
+            head.height()
+        );
+        if let Some(last_finalized) = &last_finalized {
+            assert!(
+                finalized.height() >= last_finalized.height(),
+                "The finalized height went back from {} to {}",
+                last_finalized.height(),
+                finalized.height()
+            );
+        }
+        last_finalized = Some(finalized);
+    }
+
+    let Some(last_finalized) = last_finalized else {
+        return;
+    };
+    let mut previous_height = first_finalized.map(|header| header.height());
+    while previous_height != Some(last_finalized.height()) {
+        let header = tokio::time::timeout(FINALIZED_HEADER_TIMEOUT, finalized_headers.next())
+            .await
+            .expect("The finalized headers weren't all streamed")
+            .expect("The finalized headers stream ended")
+            .expect("Failed to receive a finalized header");
+        if let Some(previous_height) = previous_height {
+            assert_eq!(
+                previous_height + 1,
+                header.height(),
+                "Finalized headers are streamed in order"
+            );
+        }
+        if header.height() == last_finalized.height() {
+            assert_eq!(last_finalized, header);
+        }
+        previous_height = Some(header.height());
+    }
+}
+
+/// Checks that the head of `da_service` is never more than [`DaService::max_reorg_depth`] blocks
+/// ahead of the last finalized block while `blobs_count` blobs are sent, and that the head
+/// subscription catches up with the last head. Each sent blob is expected to produce a block,
+/// and no fork is expected meanwhile.
+pub async fn test_head_tracking<Da: DaService>(da_service: &Da, blobs_count: usize) {
+    let mut head_headers = Box::pin(
+        da_service
+            .subscribe_head_header()
+            .await
+            .expect("Failed to subscribe to the head headers"),
+    );
+    let max_reorg_depth = da_service.max_reorg_depth();
+
+    let mut last_head = None;
+    for i in 0..blobs_count {
+        da_service
+            .send_transaction(&[i as u8])
+            .await
+            .expect("Failed to send the blob");
+        let head = da_service
+            .get_head_block_header()
+            .await
+            .expect("Failed to get the head");
+        if let Ok(finalized) = da_service.get_last_finalized_block_header().await {
+            assert!(
+                finalized.height() >= head.height().saturating_sub(max_reorg_depth),
+                "The finalized height {} is more than {} blocks below the head {}",
+                finalized.height(),
+                max_reorg_depth,
+                head.height()
+            );
+        }
+        last_head = Some(head);
+    }
+
+    let Some(last_head) = last_head else {
+        return;
+    };
+    let mut previous_height = None;
+    loop {
+        let header = tokio::time::timeout(FINALIZED_HEADER_TIMEOUT, head_headers.next())
+            .await
+            .expect("The last head wasn't streamed")
+            .expect("The head headers stream ended")
+            .expect("Failed to receive a head header");
+        if let Some(previous_height) = previous_height {
+            assert!(
+                header.height() > previous_height,
+                "The head went back from {} to {} without a fork",
+                previous_height,
+                header.height()
+            );
+        }
+        if header == last_head {
+            break;
+        }
+        previous_height = Some(header.height());
+    }
+}
+
+/// Checks that an aggregated proof sent with [`DaService::send_aggregated_zk_proof`] is returned
+/// by [`DaService::get_aggregated_proofs_at`] at the height of the block including it.
+pub async fn test_aggregated_proof_round_trip<Da: DaService>(da_service: &Da, proof: &[u8]) {
+    let height = da_service
+        .send_aggregated_zk_proof(proof)
+        .await
+        .expect("Failed to send the aggregated proof");
+    let proofs = da_service
+        .get_aggregated_proofs_at(height)
+        .await
+        .expect("Failed to fetch the aggregated proofs");
+
+    assert!(
+        proofs.iter().any(|fetched| fetched == proof),
+        "The aggregated proof sent at height {} wasn't returned, got {:?}",
+        height,
+        proofs
+    );
+}
+
+/// Checks that `blob` holding `data`, which was not read yet, exposes exactly the data read with
+/// [`BlobReaderTrait::advance`].
+pub fn test_partial_reads<B: BlobReaderTrait>(mut blob: B, data: &[u8]) {
+    assert!(blob.verified_data().is_empty(), "No data was read yet");
+    assert_eq!(data.len(), blob.total_len());
+
+    let half = data.len() / 2;
+    assert_eq!(&data[..half], blob.advance(half));
+    assert_eq!(&data[..half], blob.verified_data());
+    assert_eq!(data.len(), blob.total_len());
+
+    // Reading past the end stops at the end of the blob.
+    assert_eq!(data, blob.advance(data.len()));
+    assert_eq!(data, blob.verified_data());
+    assert_eq!(data, blob.advance(1));
+    assert_eq!(data, blob.full_data());
+    assert_eq!(data.len(), blob.total_len());
+}
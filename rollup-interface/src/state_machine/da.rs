@@ -6,9 +6,13 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::maybestd::vec::Vec;
 use crate::zk::ValidityCondition;
 use crate::BasicAddress;
 
+pub mod chunks;
+pub mod direct;
+
 /// A specification for the types used by a DA layer.
 pub trait DaSpec: 'static + Debug + PartialEq + Eq + Clone {
     /// The hash of a DA layer block
@@ -175,6 +179,11 @@ pub trait BlobReaderTrait: Serialize + DeserializeOwned + Send + Sync + 'static
     fn full_data(&mut self) -> &[u8] {
         self.advance(self.total_len())
     }
+
+    /// Creates a blob sent by `sender`, whose `data` is entirely verified. The rollup uses it
+    /// to reassemble a blob split into [`chunks`], from the verified data of the chunks, so it
+    /// must only be called with data that was already verified.
+    fn from_verified_data(sender: Self::Address, hash: [u8; 32], data: Vec<u8>) -> Self;
 }
 
 /// Trait with collection of trait bounds for a block hash.
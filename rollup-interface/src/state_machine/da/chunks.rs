@@ -0,0 +1,180 @@
+//! Splitting of blobs larger than the maximum blob size of a DA layer into chunks, which are
+//! sent in separate DA transactions and reassembled by the rollup.
+//!
+//! An encoded chunk is [`CHUNK_MAGIC`] followed by the borsh encoding of a [`BlobChunk`].
+//! Unsplit blobs are sent as is, so a blob starting with [`CHUNK_MAGIC`] is always read as a
+//! chunk. Batches can't start with it, since it would encode a count of over a billion
+//! transactions.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+
+use crate::maybestd::vec::Vec;
+
+/// The prefix of the encoded chunks, telling them apart from unsplit blobs.
+pub const CHUNK_MAGIC: [u8; 8] = *b"sovchunk";
+
+/// The maximum number of chunks a blob can be split into.
+pub const MAX_CHUNKS_COUNT: u16 = 1024;
+
+/// The number of bytes an encoded chunk adds to the data it holds: the magic, the sequence id,
+/// the index, the count, the blob hash and the length of the data.
+pub const CHUNK_OVERHEAD: usize = CHUNK_MAGIC.len() + 8 + 2 + 2 + 32 + 4;
+
+/// A chunk of a blob split across several DA transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+pub struct BlobChunk {
+    /// Identifies the split blob among the blobs split by the same sender.
+    pub sequence_id: u64,
+    /// The position of this chunk in the blob.
+    pub index: u16,
+    /// The number of chunks the blob was split into.
+    pub count: u16,
+    /// The [`blob_hash`] of the whole blob, checked once it's reassembled.
+    pub blob_hash: [u8; 32],
+    /// The part of the blob held by this chunk.
+    pub data: Vec<u8>,
+}
+
+impl BlobChunk {
+    /// Returns whether `blob` is an encoded chunk rather than an unsplit blob.
+    pub fn is_chunk(blob: &[u8]) -> bool {
+        blob.starts_with(&CHUNK_MAGIC)
+    }
+
+    /// Encodes the chunk, to be sent in a DA transaction.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = CHUNK_MAGIC.to_vec();
+        self.serialize(&mut encoded)
+            .expect("Serialization to vec is infallible");
+        encoded
+    }
+
+    /// Decodes a chunk encoded with [`BlobChunk::encode`]. Returns `None` if `blob` isn't a
+    /// well-formed chunk, including when its index or count are out of bounds.
+    pub fn decode(blob: &[u8]) -> Option<Self> {
+        let chunk = Self::try_from_slice(blob.strip_prefix(&CHUNK_MAGIC)?).ok()?;
+        let is_valid =
+            chunk.count > 0 && chunk.count <= MAX_CHUNKS_COUNT && chunk.index < chunk.count;
+        is_valid.then_some(chunk)
+    }
+}
+
+/// The hash of a whole blob committed by its chunks.
+pub fn blob_hash(blob: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(blob).into()
+}
+
+/// Returns a sequence id for a blob split now. The ids of a sender are unique as long as its
+/// clock moves forward.
+#[cfg(feature = "std")]
+pub fn new_sequence_id() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_nanos() as u64
+}
+
+/// Splits `blob` into encoded chunks of at most `max_blob_size` bytes, identified by
+/// `sequence_id`. A blob that fits in `max_blob_size` bytes is returned as is.
+pub fn split_blob(
+    blob: &[u8],
+    max_blob_size: usize,
+    sequence_id: u64,
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    if blob.len() <= max_blob_size {
+        return Ok(alloc::vec![blob.to_vec()]);
+    }
+
+    let chunk_size = max_blob_size
+        .checked_sub(CHUNK_OVERHEAD)
+        .filter(|chunk_size| *chunk_size > 0)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "The maximum blob size {} leaves no room for chunk data",
+                max_blob_size
+            )
+        })?;
+    let count = blob.len().div_ceil(chunk_size);
+    let count = u16::try_from(count)
+        .ok()
+        .filter(|count| *count <= MAX_CHUNKS_COUNT)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "A blob of {} bytes needs {} chunks, more than the maximum of {}",
+                blob.len(),
+                count,
+                MAX_CHUNKS_COUNT
+            )
+        })?;
+
+    let blob_hash = blob_hash(blob);
+    Ok(blob
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(index, data)| {
+            BlobChunk {
+                sequence_id,
+                index: index as u16,
+                count,
+                blob_hash,
+                data: data.to_vec(),
+            }
+            .encode()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_and_decode() {
+        let blob: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        assert_eq!(
+            alloc::vec![blob.clone()],
+            split_blob(&blob, 1000, 7).unwrap()
+        );
+
+        let max_blob_size = CHUNK_OVERHEAD + 300;
+        let encoded_chunks = split_blob(&blob, max_blob_size, 7).unwrap();
+        assert_eq!(4, encoded_chunks.len());
+
+        let mut reassembled = Vec::new();
+        for (index, encoded) in encoded_chunks.iter().enumerate() {
+            assert!(encoded.len() <= max_blob_size);
+            assert!(BlobChunk::is_chunk(encoded));
+            let chunk = BlobChunk::decode(encoded).unwrap();
+            assert_eq!(7, chunk.sequence_id);
+            assert_eq!(index as u16, chunk.index);
+            assert_eq!(4, chunk.count);
+            assert_eq!(blob_hash(&blob), chunk.blob_hash);
+            reassembled.extend(chunk.data);
+        }
+        assert_eq!(blob, reassembled);
+    }
+
+    #[test]
+    fn reject_invalid_chunks() {
+        assert!(split_blob(&[0; 100], CHUNK_OVERHEAD, 0).is_err());
+        assert!(split_blob(&[0; MAX_CHUNKS_COUNT as usize + 1], CHUNK_OVERHEAD + 1, 0).is_err());
+
+        let chunk = BlobChunk {
+            sequence_id: 0,
+            index: 2,
+            count: 2,
+            blob_hash: [0; 32],
+            data: alloc::vec![1, 2, 3],
+        };
+        assert_eq!(None, BlobChunk::decode(&chunk.encode()));
+        assert_eq!(
+            None,
+            BlobChunk::decode(&chunk.encode()[CHUNK_MAGIC.len()..])
+        );
+        let encoded = BlobChunk { index: 1, ..chunk }.encode();
+        assert_eq!(CHUNK_OVERHEAD + 3, encoded.len());
+        assert_eq!(None, BlobChunk::decode(&encoded[..encoded.len() - 1]));
+    }
+}
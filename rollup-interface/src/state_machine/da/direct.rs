@@ -0,0 +1,65 @@
+//! Direct blobs, which let any user force the inclusion of a transaction without going through
+//! a sequencer.
+//!
+//! A direct blob is [`DIRECT_BLOB_MAGIC`] followed by a single serialized rollup transaction.
+//! Its sender doesn't have to be a registered sequencer: the signature of the transaction
+//! authorizes it instead, and the transaction pays its own gas. Rollups should only accept
+//! direct blobs whose transaction is correctly signed by an account able to pay for its gas, and
+//! whose gas limit is at least [`MIN_DIRECT_BLOB_GAS_LIMIT`] and covers the fixed cost of the
+//! transaction.
+//! Batches can't start with [`DIRECT_BLOB_MAGIC`] for the same reason they can't start with
+//! [`CHUNK_MAGIC`](super::chunks::CHUNK_MAGIC).
+
+use crate::maybestd::vec::Vec;
+
+/// The prefix of the direct blobs, telling them apart from batches and chunks.
+pub const DIRECT_BLOB_MAGIC: [u8; 8] = *b"sovdirtx";
+
+/// The maximum size of a direct blob, magic included. Larger direct blobs are discarded, so
+/// users can't fill the deferred blobs with large transactions.
+pub const MAX_DIRECT_BLOB_SIZE: usize = 4 * 1024;
+
+/// The maximum number of direct blobs accepted in a slot. Rollups should keep the direct blobs
+/// paying the highest tips and discard the others, so users can't crowd out the blobs of the
+/// sequencers.
+pub const MAX_DIRECT_BLOBS_PER_SLOT: usize = 16;
+
+/// The minimum gas limit of the transaction of a direct blob. Direct blobs with a lower gas limit
+/// are discarded, so their senders must hold enough gas tokens to pay for them.
+pub const MIN_DIRECT_BLOB_GAS_LIMIT: u64 = 1_000;
+
+/// Returns whether `blob` is a direct blob rather than a batch or a chunk.
+pub fn is_direct_blob(blob: &[u8]) -> bool {
+    blob.starts_with(&DIRECT_BLOB_MAGIC)
+}
+
+/// Wraps a serialized transaction into a direct blob.
+pub fn encode_direct_blob(tx: &[u8]) -> Vec<u8> {
+    let mut encoded = DIRECT_BLOB_MAGIC.to_vec();
+    encoded.extend_from_slice(tx);
+    encoded
+}
+
+/// Returns the serialized transaction of a direct blob encoded with [`encode_direct_blob`], or
+/// `None` if `blob` isn't a direct blob.
+pub fn decode_direct_blob(blob: &[u8]) -> Option<&[u8]> {
+    blob.strip_prefix(&DIRECT_BLOB_MAGIC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::da::chunks::{BlobChunk, CHUNK_MAGIC};
+
+    #[test]
+    fn encode_and_decode() {
+        let tx = [1, 2, 3];
+        let blob = encode_direct_blob(&tx);
+        assert!(is_direct_blob(&blob));
+        assert!(!BlobChunk::is_chunk(&blob));
+        assert_eq!(Some(&tx[..]), decode_direct_blob(&blob));
+
+        assert!(!is_direct_blob(&tx));
+        assert_eq!(None, decode_direct_blob(&CHUNK_MAGIC));
+    }
+}
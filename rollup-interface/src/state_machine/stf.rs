@@ -11,7 +11,7 @@ use crate::da::DaSpec;
 use crate::maybestd::vec::Vec;
 use crate::zk::{ValidityCondition, Zkvm};
 
-#[cfg(any(all(test, feature = "sha2"), feature = "fuzzing"))]
+#[cfg(any(test, feature = "fuzzing"))]
 pub mod fuzzing;
 
 /// The configuration of a full node of the rollup which creates zk proofs.
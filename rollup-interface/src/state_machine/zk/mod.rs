@@ -58,6 +58,8 @@ pub trait Zkvm: Send + Sync {
     type CodeCommitment: Matches<Self::CodeCommitment>
         + Clone
         + Debug
+        + Send
+        + Sync
         + Serialize
         + DeserializeOwned;
 
@@ -0,0 +1,645 @@
+//! A seeded [`DaService`] generating adversarial chains, for property tests of the STF.
+//!
+//! [`AdversarialDaService`] mixes the batches of an honest preferred sequencer with blobs
+//! trying to break the rollup: malformed batches, duplicated blobs, blobs from unregistered
+//! senders, reorgs, deferred blobs sent out of order and transactions too big to fit in a
+//! single blob. The same configuration always produces the same chain, so a failing case
+//! can be replayed from its seed.
+//!
+//! The first block registers two more sequencers: [`DEFERRED_SEQUENCER_DA_ADDRESS`], whose
+//! well-formed batches are deferred by the rollup, and [`MALICIOUS_SEQUENCER_DA_ADDRESS`],
+//! which only sends malformed batches. The blobs of the preferred sequencer and of the
+//! deferred one are honest, see [`AdversarialDaService::honest_blobs`].
+
+use std::collections::HashMap;
+
+use anyhow::bail;
+use async_trait::async_trait;
+use borsh::ser::BorshSerialize;
+use demo_stf::runtime::Runtime;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use sov_bank::{Bank, Coins};
+use sov_mock_da::{
+    MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaSpec, MockDaVerifier,
+    MOCK_SEQUENCER_DA_ADDRESS,
+};
+use sov_mock_zkvm::MockZkVerifier;
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{Address, EncodeCall, Module, PrivateKey};
+use sov_rollup_interface::da::chunks::{blob_hash, split_blob};
+use sov_rollup_interface::da::{BlobReaderTrait, DaSpec, Time};
+use sov_rollup_interface::services::da::DaService;
+use sov_sequencer_registry::SequencerRegistry;
+
+use crate::{sender_address_with_pkey, DEFAULT_CHAIN_ID, DEFAULT_GAS_LIMIT, DEFAULT_GAS_TIP};
+
+type AdversarialRuntime = Runtime<DefaultContext, MockDaSpec, MockZkVerifier>;
+
+/// The DA address of a registered sequencer other than the preferred one, so its blobs are
+/// deferred.
+pub const DEFERRED_SEQUENCER_DA_ADDRESS: [u8; 32] = [1; 32];
+/// The DA address of a registered sequencer which only sends malformed batches.
+pub const MALICIOUS_SEQUENCER_DA_ADDRESS: [u8; 32] = [2; 32];
+/// The DA address of a sender which never registers as a sequencer.
+pub const UNREGISTERED_DA_ADDRESS: [u8; 32] = [3; 32];
+
+/// The probability that the canonical chain replaces a fork seen by the rollup, at each block.
+const REORG_PROBABILITY: f64 = 0.2;
+/// The maximum number of adversarial actions taken in a block.
+const MAX_ACTIONS_PER_BLOCK: usize = 6;
+/// The maximum number of transfers in a generated batch.
+const MAX_TRANSFERS_PER_BATCH: usize = 3;
+/// The amount of tokens the deferred sequencer's transactions can spend.
+const DEFERRED_SIGNER_FUNDS: u128 = 1_000;
+const BLOCK_TIME_SECS: i64 = 6;
+// 2023-01-01T00:00:00Z
+const GENESIS_TIME: Time = Time::from_secs(1672531200);
+
+/// The configuration of an [`AdversarialDaService`].
+#[derive(Debug, Clone)]
+pub struct AdversarialDaConfig {
+    /// The seed the chain is generated from.
+    pub seed: u64,
+    /// The number of blocks carrying adversarial blobs, after genesis.
+    pub num_blocks: u64,
+    /// The maximum number of blocks replaced by a reorg. Reorgs are disabled if it's 0.
+    pub max_reorg_depth: u64,
+    /// The maximum size of a blob. Bigger batches are split into chunks.
+    pub max_blob_size: usize,
+    /// The number of slots for which the rollup defers the blobs of non-preferred
+    /// sequencers. Chunks and deferred blobs are delayed by at most as many blocks, and as
+    /// many empty blocks follow the adversarial ones, so every blob is due by the end of the
+    /// chain.
+    pub deferred_slots_count: u64,
+    /// The key of the preferred sequencer's rollup account. It signs the honest transfers,
+    /// and registers and funds the other sequencers in the first block.
+    pub sequencer_key: DefaultPrivateKey,
+    /// The token bonded by sequencers and moved by the generated transfers.
+    pub token_address: Address,
+}
+
+/// A [`DaService`] serving a chain generated from a seed. See the [module docs](self).
+#[derive(Clone)]
+pub struct AdversarialDaService {
+    canonical_blocks: Vec<MockBlock>,
+    observed_blocks: Vec<MockBlock>,
+    honest_blobs: HashMap<[u8; 32], Vec<[u8; 32]>>,
+    max_reorg_depth: u64,
+}
+
+/// The stream of finalized or head headers of an [`AdversarialDaService`].
+pub type AdversarialHeaderStream =
+    futures::stream::Iter<std::vec::IntoIter<anyhow::Result<MockBlockHeader>>>;
+
+impl AdversarialDaService {
+    /// Generates the chain described by `config`.
+    pub fn new(config: AdversarialDaConfig) -> Self {
+        let mut generator = ChainGenerator::new(&config);
+        let genesis = MockBlock {
+            header: MockBlockHeader {
+                prev_hash: [0; 32].into(),
+                hash: generator.rng.gen::<[u8; 32]>().into(),
+                height: 0,
+                time: GENESIS_TIME,
+            },
+            validity_cond: Default::default(),
+            blobs: Vec::new(),
+        };
+        let mut service = Self {
+            canonical_blocks: vec![genesis],
+            observed_blocks: Vec::new(),
+            honest_blobs: HashMap::new(),
+            max_reorg_depth: config.max_reorg_depth,
+        };
+
+        for height in 1..=config.num_blocks + config.deferred_slots_count {
+            let parent = service.head().clone();
+            // The first block registers the sequencers, so it's never reorged.
+            let reorg = height > 1
+                && height <= config.num_blocks
+                && config.max_reorg_depth > 0
+                && generator.rng.gen_bool(REORG_PROBABILITY);
+            if reorg {
+                let depth = generator.rng.gen_range(1..=config.max_reorg_depth);
+                let mut fork = generator.fork();
+                let mut fork_parent = parent.clone();
+                for _ in 0..depth {
+                    let block = service.observe(fork.next_block(&fork_parent));
+                    fork_parent = block.header.clone();
+                }
+            }
+
+            let block = service.observe(generator.next_block(&parent));
+            service.canonical_blocks.push(block);
+        }
+        service
+    }
+
+    /// Returns the blocks of the canonical chain by height, starting with genesis.
+    pub fn canonical_blocks(&self) -> &[MockBlock] {
+        &self.canonical_blocks
+    }
+
+    /// Returns the blocks after genesis in the order a rollup following the head of the
+    /// chain sees them, including the blocks of the forks replaced by reorgs.
+    pub fn observed_blocks(&self) -> &[MockBlock] {
+        &self.observed_blocks
+    }
+
+    /// Returns the hashes of the honest blobs completed by the block with the given header,
+    /// that is its honest blobs and the honest blobs whose last chunk it contains. Honest
+    /// blobs must be executed within the deferred slots count.
+    pub fn honest_blobs(&self, header: &MockBlockHeader) -> &[[u8; 32]] {
+        self.honest_blobs
+            .get(&header.hash.0)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn head(&self) -> &MockBlockHeader {
+        &self
+            .canonical_blocks
+            .last()
+            .expect("The chain starts with genesis")
+            .header
+    }
+
+    fn observe(&mut self, (block, honest_blobs): (MockBlock, Vec<[u8; 32]>)) -> MockBlock {
+        self.honest_blobs.insert(block.header.hash.0, honest_blobs);
+        self.observed_blocks.push(block.clone());
+        block
+    }
+}
+
+#[async_trait]
+impl DaService for AdversarialDaService {
+    type Spec = MockDaSpec;
+    type Verifier = MockDaVerifier;
+    type FilteredBlock = MockBlock;
+    type HeaderStream = AdversarialHeaderStream;
+    type TransactionId = ();
+    type Error = anyhow::Error;
+
+    async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
+        match self.canonical_blocks.get(height as usize) {
+            Some(block) => Ok(block.clone()),
+            None => bail!(
+                "No block at height={height}, the chain ends at height {}",
+                self.head().height
+            ),
+        }
+    }
+
+    /// Blocks are final once they are deeper than the deepest reorg.
+    async fn get_last_finalized_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        let height = self.head().height.saturating_sub(self.max_reorg_depth);
+        Ok(self.canonical_blocks[height as usize].header.clone())
+    }
+
+    async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        let finalized = self.get_last_finalized_block_header().await?;
+        let headers: Vec<_> = self.canonical_blocks[..=finalized.height as usize]
+            .iter()
+            .map(|block| Ok(block.header.clone()))
+            .collect();
+        Ok(futures::stream::iter(headers))
+    }
+
+    async fn get_head_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        Ok(self.head().clone())
+    }
+
+    /// Streams every head in the order it was observed, including the heads of the forks
+    /// replaced by reorgs.
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        let headers: Vec<_> = self
+            .observed_blocks
+            .iter()
+            .map(|block| Ok(block.header.clone()))
+            .collect();
+        Ok(futures::stream::iter(headers))
+    }
+
+    fn max_reorg_depth(&self) -> u64 {
+        self.max_reorg_depth
+    }
+
+    fn extract_relevant_blobs(
+        &self,
+        block: &Self::FilteredBlock,
+    ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
+        block.blobs.clone()
+    }
+
+    async fn get_extraction_proof(
+        &self,
+        _block: &Self::FilteredBlock,
+        _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+    ) -> (
+        <Self::Spec as DaSpec>::InclusionMultiProof,
+        <Self::Spec as DaSpec>::CompletenessProof,
+    ) {
+        ([0u8; 32], ())
+    }
+
+    async fn send_transaction(&self, _blob: &[u8]) -> Result<(), Self::Error> {
+        bail!("The adversarial DA service only serves its generated chain")
+    }
+
+    async fn send_aggregated_zk_proof(&self, _proof: &[u8]) -> Result<u64, Self::Error> {
+        bail!("The adversarial DA service only serves its generated chain")
+    }
+
+    async fn get_aggregated_proofs_at(&self, _height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// A blob to be sent in a later block.
+#[derive(Clone)]
+struct ScheduledBlob {
+    height: u64,
+    blob: MockBlob,
+    /// The hash of the honest blob completed by this one, if any.
+    honest_hash: Option<[u8; 32]>,
+}
+
+/// Generates the blocks of a chain, keeping track of the nonces and of the blobs sent so far.
+#[derive(Clone)]
+struct ChainGenerator {
+    rng: StdRng,
+    num_blocks: u64,
+    max_blob_size: usize,
+    max_delay: u64,
+    token_address: Address,
+    sequencer_key: DefaultPrivateKey,
+    sequencer_nonce: u64,
+    deferred_key: DefaultPrivateKey,
+    deferred_nonce: u64,
+    next_sequence_id: u64,
+    scheduled: Vec<ScheduledBlob>,
+    sent: Vec<MockBlob>,
+}
+
+impl ChainGenerator {
+    fn new(config: &AdversarialDaConfig) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(config.seed),
+            num_blocks: config.num_blocks,
+            max_blob_size: config.max_blob_size,
+            max_delay: config.deferred_slots_count,
+            token_address: config.token_address,
+            sequencer_key: config.sequencer_key.clone(),
+            sequencer_nonce: 0,
+            deferred_key: sender_address_with_pkey().1,
+            deferred_nonce: 0,
+            next_sequence_id: 0,
+            scheduled: Vec::new(),
+            sent: Vec::new(),
+        }
+    }
+
+    /// Returns a generator for a fork of the chain generated so far.
+    fn fork(&mut self) -> Self {
+        let mut fork = self.clone();
+        fork.rng = StdRng::seed_from_u64(self.rng.gen());
+        fork
+    }
+
+    /// Generates the child of `parent`, along with the hashes of the honest blobs it completes.
+    fn next_block(&mut self, parent: &MockBlockHeader) -> (MockBlock, Vec<[u8; 32]>) {
+        let height = parent.height + 1;
+        if height == 1 {
+            let blob = self.setup_batch();
+            self.schedule(height, blob, true);
+        } else if height <= self.num_blocks {
+            let mut sequencer_txs = Vec::new();
+            for _ in 0..self.rng.gen_range(1..=MAX_ACTIONS_PER_BLOCK) {
+                match self.rng.gen_range(0..6) {
+                    // The preferred sequencer's transfers are sent in a single batch, so they
+                    // are executed in the order of their nonces.
+                    0 => {
+                        let key = self.sequencer_key.clone();
+                        for _ in 0..self.rng.gen_range(1..=MAX_TRANSFERS_PER_BATCH) {
+                            sequencer_txs.push(self.transfer(&key, self.sequencer_nonce));
+                            self.sequencer_nonce += 1;
+                        }
+                    }
+                    1 => {
+                        let blob = self.deferred_batch();
+                        let delay = self.rng.gen_range(0..=self.max_delay);
+                        self.schedule(height + delay, blob, true);
+                    }
+                    2 => {
+                        let blob = self.malformed_batch();
+                        self.schedule(height, blob, false);
+                    }
+                    3 => {
+                        if let Some(blob) = self.sent.choose(&mut self.rng).cloned() {
+                            self.schedule(height, blob, false);
+                        }
+                    }
+                    4 => {
+                        let blob = self.unregistered_batch();
+                        self.schedule(height, blob, false);
+                    }
+                    _ => self.schedule_oversized_batch(height),
+                }
+            }
+            if !sequencer_txs.is_empty() {
+                let blob = new_blob(MOCK_SEQUENCER_DA_ADDRESS, encode_batch(sequencer_txs));
+                self.schedule(height, blob, true);
+            }
+        }
+
+        let (due, later) = std::mem::take(&mut self.scheduled)
+            .into_iter()
+            .partition::<Vec<_>, _>(|scheduled| scheduled.height <= height);
+        self.scheduled = later;
+
+        let mut blobs = Vec::with_capacity(due.len());
+        let mut honest_blobs = Vec::new();
+        for scheduled in due {
+            honest_blobs.extend(scheduled.honest_hash);
+            blobs.push(scheduled.blob);
+        }
+        blobs.shuffle(&mut self.rng);
+        self.sent.extend(blobs.iter().cloned());
+
+        let block = MockBlock {
+            header: MockBlockHeader {
+                prev_hash: parent.hash,
+                hash: self.rng.gen::<[u8; 32]>().into(),
+                height,
+                time: Time::from_secs(parent.time.secs() + BLOCK_TIME_SECS),
+            },
+            validity_cond: Default::default(),
+            blobs,
+        };
+        (block, honest_blobs)
+    }
+
+    /// Schedules `blob` to be sent at `height`, or in the last adversarial block if it's
+    /// later, so it's due by the end of the chain.
+    fn schedule(&mut self, height: u64, blob: MockBlob, is_honest: bool) {
+        let honest_hash = is_honest.then(|| blob.hash());
+        self.scheduled.push(ScheduledBlob {
+            height: height.min(self.num_blocks),
+            blob,
+            honest_hash,
+        });
+    }
+
+    /// Registers the other sequencers and funds the deferred sequencer's transactions.
+    fn setup_batch(&mut self) -> MockBlob {
+        let key = self.sequencer_key.clone();
+        let mut txs = Vec::new();
+        for da_address in [
+            DEFERRED_SEQUENCER_DA_ADDRESS,
+            MALICIOUS_SEQUENCER_DA_ADDRESS,
+        ] {
+            let message = sov_sequencer_registry::CallMessage::Register {
+                da_address: da_address.to_vec(),
+            };
+            let message = encode_call::<SequencerRegistry<DefaultContext, MockDaSpec>>(message);
+            txs.push(signed_tx(&key, message, self.sequencer_nonce));
+            self.sequencer_nonce += 1;
+        }
+
+        let message = sov_bank::CallMessage::Transfer {
+            to: self.deferred_key.default_address(),
+            coins: Coins {
+                amount: DEFERRED_SIGNER_FUNDS,
+                token_address: self.token_address,
+            },
+        };
+        txs.push(signed_tx(
+            &key,
+            encode_call::<Bank<DefaultContext>>(message),
+            self.sequencer_nonce,
+        ));
+        self.sequencer_nonce += 1;
+
+        new_blob(MOCK_SEQUENCER_DA_ADDRESS, encode_batch(txs))
+    }
+
+    /// A well-formed batch of the deferred sequencer. Since it can be scheduled after the
+    /// batches generated later, its transactions may have to be reverted for their nonces.
+    fn deferred_batch(&mut self) -> MockBlob {
+        let key = self.deferred_key.clone();
+        let mut txs = Vec::new();
+        for _ in 0..self.rng.gen_range(1..=MAX_TRANSFERS_PER_BATCH) {
+            txs.push(self.transfer(&key, self.deferred_nonce));
+            self.deferred_nonce += 1;
+        }
+        new_blob(DEFERRED_SEQUENCER_DA_ADDRESS, encode_batch(txs))
+    }
+
+    /// A batch of the malicious sequencer, for which it gets slashed.
+    fn malformed_batch(&mut self) -> MockBlob {
+        let key = self.random_key();
+        let data = match self.rng.gen_range(0..3) {
+            // A truncated batch, which can't be deserialized.
+            0 => {
+                let mut batch = encode_batch(vec![self.transfer(&key, 0)]);
+                batch.pop();
+                batch
+            }
+            // A transaction signed by another key than its sender's.
+            1 => {
+                let message = self.transfer_message();
+                let signed = Transaction::<DefaultContext>::new_signed_tx(
+                    &key,
+                    message.clone(),
+                    DEFAULT_CHAIN_ID,
+                    DEFAULT_GAS_TIP,
+                    DEFAULT_GAS_LIMIT,
+                    0,
+                );
+                let forged = Transaction::<DefaultContext>::new(
+                    self.random_key().pub_key(),
+                    message,
+                    signed
+                        .signature()
+                        .expect("Transaction signed by a rollup key")
+                        .clone(),
+                    DEFAULT_CHAIN_ID,
+                    DEFAULT_GAS_TIP,
+                    DEFAULT_GAS_LIMIT,
+                    0,
+                );
+                encode_batch(vec![forged.try_to_vec().unwrap()])
+            }
+            // A transaction which doesn't decode into a call message.
+            _ => {
+                let mut message = vec![0u8; self.rng.gen_range(1..64)];
+                self.rng.fill(&mut message[..]);
+                // No runtime module has this prefix.
+                message[0] = u8::MAX;
+                encode_batch(vec![signed_tx(&key, message, 0)])
+            }
+        };
+        new_blob(MALICIOUS_SEQUENCER_DA_ADDRESS, data)
+    }
+
+    /// A well-formed batch from a sender which isn't a sequencer.
+    fn unregistered_batch(&mut self) -> MockBlob {
+        let key = self.random_key();
+        let txs = vec![self.transfer(&key, 0)];
+        new_blob(UNREGISTERED_DA_ADDRESS, encode_batch(txs))
+    }
+
+    /// Schedules a batch of the preferred sequencer holding a transaction bigger than the
+    /// maximum blob size. Its chunks are spread over the next blocks in any order, but
+    /// within the deferred slots count so they don't expire.
+    fn schedule_oversized_batch(&mut self, height: u64) {
+        // The transaction has its own signer, so the preferred sequencer's nonces don't
+        // depend on the order in which the chunks arrive.
+        let key = self.random_key();
+        let name_len = self.max_blob_size * self.rng.gen_range(2..=4);
+        let token_name: String = (&mut self.rng)
+            .sample_iter(rand::distributions::Alphanumeric)
+            .take(name_len)
+            .map(char::from)
+            .collect();
+        let message = sov_bank::CallMessage::CreateToken {
+            salt: self.rng.gen(),
+            token_name,
+            symbol: "ADV".to_owned(),
+            decimals: 0,
+            initial_balance: 1,
+            minter_address: key.default_address(),
+            max_supply: None,
+            authorized_minters: Vec::new(),
+        };
+        let batch = encode_batch(vec![signed_tx(
+            &key,
+            encode_call::<Bank<DefaultContext>>(message),
+            0,
+        )]);
+
+        self.next_sequence_id += 1;
+        let chunks = split_blob(&batch, self.max_blob_size, self.next_sequence_id)
+            .expect("The maximum blob size must leave room for chunk data");
+        let heights: Vec<u64> = chunks
+            .iter()
+            .map(|_| (height + self.rng.gen_range(0..=self.max_delay)).min(self.num_blocks))
+            .collect();
+        let last_index = (0..heights.len())
+            .max_by_key(|index| heights[*index])
+            .expect("A blob has at least one chunk");
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            self.scheduled.push(ScheduledBlob {
+                height: heights[index],
+                blob: new_blob(MOCK_SEQUENCER_DA_ADDRESS, chunk),
+                honest_hash: (index == last_index).then(|| blob_hash(&batch)),
+            });
+        }
+    }
+
+    fn transfer(&mut self, key: &DefaultPrivateKey, nonce: u64) -> Vec<u8> {
+        let message = self.transfer_message();
+        signed_tx(key, message, nonce)
+    }
+
+    fn transfer_message(&mut self) -> Vec<u8> {
+        let message = sov_bank::CallMessage::Transfer {
+            to: Address::from(self.rng.gen::<[u8; 32]>()),
+            coins: Coins {
+                amount: self.rng.gen_range(1..=10),
+                token_address: self.token_address,
+            },
+        };
+        encode_call::<Bank<DefaultContext>>(message)
+    }
+
+    fn random_key(&mut self) -> DefaultPrivateKey {
+        DefaultPrivateKey::try_from(&self.rng.gen::<[u8; 32]>()[..])
+            .expect("A private key is 32 bytes long")
+    }
+}
+
+fn encode_call<M: Module>(message: M::CallMessage) -> Vec<u8>
+where
+    AdversarialRuntime: EncodeCall<M>,
+{
+    <AdversarialRuntime as EncodeCall<M>>::encode_call(message)
+}
+
+fn signed_tx(key: &DefaultPrivateKey, message: Vec<u8>, nonce: u64) -> Vec<u8> {
+    Transaction::<DefaultContext>::new_signed_tx(
+        key,
+        message,
+        DEFAULT_CHAIN_ID,
+        DEFAULT_GAS_TIP,
+        DEFAULT_GAS_LIMIT,
+        nonce,
+    )
+    .try_to_vec()
+    .unwrap()
+}
+
+fn encode_batch(txs: Vec<Vec<u8>>) -> Vec<u8> {
+    txs.try_to_vec().unwrap()
+}
+
+fn new_blob(sender: [u8; 32], data: Vec<u8>) -> MockBlob {
+    let hash = blob_hash(&data);
+    MockBlob::new(data, MockAddress::from(sender), hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(seed: u64, sequencer_key: DefaultPrivateKey) -> AdversarialDaConfig {
+        AdversarialDaConfig {
+            seed,
+            num_blocks: 20,
+            max_reorg_depth: 3,
+            max_blob_size: 512,
+            deferred_slots_count: 2,
+            sequencer_key,
+            token_address: Address::from([7; 32]),
+        }
+    }
+
+    #[test]
+    fn same_seed_generates_same_chain() {
+        let key = DefaultPrivateKey::generate();
+        let service = AdversarialDaService::new(config(5, key.clone()));
+        let replayed = AdversarialDaService::new(config(5, key.clone()));
+        let other = AdversarialDaService::new(config(6, key));
+
+        assert_eq!(service.observed_blocks(), replayed.observed_blocks());
+        assert_ne!(service.observed_blocks(), other.observed_blocks());
+    }
+
+    #[test]
+    fn observed_blocks_extend_seen_blocks() {
+        let key = DefaultPrivateKey::generate();
+        for seed in 0..10 {
+            let service = AdversarialDaService::new(config(seed, key.clone()));
+            let canonical = service.canonical_blocks();
+            assert_eq!(canonical.len(), 23);
+
+            let mut seen = vec![canonical[0].header.hash];
+            for block in service.observed_blocks() {
+                assert!(seen.contains(&block.header.prev_hash));
+                seen.push(block.header.hash);
+            }
+            for (parent, child) in canonical.iter().zip(&canonical[1..]) {
+                assert_eq!(child.header.prev_hash, parent.header.hash);
+                assert_eq!(child.header.height, parent.header.height + 1);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod adversarial;
+
 use std::env;
 
 use async_trait::async_trait;
@@ -8,6 +10,7 @@ use sov_mock_da::{
     MockAddress, MockBlob, MockBlock, MockBlockHeader, MockHash, MockValidityCond,
     MOCK_SEQUENCER_DA_ADDRESS,
 };
+use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_api::transaction::Transaction;
@@ -117,6 +120,15 @@ impl DaService for RngDaService {
         unimplemented!()
     }
 
+    async fn subscribe_head_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        unimplemented!()
+    }
+
+    // Every generated block is final.
+    fn max_reorg_depth(&self) -> u64 {
+        0
+    }
+
     fn extract_relevant_blobs(
         &self,
         block: &Self::FilteredBlock,
@@ -203,10 +215,9 @@ pub fn generate_transfers(n: usize, start_nonce: u64) -> Vec<u8> {
                     token_address,
                 },
             };
-        let enc_msg =
-            <Runtime<DefaultContext, RngDaSpec> as EncodeCall<Bank<DefaultContext>>>::encode_call(
-                msg,
-            );
+        let enc_msg = <Runtime<DefaultContext, RngDaSpec, MockZkVerifier> as EncodeCall<
+            Bank<DefaultContext>,
+        >>::encode_call(msg);
         let tx = Transaction::<DefaultContext>::new_signed_tx(
             &pk,
             enc_msg,
@@ -229,12 +240,16 @@ pub fn generate_create_token_payload(start_nonce: u64) -> Vec<u8> {
         sov_bank::CallMessage::<DefaultContext>::CreateToken {
             salt: 11,
             token_name: "sov-test-token".to_string(),
+            symbol: "TKN".to_owned(),
+            decimals: 18,
+            max_supply: None,
             initial_balance: 100000000,
             minter_address,
             authorized_minters: vec![minter_address],
         };
-    let enc_msg =
-        <Runtime<DefaultContext, RngDaSpec> as EncodeCall<Bank<DefaultContext>>>::encode_call(msg);
+    let enc_msg = <Runtime<DefaultContext, RngDaSpec, MockZkVerifier> as EncodeCall<
+        Bank<DefaultContext>,
+    >>::encode_call(msg);
     let tx = Transaction::<DefaultContext>::new_signed_tx(
         &pk,
         enc_msg,
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use demo_stf::genesis_config::{get_genesis_config, GenesisPaths};
+use demo_stf::runtime::{GenesisConfig, Runtime};
+use proptest::prelude::*;
+use sov_blob_storage::DEFERRED_SLOTS_COUNT;
+use sov_mock_da::{MockAddress, MockBlock, MockDaSpec};
+use sov_mock_zkvm::{MockZkVerifier, MockZkvm};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::DaSpec;
+use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
+use sov_modules_stf_blueprint::{GenesisParams, SequencerOutcome, StfBlueprint, TxEffect};
+use sov_prover_storage_manager::ProverStorageManager;
+use sov_rng_da_service::adversarial::{AdversarialDaConfig, AdversarialDaService};
+use sov_rollup_interface::services::da::SlotData;
+use sov_rollup_interface::stf::{BatchReceipt, StateTransitionFunction};
+use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_state::DefaultStorageSpec;
+use sov_stf_runner::read_json_file;
+
+type C = DefaultContext;
+type Da = MockDaSpec;
+type TestStf = StfBlueprint<
+    C,
+    Da,
+    MockZkvm<<Da as DaSpec>::ValidityCondition>,
+    Runtime<C, Da, MockZkVerifier>,
+    BasicKernel<C, Da>,
+>;
+type BatchReceipts = Vec<BatchReceipt<SequencerOutcome<MockAddress>, TxEffect>>;
+
+const GENESIS_DIR: &str = "../../examples/test-data/genesis/integration-tests";
+const SEQUENCER_KEY_PATH: &str = "../../examples/test-data/keys/token_deployer_private_key.json";
+
+#[derive(serde::Deserialize)]
+struct KeyFile {
+    private_key: DefaultPrivateKey,
+}
+
+fn genesis_params(
+) -> GenesisParams<GenesisConfig<C, Da, MockZkVerifier>, BasicKernelGenesisConfig<C, Da>> {
+    let genesis_dir = Path::new(GENESIS_DIR);
+    let runtime =
+        get_genesis_config::<C, Da, MockZkVerifier>(&GenesisPaths::from_dir(genesis_dir)).unwrap();
+    let chain_state = read_json_file(genesis_dir.join("chain_state.json")).unwrap();
+    GenesisParams {
+        runtime,
+        kernel: BasicKernelGenesisConfig { chain_state },
+    }
+}
+
+fn adversarial_config(seed: u64) -> AdversarialDaConfig {
+    let key_file: KeyFile =
+        serde_json::from_str(&std::fs::read_to_string(SEQUENCER_KEY_PATH).unwrap()).unwrap();
+    AdversarialDaConfig {
+        seed,
+        num_blocks: 12,
+        max_reorg_depth: 3,
+        max_blob_size: 1024,
+        deferred_slots_count: DEFERRED_SLOTS_COUNT,
+        sequencer_key: key_file.private_key,
+        token_address: genesis_params()
+            .runtime
+            .sequencer_registry
+            .coins_to_lock
+            .token_address,
+    }
+}
+
+/// Applies `blocks` on top of `genesis`, each of them on the state left by its parent, and
+/// returns the state root and batch receipts of each block by hash.
+fn apply_blocks(
+    genesis: &MockBlock,
+    blocks: &[MockBlock],
+) -> HashMap<[u8; 32], (Vec<u8>, BatchReceipts)> {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager =
+        ProverStorageManager::<Da, DefaultStorageSpec>::new(sov_state::config::Config {
+            path: tempdir.path().to_path_buf(),
+        })
+        .unwrap();
+    let stf = TestStf::new();
+
+    let storage = storage_manager.create_storage_on(genesis.header()).unwrap();
+    let (genesis_root, storage) = stf.init_chain(storage, genesis_params());
+    storage_manager
+        .save_change_set(genesis.header(), storage)
+        .unwrap();
+
+    let mut state_roots = HashMap::from([(genesis.header.hash.0, genesis_root)]);
+    let mut results = HashMap::new();
+    for block in blocks {
+        let pre_state_root = state_roots[&block.header.prev_hash.0].clone();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let mut blobs = block.blobs.clone();
+        let result = stf.apply_slot(
+            &pre_state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+
+        results.insert(
+            block.header.hash.0,
+            (result.state_root.as_ref().to_vec(), result.batch_receipts),
+        );
+        state_roots.insert(block.header.hash.0, result.state_root);
+    }
+    results
+}
+
+proptest! {
+    // Each case executes the generated chain twice, so run fewer than the default 256 cases.
+    #![proptest_config(ProptestConfig::with_cases(16))]
+    #[test]
+    fn adversarial_chains_keep_state_deterministic_and_honest_blobs(seed in any::<u64>()) {
+        let service = AdversarialDaService::new(adversarial_config(seed));
+        let replayed = AdversarialDaService::new(adversarial_config(seed));
+        prop_assert_eq!(service.observed_blocks(), replayed.observed_blocks());
+
+        let (genesis, canonical_blocks) = service.canonical_blocks().split_first().unwrap();
+        let observed = apply_blocks(genesis, service.observed_blocks());
+        let canonical = apply_blocks(genesis, canonical_blocks);
+
+        // The blocks of forks replaced by reorgs must leave no trace in the canonical chain.
+        for block in canonical_blocks {
+            let hash = block.header.hash.0;
+            prop_assert_eq!(&observed[&hash].0, &canonical[&hash].0);
+        }
+
+        // Every honest blob must be executed by the time it's due.
+        let mut pending: HashMap<[u8; 32], u64> = HashMap::new();
+        for block in canonical_blocks {
+            let height = block.header.height;
+            for hash in service.honest_blobs(&block.header) {
+                pending.entry(*hash).or_insert(height + DEFERRED_SLOTS_COUNT);
+            }
+            for receipt in &canonical[&block.header.hash.0].1 {
+                if matches!(receipt.inner, SequencerOutcome::Rewarded { .. }) {
+                    pending.remove(&receipt.batch_hash);
+                }
+            }
+            let dropped: Vec<_> = pending.iter().filter(|(_, due)| **due <= height).collect();
+            prop_assert!(dropped.is_empty(), "Honest blobs were dropped at height {}: {:?}", height, dropped);
+        }
+        prop_assert!(pending.is_empty());
+    }
+}